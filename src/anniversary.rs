@@ -0,0 +1,218 @@
+//! Birthdays, yahrzeits and other anniversaries of Hebrew dates.
+use crate::{
+    astronomical_calculator::AstronomicalCalculatorTrait,
+    constants::JewishMonth,
+    geolocation::GeoLocation,
+    halachic_day::{DayBoundary, HalachicDay},
+    jewish_calendar::{JewishCalendar, JewishCalendarTrait},
+    jewish_year,
+};
+use chrono::{DateTime, TimeZone};
+
+/// How an anniversary of a date in Adar of a non-leap year is observed in a leap year.
+/// Communities differ on this, so there is no single correct default.
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default, Hash)]
+pub enum AdarPolicy {
+    /// Observe in Adar I.
+    AdarI,
+    /// Observe in Adar II. This is the common practice for a bar or bas mitzvah.
+    #[default]
+    AdarII,
+    /// Observe in both Adar I and Adar II.
+    Both,
+}
+
+/// The date(s) on which an anniversary is observed in a given year. `second` is only set
+/// when [`AdarPolicy::Both`] applies.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AnniversaryDates<N: AstronomicalCalculatorTrait> {
+    pub first: JewishCalendar<N>,
+    pub second: Option<JewishCalendar<N>>,
+}
+
+impl<N: AstronomicalCalculatorTrait> AnniversaryDates<N> {
+    /// Returns an iterator over the observed dates in order.
+    pub fn iter(&self) -> impl Iterator<Item = &JewishCalendar<N>> {
+        core::iter::once(&self.first).chain(self.second.as_ref())
+    }
+}
+
+impl<N: AstronomicalCalculatorTrait> JewishCalendar<N> {
+    /// Creates a calendar for the Hebrew date on which an event (a birth, a death, a wedding)
+    /// at the civil `date_time` took place. Events at or after sunset at `geo_location` belong
+    /// to the next Hebrew date. If the sun does not set on that day, the civil date is used.
+    pub fn from_event_date_time<Tz: TimeZone>(
+        date_time: &DateTime<Tz>,
+        geo_location: &GeoLocation,
+        in_israel: bool,
+        is_mukaf_choma: bool,
+        use_modern_holidays: bool,
+        calculator: N,
+    ) -> Option<Self> {
+        HalachicDay::new(
+            date_time.clone(),
+            geo_location,
+            DayBoundary::Sunset,
+            in_israel,
+            is_mukaf_choma,
+            use_modern_holidays,
+            calculator,
+        )
+        .map(|day| day.jewish_calendar)
+    }
+
+    /// Returns the month(s) of `year` that correspond to the month of this date.
+    fn get_anniversary_months(&self, year: i32, adar_policy: AdarPolicy) -> (JewishMonth, Option<JewishMonth>) {
+        let is_target_leap_year = jewish_year::is_jewish_leap_year(year);
+        match self.get_jewish_month() {
+            JewishMonth::AdarII if !is_target_leap_year => (JewishMonth::Adar, None),
+            JewishMonth::Adar if !self.is_jewish_leap_year() && is_target_leap_year => match adar_policy {
+                AdarPolicy::AdarI => (JewishMonth::Adar, None),
+                AdarPolicy::AdarII => (JewishMonth::AdarII, None),
+                AdarPolicy::Both => (JewishMonth::Adar, Some(JewishMonth::AdarII)),
+            },
+            month => (month, None),
+        }
+    }
+
+    /// Returns the given day of the month in `year`, moving to the first of the next month
+    /// if the month is too short.
+    fn copy_with_anniversary_ymd(&self, year: i32, month: JewishMonth, day: u8) -> Option<Self> {
+        if day > jewish_year::get_days_in_jewish_month(month, year) {
+            let is_leap_year = jewish_year::is_jewish_leap_year(year);
+            self.copy_with_hebrew_ymd(year, month.next_month(is_leap_year), 1)
+        } else {
+            self.copy_with_hebrew_ymd(year, month, day)
+        }
+    }
+
+    /// Returns the date(s) in the Jewish `year` on which a birthday or other anniversary of
+    /// this date is observed. A 30th day that does not exist in `year` is observed on the
+    /// first day of the following month.
+    pub fn get_birthday_in_year(&self, year: i32, adar_policy: AdarPolicy) -> Option<AnniversaryDates<N>> {
+        let day = self.get_jewish_day_of_month();
+        let (first, second) = self.get_anniversary_months(year, adar_policy);
+        Some(AnniversaryDates {
+            first: self.copy_with_anniversary_ymd(year, first, day)?,
+            second: match second {
+                Some(month) => Some(self.copy_with_anniversary_ymd(year, month, day)?),
+                None => None,
+            },
+        })
+    }
+
+    /// Returns the date(s) in the Jewish `year` on which the yahrzeit of a death on this date
+    /// is observed.
+    ///
+    /// A death on 30 Cheshvan or 30 Kislev is observed on the 29th if the first anniversary
+    /// had no 30th day, a death on 30 Adar I is observed on 30 Shevat in a non-leap year, and
+    /// otherwise a missing 30th day moves to the first of the following month.
+    pub fn get_yahrzeit_in_year(&self, year: i32, adar_policy: AdarPolicy) -> Option<AnniversaryDates<N>> {
+        let month = self.get_jewish_month();
+        let day = self.get_jewish_day_of_month();
+        let first_anniversary = self.get_jewish_year() + 1;
+        let is_target_leap_year = jewish_year::is_jewish_leap_year(year);
+        if day == 30 {
+            let observed = match month {
+                JewishMonth::Cheshvan if jewish_year::get_days_in_jewish_month(month, first_anniversary) < 30 => {
+                    Some((JewishMonth::Cheshvan, 29))
+                }
+                JewishMonth::Kislev if jewish_year::get_days_in_jewish_month(month, first_anniversary) < 30 => {
+                    Some((JewishMonth::Kislev, 29))
+                }
+                JewishMonth::Adar if self.is_jewish_leap_year() && !is_target_leap_year => {
+                    Some((JewishMonth::Shevat, 30))
+                }
+                _ => None,
+            };
+            if let Some((month, day)) = observed {
+                return Some(AnniversaryDates {
+                    first: self.copy_with_hebrew_ymd(year, month, day)?,
+                    second: None,
+                });
+            }
+        }
+        self.get_birthday_in_year(year, adar_policy)
+    }
+}
+
+/// A kind of event on a date in Adar, which determines which Adar it is observed in during a
+/// leap year.
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub enum AdarEvent {
+    /// Purim, Shushan Purim and Taanis Esther, which are in Adar II. Their dates in Adar I are
+    /// Purim Katan and Shushan Purim Katan.
+    Purim,
+    /// 7 Adar, the yahrzeit of Moshe Rabbeinu, observed in Adar I according to the Magen
+    /// Avraham. Some observe it in Adar II.
+    SevenAdar,
+    /// A yahrzeit of a death in Adar of a non-leap year, observed in Adar I according to the
+    /// Rema. Many observe it in both.
+    Yahrzeit,
+    /// A birthday, such as a bar or bas mitzvah, of a birth in Adar of a non-leap year, which is
+    /// observed in Adar II.
+    Birthday,
+}
+
+impl AdarEvent {
+    /// Returns the Adar this event is customarily observed in during a leap year.
+    pub fn customary_policy(&self) -> AdarPolicy {
+        match self {
+            AdarEvent::Purim | AdarEvent::Birthday => AdarPolicy::AdarII,
+            AdarEvent::SevenAdar | AdarEvent::Yahrzeit => AdarPolicy::AdarI,
+        }
+    }
+}
+
+/// The dates in a Jewish year that a date in Adar maps to.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AdarCandidates<N: AstronomicalCalculatorTrait> {
+    /// The date in Adar, which is Adar I in a leap year.
+    pub adar_i: JewishCalendar<N>,
+    /// The date in Adar II, if the year is a leap year.
+    pub adar_ii: Option<JewishCalendar<N>>,
+    /// Which of the candidates the event is customarily observed on.
+    pub policy: AdarPolicy,
+}
+
+impl<N: AstronomicalCalculatorTrait> AdarCandidates<N> {
+    /// Returns the candidates on which the event is observed according to `policy`. In a
+    /// non-leap year this is always the date in Adar.
+    pub fn observed_with(&self, policy: AdarPolicy) -> impl Iterator<Item = &JewishCalendar<N>> {
+        let (adar_i, adar_ii) = match (policy, &self.adar_ii) {
+            (_, None) | (AdarPolicy::AdarI, _) => (Some(&self.adar_i), None),
+            (AdarPolicy::AdarII, adar_ii) => (None, adar_ii.as_ref()),
+            (AdarPolicy::Both, adar_ii) => (Some(&self.adar_i), adar_ii.as_ref()),
+        };
+        adar_i.into_iter().chain(adar_ii)
+    }
+
+    /// Returns the candidates on which the event is customarily observed.
+    pub fn observed(&self) -> impl Iterator<Item = &JewishCalendar<N>> {
+        self.observed_with(self.policy)
+    }
+}
+
+impl<N: AstronomicalCalculatorTrait> JewishCalendar<N> {
+    /// Returns the dates in the Jewish `year` that this date in Adar, Adar I or Adar II maps to,
+    /// along with the Adar that `event` is customarily observed in. A 30th day that does not
+    /// exist in `year` moves to the first of the following month. Returns `None` if this date is
+    /// not in Adar.
+    pub fn get_adar_candidates(&self, year: i32, event: AdarEvent) -> Option<AdarCandidates<N>> {
+        if !matches!(self.get_jewish_month(), JewishMonth::Adar | JewishMonth::AdarII) {
+            return None;
+        }
+        let day = self.get_jewish_day_of_month();
+        let adar_ii = match jewish_year::is_jewish_leap_year(year) {
+            true => Some(self.copy_with_anniversary_ymd(year, JewishMonth::AdarII, day)?),
+            false => None,
+        };
+        Some(AdarCandidates {
+            adar_i: self.copy_with_anniversary_ymd(year, JewishMonth::Adar, day)?,
+            adar_ii,
+            policy: event.customary_policy(),
+        })
+    }
+}
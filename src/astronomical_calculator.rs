@@ -1,5 +1,8 @@
-use crate::{constants::*, geolocation::GeoLocationTrait};
-use chrono::{DateTime, Datelike, TimeZone, Timelike};
+use crate::{
+    constants::*,
+    geolocation::{GeoLocation, GeoLocationTrait},
+};
+use chrono::{DateTime, Datelike, FixedOffset, TimeZone, Timelike};
 use core::f64::consts::PI;
 use core::fmt::Debug;
 #[allow(unused_imports)]
@@ -13,11 +16,20 @@ pub trait AstronomicalCalculatorTraitDefmt: defmt::Format {}
 #[cfg(not(feature = "defmt"))]
 pub trait AstronomicalCalculatorTraitDefmt {}
 
+/// The raw solar calculations used by the calendars. All times are returned as fractional hours
+/// after midnight UTC of the date of `date_time` (e.g. `13.25` is 13:15 UTC), without any timezone
+/// or date-line handling. Values may fall outside of the 0-24 range.
 pub trait AstronomicalCalculatorTrait: Clone + AstronomicalCalculatorTraitDefmt + Debug {
+    /// Returns solar noon (sun transit) in fractional UTC hours.
     fn get_utc_noon<Tz: TimeZone, G: GeoLocationTrait>(&self, date_time: &DateTime<Tz>, geo_location: &G) -> f64;
 
+    /// Returns solar midnight in fractional UTC hours.
     fn get_utc_midnight<Tz: TimeZone, G: GeoLocationTrait>(&self, date_time: &DateTime<Tz>, geo_location: &G) -> f64;
 
+    /// Returns the time the sun reaches `zenith` in the morning in fractional UTC hours.
+    /// The geometric zenith (90°) is adjusted for refraction, the solar radius and, if
+    /// `adjust_for_elevation` is set, the elevation of `geo_location`.
+    /// Returns `None` if the sun does not reach the zenith on this date.
     fn get_utc_sunrise<Tz: TimeZone, G: GeoLocationTrait>(
         &self,
         date_time: &DateTime<Tz>,
@@ -26,6 +38,8 @@ pub trait AstronomicalCalculatorTrait: Clone + AstronomicalCalculatorTraitDefmt
         adjust_for_elevation: bool,
     ) -> Option<f64>;
 
+    /// Returns the time the sun reaches `zenith` in the evening in fractional UTC hours.
+    /// See [`AstronomicalCalculatorTrait::get_utc_sunrise`].
     fn get_utc_sunset<Tz: TimeZone, G: GeoLocationTrait>(
         &self,
         date_time: &DateTime<Tz>,
@@ -38,6 +52,63 @@ pub trait AstronomicalCalculatorTrait: Clone + AstronomicalCalculatorTraitDefmt
     -> f64;
 
     fn get_solar_azimuth<Tz: TimeZone, G: GeoLocationTrait>(&self, date_time: &DateTime<Tz>, geo_location: &G) -> f64;
+
+    /// Returns the accuracy of this calculator if it is one of the NOAA calculators of this crate,
+    /// whose position of the sun can be shared by the locations of a date, as
+    /// `ZmanimCalendar::compute_at_locations` does. Defaults to `None`.
+    fn get_noaa_accuracy(&self) -> Option<NOAAAccuracy> {
+        None
+    }
+}
+
+impl<N: AstronomicalCalculatorTrait> AstronomicalCalculatorTraitDefmt for &N {}
+
+/// A calculator can be shared by reference, so that calendars which are copied many times (for
+/// example while scanning for a date) do not clone the calculator each time.
+impl<N: AstronomicalCalculatorTrait> AstronomicalCalculatorTrait for &N {
+    fn get_utc_noon<Tz: TimeZone, G: GeoLocationTrait>(&self, date_time: &DateTime<Tz>, geo_location: &G) -> f64 {
+        (**self).get_utc_noon(date_time, geo_location)
+    }
+
+    fn get_utc_midnight<Tz: TimeZone, G: GeoLocationTrait>(&self, date_time: &DateTime<Tz>, geo_location: &G) -> f64 {
+        (**self).get_utc_midnight(date_time, geo_location)
+    }
+
+    fn get_utc_sunrise<Tz: TimeZone, G: GeoLocationTrait>(
+        &self,
+        date_time: &DateTime<Tz>,
+        geo_location: &G,
+        zenith: f64,
+        adjust_for_elevation: bool,
+    ) -> Option<f64> {
+        (**self).get_utc_sunrise(date_time, geo_location, zenith, adjust_for_elevation)
+    }
+
+    fn get_utc_sunset<Tz: TimeZone, G: GeoLocationTrait>(
+        &self,
+        date_time: &DateTime<Tz>,
+        geo_location: &G,
+        zenith: f64,
+        adjust_for_elevation: bool,
+    ) -> Option<f64> {
+        (**self).get_utc_sunset(date_time, geo_location, zenith, adjust_for_elevation)
+    }
+
+    fn get_solar_elevation<Tz: TimeZone, G: GeoLocationTrait>(
+        &self,
+        date_time: &DateTime<Tz>,
+        geo_location: &G,
+    ) -> f64 {
+        (**self).get_solar_elevation(date_time, geo_location)
+    }
+
+    fn get_solar_azimuth<Tz: TimeZone, G: GeoLocationTrait>(&self, date_time: &DateTime<Tz>, geo_location: &G) -> f64 {
+        (**self).get_solar_azimuth(date_time, geo_location)
+    }
+
+    fn get_noaa_accuracy(&self) -> Option<NOAAAccuracy> {
+        (**self).get_noaa_accuracy()
+    }
 }
 
 #[cfg_attr(feature = "defmt", derive(defmt::Format))]
@@ -46,6 +117,52 @@ pub struct NOAACalculator;
 
 impl AstronomicalCalculatorTraitDefmt for NOAACalculator {}
 
+/// How many times the time of sunrise, sunset or another zenith is refined. The hour angle of the
+/// sun is first calculated with its position at noon, and each further pass recalculates it with
+/// its position at the previous estimate.
+///
+/// The error bounds below were measured against a fully converged result at latitudes up to 50°,
+/// for sunrise, sunset, and the sun 8.5° and 16.1° below the horizon. Closer to the poles, where
+/// the sun barely reaches the zenith, every mode can be off by more.
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub enum NOAAAccuracy {
+    /// A single pass with the position of the sun at noon. Half the trigonometry of
+    /// [`NOAAAccuracy::TwoPass`], but up to a minute and a half off.
+    SinglePass,
+    /// Two passes, as [`NOAACalculator`] and KosherJava calculate. Within a quarter of a second.
+    #[default]
+    TwoPass,
+    /// Passes until the estimate moves by less than a millisecond, up to 10 passes.
+    Iterative,
+}
+
+impl NOAAAccuracy {
+    fn max_passes(self) -> u8 {
+        match self {
+            NOAAAccuracy::SinglePass => 1,
+            NOAAAccuracy::TwoPass => 2,
+            NOAAAccuracy::Iterative => 10,
+        }
+    }
+}
+
+/// [`NOAACalculator`] with a choice of how precisely the times the sun is at a zenith, such as
+/// sunrise and sunset, are calculated. All other calculations are the same.
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct NOAACalculatorWithAccuracy {
+    pub accuracy: NOAAAccuracy,
+}
+
+impl NOAACalculatorWithAccuracy {
+    pub const fn new(accuracy: NOAAAccuracy) -> Self {
+        Self { accuracy }
+    }
+}
+
+impl AstronomicalCalculatorTraitDefmt for NOAACalculatorWithAccuracy {}
+
 pub(crate) fn get_julian_day<Tz: TimeZone>(date_time: &DateTime<Tz>) -> f64 {
     let mut year = date_time.year();
     let mut month: u8 = date_time.month() as u8;
@@ -60,17 +177,20 @@ pub(crate) fn get_julian_day<Tz: TimeZone>(date_time: &DateTime<Tz>) -> f64 {
     (365.25 * (year + 4716) as f64).floor() + (30.6001 * (month + 1) as f64).floor() + day as f64 + b as f64 - 1524.5
 }
 
+// The helpers take plain numbers rather than generic date and location types, so that they are
+// compiled once no matter how many time zone and location types a program uses.
 impl NOAACalculator {
-    fn _get_elevation_adjustment(&self, elevation_meters: f64) -> f64 {
-        (_EARTH_RADIUS / (_EARTH_RADIUS + (elevation_meters / 1000.0)))
+    fn _get_elevation_adjustment(&self, elevation_meters: f64, earth_radius_km: f64) -> f64 {
+        (earth_radius_km / (earth_radius_km + (elevation_meters / 1000.0)))
             .acos()
             .to_degrees()
     }
 
-    fn _adjust_zenith(&self, zenith: f64, elevation: f64) -> f64 {
+    fn _adjust_zenith(&self, zenith: f64, elevation: f64, earth_radius_km: f64) -> f64 {
         let mut adjusted_zenith = zenith;
         if zenith == _GEOMETRIC_ZENITH {
-            adjusted_zenith = zenith + (_SOLAR_RADIUS + _REFRACTION + self._get_elevation_adjustment(elevation));
+            adjusted_zenith =
+                zenith + (_SOLAR_RADIUS + _REFRACTION + self._get_elevation_adjustment(elevation, earth_radius_km));
         }
         adjusted_zenith
     }
@@ -163,91 +283,158 @@ impl NOAACalculator {
         equation_of_time.to_degrees() * 4.0
     }
 
-    fn _get_sun_rise_set_utc<Tz: TimeZone>(
+    /// Returns the equation of time at `julian_day`, interpolated from `solar_date` if it is given.
+    fn _get_equation_of_time_at(&self, julian_day: f64, solar_date: Option<&SolarDate>) -> f64 {
+        match solar_date {
+            Some(solar_date) => solar_date.interpolate(&solar_date.equation_of_time, julian_day),
+            None => self._get_equation_of_time(self._get_julian_centuries_from_julian_day(julian_day)),
+        }
+    }
+
+    /// Returns the declination of the sun at `julian_day`, interpolated from `solar_date` if it is
+    /// given.
+    fn _get_sun_declination_at(&self, julian_day: f64, solar_date: Option<&SolarDate>) -> f64 {
+        match solar_date {
+            Some(solar_date) => solar_date.interpolate(&solar_date.declination, julian_day),
+            None => self._get_sun_declination(self._get_julian_centuries_from_julian_day(julian_day)),
+        }
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn _get_sun_rise_set_utc(
         &self,
-        date_time: &DateTime<Tz>,
+        julian_day: f64,
         latitude: f64,
         longitude: f64,
         zenith: f64,
-        solar_event: _SolarEvent,
+        solar_event: SolarEvent,
+        accuracy: NOAAAccuracy,
+        solar_date: Option<&SolarDate>,
     ) -> f64 {
-        let julian_day = get_julian_day(date_time);
-
-        let noonmin = self._get_solar_noon_midnight_utc(julian_day, longitude, _SolarEvent::Noon);
-        let tnoon = self._get_julian_centuries_from_julian_day(julian_day + noonmin / 1440.0);
-
-        let mut equation_of_time = self._get_equation_of_time(tnoon);
-        let mut solar_declination = self._get_sun_declination(tnoon);
-        let mut hour_angle = self._get_sun_hour_angle(latitude, solar_declination, zenith, solar_event);
-        let mut delta = longitude - hour_angle.to_degrees();
-        let mut time_diff = 4.0 * delta;
-        let mut time_utc = 720.0 + time_diff - equation_of_time;
-
-        let newt = self._get_julian_centuries_from_julian_day(julian_day + time_utc / 1440.0);
-        equation_of_time = self._get_equation_of_time(newt);
-        solar_declination = self._get_sun_declination(newt);
-        hour_angle = self._get_sun_hour_angle(latitude, solar_declination, zenith, solar_event);
-        delta = longitude - hour_angle.to_degrees();
-        time_diff = 4.0 * delta;
-        time_utc = 720.0 + time_diff - equation_of_time;
-
+        let solar_date = solar_date.filter(|solar_date| solar_date.julian_day == julian_day);
+        let noonmin = self._get_solar_noon_midnight_utc(julian_day, longitude, SolarEvent::Noon, solar_date);
+        // The position of the sun is first taken at noon, then at each estimate of the event.
+        let mut event_julian_day = julian_day + noonmin / 1440.0;
+        let mut time_utc = f64::NAN;
+        for pass in 1..=accuracy.max_passes() {
+            let equation_of_time = self._get_equation_of_time_at(event_julian_day, solar_date);
+            let solar_declination = self._get_sun_declination_at(event_julian_day, solar_date);
+            let hour_angle = self._get_sun_hour_angle(latitude, solar_declination, zenith, solar_event);
+            let delta = longitude - hour_angle.to_degrees();
+            let previous = time_utc;
+            time_utc = 720.0 + 4.0 * delta - equation_of_time;
+            if pass > 1 && (time_utc - previous).abs() < _ITERATION_TOLERANCE_MINUTES {
+                break;
+            }
+            event_julian_day = julian_day + time_utc / 1440.0;
+        }
         time_utc
     }
 
-    fn _get_sun_hour_angle(&self, latitude: f64, solar_declination: f64, zenith: f64, solar_event: _SolarEvent) -> f64 {
+    fn _get_sun_hour_angle(&self, latitude: f64, solar_declination: f64, zenith: f64, solar_event: SolarEvent) -> f64 {
         let lat_rad = latitude.to_radians();
         let sd_rad = solar_declination.to_radians();
 
         let hour_angle =
             (zenith.to_radians().cos() / (lat_rad.cos() * sd_rad.cos()) - lat_rad.tan() * sd_rad.tan()).acos();
 
-        if solar_event == _SolarEvent::Sunset {
+        if solar_event == SolarEvent::Sunset {
             -hour_angle
         } else {
             hour_angle
         }
     }
 
-    fn _get_solar_noon_midnight_utc(&self, julian_day: f64, longitude: f64, solar_event: _SolarEvent) -> f64 {
-        let julian_day = if solar_event == _SolarEvent::Noon {
+    fn _get_solar_noon_midnight_utc(
+        &self,
+        julian_day: f64,
+        longitude: f64,
+        solar_event: SolarEvent,
+        solar_date: Option<&SolarDate>,
+    ) -> f64 {
+        let solar_date = solar_date.filter(|solar_date| solar_date.julian_day == julian_day);
+        let julian_day = if solar_event == SolarEvent::Noon {
             julian_day
         } else {
             julian_day + 0.5
         };
 
-        let t_noon = self._get_julian_centuries_from_julian_day(julian_day + longitude / 360.0);
-        let mut equation_of_time = self._get_equation_of_time(t_noon);
+        let mut equation_of_time = self._get_equation_of_time_at(julian_day + longitude / 360.0, solar_date);
         let sol_noon_utc = (longitude * 4.0) - equation_of_time;
 
-        let new_t = self._get_julian_centuries_from_julian_day(julian_day + sol_noon_utc / 1440.0);
-        equation_of_time = self._get_equation_of_time(new_t);
+        equation_of_time = self._get_equation_of_time_at(julian_day + sol_noon_utc / 1440.0, solar_date);
 
-        let base_minutes = if solar_event == _SolarEvent::Noon {
-            720.0
-        } else {
-            1440.0
-        };
+        let base_minutes = if solar_event == SolarEvent::Noon { 720.0 } else { 1440.0 };
         base_minutes + (longitude * 4.0) - equation_of_time
     }
 
-    fn _get_solar_elevation_azimuth<Tz: TimeZone>(
+    /// Returns solar noon or midnight in fractional UTC hours, between 0 and 24.
+    fn _get_utc_noon_midnight(
         &self,
-        date_time: &DateTime<Tz>,
-        geo_location: &impl GeoLocationTrait,
-        is_azimuth: bool,
+        julian_day: f64,
+        longitude: f64,
+        solar_event: SolarEvent,
+        solar_date: Option<&SolarDate>,
     ) -> f64 {
-        let date_time = date_time.to_utc();
-        let latitude = geo_location.get_latitude();
-        let longitude = geo_location.get_longitude();
+        let minutes = self._get_solar_noon_midnight_utc(julian_day, -longitude, solar_event, solar_date);
+        let hours = minutes / 60.0;
+        if hours > 0.0 { hours % 24.0 } else { hours % 24.0 + 24.0 }
+    }
 
+    /// Returns the julian day of the UTC date of `date_time` and the fraction of that day that
+    /// has elapsed at `date_time`.
+    fn _get_utc_julian_day_and_time<Tz: TimeZone>(&self, date_time: &DateTime<Tz>) -> (f64, f64) {
+        let date_time = date_time.to_utc();
         let minute: f64 = date_time.minute() as f64;
         let second: f64 = date_time.second() as f64;
         let hour: f64 = date_time.hour() as f64;
         let milli: f64 = date_time.nanosecond() as f64 / 1000000.0;
 
         let time: f64 = (hour + (minute + (second + (milli / 1000.0)) / 60.0) / 60.0) / 24.0;
+        (get_julian_day(&date_time), time)
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn _get_utc_sun_rise_set<Tz: TimeZone, G: GeoLocationTrait>(
+        &self,
+        date_time: &DateTime<Tz>,
+        geo_location: &G,
+        zenith: f64,
+        adjust_for_elevation: bool,
+        solar_event: SolarEvent,
+        accuracy: NOAAAccuracy,
+        solar_date: Option<&SolarDate>,
+    ) -> Option<f64> {
+        let elevation = if adjust_for_elevation {
+            geo_location.get_elevation()
+        } else {
+            0.0
+        };
+        let adjusted_zenith =
+            self._adjust_zenith(zenith, elevation, geo_location.get_earth_model().elevation_radius_km());
+        let time = self._get_sun_rise_set_utc(
+            get_julian_day(date_time),
+            geo_location.get_latitude(),
+            -geo_location.get_longitude(),
+            adjusted_zenith,
+            solar_event,
+            accuracy,
+            solar_date,
+        );
+        let hours = time / 60.0;
+        let result = if hours > 0.0 { hours % 24.0 } else { hours % 24.0 + 24.0 };
+        if result.is_nan() { None } else { Some(result) }
+    }
 
-        let julian_day = get_julian_day(&date_time) + time;
+    fn _get_solar_elevation_azimuth(
+        &self,
+        julian_day: f64,
+        time: f64,
+        latitude: f64,
+        longitude: f64,
+        is_azimuth: bool,
+    ) -> f64 {
+        let julian_day = julian_day + time;
         let julian_centuries = self._get_julian_centuries_from_julian_day(julian_day);
 
         let eot = self._get_equation_of_time(julian_centuries);
@@ -287,27 +474,476 @@ impl NOAACalculator {
 
 impl AstronomicalCalculatorTrait for NOAACalculator {
     fn get_utc_noon<Tz: TimeZone, G: GeoLocationTrait>(&self, date_time: &DateTime<Tz>, geo_location: &G) -> f64 {
-        let julian_day = get_julian_day(date_time);
-        let noon = self._get_solar_noon_midnight_utc(julian_day, -geo_location.get_longitude(), _SolarEvent::Noon);
-        let noon_hours = noon / 60.0;
-        if noon_hours > 0.0 {
-            noon_hours % 24.0
-        } else {
-            noon_hours % 24.0 + 24.0
+        self._get_utc_noon_midnight(
+            get_julian_day(date_time),
+            geo_location.get_longitude(),
+            SolarEvent::Noon,
+            None,
+        )
+    }
+
+    fn get_utc_midnight<Tz: TimeZone, G: GeoLocationTrait>(&self, date_time: &DateTime<Tz>, geo_location: &G) -> f64 {
+        self._get_utc_noon_midnight(
+            get_julian_day(date_time),
+            geo_location.get_longitude(),
+            SolarEvent::Midnight,
+            None,
+        )
+    }
+
+    fn get_utc_sunrise<Tz: TimeZone, G: GeoLocationTrait>(
+        &self,
+        date_time: &DateTime<Tz>,
+        geo_location: &G,
+        zenith: f64,
+        adjust_for_elevation: bool,
+    ) -> Option<f64> {
+        self._get_utc_sun_rise_set(
+            date_time,
+            geo_location,
+            zenith,
+            adjust_for_elevation,
+            SolarEvent::Sunrise,
+            NOAAAccuracy::TwoPass,
+            None,
+        )
+    }
+
+    fn get_utc_sunset<Tz: TimeZone, G: GeoLocationTrait>(
+        &self,
+        date_time: &DateTime<Tz>,
+        geo_location: &G,
+        zenith: f64,
+        adjust_for_elevation: bool,
+    ) -> Option<f64> {
+        self._get_utc_sun_rise_set(
+            date_time,
+            geo_location,
+            zenith,
+            adjust_for_elevation,
+            SolarEvent::Sunset,
+            NOAAAccuracy::TwoPass,
+            None,
+        )
+    }
+
+    fn get_solar_elevation<Tz: TimeZone, G: GeoLocationTrait>(
+        &self,
+        date_time: &DateTime<Tz>,
+        geo_location: &G,
+    ) -> f64 {
+        let (julian_day, time) = self._get_utc_julian_day_and_time(date_time);
+        self._get_solar_elevation_azimuth(
+            julian_day,
+            time,
+            geo_location.get_latitude(),
+            geo_location.get_longitude(),
+            false,
+        )
+    }
+
+    fn get_solar_azimuth<Tz: TimeZone, G: GeoLocationTrait>(&self, date_time: &DateTime<Tz>, geo_location: &G) -> f64 {
+        let (julian_day, time) = self._get_utc_julian_day_and_time(date_time);
+        self._get_solar_elevation_azimuth(
+            julian_day,
+            time,
+            geo_location.get_latitude(),
+            geo_location.get_longitude(),
+            true,
+        )
+    }
+
+    fn get_noaa_accuracy(&self) -> Option<NOAAAccuracy> {
+        Some(NOAAAccuracy::TwoPass)
+    }
+}
+
+impl AstronomicalCalculatorTrait for NOAACalculatorWithAccuracy {
+    fn get_utc_noon<Tz: TimeZone, G: GeoLocationTrait>(&self, date_time: &DateTime<Tz>, geo_location: &G) -> f64 {
+        NOAACalculator.get_utc_noon(date_time, geo_location)
+    }
+
+    fn get_utc_midnight<Tz: TimeZone, G: GeoLocationTrait>(&self, date_time: &DateTime<Tz>, geo_location: &G) -> f64 {
+        NOAACalculator.get_utc_midnight(date_time, geo_location)
+    }
+
+    fn get_utc_sunrise<Tz: TimeZone, G: GeoLocationTrait>(
+        &self,
+        date_time: &DateTime<Tz>,
+        geo_location: &G,
+        zenith: f64,
+        adjust_for_elevation: bool,
+    ) -> Option<f64> {
+        NOAACalculator._get_utc_sun_rise_set(
+            date_time,
+            geo_location,
+            zenith,
+            adjust_for_elevation,
+            SolarEvent::Sunrise,
+            self.accuracy,
+            None,
+        )
+    }
+
+    fn get_utc_sunset<Tz: TimeZone, G: GeoLocationTrait>(
+        &self,
+        date_time: &DateTime<Tz>,
+        geo_location: &G,
+        zenith: f64,
+        adjust_for_elevation: bool,
+    ) -> Option<f64> {
+        NOAACalculator._get_utc_sun_rise_set(
+            date_time,
+            geo_location,
+            zenith,
+            adjust_for_elevation,
+            SolarEvent::Sunset,
+            self.accuracy,
+            None,
+        )
+    }
+
+    fn get_solar_elevation<Tz: TimeZone, G: GeoLocationTrait>(
+        &self,
+        date_time: &DateTime<Tz>,
+        geo_location: &G,
+    ) -> f64 {
+        NOAACalculator.get_solar_elevation(date_time, geo_location)
+    }
+
+    fn get_solar_azimuth<Tz: TimeZone, G: GeoLocationTrait>(&self, date_time: &DateTime<Tz>, geo_location: &G) -> f64 {
+        NOAACalculator.get_solar_azimuth(date_time, geo_location)
+    }
+
+    fn get_noaa_accuracy(&self) -> Option<NOAAAccuracy> {
+        Some(self.accuracy)
+    }
+}
+
+/// One of the calculators of this crate, chosen at runtime. A program that lets its users pick
+/// the calculator can use a single `ZmanimCalendar<Tz, GeoLocation, AnyCalculator>` type (see
+/// [`crate::zmanim_calendar::AnyZmanimCalendar`]) for all of them.
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub enum AnyCalculator {
+    /// [`NOAACalculator`].
+    #[default]
+    Noaa,
+    /// [`NOAACalculatorWithAccuracy`].
+    NoaaWithAccuracy(NOAACalculatorWithAccuracy),
+}
+
+impl From<NOAACalculator> for AnyCalculator {
+    fn from(_: NOAACalculator) -> Self {
+        AnyCalculator::Noaa
+    }
+}
+
+impl From<NOAACalculatorWithAccuracy> for AnyCalculator {
+    fn from(calculator: NOAACalculatorWithAccuracy) -> Self {
+        AnyCalculator::NoaaWithAccuracy(calculator)
+    }
+}
+
+impl AstronomicalCalculatorTraitDefmt for AnyCalculator {}
+
+impl AstronomicalCalculatorTrait for AnyCalculator {
+    fn get_utc_noon<Tz: TimeZone, G: GeoLocationTrait>(&self, date_time: &DateTime<Tz>, geo_location: &G) -> f64 {
+        match self {
+            AnyCalculator::Noaa => NOAACalculator.get_utc_noon(date_time, geo_location),
+            AnyCalculator::NoaaWithAccuracy(calculator) => calculator.get_utc_noon(date_time, geo_location),
         }
     }
 
     fn get_utc_midnight<Tz: TimeZone, G: GeoLocationTrait>(&self, date_time: &DateTime<Tz>, geo_location: &G) -> f64 {
+        match self {
+            AnyCalculator::Noaa => NOAACalculator.get_utc_midnight(date_time, geo_location),
+            AnyCalculator::NoaaWithAccuracy(calculator) => calculator.get_utc_midnight(date_time, geo_location),
+        }
+    }
+
+    fn get_utc_sunrise<Tz: TimeZone, G: GeoLocationTrait>(
+        &self,
+        date_time: &DateTime<Tz>,
+        geo_location: &G,
+        zenith: f64,
+        adjust_for_elevation: bool,
+    ) -> Option<f64> {
+        match self {
+            AnyCalculator::Noaa => {
+                NOAACalculator.get_utc_sunrise(date_time, geo_location, zenith, adjust_for_elevation)
+            }
+            AnyCalculator::NoaaWithAccuracy(calculator) => {
+                calculator.get_utc_sunrise(date_time, geo_location, zenith, adjust_for_elevation)
+            }
+        }
+    }
+
+    fn get_utc_sunset<Tz: TimeZone, G: GeoLocationTrait>(
+        &self,
+        date_time: &DateTime<Tz>,
+        geo_location: &G,
+        zenith: f64,
+        adjust_for_elevation: bool,
+    ) -> Option<f64> {
+        match self {
+            AnyCalculator::Noaa => NOAACalculator.get_utc_sunset(date_time, geo_location, zenith, adjust_for_elevation),
+            AnyCalculator::NoaaWithAccuracy(calculator) => {
+                calculator.get_utc_sunset(date_time, geo_location, zenith, adjust_for_elevation)
+            }
+        }
+    }
+
+    fn get_solar_elevation<Tz: TimeZone, G: GeoLocationTrait>(
+        &self,
+        date_time: &DateTime<Tz>,
+        geo_location: &G,
+    ) -> f64 {
+        match self {
+            AnyCalculator::Noaa => NOAACalculator.get_solar_elevation(date_time, geo_location),
+            AnyCalculator::NoaaWithAccuracy(calculator) => calculator.get_solar_elevation(date_time, geo_location),
+        }
+    }
+
+    fn get_solar_azimuth<Tz: TimeZone, G: GeoLocationTrait>(&self, date_time: &DateTime<Tz>, geo_location: &G) -> f64 {
+        match self {
+            AnyCalculator::Noaa => NOAACalculator.get_solar_azimuth(date_time, geo_location),
+            AnyCalculator::NoaaWithAccuracy(calculator) => calculator.get_solar_azimuth(date_time, geo_location),
+        }
+    }
+
+    fn get_noaa_accuracy(&self) -> Option<NOAAAccuracy> {
+        match self {
+            AnyCalculator::Noaa => NOAACalculator.get_noaa_accuracy(),
+            AnyCalculator::NoaaWithAccuracy(calculator) => calculator.get_noaa_accuracy(),
+        }
+    }
+}
+
+/// The methods of [`AstronomicalCalculatorTrait`] for fixed offset times and [`GeoLocation`], which
+/// can be called through a `dyn` reference because they are not generic.
+pub(crate) trait DynCalculator: Debug {
+    fn utc_noon(&self, date_time: &DateTime<FixedOffset>, geo_location: &GeoLocation) -> f64;
+
+    fn utc_midnight(&self, date_time: &DateTime<FixedOffset>, geo_location: &GeoLocation) -> f64;
+
+    fn utc_sunrise(
+        &self,
+        date_time: &DateTime<FixedOffset>,
+        geo_location: &GeoLocation,
+        zenith: f64,
+        adjust_for_elevation: bool,
+    ) -> Option<f64>;
+
+    fn utc_sunset(
+        &self,
+        date_time: &DateTime<FixedOffset>,
+        geo_location: &GeoLocation,
+        zenith: f64,
+        adjust_for_elevation: bool,
+    ) -> Option<f64>;
+
+    fn solar_elevation(&self, date_time: &DateTime<FixedOffset>, geo_location: &GeoLocation) -> f64;
+
+    fn solar_azimuth(&self, date_time: &DateTime<FixedOffset>, geo_location: &GeoLocation) -> f64;
+
+    fn noaa_accuracy(&self) -> Option<NOAAAccuracy>;
+}
+
+impl<N: AstronomicalCalculatorTrait> DynCalculator for N {
+    fn utc_noon(&self, date_time: &DateTime<FixedOffset>, geo_location: &GeoLocation) -> f64 {
+        self.get_utc_noon(date_time, geo_location)
+    }
+
+    fn utc_midnight(&self, date_time: &DateTime<FixedOffset>, geo_location: &GeoLocation) -> f64 {
+        self.get_utc_midnight(date_time, geo_location)
+    }
+
+    fn utc_sunrise(
+        &self,
+        date_time: &DateTime<FixedOffset>,
+        geo_location: &GeoLocation,
+        zenith: f64,
+        adjust_for_elevation: bool,
+    ) -> Option<f64> {
+        self.get_utc_sunrise(date_time, geo_location, zenith, adjust_for_elevation)
+    }
+
+    fn utc_sunset(
+        &self,
+        date_time: &DateTime<FixedOffset>,
+        geo_location: &GeoLocation,
+        zenith: f64,
+        adjust_for_elevation: bool,
+    ) -> Option<f64> {
+        self.get_utc_sunset(date_time, geo_location, zenith, adjust_for_elevation)
+    }
+
+    fn solar_elevation(&self, date_time: &DateTime<FixedOffset>, geo_location: &GeoLocation) -> f64 {
+        self.get_solar_elevation(date_time, geo_location)
+    }
+
+    fn solar_azimuth(&self, date_time: &DateTime<FixedOffset>, geo_location: &GeoLocation) -> f64 {
+        self.get_solar_azimuth(date_time, geo_location)
+    }
+
+    fn noaa_accuracy(&self) -> Option<NOAAAccuracy> {
+        self.get_noaa_accuracy()
+    }
+}
+
+/// Any calculator behind a `dyn` reference. The zmanim are calculated with this calculator, so
+/// that they are compiled once rather than once for each calculator type.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct ErasedCalculator<'a>(pub(crate) &'a dyn DynCalculator);
+
+#[cfg(feature = "defmt")]
+impl defmt::Format for ErasedCalculator<'_> {
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(f, "ErasedCalculator")
+    }
+}
+
+impl AstronomicalCalculatorTraitDefmt for ErasedCalculator<'_> {}
+
+impl AstronomicalCalculatorTrait for ErasedCalculator<'_> {
+    fn get_utc_noon<Tz: TimeZone, G: GeoLocationTrait>(&self, date_time: &DateTime<Tz>, geo_location: &G) -> f64 {
+        self.0
+            .utc_noon(&date_time.fixed_offset(), &GeoLocation::from_location(geo_location))
+    }
+
+    fn get_utc_midnight<Tz: TimeZone, G: GeoLocationTrait>(&self, date_time: &DateTime<Tz>, geo_location: &G) -> f64 {
+        self.0
+            .utc_midnight(&date_time.fixed_offset(), &GeoLocation::from_location(geo_location))
+    }
+
+    fn get_utc_sunrise<Tz: TimeZone, G: GeoLocationTrait>(
+        &self,
+        date_time: &DateTime<Tz>,
+        geo_location: &G,
+        zenith: f64,
+        adjust_for_elevation: bool,
+    ) -> Option<f64> {
+        self.0.utc_sunrise(
+            &date_time.fixed_offset(),
+            &GeoLocation::from_location(geo_location),
+            zenith,
+            adjust_for_elevation,
+        )
+    }
+
+    fn get_utc_sunset<Tz: TimeZone, G: GeoLocationTrait>(
+        &self,
+        date_time: &DateTime<Tz>,
+        geo_location: &G,
+        zenith: f64,
+        adjust_for_elevation: bool,
+    ) -> Option<f64> {
+        self.0.utc_sunset(
+            &date_time.fixed_offset(),
+            &GeoLocation::from_location(geo_location),
+            zenith,
+            adjust_for_elevation,
+        )
+    }
+
+    fn get_solar_elevation<Tz: TimeZone, G: GeoLocationTrait>(
+        &self,
+        date_time: &DateTime<Tz>,
+        geo_location: &G,
+    ) -> f64 {
+        self.0
+            .solar_elevation(&date_time.fixed_offset(), &GeoLocation::from_location(geo_location))
+    }
+
+    fn get_solar_azimuth<Tz: TimeZone, G: GeoLocationTrait>(&self, date_time: &DateTime<Tz>, geo_location: &G) -> f64 {
+        self.0
+            .solar_azimuth(&date_time.fixed_offset(), &GeoLocation::from_location(geo_location))
+    }
+
+    fn get_noaa_accuracy(&self) -> Option<NOAAAccuracy> {
+        self.0.noaa_accuracy()
+    }
+}
+
+/// The equation of time and declination of the sun around one date, so that the times of many
+/// locations on that date are interpolated from them rather than each recalculating the position
+/// of the sun. They are sampled a day apart, from half a day before the date to two and a half
+/// days after it, which covers every event of the date at any longitude; the cubic interpolation
+/// between the samples is within a millisecond of the full calculation.
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) struct SolarDate {
+    julian_day: f64,
+    equation_of_time: [f64; 4],
+    declination: [f64; 4],
+}
+
+impl SolarDate {
+    /// The days after the julian day of the date at which the position of the sun is sampled.
+    const SAMPLE_DAYS: [f64; 4] = [-0.5, 0.5, 1.5, 2.5];
+
+    pub(crate) fn new<Tz: TimeZone>(date_time: &DateTime<Tz>) -> Self {
         let julian_day = get_julian_day(date_time);
-        let midnight =
-            self._get_solar_noon_midnight_utc(julian_day, -geo_location.get_longitude(), _SolarEvent::Midnight);
-        let midnight_hours = midnight / 60.0;
-        if midnight_hours > 0.0 {
-            midnight_hours % 24.0
-        } else {
-            midnight_hours % 24.0 + 24.0
+        let julian_centuries =
+            Self::SAMPLE_DAYS.map(|days| NOAACalculator._get_julian_centuries_from_julian_day(julian_day + days));
+        Self {
+            julian_day,
+            equation_of_time: julian_centuries.map(|t| NOAACalculator._get_equation_of_time(t)),
+            declination: julian_centuries.map(|t| NOAACalculator._get_sun_declination(t)),
+        }
+    }
+
+    /// Returns the value of `samples` at `julian_day` by Lagrange interpolation.
+    fn interpolate(&self, samples: &[f64; 4], julian_day: f64) -> f64 {
+        // Days since the first sample, so that the samples are at 0, 1, 2 and 3.
+        let x = julian_day - self.julian_day - Self::SAMPLE_DAYS[0];
+        samples[0] * -(x - 1.0) * (x - 2.0) * (x - 3.0) / 6.0
+            + samples[1] * x * (x - 2.0) * (x - 3.0) / 2.0
+            + samples[2] * -x * (x - 1.0) * (x - 3.0) / 2.0
+            + samples[3] * x * (x - 1.0) * (x - 2.0) / 6.0
+    }
+}
+
+/// A NOAA calculator for the locations of one date, which interpolates the position of the sun
+/// from a [`SolarDate`]. Other dates, such as those moved across the antimeridian, are calculated
+/// in full.
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) struct NOAACalculatorForDate {
+    solar_date: SolarDate,
+    accuracy: NOAAAccuracy,
+}
+
+impl NOAACalculatorForDate {
+    pub(crate) fn new<Tz: TimeZone>(date_time: &DateTime<Tz>, accuracy: NOAAAccuracy) -> Self {
+        Self {
+            solar_date: SolarDate::new(date_time),
+            accuracy,
         }
     }
+}
+
+impl AstronomicalCalculatorTraitDefmt for NOAACalculatorForDate {}
+
+impl AstronomicalCalculatorTrait for NOAACalculatorForDate {
+    fn get_utc_noon<Tz: TimeZone, G: GeoLocationTrait>(&self, date_time: &DateTime<Tz>, geo_location: &G) -> f64 {
+        NOAACalculator._get_utc_noon_midnight(
+            get_julian_day(date_time),
+            geo_location.get_longitude(),
+            SolarEvent::Noon,
+            Some(&self.solar_date),
+        )
+    }
+
+    fn get_utc_midnight<Tz: TimeZone, G: GeoLocationTrait>(&self, date_time: &DateTime<Tz>, geo_location: &G) -> f64 {
+        NOAACalculator._get_utc_noon_midnight(
+            get_julian_day(date_time),
+            geo_location.get_longitude(),
+            SolarEvent::Midnight,
+            Some(&self.solar_date),
+        )
+    }
 
     fn get_utc_sunrise<Tz: TimeZone, G: GeoLocationTrait>(
         &self,
@@ -316,26 +952,15 @@ impl AstronomicalCalculatorTrait for NOAACalculator {
         zenith: f64,
         adjust_for_elevation: bool,
     ) -> Option<f64> {
-        let elevation = if adjust_for_elevation {
-            geo_location.get_elevation()
-        } else {
-            0.0
-        };
-        let adjusted_zenith = self._adjust_zenith(zenith, elevation);
-        let sunrise = self._get_sun_rise_set_utc(
+        NOAACalculator._get_utc_sun_rise_set(
             date_time,
-            geo_location.get_latitude(),
-            -geo_location.get_longitude(),
-            adjusted_zenith,
-            _SolarEvent::Sunrise,
-        );
-        let sunrise_hours = sunrise / 60.0;
-        let result = if sunrise_hours > 0.0 {
-            sunrise_hours % 24.0
-        } else {
-            sunrise_hours % 24.0 + 24.0
-        };
-        if result.is_nan() { None } else { Some(result) }
+            geo_location,
+            zenith,
+            adjust_for_elevation,
+            SolarEvent::Sunrise,
+            self.accuracy,
+            Some(&self.solar_date),
+        )
     }
 
     fn get_utc_sunset<Tz: TimeZone, G: GeoLocationTrait>(
@@ -345,26 +970,15 @@ impl AstronomicalCalculatorTrait for NOAACalculator {
         zenith: f64,
         adjust_for_elevation: bool,
     ) -> Option<f64> {
-        let elevation = if adjust_for_elevation {
-            geo_location.get_elevation()
-        } else {
-            0.0
-        };
-        let adjusted_zenith = self._adjust_zenith(zenith, elevation);
-        let sunset = self._get_sun_rise_set_utc(
+        NOAACalculator._get_utc_sun_rise_set(
             date_time,
-            geo_location.get_latitude(),
-            -geo_location.get_longitude(),
-            adjusted_zenith,
-            _SolarEvent::Sunset,
-        );
-        let sunset_hours = sunset / 60.0;
-        let result = if sunset_hours > 0.0 {
-            sunset_hours % 24.0
-        } else {
-            sunset_hours % 24.0 + 24.0
-        };
-        if result.is_nan() { None } else { Some(result) }
+            geo_location,
+            zenith,
+            adjust_for_elevation,
+            SolarEvent::Sunset,
+            self.accuracy,
+            Some(&self.solar_date),
+        )
     }
 
     fn get_solar_elevation<Tz: TimeZone, G: GeoLocationTrait>(
@@ -372,10 +986,14 @@ impl AstronomicalCalculatorTrait for NOAACalculator {
         date_time: &DateTime<Tz>,
         geo_location: &G,
     ) -> f64 {
-        self._get_solar_elevation_azimuth(date_time, geo_location, false)
+        NOAACalculator.get_solar_elevation(date_time, geo_location)
     }
 
     fn get_solar_azimuth<Tz: TimeZone, G: GeoLocationTrait>(&self, date_time: &DateTime<Tz>, geo_location: &G) -> f64 {
-        self._get_solar_elevation_azimuth(date_time, geo_location, true)
+        NOAACalculator.get_solar_azimuth(date_time, geo_location)
+    }
+
+    fn get_noaa_accuracy(&self) -> Option<NOAAAccuracy> {
+        Some(self.accuracy)
     }
 }
@@ -0,0 +1,69 @@
+//! BeHaB, the fasts of Monday, Thursday and Monday observed by some in Cheshvan and Iyar.
+//! Communities differ on which week they begin.
+use chrono::Weekday;
+
+use crate::{
+    astronomical_calculator::AstronomicalCalculatorTrait,
+    constants::JewishMonth,
+    jewish_calendar::{JewishCalendar, JewishCalendarTrait},
+};
+
+/// The rule for which Monday BeHaB begins on.
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default, Hash)]
+pub enum BehabRule {
+    /// The Monday after the first Shabbos following Rosh Chodesh, on which the Mi Shebeirach for
+    /// those fasting is said. This is the rule used by [`JewishCalendarTrait::is_be_hab`].
+    #[default]
+    AfterFirstShabbos,
+    /// The first Monday after Rosh Chodesh.
+    FirstMondayAfterRoshChodesh,
+}
+
+impl<N: AstronomicalCalculatorTrait> JewishCalendar<N> {
+    /// Returns the three days of BeHaB in the month of this date according to `rule`, or `None`
+    /// if this date is not in Cheshvan or Iyar.
+    pub fn get_be_hab_days(&self, rule: BehabRule) -> Option<[Self; 3]> {
+        let first_monday = self.get_be_hab_first_monday(rule)?;
+        Some([
+            first_monday.clone(),
+            first_monday.copy_with_days_added(3)?,
+            first_monday.copy_with_days_added(7)?,
+        ])
+    }
+
+    /// Returns true if this date is one of the days of BeHaB according to `rule`.
+    pub fn is_be_hab_with(&self, rule: BehabRule) -> bool {
+        self.with_borrowed_calculator()
+            .get_be_hab_days(rule)
+            .is_some_and(|days| days.iter().any(|day| day.hebrew_date == self.hebrew_date))
+    }
+
+    /// Returns true if this date is the Shabbos before BeHaB according to `rule`, on which the
+    /// fasts are announced.
+    pub fn is_shabbos_before_be_hab(&self, rule: BehabRule) -> bool {
+        self.get_day_of_week() == Weekday::Sat
+            && self
+                .with_borrowed_calculator()
+                .get_be_hab_first_monday(rule)
+                .and_then(|monday| monday.copy_with_days_added(-2))
+                .is_some_and(|shabbos| shabbos.hebrew_date == self.hebrew_date)
+    }
+
+    fn get_be_hab_first_monday(&self, rule: BehabRule) -> Option<Self> {
+        let month = self.get_jewish_month();
+        if month != JewishMonth::Cheshvan && month != JewishMonth::Iyar {
+            return None;
+        }
+        // Rosh Chodesh of both months ends on the first, so both rules start from the second.
+        let second = self.copy_with_hebrew_ymd(self.get_jewish_year(), month, 2)?;
+        let days_until = |weekday: Weekday| {
+            (weekday.num_days_from_sunday() + 7 - second.get_day_of_week().num_days_from_sunday()) % 7
+        };
+        let days_after_second = match rule {
+            BehabRule::AfterFirstShabbos => days_until(Weekday::Sat) + 2,
+            BehabRule::FirstMondayAfterRoshChodesh => days_until(Weekday::Mon),
+        };
+        second.copy_with_days_added(days_after_second as i64)
+    }
+}
@@ -0,0 +1,73 @@
+//! Birkas Hachamah, the blessing on the sun recited once every 28 years on the morning the sun
+//! returns to its position at creation according to the tekufah of Shmuel.
+use chrono::{DateTime, Datelike, Duration, NaiveDate, TimeZone};
+
+use crate::{
+    astronomical_calculator::AstronomicalCalculatorTrait,
+    constants::Zman,
+    geolocation::GeoLocation,
+    jewish_calendar::{JewishCalendar, JewishCalendarTrait},
+    jewish_year,
+    zmanim_calendar::{ZmanimCalendar, ZmanimCalendarTrait},
+};
+
+/// The number of days in the 28 year solar cycle.
+const SOLAR_CYCLE_DAYS: i32 = 10227;
+
+impl<N: AstronomicalCalculatorTrait> JewishCalendar<N> {
+    /// Returns the next day on which Birkas Hachamah is recited, starting from this date.
+    pub fn get_next_birkas_hachamah(&self) -> Option<Self> {
+        let elapsed_days = jewish_year::get_jewish_calendar_elapsed_days(self.get_jewish_year())
+            + self.get_days_since_start_of_jewish_year();
+        let days_until = (172 - elapsed_days).rem_euclid(SOLAR_CYCLE_DAYS);
+        self.copy_with_days_added(days_until as i64)
+    }
+}
+
+/// The day and times of Birkas Hachamah at a location.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BirkasHachamah<Tz: TimeZone> {
+    pub date: NaiveDate,
+    /// The blessing is ideally recited at sunrise.
+    pub sunrise: DateTime<Tz>,
+    /// The latest time for the blessing.
+    pub chatzos: DateTime<Tz>,
+}
+
+impl<Tz: TimeZone> BirkasHachamah<Tz> {
+    /// Returns the next Birkas Hachamah on or after `date` at `geo_location`, or `None` if the
+    /// sun does not rise there on that day.
+    pub fn next_after<N: AstronomicalCalculatorTrait>(
+        date: NaiveDate,
+        timezone: Tz,
+        geo_location: &GeoLocation,
+        calculator: N,
+    ) -> Option<Self> {
+        let jewish_calendar = JewishCalendar::from_gregorian_date(
+            date.year(),
+            date.month() as u8,
+            date.day() as u8,
+            false,
+            false,
+            false,
+            calculator.clone(),
+        )?;
+        let birkas_hachamah = jewish_calendar.get_next_birkas_hachamah()?;
+        let date = birkas_hachamah.get_gregorian_naive_date()?;
+        let zmanim_calendar = ZmanimCalendar::new(
+            date,
+            timezone,
+            geo_location.clone(),
+            calculator,
+            false,
+            false,
+            Duration::zero(),
+            Duration::zero(),
+        )?;
+        Some(Self {
+            date,
+            sunrise: zmanim_calendar.get_sunrise()?,
+            chatzos: zmanim_calendar.get_zman(&Zman::Chatzos)?,
+        })
+    }
+}
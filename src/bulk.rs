@@ -0,0 +1,47 @@
+//! Computing the same zmanim at many locations on one date, such as for all the branches of an
+//! organization.
+use std::collections::BTreeMap;
+
+use chrono::{DateTime, TimeZone};
+
+use crate::{
+    astronomical_calculator::{AstronomicalCalculatorTrait, ErasedCalculator, NOAACalculatorForDate},
+    constants::Zman,
+    geolocation::GeoLocation,
+    zmanim_calendar::{ZmanimCalendar, ZmanimCalendarTrait},
+};
+
+impl<Tz: TimeZone, N: AstronomicalCalculatorTrait> ZmanimCalendar<Tz, GeoLocation, N> {
+    /// Computes `zmanim` at each of `locations` on the date of this calendar, with its time
+    /// zone and settings, keyed by the key given with each location. See
+    /// [`ZmanimCalendar::compute`].
+    ///
+    /// With the NOAA calculators of this crate, the julian day, equation of time and declination
+    /// of the sun are calculated once for the date and interpolated for each location, so the
+    /// times can differ from [`ZmanimCalendar::compute`] by up to a millisecond.
+    pub fn compute_at_locations<K: Ord>(
+        &self,
+        locations: impl IntoIterator<Item = (K, GeoLocation)>,
+        zmanim: &[Zman],
+    ) -> BTreeMap<K, Vec<(Zman, Option<DateTime<Tz>>)>> {
+        let date_calculator = self
+            .get_calculator()
+            .get_noaa_accuracy()
+            .map(|accuracy| NOAACalculatorForDate::new(self.get_date_time(), accuracy));
+        let calculator = match &date_calculator {
+            Some(date_calculator) => ErasedCalculator(date_calculator),
+            None => ErasedCalculator(self.get_calculator()),
+        };
+        locations
+            .into_iter()
+            .map(|(key, geo_location)| {
+                let core = self.core_at(&geo_location, calculator);
+                let times = zmanim
+                    .iter()
+                    .map(|zman| (*zman, self.localize(core.get_zman(zman))))
+                    .collect();
+                (key, times)
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,116 @@
+//! The times for lighting the Chanukah menorah on each of the eight nights.
+use chrono::{DateTime, Datelike, Duration, NaiveDate, TimeZone, Weekday};
+
+use crate::{
+    astronomical_calculator::AstronomicalCalculatorTrait,
+    constants::{JewishMonth, Zman},
+    geolocation::GeoLocation,
+    jewish_calendar::JewishCalendar,
+    zmanim_calendar::{ZmanimCalendar, ZmanimCalendarTrait},
+};
+
+/// How the lighting on a night of Chanukah is affected by Shabbos.
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub enum ChanukahLighting {
+    /// The menorah is lit at nightfall.
+    Weekday,
+    /// Friday, when the menorah is lit after plag hamincha and before the Shabbos candles.
+    ErevShabbos,
+    /// Motzai Shabbos, when the menorah is lit after Shabbos ends.
+    MotzaiShabbos,
+}
+
+/// The lighting times for one night of Chanukah.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ChanukahNight<Tz: TimeZone> {
+    /// The night (1-8), which is the number of candles lit.
+    pub night: u8,
+    /// The civil date on whose evening the menorah is lit.
+    pub date: NaiveDate,
+    /// The earliest time the menorah may be lit.
+    pub earliest: DateTime<Tz>,
+    /// The time the menorah is ideally lit.
+    pub preferred: DateTime<Tz>,
+    pub lighting: ChanukahLighting,
+}
+
+impl<Tz: TimeZone> ChanukahNight<Tz> {
+    /// Returns the lighting times for `night` (1-8) of the Chanukah of the Jewish `year`.
+    ///
+    /// On a weekday the menorah may be lit from plag hamincha, and is ideally lit at tzais. On
+    /// Friday it is lit before candle lighting, `candle_lighting_offset` before sunset, and on
+    /// Motzai Shabbos at tzais, when Shabbos ends.
+    pub fn for_night<N: AstronomicalCalculatorTrait>(
+        year: i32,
+        night: u8,
+        timezone: Tz,
+        geo_location: &GeoLocation,
+        calculator: N,
+        candle_lighting_offset: Duration,
+    ) -> Option<Self> {
+        if !(1..=8).contains(&night) {
+            return None;
+        }
+        let erev_chanukah =
+            JewishCalendar::from_hebrew_date(year, JewishMonth::Kislev, 24, false, false, false, calculator.clone())?;
+        let date = erev_chanukah
+            .get_gregorian_naive_date()?
+            .checked_add_signed(Duration::days(night as i64 - 1))?;
+        let calendar = ZmanimCalendar::new(
+            date,
+            timezone,
+            geo_location.clone(),
+            calculator,
+            false,
+            false,
+            candle_lighting_offset,
+            Duration::zero(),
+        )?;
+        let (lighting, earliest, preferred) = match date.weekday() {
+            Weekday::Fri => (
+                ChanukahLighting::ErevShabbos,
+                calendar.get_zman(&Zman::PlagHamincha)?,
+                calendar.get_zman(&Zman::CandleLighting)?,
+            ),
+            Weekday::Sat => {
+                let tzais = calendar.get_zman(&Zman::Tzais)?;
+                (ChanukahLighting::MotzaiShabbos, tzais.clone(), tzais)
+            }
+            _ => (
+                ChanukahLighting::Weekday,
+                calendar.get_zman(&Zman::PlagHamincha)?,
+                calendar.get_zman(&Zman::Tzais)?,
+            ),
+        };
+        Some(Self {
+            night,
+            date,
+            earliest,
+            preferred,
+            lighting,
+        })
+    }
+
+    /// Returns the lighting times for the eight nights of the Chanukah of the Jewish `year`,
+    /// skipping nights on which the zmanim cannot be calculated. See [`Self::for_night`].
+    pub fn for_year<N: AstronomicalCalculatorTrait>(
+        year: i32,
+        timezone: Tz,
+        geo_location: &GeoLocation,
+        calculator: N,
+        candle_lighting_offset: Duration,
+    ) -> impl Iterator<Item = Self> + use<Tz, N> {
+        let geo_location = geo_location.clone();
+        (1..=8).filter_map(move |night| {
+            Self::for_night(
+                year,
+                night,
+                timezone.clone(),
+                &geo_location,
+                calculator.clone(),
+                candle_lighting_offset,
+            )
+        })
+    }
+}
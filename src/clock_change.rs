@@ -0,0 +1,93 @@
+//! Detecting daylight saving time transitions, so that tables of zmanim can point out when the
+//! clocks change.
+use chrono::{DateTime, Duration, FixedOffset, NaiveDate, Offset, TimeZone, Utc};
+
+use crate::{
+    astronomical_calculator::AstronomicalCalculatorTrait,
+    constants::Zman,
+    geolocation::GeoLocation,
+    zmanim_calendar::{ZmanimCalendar, ZmanimCalendarTrait},
+};
+
+/// A change of the UTC offset of a time zone, usually the start or end of daylight saving time.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ClockChange<Tz: TimeZone> {
+    /// The local date on which the clocks change.
+    pub date: NaiveDate,
+    /// The first instant with the new offset.
+    pub instant: DateTime<Tz>,
+    pub offset_before: FixedOffset,
+    pub offset_after: FixedOffset,
+}
+
+impl<Tz: TimeZone> ClockChange<Tz> {
+    /// Returns the first change of offset in `timezone` whose local date is between `start` and
+    /// `end` (inclusive), or `None` if the offset does not change in that range.
+    pub fn find(timezone: &Tz, start: NaiveDate, end: NaiveDate) -> Option<Self> {
+        let offset_at = |instant: DateTime<Utc>| timezone.offset_from_utc_datetime(&instant.naive_utc()).fix();
+        // Start a day early and end a day late so that local dates at any offset are covered.
+        let mut instant = start.pred_opt()?.and_hms_opt(0, 0, 0)?.and_utc();
+        let last = end.succ_opt()?.succ_opt()?.and_hms_opt(0, 0, 0)?.and_utc();
+        let step = Duration::hours(1);
+        while instant < last {
+            let next = instant + step;
+            let offset_before = offset_at(instant);
+            let offset_after = offset_at(next);
+            if offset_before != offset_after {
+                // The offset changes within this hour; find the second at which it does.
+                let (mut low, mut high) = (instant, next);
+                while high - low > Duration::seconds(1) {
+                    let middle = low + (high - low) / 2;
+                    if offset_at(middle) == offset_before {
+                        low = middle;
+                    } else {
+                        high = middle;
+                    }
+                }
+                let local = high.with_timezone(timezone);
+                let date = local.date_naive();
+                if date >= start && date <= end {
+                    return Some(Self {
+                        date,
+                        instant: local,
+                        offset_before,
+                        offset_after,
+                    });
+                }
+            }
+            instant = next;
+        }
+        None
+    }
+
+    /// Returns how far the clocks move, positive when they move forward.
+    pub fn shift(&self) -> Duration {
+        Duration::seconds((self.offset_after.local_minus_utc() - self.offset_before.local_minus_utc()) as i64)
+    }
+
+    /// Returns the zmanim on the day of the change that fall after it, and so are shown in the
+    /// new offset while the earlier zmanim of that day are shown in the old one.
+    pub fn affected_zmanim<N: AstronomicalCalculatorTrait>(
+        &self,
+        geo_location: &GeoLocation,
+        calculator: N,
+    ) -> impl Iterator<Item = Zman> + use<Tz, N> {
+        let calendar = ZmanimCalendar::new(
+            self.date,
+            self.instant.timezone(),
+            geo_location.clone(),
+            calculator,
+            false,
+            false,
+            Duration::zero(),
+            Duration::zero(),
+        );
+        let instant = self.instant.clone();
+        Zman::values().into_iter().filter(move |zman| {
+            calendar
+                .as_ref()
+                .and_then(|calendar| calendar.get_zman(zman))
+                .is_some_and(|time| time >= instant)
+        })
+    }
+}
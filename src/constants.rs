@@ -4,48 +4,49 @@ use num_enum::{IntoPrimitive, TryFromPrimitive};
 pub(crate) static _JULIAN_DAY_JAN_1_2000: f64 = 2451545.0;
 pub(crate) static _JULIAN_DAYS_PER_CENTURY: f64 = 36525.0;
 pub(crate) static _EARTH_RADIUS: f64 = 6356.9;
-pub(crate) static _GEOMETRIC_ZENITH: f64 = 90.0;
-pub(crate) static _CIVIL_ZENITH: f64 = 96.0;
-pub(crate) static _NAUTICAL_ZENITH: f64 = 102.0;
-pub(crate) static _ASTRONOMICAL_ZENITH: f64 = 108.0;
+pub(crate) static _GEOMETRIC_ZENITH: f64 = crate::zenith::GEOMETRIC;
+pub(crate) static _CIVIL_ZENITH: f64 = crate::zenith::CIVIL;
+pub(crate) static _NAUTICAL_ZENITH: f64 = crate::zenith::NAUTICAL;
+pub(crate) static _ASTRONOMICAL_ZENITH: f64 = crate::zenith::ASTRONOMICAL;
 pub(crate) static _SOLAR_RADIUS: f64 = 16.0 / 60.0;
+pub(crate) static _ITERATION_TOLERANCE_MINUTES: f64 = 1.0 / 60_000.0;
 pub(crate) static _REFRACTION: f64 = 34.0 / 60.0;
-pub(crate) static _ZENITH_16_POINT_1: f64 = 90.0 + 16.1;
-pub(crate) static _ZENITH_8_POINT_5: f64 = 90.0 + 8.5;
-pub(crate) static _ZENITH_3_POINT_7: f64 = 90.0 + 3.7;
-pub(crate) static _ZENITH_3_POINT_8: f64 = 90.0 + 3.8;
-pub(crate) static _ZENITH_5_POINT_95: f64 = 90.0 + 5.95;
-pub(crate) static _ZENITH_7_POINT_083: f64 = 90.0 + 7.0 + (5.0 / 60.0);
-pub(crate) static _ZENITH_10_POINT_2: f64 = 90.0 + 10.2;
-pub(crate) static _ZENITH_11_DEGREES: f64 = 90.0 + 11.0;
-pub(crate) static _ZENITH_11_POINT_5: f64 = 90.0 + 11.5;
-pub(crate) static _ZENITH_13_POINT_24: f64 = 90.0 + 13.24;
-pub(crate) static _ZENITH_19_DEGREES: f64 = 90.0 + 19.0;
-pub(crate) static _ZENITH_19_POINT_8: f64 = 90.0 + 19.8;
-pub(crate) static _ZENITH_26_DEGREES: f64 = 90.0 + 26.0;
-pub(crate) static _ZENITH_4_POINT_37: f64 = 90.0 + 4.37;
-pub(crate) static _ZENITH_4_POINT_61: f64 = 90.0 + 4.61;
-pub(crate) static _ZENITH_4_POINT_8: f64 = 90.0 + 4.8;
-pub(crate) static _ZENITH_3_POINT_65: f64 = 90.0 + 3.65;
-pub(crate) static _ZENITH_3_POINT_676: f64 = 90.0 + 3.676;
-pub(crate) static _ZENITH_5_POINT_88: f64 = 90.0 + 5.88;
-pub(crate) static _ZENITH_1_POINT_583: f64 = 90.0 + 1.583;
-pub(crate) static _ZENITH_16_POINT_9: f64 = 90.0 + 16.9;
-pub(crate) static _ZENITH_6_DEGREES: f64 = 90.0 + 6.0;
-pub(crate) static _ZENITH_6_POINT_45: f64 = 90.0 + 6.45;
-pub(crate) static _ZENITH_7_POINT_65: f64 = 90.0 + 7.65;
-pub(crate) static _ZENITH_7_POINT_67: f64 = 90.0 + 7.67;
-pub(crate) static _ZENITH_9_POINT_3: f64 = 90.0 + 9.3;
-pub(crate) static _ZENITH_9_POINT_5: f64 = 90.0 + 9.5;
-pub(crate) static _ZENITH_9_POINT_75: f64 = 90.0 + 9.75;
-pub(crate) static _ZENITH_MINUS_2_POINT_1: f64 = 90.0 - 2.1;
-pub(crate) static _ZENITH_MINUS_2_POINT_8: f64 = 90.0 - 2.8;
-pub(crate) static _ZENITH_MINUS_3_POINT_05: f64 = 90.0 - 3.05;
-pub(crate) static _CHALAKIM_PER_MINUTE: i64 = 18;
-pub(crate) static _CHALAKIM_PER_HOUR: i64 = 1080;
-pub(crate) static _CHALAKIM_PER_DAY: i64 = 25920;
-pub(crate) static _CHALAKIM_PER_MONTH: i64 = 765433;
-pub(crate) static _CHALAKIM_MOLAD_TOHU: i64 = 31524;
+pub(crate) static _ZENITH_16_POINT_1: f64 = crate::zenith::DEGREES_16_POINT_1;
+pub(crate) static _ZENITH_8_POINT_5: f64 = crate::zenith::DEGREES_8_POINT_5;
+pub(crate) static _ZENITH_3_POINT_7: f64 = crate::zenith::DEGREES_3_POINT_7;
+pub(crate) static _ZENITH_3_POINT_8: f64 = crate::zenith::DEGREES_3_POINT_8;
+pub(crate) static _ZENITH_5_POINT_95: f64 = crate::zenith::DEGREES_5_POINT_95;
+pub(crate) static _ZENITH_7_POINT_083: f64 = crate::zenith::DEGREES_7_POINT_083;
+pub(crate) static _ZENITH_10_POINT_2: f64 = crate::zenith::DEGREES_10_POINT_2;
+pub(crate) static _ZENITH_11_DEGREES: f64 = crate::zenith::DEGREES_11;
+pub(crate) static _ZENITH_11_POINT_5: f64 = crate::zenith::DEGREES_11_POINT_5;
+pub(crate) static _ZENITH_13_POINT_24: f64 = crate::zenith::DEGREES_13_POINT_24;
+pub(crate) static _ZENITH_19_DEGREES: f64 = crate::zenith::DEGREES_19;
+pub(crate) static _ZENITH_19_POINT_8: f64 = crate::zenith::DEGREES_19_POINT_8;
+pub(crate) static _ZENITH_26_DEGREES: f64 = crate::zenith::DEGREES_26;
+pub(crate) static _ZENITH_4_POINT_37: f64 = crate::zenith::DEGREES_4_POINT_37;
+pub(crate) static _ZENITH_4_POINT_61: f64 = crate::zenith::DEGREES_4_POINT_61;
+pub(crate) static _ZENITH_4_POINT_8: f64 = crate::zenith::DEGREES_4_POINT_8;
+pub(crate) static _ZENITH_3_POINT_65: f64 = crate::zenith::DEGREES_3_POINT_65;
+pub(crate) static _ZENITH_3_POINT_676: f64 = crate::zenith::DEGREES_3_POINT_676;
+pub(crate) static _ZENITH_5_POINT_88: f64 = crate::zenith::DEGREES_5_POINT_88;
+pub(crate) static _ZENITH_1_POINT_583: f64 = crate::zenith::DEGREES_1_POINT_583;
+pub(crate) static _ZENITH_16_POINT_9: f64 = crate::zenith::DEGREES_16_POINT_9;
+pub(crate) static _ZENITH_6_DEGREES: f64 = crate::zenith::DEGREES_6;
+pub(crate) static _ZENITH_6_POINT_45: f64 = crate::zenith::DEGREES_6_POINT_45;
+pub(crate) static _ZENITH_7_POINT_65: f64 = crate::zenith::DEGREES_7_POINT_65;
+pub(crate) static _ZENITH_7_POINT_67: f64 = crate::zenith::DEGREES_7_POINT_67;
+pub(crate) static _ZENITH_9_POINT_3: f64 = crate::zenith::DEGREES_9_POINT_3;
+pub(crate) static _ZENITH_9_POINT_5: f64 = crate::zenith::DEGREES_9_POINT_5;
+pub(crate) static _ZENITH_9_POINT_75: f64 = crate::zenith::DEGREES_9_POINT_75;
+pub(crate) static _ZENITH_MINUS_2_POINT_1: f64 = crate::zenith::DEGREES_MINUS_2_POINT_1;
+pub(crate) static _ZENITH_MINUS_2_POINT_8: f64 = crate::zenith::DEGREES_MINUS_2_POINT_8;
+pub(crate) static _ZENITH_MINUS_3_POINT_05: f64 = crate::zenith::DEGREES_MINUS_3_POINT_05;
+pub(crate) static _CHALAKIM_PER_MINUTE: i64 = crate::molad::CHALAKIM_PER_MINUTE;
+pub(crate) static _CHALAKIM_PER_HOUR: i64 = crate::molad::CHALAKIM_PER_HOUR;
+pub(crate) static _CHALAKIM_PER_DAY: i64 = crate::molad::CHALAKIM_PER_DAY;
+pub(crate) static _CHALAKIM_PER_MONTH: i64 = crate::molad::CHALAKIM_PER_MONTH;
+pub(crate) static _CHALAKIM_MOLAD_TOHU: i64 = crate::molad::CHALAKIM_MOLAD_TOHU;
 pub(crate) static _JEWISH_EPOCH: i64 = -1373429;
 pub(crate) static _MINUTE_MILLIS: i64 = 60 * 1000;
 pub(crate) static _HOUR_MILLIS: i64 = 60 * 1000 * 60;
@@ -55,13 +56,23 @@ pub(crate) static _YERUSHALMI_DAF_YOMI_START_DAY: DateTime<Utc> =
     DateTime::from_timestamp_millis(318297600000).unwrap();
 pub(crate) static _YERUSHALMI_LENGTH: u64 = 1554;
 
+/// The event a time of day was calculated for, which determines the date it falls on when a
+/// time in UTC hours is converted to a date and time.
+///
+/// Near the antimeridian a UTC time can belong to the previous or next day, so each event is
+/// placed on the day it must occur: sunrise in the morning, sunset in the evening, solar noon
+/// during the day and solar midnight after the day.
 #[cfg_attr(feature = "defmt", derive(defmt::Format))]
 #[derive(Debug, PartialEq, Eq, Clone, Copy, IntoPrimitive, TryFromPrimitive)]
 #[repr(u8)]
-pub enum _SolarEvent {
+pub enum SolarEvent {
+    /// Sunrise, or any time the sun reaches a zenith in the morning.
     Sunrise = 0,
+    /// Sunset, or any time the sun reaches a zenith in the evening.
     Sunset = 1,
+    /// Solar noon, when the sun crosses the meridian.
     Noon = 2,
+    /// Solar midnight, 12 hours after solar noon.
     Midnight = 3,
 }
 #[cfg_attr(feature = "defmt", derive(defmt::Format))]
@@ -73,6 +84,7 @@ pub enum _Formula {
     FinalBearing = 2,
 }
 #[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Debug, PartialEq, Eq, Clone, Copy, IntoPrimitive, TryFromPrimitive)]
 #[repr(u8)]
 pub enum Parsha {
@@ -148,6 +160,66 @@ pub enum Parsha {
     Nachamu = 69,
 }
 impl Parsha {
+    /// Returns an iterator over the 54 parshiyos in reading order, from Bereshis to Vezos Habracha.
+    /// Combined parshiyos and special shabbosos are not included.
+    pub fn standard_sequence() -> impl DoubleEndedIterator<Item = Parsha> + Clone {
+        (Parsha::Bereshis as u8..=Parsha::VezosHabracha as u8).filter_map(|index| Parsha::try_from(index).ok())
+    }
+
+    /// Returns the two parshiyos that make up a combined parsha, or `None` if this parsha is not combined.
+    pub fn combined_parts(&self) -> Option<(Parsha, Parsha)> {
+        match self {
+            Parsha::VayakhelPekudei => Some((Parsha::Vayakhel, Parsha::Pekudei)),
+            Parsha::TazriaMetzora => Some((Parsha::Tazria, Parsha::Metzora)),
+            Parsha::AchreiMosKedoshim => Some((Parsha::AchreiMos, Parsha::Kedoshim)),
+            Parsha::BeharBechukosai => Some((Parsha::Behar, Parsha::Bechukosai)),
+            Parsha::ChukasBalak => Some((Parsha::Chukas, Parsha::Balak)),
+            Parsha::MatosMasei => Some((Parsha::Matos, Parsha::Masei)),
+            Parsha::NitzavimVayeilech => Some((Parsha::Nitzavim, Parsha::Vayeilech)),
+            _ => None,
+        }
+    }
+
+    /// Returns true if this is one of the special shabbosos (Shekalim, Zachor, Hagadol, etc.).
+    pub fn is_special_shabbos(&self) -> bool {
+        *self as u8 >= Parsha::Shekalim as u8
+    }
+
+    /// Returns the parsha that follows this one in the reading order. For a combined parsha this
+    /// is the parsha after its second part, and Vezos Habracha is followed by Bereshis.
+    /// Returns `None` for special shabbosos, which are not part of the sequence.
+    pub fn next(&self) -> Option<Parsha> {
+        if self.is_special_shabbos() {
+            return None;
+        }
+        let last = match self.combined_parts() {
+            Some((_, second)) => second,
+            None => *self,
+        };
+        match last {
+            Parsha::VezosHabracha => Some(Parsha::Bereshis),
+            _ => Parsha::try_from(last as u8 + 1).ok(),
+        }
+    }
+
+    /// Returns the parsha that precedes this one in the reading order. For a combined parsha this
+    /// is the parsha before its first part, and Bereshis is preceded by Vezos Habracha.
+    /// Returns `None` for special shabbosos, which are not part of the sequence.
+    pub fn prev(&self) -> Option<Parsha> {
+        if self.is_special_shabbos() {
+            return None;
+        }
+        let first = match self.combined_parts() {
+            Some((first, _)) => first,
+            None => *self,
+        };
+        match first {
+            Parsha::Bereshis => Some(Parsha::VezosHabracha),
+            _ => Parsha::try_from(first as u8 - 1).ok(),
+        }
+    }
+
+    #[cfg(feature = "names")]
     pub fn en_string(&self) -> &str {
         match self {
             Parsha::Bereshis => "Bereshis",
@@ -222,6 +294,7 @@ impl Parsha {
             Parsha::Nachamu => "Nachamu",
         }
     }
+    #[cfg(feature = "names")]
     pub fn he_string(&self) -> &str {
         match self {
             Parsha::Bereshis => "בראשית",
@@ -296,8 +369,38 @@ impl Parsha {
             Parsha::Nachamu => "נחמו",
         }
     }
+    /// Returns the Yiddish name. Yiddish uses the Hebrew names of the parshiyos.
+    #[cfg(feature = "names")]
+    pub fn yi_string(&self) -> &str {
+        match self {
+            Parsha::VezosHabracha => "וזאת הברכה",
+            _ => self.he_string(),
+        }
+    }
+}
+/// A broad classification of [`JewishHoliday`]s, useful for filtering or coloring events.
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub enum HolidayCategory {
+    /// A Yom Tov on which melacha is forbidden, including Yom Kippur.
+    YomTov,
+    /// Chol Hamoed, including Hoshana Rabbah.
+    CholHamoed,
+    /// The day before a Yom Tov or Chanukah.
+    Erev,
+    /// A rabbinic or customary fast day.
+    Fast,
+    /// Chanukah, Purim and Shushan Purim.
+    Rabbinic,
+    /// Minor days such as Tu B'Shvat, Lag Ba'omer and Pesach Sheni.
+    Minor,
+    RoshChodesh,
+    /// The modern Israeli holidays.
+    Modern,
 }
+
 #[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Debug, PartialEq, Eq, Clone, Copy, IntoPrimitive, TryFromPrimitive)]
 #[repr(u8)]
 pub enum JewishHoliday {
@@ -341,6 +444,80 @@ pub enum JewishHoliday {
     Behab = 37,
 }
 impl JewishHoliday {
+    /// Returns the broad category this holiday falls into. Yom Kippur is categorized as a
+    /// Yom Tov, use [`JewishHoliday::is_fast_day`] to find all fasts.
+    pub fn category(&self) -> HolidayCategory {
+        match self {
+            JewishHoliday::Pesach
+            | JewishHoliday::Shavuos
+            | JewishHoliday::RoshHashana
+            | JewishHoliday::YomKippur
+            | JewishHoliday::Succos
+            | JewishHoliday::SheminiAtzeres
+            | JewishHoliday::SimchasTorah => HolidayCategory::YomTov,
+            JewishHoliday::CholHamoedPesach | JewishHoliday::CholHamoedSuccos | JewishHoliday::HoshanaRabbah => {
+                HolidayCategory::CholHamoed
+            }
+            JewishHoliday::ErevPesach
+            | JewishHoliday::ErevShavuos
+            | JewishHoliday::ErevRoshHashana
+            | JewishHoliday::ErevYomKippur
+            | JewishHoliday::ErevSuccos
+            | JewishHoliday::ErevChanukah => HolidayCategory::Erev,
+            JewishHoliday::SeventeenthOfTammuz
+            | JewishHoliday::TishahBav
+            | JewishHoliday::FastOfGedalyah
+            | JewishHoliday::TenthOfTeves
+            | JewishHoliday::FastOfEsther
+            | JewishHoliday::YomKippurKatan
+            | JewishHoliday::Behab => HolidayCategory::Fast,
+            JewishHoliday::Chanukah | JewishHoliday::Purim | JewishHoliday::ShushanPurim => HolidayCategory::Rabbinic,
+            JewishHoliday::PesachSheni
+            | JewishHoliday::TuBav
+            | JewishHoliday::TuBshvat
+            | JewishHoliday::PurimKatan
+            | JewishHoliday::ShushanPurimKatan
+            | JewishHoliday::LagBomer
+            | JewishHoliday::IsruChag => HolidayCategory::Minor,
+            JewishHoliday::RoshChodesh => HolidayCategory::RoshChodesh,
+            JewishHoliday::YomHaShoah
+            | JewishHoliday::YomHazikaron
+            | JewishHoliday::YomHaatzmaut
+            | JewishHoliday::YomYerushalayim => HolidayCategory::Modern,
+        }
+    }
+
+    /// Returns true if this is a Yom Tov on which melacha (work) is forbidden.
+    pub fn is_yom_tov_assur_bemelacha(&self) -> bool {
+        self.category() == HolidayCategory::YomTov
+    }
+
+    /// Returns true if this is a fast day, including Yom Kippur and the customary fasts of
+    /// Yom Kippur Katan and BeHaB.
+    pub fn is_fast_day(&self) -> bool {
+        *self == JewishHoliday::YomKippur || self.category() == HolidayCategory::Fast
+    }
+
+    /// Returns true if this observance was instituted by the Sages: Chanukah, Purim and the fasts
+    /// other than Yom Kippur. The customary fasts of [`JewishHoliday::is_custom`] are not, and
+    /// neither is Rosh Chodesh, which is from the Torah.
+    pub fn is_rabbinic(&self) -> bool {
+        matches!(self.category(), HolidayCategory::Rabbinic | HolidayCategory::Fast) && !self.is_custom()
+    }
+
+    /// Returns true if this is an observance kept by custom rather than instituted by the Sages:
+    /// the fasts of Yom Kippur Katan and BeHaB.
+    pub fn is_custom(&self) -> bool {
+        matches!(self, JewishHoliday::YomKippurKatan | JewishHoliday::Behab)
+    }
+
+    /// Returns true if this is one of the modern Israeli holidays, which are only returned by the
+    /// calendar when `use_modern_holidays` is set.
+    pub fn is_modern(&self) -> bool {
+        self.category() == HolidayCategory::Modern
+    }
+
+    #[cfg(feature = "names")]
     pub fn en_string(&self) -> &str {
         match self {
             JewishHoliday::ErevPesach => "Erev Pesach",
@@ -383,6 +560,7 @@ impl JewishHoliday {
             JewishHoliday::Behab => "Behab",
         }
     }
+    #[cfg(feature = "names")]
     pub fn he_string(&self) -> &str {
         match self {
             JewishHoliday::ErevPesach => "ערב פסח",
@@ -425,9 +603,54 @@ impl JewishHoliday {
             JewishHoliday::Behab => "יום כיפור קטן",
         }
     }
+    /// Returns the Yiddish name.
+    #[cfg(feature = "names")]
+    pub fn yi_string(&self) -> &str {
+        match self {
+            JewishHoliday::ErevPesach => "ערב פסח",
+            JewishHoliday::Pesach => "פסח",
+            JewishHoliday::CholHamoedPesach => "חול המועד פסח",
+            JewishHoliday::PesachSheni => "פסח שני",
+            JewishHoliday::ErevShavuos => "ערב שבועות",
+            JewishHoliday::Shavuos => "שבועות",
+            JewishHoliday::SeventeenthOfTammuz => "שבעה עשר בתמוז",
+            JewishHoliday::TishahBav => "תשעה באב",
+            JewishHoliday::TuBav => "חמשה עשר באב",
+            JewishHoliday::ErevRoshHashana => "ערב ראש השנה",
+            JewishHoliday::RoshHashana => "ראש השנה",
+            JewishHoliday::FastOfGedalyah => "צום גדליה",
+            JewishHoliday::ErevYomKippur => "ערב יום כיפור",
+            JewishHoliday::YomKippur => "יום כיפור",
+            JewishHoliday::ErevSuccos => "ערב סוכות",
+            JewishHoliday::Succos => "סוכות",
+            JewishHoliday::CholHamoedSuccos => "חול המועד סוכות",
+            JewishHoliday::HoshanaRabbah => "הושענא רבה",
+            JewishHoliday::SheminiAtzeres => "שמיני עצרת",
+            JewishHoliday::SimchasTorah => "שמחת תורה",
+            JewishHoliday::ErevChanukah => "ערב חנוכה",
+            JewishHoliday::Chanukah => "חנוכה",
+            JewishHoliday::TenthOfTeves => "עשרה בטבת",
+            JewishHoliday::TuBshvat => "חמשה עשר בשבט",
+            JewishHoliday::FastOfEsther => "תענית אסתר",
+            JewishHoliday::Purim => "פורים",
+            JewishHoliday::ShushanPurim => "שושן פורים",
+            JewishHoliday::PurimKatan => "פורים קטן",
+            JewishHoliday::RoshChodesh => "ראש חודש",
+            JewishHoliday::YomHaShoah => "יום השואה",
+            JewishHoliday::YomHazikaron => "יום הזכרון",
+            JewishHoliday::YomHaatzmaut => "יום העצמאות",
+            JewishHoliday::YomYerushalayim => "יום ירושלים",
+            JewishHoliday::LagBomer => "ל״ג בעומר",
+            JewishHoliday::ShushanPurimKatan => "שושן פורים קטן",
+            JewishHoliday::IsruChag => "איסרו חג",
+            JewishHoliday::YomKippurKatan => "יום כיפור קטן",
+            JewishHoliday::Behab => "בה״ב",
+        }
+    }
 }
 
 #[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Debug, PartialEq, Eq, Clone, Copy, IntoPrimitive, TryFromPrimitive, PartialOrd, Ord)]
 #[repr(u8)]
 pub enum JewishMonth {
@@ -447,7 +670,9 @@ pub enum JewishMonth {
 }
 
 impl JewishMonth {
-    pub(crate) fn next(&self, is_leap_year: bool) -> JewishMonth {
+    /// Returns the month that follows this one. Adar is followed by Adar II in a leap year
+    /// and by Nissan otherwise, and Elul is followed by Tishrei of the next year.
+    pub fn next_month(&self, is_leap_year: bool) -> JewishMonth {
         match self {
             JewishMonth::Nissan => Self::Iyar,
             JewishMonth::Iyar => Self::Sivan,
@@ -471,6 +696,105 @@ impl JewishMonth {
         }
     }
 
+    /// Returns the month that precedes this one. Nissan is preceded by Adar II in a leap year
+    /// and by Adar otherwise, and Tishrei is preceded by Elul of the previous year.
+    pub fn prev_month(&self, is_leap_year: bool) -> JewishMonth {
+        match self {
+            JewishMonth::Nissan => {
+                if is_leap_year {
+                    Self::AdarII
+                } else {
+                    Self::Adar
+                }
+            }
+            JewishMonth::Iyar => Self::Nissan,
+            JewishMonth::Sivan => Self::Iyar,
+            JewishMonth::Tammuz => Self::Sivan,
+            JewishMonth::Av => Self::Tammuz,
+            JewishMonth::Elul => Self::Av,
+            JewishMonth::Tishrei => Self::Elul,
+            JewishMonth::Cheshvan => Self::Tishrei,
+            JewishMonth::Kislev => Self::Cheshvan,
+            JewishMonth::Teves => Self::Kislev,
+            JewishMonth::Shevat => Self::Teves,
+            JewishMonth::Adar => Self::Shevat,
+            JewishMonth::AdarII => Self::Adar,
+        }
+    }
+
+    /// Returns the 1-based position of this month in the year, counting from Tishrei,
+    /// or `None` for Adar II in a non-leap year.
+    pub fn position_in_year(&self, is_leap_year: bool) -> Option<u8> {
+        let month = *self as u8;
+        if *self == JewishMonth::AdarII && !is_leap_year {
+            None
+        } else if month >= JewishMonth::Tishrei as u8 {
+            Some(month - 6)
+        } else {
+            Some(month + 6 + is_leap_year as u8)
+        }
+    }
+
+    /// Returns an iterator over the months of a year in calendar order, from Tishrei to Elul.
+    ///
+    /// ```
+    /// use jew_sdk::prelude::JewishMonth;
+    ///
+    /// let months: Vec<_> = JewishMonth::months_of_year(true).collect();
+    /// assert_eq!(months.len(), 13);
+    /// assert_eq!(months[5], JewishMonth::Adar);
+    /// assert_eq!(months[6], JewishMonth::AdarII);
+    /// assert_eq!(months[7], JewishMonth::Nissan);
+    /// ```
+    pub fn months_of_year(is_leap_year: bool) -> JewishMonthIter {
+        Self::range_inclusive(JewishMonth::Tishrei, JewishMonth::Elul, is_leap_year)
+    }
+
+    /// Returns an iterator over the months from `start` up to, but not including, `end` in calendar
+    /// order. The iterator is empty if `start` does not come before `end` in the year.
+    ///
+    /// ```
+    /// use jew_sdk::prelude::JewishMonth;
+    ///
+    /// let months: Vec<_> = JewishMonth::range(JewishMonth::Shevat, JewishMonth::Iyar, false).collect();
+    /// assert_eq!(months, [JewishMonth::Shevat, JewishMonth::Adar, JewishMonth::Nissan]);
+    /// ```
+    pub fn range(start: JewishMonth, end: JewishMonth, is_leap_year: bool) -> JewishMonthIter {
+        let done = match (start.position_in_year(is_leap_year), end.position_in_year(is_leap_year)) {
+            (Some(start), Some(end)) => start >= end,
+            _ => true,
+        };
+        JewishMonthIter {
+            front: start,
+            back: end.prev_month(is_leap_year),
+            is_leap_year,
+            done,
+        }
+    }
+
+    /// Returns an iterator over the months from `start` through `end` in calendar order.
+    /// The iterator is empty if `start` comes after `end` in the year.
+    ///
+    /// ```
+    /// use jew_sdk::prelude::JewishMonth;
+    ///
+    /// let months: Vec<_> = JewishMonth::range_inclusive(JewishMonth::Adar, JewishMonth::Nissan, true).collect();
+    /// assert_eq!(months, [JewishMonth::Adar, JewishMonth::AdarII, JewishMonth::Nissan]);
+    /// ```
+    pub fn range_inclusive(start: JewishMonth, end: JewishMonth, is_leap_year: bool) -> JewishMonthIter {
+        let done = match (start.position_in_year(is_leap_year), end.position_in_year(is_leap_year)) {
+            (Some(start), Some(end)) => start > end,
+            _ => true,
+        };
+        JewishMonthIter {
+            front: start,
+            back: end,
+            is_leap_year,
+            done,
+        }
+    }
+
+    #[cfg(feature = "names")]
     pub fn en_string(&self, is_leap_year: bool) -> &str {
         match self {
             JewishMonth::Nissan => "Nissan",
@@ -494,6 +818,7 @@ impl JewishMonth {
             JewishMonth::AdarII => "Adar II",
         }
     }
+    #[cfg(feature = "names")]
     pub fn he_string(&self, is_leap_year: bool) -> &str {
         match self {
             JewishMonth::Nissan => "ניסן",
@@ -517,7 +842,74 @@ impl JewishMonth {
             JewishMonth::AdarII => "אדר ב",
         }
     }
+    /// Returns the Yiddish name.
+    #[cfg(feature = "names")]
+    pub fn yi_string(&self, is_leap_year: bool) -> &str {
+        match self {
+            JewishMonth::Nissan => "ניסן",
+            JewishMonth::Iyar => "אייר",
+            JewishMonth::Sivan => "סיון",
+            JewishMonth::Tammuz => "תמוז",
+            JewishMonth::Av => "מנחם אב",
+            JewishMonth::Elul => "אלול",
+            JewishMonth::Tishrei => "תשרי",
+            JewishMonth::Cheshvan => "מרחשון",
+            JewishMonth::Kislev => "כסלו",
+            JewishMonth::Teves => "טבת",
+            JewishMonth::Shevat => "שבט",
+            JewishMonth::Adar => {
+                if is_leap_year {
+                    "אדר ראשון"
+                } else {
+                    "אדר"
+                }
+            }
+            JewishMonth::AdarII => "אדר שני",
+        }
+    }
 }
+/// An iterator over Jewish months in calendar order, created by [`JewishMonth::range`],
+/// [`JewishMonth::range_inclusive`] and [`JewishMonth::months_of_year`].
+#[derive(Debug, Clone)]
+pub struct JewishMonthIter {
+    front: JewishMonth,
+    back: JewishMonth,
+    is_leap_year: bool,
+    done: bool,
+}
+
+impl Iterator for JewishMonthIter {
+    type Item = JewishMonth;
+
+    fn next(&mut self) -> Option<JewishMonth> {
+        if self.done {
+            return None;
+        }
+        let month = self.front;
+        if month == self.back {
+            self.done = true;
+        } else {
+            self.front = month.next_month(self.is_leap_year);
+        }
+        Some(month)
+    }
+}
+
+impl DoubleEndedIterator for JewishMonthIter {
+    fn next_back(&mut self) -> Option<JewishMonth> {
+        if self.done {
+            return None;
+        }
+        let month = self.back;
+        if month == self.front {
+            self.done = true;
+        } else {
+            self.back = month.prev_month(self.is_leap_year);
+        }
+        Some(month)
+    }
+}
+
 #[derive(Debug, PartialEq, Eq, Clone, Copy, IntoPrimitive, TryFromPrimitive)]
 #[repr(u8)]
 pub enum YearLengthType {
@@ -526,6 +918,7 @@ pub enum YearLengthType {
     Shelaimim = 2,
 }
 impl YearLengthType {
+    #[cfg(feature = "names")]
     pub fn en_string(&self) -> &str {
         match self {
             YearLengthType::Chaserim => "Chaserim",
@@ -533,6 +926,7 @@ impl YearLengthType {
             YearLengthType::Shelaimim => "Shelaimim",
         }
     }
+    #[cfg(feature = "names")]
     pub fn he_string(&self) -> &str {
         match self {
             YearLengthType::Chaserim => "חסרים",
@@ -541,7 +935,53 @@ impl YearLengthType {
         }
     }
 }
+
+/// Names for the days of the week, to go with the names of the other calendar types.
+#[cfg(feature = "names")]
+pub trait WeekdayStrings {
+    fn en_string(&self) -> &str;
+    fn he_string(&self) -> &str;
+    fn yi_string(&self) -> &str;
+}
+
+#[cfg(feature = "names")]
+impl WeekdayStrings for chrono::Weekday {
+    fn en_string(&self) -> &str {
+        match self {
+            chrono::Weekday::Sun => "Sunday",
+            chrono::Weekday::Mon => "Monday",
+            chrono::Weekday::Tue => "Tuesday",
+            chrono::Weekday::Wed => "Wednesday",
+            chrono::Weekday::Thu => "Thursday",
+            chrono::Weekday::Fri => "Friday",
+            chrono::Weekday::Sat => "Shabbos",
+        }
+    }
+    fn he_string(&self) -> &str {
+        match self {
+            chrono::Weekday::Sun => "ראשון",
+            chrono::Weekday::Mon => "שני",
+            chrono::Weekday::Tue => "שלישי",
+            chrono::Weekday::Wed => "רביעי",
+            chrono::Weekday::Thu => "חמישי",
+            chrono::Weekday::Fri => "שישי",
+            chrono::Weekday::Sat => "שבת",
+        }
+    }
+    fn yi_string(&self) -> &str {
+        match self {
+            chrono::Weekday::Sun => "זונטיק",
+            chrono::Weekday::Mon => "מאנטיק",
+            chrono::Weekday::Tue => "דינסטיק",
+            chrono::Weekday::Wed => "מיטוואך",
+            chrono::Weekday::Thu => "דאנערשטיק",
+            chrono::Weekday::Fri => "פרייטיק",
+            chrono::Weekday::Sat => "שבת",
+        }
+    }
+}
 #[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Debug, PartialEq, Eq, Clone, Copy, PartialOrd, Ord, IntoPrimitive, TryFromPrimitive)]
 #[repr(u8)]
 pub enum BavliTractate {
@@ -587,6 +1027,7 @@ pub enum BavliTractate {
     Niddah = 39,
 }
 impl BavliTractate {
+    #[cfg(feature = "names")]
     pub fn en_string(&self) -> &str {
         match self {
             BavliTractate::Berachos => "Berachos",
@@ -631,6 +1072,7 @@ impl BavliTractate {
             BavliTractate::Niddah => "Niddah",
         }
     }
+    #[cfg(feature = "names")]
     pub fn he_string(&self) -> &str {
         match self {
             BavliTractate::Berachos => "ברכות",
@@ -721,6 +1163,7 @@ pub enum YerushalmiTractate {
     Nidah = 38,
 }
 impl YerushalmiTractate {
+    #[cfg(feature = "names")]
     pub fn en_string(&self) -> &str {
         match self {
             YerushalmiTractate::Berachos => "Berachos",
@@ -764,6 +1207,7 @@ impl YerushalmiTractate {
             YerushalmiTractate::Nidah => "Nidah",
         }
     }
+    #[cfg(feature = "names")]
     pub fn he_string(&self) -> &str {
         match self {
             YerushalmiTractate::Berachos => "ברכות",
@@ -969,53 +1413,55 @@ impl YerushalmiTractate {
 //     }
 // }
 #[cfg_attr(feature = "defmt", derive(defmt::Format))]
-#[derive(Eq, Hash, PartialEq, Debug, Clone, Copy)]
+#[derive(Eq, Hash, PartialEq, Debug, Clone, Copy, PartialOrd, Ord)]
 #[repr(u16)]
 pub enum Zman {
-    // Alos16Point1Degrees,
-    // Alos18Degrees,
-    // Alos19Degrees,
-    // Alos19Point8Degrees,
-    // Alos60,
+    Alos120,
+    Alos120Zmanis,
+    Alos18Degrees,
+    Alos19Degrees,
+    Alos19Point8Degrees,
+    Alos26Degrees,
+    Alos60,
     Alos72,
-    // Alos72Zmanis,
-    // Alos90,
-    // Alos90Zmanis,
-    // Alos96,
-    // Alos96Zmanis,
+    Alos72Zmanis,
+    Alos90,
+    Alos90Zmanis,
+    Alos96,
+    Alos96Zmanis,
     // AlosBaalHatanya,
     AlosHashachar,
-    // BainHashmashosRT13Point24Degrees,
-    // BainHashmashosRT13Point5MinutesBefore7Point083Degrees,
-    // BainHashmashosRT2Stars,
-    // BainHashmashosRT58Point5Minutes,
-    // BainHashmashosYereim13Point5Minutes,
-    // BainHashmashosYereim16Point875Minutes,
-    // BainHashmashosYereim18Minutes,
-    // BainHashmashosYereim2Point1Degrees,
-    // BainHashmashosYereim2Point8Degrees,
-    // BainHashmashosYereim3Point05Degrees,
+    BainHashmashosRT13Point24Degrees,
+    BainHashmashosRT13Point5MinutesBefore7Point083Degrees,
+    BainHashmashosRT2Stars,
+    BainHashmashosRT58Point5Minutes,
+    BainHashmashosYereim13Point5Minutes,
+    BainHashmashosYereim16Point875Minutes,
+    BainHashmashosYereim18Minutes,
+    BainHashmashosYereim2Point1Degrees,
+    BainHashmashosYereim2Point8Degrees,
+    BainHashmashosYereim3Point05Degrees,
     CandleLighting,
     Chatzos,
     ChatzosAsHalfDay,
     // FixedLocalChatzos,
     MinchaGedola,
-    // MinchaGedola16Point1Degrees,
-    // MinchaGedola30Minutes,
+    MinchaGedola16Point1Degrees,
+    MinchaGedola30Minutes,
     // MinchaGedola72Minutes,
     // MinchaGedolaAhavatShalom,
-    // MinchaGedolaAteretTorah,
+    MinchaGedolaAteretTorah,
     // MinchaGedolaBaalHatanya,
     // MinchaGedolaBaalHatanyaGreaterThan30,
     // MinchaGedolaGRAFixedLocalChatzos30Minutes,
-    // MinchaGedolaGreaterThan30,
+    MinchaGedolaGreaterThan30,
     MinchaKetana,
-    // MinchaKetana16Point1Degrees,
-    // MinchaKetana72Minutes,
+    MinchaKetana16Point1Degrees,
+    MinchaKetana72Minutes,
     // MinchaKetanaAhavatShalom,
-    // MinchaKetanaAteretTorah,
+    MinchaKetanaAteretTorah,
     // MinchaKetanaBaalHatanya,
-    // MinchaKetanaGRAFixedLocalChatzosToSunset,
+    MinchaKetanaGRAFixedLocalChatzosToSunset,
     // Misheyakir10Point2Degrees,
     // Misheyakir11Degrees,
     // Misheyakir11Point5Degrees,
@@ -1026,7 +1472,7 @@ pub enum Zman {
     // PlagAlos16Point1ToTzaisGeonim7Point083Degrees,
     PlagHamincha,
     // PlagHamincha60Minutes,
-    // PlagHaminchaAteretTorah,
+    PlagHaminchaAteretTorah,
     // PlagHaminchaBaalHatanya,
     // PlagHaminchaGRAFixedLocalChatzosToSunset,
     // SamuchLeMinchaKetana16Point1Degrees,
@@ -1047,74 +1493,615 @@ pub enum Zman {
     // SofZmanShma3HoursBeforeChatzos,
     // SofZmanShmaAlos16Point1ToSunset,
     // SofZmanShmaAlos16Point1ToTzaisGeonim7Point083Degrees,
-    // SofZmanShmaAteretTorah,
+    SofZmanShmaAteretTorah,
     // SofZmanShmaBaalHatanya,
     SofZmanShmaGRA,
     // SofZmanShmaGRASunriseToFixedLocalChatzos,
     SofZmanShmaMGA,
-    // SofZmanShmaMGA120Minutes,
-    // SofZmanShmaMGA16Point1Degrees,
+    SofZmanShmaMGA120Minutes,
+    SofZmanShmaMGA16Point1Degrees,
     // SofZmanShmaMGA16Point1DegreesToFixedLocalChatzos,
-    // SofZmanShmaMGA18Degrees,
+    SofZmanShmaMGA18Degrees,
     // SofZmanShmaMGA18DegreesToFixedLocalChatzos,
-    // SofZmanShmaMGA19Point8Degrees,
-    // SofZmanShmaMGA72Minutes,
+    SofZmanShmaMGA19Point8Degrees,
     // SofZmanShmaMGA72MinutesToFixedLocalChatzos,
-    // SofZmanShmaMGA72MinutesZmanis,
-    // SofZmanShmaMGA90Minutes,
+    SofZmanShmaMGA72MinutesZmanis,
+    SofZmanShmaMGA90Minutes,
     // SofZmanShmaMGA90MinutesToFixedLocalChatzos,
-    // SofZmanShmaMGA90MinutesZmanis,
-    // SofZmanShmaMGA96Minutes,
-    // SofZmanShmaMGA96MinutesZmanis,
-    // SofZmanTfila2HoursBeforeChatzos,
-    // SofZmanTfilaAteretTorah,
+    SofZmanShmaMGA90MinutesZmanis,
+    SofZmanShmaMGA96Minutes,
+    SofZmanShmaMGA96MinutesZmanis,
+    SofZmanTfila2HoursBeforeChatzos,
+    SofZmanTfilaAteretTorah,
     // SofZmanTfilaBaalHatanya,
     SofZmanTfilaGRA,
     // SofZmanTfilaGRASunriseToFixedLocalChatzos,
     SofZmanTfilaMGA,
-    // SofZmanTfilaMGA120Minutes,
-    // SofZmanTfilaMGA16Point1Degrees,
-    // SofZmanTfilaMGA18Degrees,
-    // SofZmanTfilaMGA19Point8Degrees,
-    // SofZmanTfilaMGA72Minutes,
-    // SofZmanTfilaMGA72MinutesZmanis,
-    // SofZmanTfilaMGA90Minutes,
-    // SofZmanTfilaMGA90MinutesZmanis,
-    // SofZmanTfilaMGA96Minutes,
-    // SofZmanTfilaMGA96MinutesZmanis,
+    SofZmanTfilaMGA120Minutes,
+    SofZmanTfilaMGA16Point1Degrees,
+    SofZmanTfilaMGA18Degrees,
+    SofZmanTfilaMGA19Point8Degrees,
+    SofZmanTfilaMGA72MinutesZmanis,
+    SofZmanTfilaMGA90Minutes,
+    SofZmanTfilaMGA90MinutesZmanis,
+    SofZmanTfilaMGA96Minutes,
+    SofZmanTfilaMGA96MinutesZmanis,
     // TchilasZmanKidushLevana3Days,
     // TchilasZmanKidushLevana7Days,
     Tzais,
-    // Tzais16Point1Degrees,
-    // Tzais18Degrees,
-    // Tzais19Point8Degrees,
-    // Tzais50,
-    // Tzais60,
+    Tzais120,
+    Tzais120Zmanis,
+    Tzais16Point1Degrees,
+    Tzais18Degrees,
+    Tzais19Point8Degrees,
+    Tzais26Degrees,
+    Tzais50,
+    Tzais60,
     Tzais72,
-    // Tzais72Zmanis,
-    // Tzais90,
-    // Tzais90Zmanis,
-    // Tzais96,
-    // Tzais96Zmanis,
-    // TzaisAteretTorah,
+    Tzais72Zmanis,
+    Tzais90,
+    Tzais90Zmanis,
+    Tzais96,
+    Tzais96Zmanis,
+    TzaisAteretTorah,
     // TzaisBaalHatanya,
-    // TzaisGeonim3Point7Degrees,
-    // TzaisGeonim3Point8Degrees,
-    // TzaisGeonim4Point37Degrees,
-    // TzaisGeonim4Point61Degrees,
-    // TzaisGeonim4Point8Degrees,
-    // TzaisGeonim5Point88Degrees,
-    // TzaisGeonim5Point95Degrees,
-    // TzaisGeonim6Point45Degrees,
-    // TzaisGeonim7Point083Degrees,
-    // TzaisGeonim7Point67Degrees,
+    TzaisGeonim3Point65Degrees,
+    TzaisGeonim3Point676Degrees,
+    TzaisGeonim3Point7Degrees,
+    TzaisGeonim3Point8Degrees,
+    TzaisGeonim4Point37Degrees,
+    TzaisGeonim4Point61Degrees,
+    TzaisGeonim4Point8Degrees,
+    TzaisGeonim5Point88Degrees,
+    TzaisGeonim5Point95Degrees,
+    TzaisGeonim6Point45Degrees,
+    TzaisGeonim7Point083Degrees,
+    TzaisGeonim7Point67Degrees,
     // TzaisGeonim8Point5Degrees,
-    // TzaisGeonim9Point3Degrees,
-    // TzaisGeonim9Point75Degrees,
+    TzaisGeonim9Point3Degrees,
+    TzaisGeonim9Point75Degrees,
 }
 
 impl Zman {
-    pub fn values() -> [Zman; 14] {
+    /// The number of zmanim, the length of [`Zman::values`].
+    pub const COUNT: usize = 94;
+
+    #[cfg(feature = "names")]
+    pub fn en_string(&self) -> &str {
+        match self {
+            Zman::Alos120 => "Alos 120 Minutes",
+            Zman::Alos120Zmanis => "Alos 120 Minutes Zmaniyos",
+            Zman::Alos18Degrees => "Alos 18°",
+            Zman::Alos19Degrees => "Alos 19°",
+            Zman::Alos19Point8Degrees => "Alos 19.8°",
+            Zman::Alos26Degrees => "Alos 26°",
+            Zman::Alos60 => "Alos 60 Minutes",
+            Zman::Alos72 => "Alos 72 Minutes",
+            Zman::Alos72Zmanis => "Alos 72 Minutes Zmaniyos",
+            Zman::Alos90 => "Alos 90 Minutes",
+            Zman::Alos90Zmanis => "Alos 90 Minutes Zmaniyos",
+            Zman::Alos96 => "Alos 96 Minutes",
+            Zman::Alos96Zmanis => "Alos 96 Minutes Zmaniyos",
+            Zman::AlosHashachar => "Alos Hashachar",
+            Zman::BainHashmashosRT13Point24Degrees => "Bain Hashmashos Rabbeinu Tam 13.24°",
+            Zman::BainHashmashosRT13Point5MinutesBefore7Point083Degrees => {
+                "Bain Hashmashos Rabbeinu Tam 13.5 Minutes Before 7.083°"
+            }
+            Zman::BainHashmashosRT2Stars => "Bain Hashmashos Rabbeinu Tam 2 Stars",
+            Zman::BainHashmashosRT58Point5Minutes => "Bain Hashmashos Rabbeinu Tam 58.5 Minutes",
+            Zman::BainHashmashosYereim13Point5Minutes => "Bain Hashmashos Yereim 13.5 Minutes",
+            Zman::BainHashmashosYereim16Point875Minutes => "Bain Hashmashos Yereim 16.875 Minutes",
+            Zman::BainHashmashosYereim18Minutes => "Bain Hashmashos Yereim 18 Minutes",
+            Zman::BainHashmashosYereim2Point1Degrees => "Bain Hashmashos Yereim 2.1°",
+            Zman::BainHashmashosYereim2Point8Degrees => "Bain Hashmashos Yereim 2.8°",
+            Zman::BainHashmashosYereim3Point05Degrees => "Bain Hashmashos Yereim 3.05°",
+            Zman::CandleLighting => "Candle Lighting",
+            Zman::Chatzos => "Chatzos",
+            Zman::ChatzosAsHalfDay => "Chatzos (Half Day)",
+            Zman::MinchaGedola => "Mincha Gedola",
+            Zman::MinchaGedola16Point1Degrees => "Mincha Gedola 16.1°",
+            Zman::MinchaGedola30Minutes => "Mincha Gedola 30 Minutes",
+            Zman::MinchaGedolaAteretTorah => "Mincha Gedola Ateret Torah",
+            Zman::MinchaGedolaGreaterThan30 => "Mincha Gedola (At Least 30 Minutes)",
+            Zman::MinchaKetana => "Mincha Ketana",
+            Zman::MinchaKetana16Point1Degrees => "Mincha Ketana 16.1°",
+            Zman::MinchaKetana72Minutes => "Mincha Ketana 72 Minutes",
+            Zman::MinchaKetanaAteretTorah => "Mincha Ketana Ateret Torah",
+            Zman::MinchaKetanaGRAFixedLocalChatzosToSunset => "Mincha Ketana GRA Fixed Local Chatzos to Sunset",
+            Zman::PlagHamincha => "Plag Hamincha",
+            Zman::PlagHaminchaAteretTorah => "Plag Hamincha Ateret Torah",
+            Zman::SofZmanShmaAteretTorah => "Sof Zman Shma Ateret Torah",
+            Zman::SofZmanShmaGRA => "Sof Zman Shma GRA",
+            Zman::SofZmanShmaMGA => "Sof Zman Shma MGA",
+            Zman::SofZmanShmaMGA120Minutes => "Sof Zman Shma MGA 120 Minutes",
+            Zman::SofZmanShmaMGA16Point1Degrees => "Sof Zman Shma MGA 16.1°",
+            Zman::SofZmanShmaMGA18Degrees => "Sof Zman Shma MGA 18°",
+            Zman::SofZmanShmaMGA19Point8Degrees => "Sof Zman Shma MGA 19.8°",
+            Zman::SofZmanShmaMGA72MinutesZmanis => "Sof Zman Shma MGA 72 Minutes Zmaniyos",
+            Zman::SofZmanShmaMGA90Minutes => "Sof Zman Shma MGA 90 Minutes",
+            Zman::SofZmanShmaMGA90MinutesZmanis => "Sof Zman Shma MGA 90 Minutes Zmaniyos",
+            Zman::SofZmanShmaMGA96Minutes => "Sof Zman Shma MGA 96 Minutes",
+            Zman::SofZmanShmaMGA96MinutesZmanis => "Sof Zman Shma MGA 96 Minutes Zmaniyos",
+            Zman::SofZmanTfila2HoursBeforeChatzos => "Sof Zman Tfila 2 Hours Before Chatzos",
+            Zman::SofZmanTfilaAteretTorah => "Sof Zman Tfila Ateret Torah",
+            Zman::SofZmanTfilaGRA => "Sof Zman Tfila GRA",
+            Zman::SofZmanTfilaMGA => "Sof Zman Tfila MGA",
+            Zman::SofZmanTfilaMGA120Minutes => "Sof Zman Tfila MGA 120 Minutes",
+            Zman::SofZmanTfilaMGA16Point1Degrees => "Sof Zman Tfila MGA 16.1°",
+            Zman::SofZmanTfilaMGA18Degrees => "Sof Zman Tfila MGA 18°",
+            Zman::SofZmanTfilaMGA19Point8Degrees => "Sof Zman Tfila MGA 19.8°",
+            Zman::SofZmanTfilaMGA72MinutesZmanis => "Sof Zman Tfila MGA 72 Minutes Zmaniyos",
+            Zman::SofZmanTfilaMGA90Minutes => "Sof Zman Tfila MGA 90 Minutes",
+            Zman::SofZmanTfilaMGA90MinutesZmanis => "Sof Zman Tfila MGA 90 Minutes Zmaniyos",
+            Zman::SofZmanTfilaMGA96Minutes => "Sof Zman Tfila MGA 96 Minutes",
+            Zman::SofZmanTfilaMGA96MinutesZmanis => "Sof Zman Tfila MGA 96 Minutes Zmaniyos",
+            Zman::Tzais => "Tzais",
+            Zman::Tzais120 => "Tzais 120 Minutes",
+            Zman::Tzais120Zmanis => "Tzais 120 Minutes Zmaniyos",
+            Zman::Tzais16Point1Degrees => "Tzais 16.1°",
+            Zman::Tzais18Degrees => "Tzais 18°",
+            Zman::Tzais19Point8Degrees => "Tzais 19.8°",
+            Zman::Tzais26Degrees => "Tzais 26°",
+            Zman::Tzais50 => "Tzais 50 Minutes",
+            Zman::Tzais60 => "Tzais 60 Minutes",
+            Zman::Tzais72 => "Tzais 72 Minutes",
+            Zman::Tzais72Zmanis => "Tzais 72 Minutes Zmaniyos",
+            Zman::Tzais90 => "Tzais 90 Minutes",
+            Zman::Tzais90Zmanis => "Tzais 90 Minutes Zmaniyos",
+            Zman::Tzais96 => "Tzais 96 Minutes",
+            Zman::Tzais96Zmanis => "Tzais 96 Minutes Zmaniyos",
+            Zman::TzaisAteretTorah => "Tzais Ateret Torah",
+            Zman::TzaisGeonim3Point65Degrees => "Tzais Geonim 3.65°",
+            Zman::TzaisGeonim3Point676Degrees => "Tzais Geonim 3.676°",
+            Zman::TzaisGeonim3Point7Degrees => "Tzais Geonim 3.7°",
+            Zman::TzaisGeonim3Point8Degrees => "Tzais Geonim 3.8°",
+            Zman::TzaisGeonim4Point37Degrees => "Tzais Geonim 4.37°",
+            Zman::TzaisGeonim4Point61Degrees => "Tzais Geonim 4.61°",
+            Zman::TzaisGeonim4Point8Degrees => "Tzais Geonim 4.8°",
+            Zman::TzaisGeonim5Point88Degrees => "Tzais Geonim 5.88°",
+            Zman::TzaisGeonim5Point95Degrees => "Tzais Geonim 5.95°",
+            Zman::TzaisGeonim6Point45Degrees => "Tzais Geonim 6.45°",
+            Zman::TzaisGeonim7Point083Degrees => "Tzais Geonim 7.083°",
+            Zman::TzaisGeonim7Point67Degrees => "Tzais Geonim 7.67°",
+            Zman::TzaisGeonim9Point3Degrees => "Tzais Geonim 9.3°",
+            Zman::TzaisGeonim9Point75Degrees => "Tzais Geonim 9.75°",
+        }
+    }
+    #[cfg(feature = "names")]
+    pub fn he_string(&self) -> &str {
+        match self {
+            Zman::Alos120 => "עלות השחר 120 דקות",
+            Zman::Alos120Zmanis => "עלות השחר 120 דקות זמניות",
+            Zman::Alos18Degrees => "עלות השחר 18°",
+            Zman::Alos19Degrees => "עלות השחר 19°",
+            Zman::Alos19Point8Degrees => "עלות השחר 19.8°",
+            Zman::Alos26Degrees => "עלות השחר 26°",
+            Zman::Alos60 => "עלות השחר 60 דקות",
+            Zman::Alos72 => "עלות השחר 72 דקות",
+            Zman::Alos72Zmanis => "עלות השחר 72 דקות זמניות",
+            Zman::Alos90 => "עלות השחר 90 דקות",
+            Zman::Alos90Zmanis => "עלות השחר 90 דקות זמניות",
+            Zman::Alos96 => "עלות השחר 96 דקות",
+            Zman::Alos96Zmanis => "עלות השחר 96 דקות זמניות",
+            Zman::AlosHashachar => "עלות השחר",
+            Zman::BainHashmashosRT13Point24Degrees => "בין השמשות ר״ת 13.24°",
+            Zman::BainHashmashosRT13Point5MinutesBefore7Point083Degrees => "בין השמשות ר״ת 13.5 דקות לפני 7.083°",
+            Zman::BainHashmashosRT2Stars => "בין השמשות ר״ת שני כוכבים",
+            Zman::BainHashmashosRT58Point5Minutes => "בין השמשות ר״ת 58.5 דקות",
+            Zman::BainHashmashosYereim13Point5Minutes => "בין השמשות יראים 13.5 דקות",
+            Zman::BainHashmashosYereim16Point875Minutes => "בין השמשות יראים 16.875 דקות",
+            Zman::BainHashmashosYereim18Minutes => "בין השמשות יראים 18 דקות",
+            Zman::BainHashmashosYereim2Point1Degrees => "בין השמשות יראים 2.1°",
+            Zman::BainHashmashosYereim2Point8Degrees => "בין השמשות יראים 2.8°",
+            Zman::BainHashmashosYereim3Point05Degrees => "בין השמשות יראים 3.05°",
+            Zman::CandleLighting => "הדלקת נרות",
+            Zman::Chatzos => "חצות",
+            Zman::ChatzosAsHalfDay => "חצות (מחצית היום)",
+            Zman::MinchaGedola => "מנחה גדולה",
+            Zman::MinchaGedola16Point1Degrees => "מנחה גדולה 16.1°",
+            Zman::MinchaGedola30Minutes => "מנחה גדולה 30 דקות",
+            Zman::MinchaGedolaAteretTorah => "מנחה גדולה עטרת תורה",
+            Zman::MinchaGedolaGreaterThan30 => "מנחה גדולה (לפחות 30 דקות)",
+            Zman::MinchaKetana => "מנחה קטנה",
+            Zman::MinchaKetana16Point1Degrees => "מנחה קטנה 16.1°",
+            Zman::MinchaKetana72Minutes => "מנחה קטנה 72 דקות",
+            Zman::MinchaKetanaAteretTorah => "מנחה קטנה עטרת תורה",
+            Zman::MinchaKetanaGRAFixedLocalChatzosToSunset => "מנחה קטנה גר״א מחצות קבוע עד השקיעה",
+            Zman::PlagHamincha => "פלג המנחה",
+            Zman::PlagHaminchaAteretTorah => "פלג המנחה עטרת תורה",
+            Zman::SofZmanShmaAteretTorah => "סוף זמן שמע עטרת תורה",
+            Zman::SofZmanShmaGRA => "סוף זמן שמע גר״א",
+            Zman::SofZmanShmaMGA => "סוף זמן שמע מג״א",
+            Zman::SofZmanShmaMGA120Minutes => "סוף זמן שמע מג״א 120 דקות",
+            Zman::SofZmanShmaMGA16Point1Degrees => "סוף זמן שמע מג״א 16.1°",
+            Zman::SofZmanShmaMGA18Degrees => "סוף זמן שמע מג״א 18°",
+            Zman::SofZmanShmaMGA19Point8Degrees => "סוף זמן שמע מג״א 19.8°",
+            Zman::SofZmanShmaMGA72MinutesZmanis => "סוף זמן שמע מג״א 72 דקות זמניות",
+            Zman::SofZmanShmaMGA90Minutes => "סוף זמן שמע מג״א 90 דקות",
+            Zman::SofZmanShmaMGA90MinutesZmanis => "סוף זמן שמע מג״א 90 דקות זמניות",
+            Zman::SofZmanShmaMGA96Minutes => "סוף זמן שמע מג״א 96 דקות",
+            Zman::SofZmanShmaMGA96MinutesZmanis => "סוף זמן שמע מג״א 96 דקות זמניות",
+            Zman::SofZmanTfila2HoursBeforeChatzos => "סוף זמן תפילה שעתיים לפני חצות",
+            Zman::SofZmanTfilaAteretTorah => "סוף זמן תפילה עטרת תורה",
+            Zman::SofZmanTfilaGRA => "סוף זמן תפילה גר״א",
+            Zman::SofZmanTfilaMGA => "סוף זמן תפילה מג״א",
+            Zman::SofZmanTfilaMGA120Minutes => "סוף זמן תפילה מג״א 120 דקות",
+            Zman::SofZmanTfilaMGA16Point1Degrees => "סוף זמן תפילה מג״א 16.1°",
+            Zman::SofZmanTfilaMGA18Degrees => "סוף זמן תפילה מג״א 18°",
+            Zman::SofZmanTfilaMGA19Point8Degrees => "סוף זמן תפילה מג״א 19.8°",
+            Zman::SofZmanTfilaMGA72MinutesZmanis => "סוף זמן תפילה מג״א 72 דקות זמניות",
+            Zman::SofZmanTfilaMGA90Minutes => "סוף זמן תפילה מג״א 90 דקות",
+            Zman::SofZmanTfilaMGA90MinutesZmanis => "סוף זמן תפילה מג״א 90 דקות זמניות",
+            Zman::SofZmanTfilaMGA96Minutes => "סוף זמן תפילה מג״א 96 דקות",
+            Zman::SofZmanTfilaMGA96MinutesZmanis => "סוף זמן תפילה מג״א 96 דקות זמניות",
+            Zman::Tzais => "צאת הכוכבים",
+            Zman::Tzais120 => "צאת הכוכבים 120 דקות",
+            Zman::Tzais120Zmanis => "צאת הכוכבים 120 דקות זמניות",
+            Zman::Tzais16Point1Degrees => "צאת הכוכבים 16.1°",
+            Zman::Tzais18Degrees => "צאת הכוכבים 18°",
+            Zman::Tzais19Point8Degrees => "צאת הכוכבים 19.8°",
+            Zman::Tzais26Degrees => "צאת הכוכבים 26°",
+            Zman::Tzais50 => "צאת הכוכבים 50 דקות",
+            Zman::Tzais60 => "צאת הכוכבים 60 דקות",
+            Zman::Tzais72 => "צאת הכוכבים 72 דקות",
+            Zman::Tzais72Zmanis => "צאת הכוכבים 72 דקות זמניות",
+            Zman::Tzais90 => "צאת הכוכבים 90 דקות",
+            Zman::Tzais90Zmanis => "צאת הכוכבים 90 דקות זמניות",
+            Zman::Tzais96 => "צאת הכוכבים 96 דקות",
+            Zman::Tzais96Zmanis => "צאת הכוכבים 96 דקות זמניות",
+            Zman::TzaisAteretTorah => "צאת הכוכבים עטרת תורה",
+            Zman::TzaisGeonim3Point65Degrees => "צאת הכוכבים גאונים 3.65°",
+            Zman::TzaisGeonim3Point676Degrees => "צאת הכוכבים גאונים 3.676°",
+            Zman::TzaisGeonim3Point7Degrees => "צאת הכוכבים גאונים 3.7°",
+            Zman::TzaisGeonim3Point8Degrees => "צאת הכוכבים גאונים 3.8°",
+            Zman::TzaisGeonim4Point37Degrees => "צאת הכוכבים גאונים 4.37°",
+            Zman::TzaisGeonim4Point61Degrees => "צאת הכוכבים גאונים 4.61°",
+            Zman::TzaisGeonim4Point8Degrees => "צאת הכוכבים גאונים 4.8°",
+            Zman::TzaisGeonim5Point88Degrees => "צאת הכוכבים גאונים 5.88°",
+            Zman::TzaisGeonim5Point95Degrees => "צאת הכוכבים גאונים 5.95°",
+            Zman::TzaisGeonim6Point45Degrees => "צאת הכוכבים גאונים 6.45°",
+            Zman::TzaisGeonim7Point083Degrees => "צאת הכוכבים גאונים 7.083°",
+            Zman::TzaisGeonim7Point67Degrees => "צאת הכוכבים גאונים 7.67°",
+            Zman::TzaisGeonim9Point3Degrees => "צאת הכוכבים גאונים 9.3°",
+            Zman::TzaisGeonim9Point75Degrees => "צאת הכוכבים גאונים 9.75°",
+        }
+    }
+    /// Returns the Yiddish name. Yiddish uses the Hebrew names of the zmanim.
+    #[cfg(feature = "names")]
+    pub fn yi_string(&self) -> &str {
+        self.he_string()
+    }
+    /// Returns a short identifier of this zman in snake case, such as `sof_zman_shma_mga_16_1`, for
+    /// configuration files and query parameters. Unlike the names and the discriminants,
+    /// identifiers do not change. [`Zman::from_str`](core::str::FromStr::from_str) parses them.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Zman::Alos120 => "alos_120",
+            Zman::Alos120Zmanis => "alos_120_zmanis",
+            Zman::Alos18Degrees => "alos_18",
+            Zman::Alos19Degrees => "alos_19",
+            Zman::Alos19Point8Degrees => "alos_19_8",
+            Zman::Alos26Degrees => "alos_26",
+            Zman::Alos60 => "alos_60",
+            Zman::Alos72 => "alos_72",
+            Zman::Alos72Zmanis => "alos_72_zmanis",
+            Zman::Alos90 => "alos_90",
+            Zman::Alos90Zmanis => "alos_90_zmanis",
+            Zman::Alos96 => "alos_96",
+            Zman::Alos96Zmanis => "alos_96_zmanis",
+            Zman::AlosHashachar => "alos_hashachar",
+            Zman::BainHashmashosRT13Point24Degrees => "bain_hashmashos_rt_13_24",
+            Zman::BainHashmashosRT13Point5MinutesBefore7Point083Degrees => "bain_hashmashos_rt_13_5_before_7_083",
+            Zman::BainHashmashosRT2Stars => "bain_hashmashos_rt_2_stars",
+            Zman::BainHashmashosRT58Point5Minutes => "bain_hashmashos_rt_58_5",
+            Zman::BainHashmashosYereim13Point5Minutes => "bain_hashmashos_yereim_13_5",
+            Zman::BainHashmashosYereim16Point875Minutes => "bain_hashmashos_yereim_16_875",
+            Zman::BainHashmashosYereim18Minutes => "bain_hashmashos_yereim_18",
+            Zman::BainHashmashosYereim2Point1Degrees => "bain_hashmashos_yereim_2_1",
+            Zman::BainHashmashosYereim2Point8Degrees => "bain_hashmashos_yereim_2_8",
+            Zman::BainHashmashosYereim3Point05Degrees => "bain_hashmashos_yereim_3_05",
+            Zman::CandleLighting => "candle_lighting",
+            Zman::Chatzos => "chatzos",
+            Zman::ChatzosAsHalfDay => "chatzos_as_half_day",
+            Zman::MinchaGedola => "mincha_gedola",
+            Zman::MinchaGedola16Point1Degrees => "mincha_gedola_16_1",
+            Zman::MinchaGedola30Minutes => "mincha_gedola_30",
+            Zman::MinchaGedolaAteretTorah => "mincha_gedola_ateret_torah",
+            Zman::MinchaGedolaGreaterThan30 => "mincha_gedola_greater_than_30",
+            Zman::MinchaKetana => "mincha_ketana",
+            Zman::MinchaKetana16Point1Degrees => "mincha_ketana_16_1",
+            Zman::MinchaKetana72Minutes => "mincha_ketana_72",
+            Zman::MinchaKetanaAteretTorah => "mincha_ketana_ateret_torah",
+            Zman::MinchaKetanaGRAFixedLocalChatzosToSunset => "mincha_ketana_gra_fixed_local_chatzos_to_sunset",
+            Zman::PlagHamincha => "plag_hamincha",
+            Zman::PlagHaminchaAteretTorah => "plag_hamincha_ateret_torah",
+            Zman::SofZmanShmaAteretTorah => "sof_zman_shma_ateret_torah",
+            Zman::SofZmanShmaGRA => "sof_zman_shma_gra",
+            Zman::SofZmanShmaMGA => "sof_zman_shma_mga",
+            Zman::SofZmanShmaMGA120Minutes => "sof_zman_shma_mga_120",
+            Zman::SofZmanShmaMGA16Point1Degrees => "sof_zman_shma_mga_16_1",
+            Zman::SofZmanShmaMGA18Degrees => "sof_zman_shma_mga_18",
+            Zman::SofZmanShmaMGA19Point8Degrees => "sof_zman_shma_mga_19_8",
+            Zman::SofZmanShmaMGA72MinutesZmanis => "sof_zman_shma_mga_72_zmanis",
+            Zman::SofZmanShmaMGA90Minutes => "sof_zman_shma_mga_90",
+            Zman::SofZmanShmaMGA90MinutesZmanis => "sof_zman_shma_mga_90_zmanis",
+            Zman::SofZmanShmaMGA96Minutes => "sof_zman_shma_mga_96",
+            Zman::SofZmanShmaMGA96MinutesZmanis => "sof_zman_shma_mga_96_zmanis",
+            Zman::SofZmanTfila2HoursBeforeChatzos => "sof_zman_tfila_2_hours_before_chatzos",
+            Zman::SofZmanTfilaAteretTorah => "sof_zman_tfila_ateret_torah",
+            Zman::SofZmanTfilaGRA => "sof_zman_tfila_gra",
+            Zman::SofZmanTfilaMGA => "sof_zman_tfila_mga",
+            Zman::SofZmanTfilaMGA120Minutes => "sof_zman_tfila_mga_120",
+            Zman::SofZmanTfilaMGA16Point1Degrees => "sof_zman_tfila_mga_16_1",
+            Zman::SofZmanTfilaMGA18Degrees => "sof_zman_tfila_mga_18",
+            Zman::SofZmanTfilaMGA19Point8Degrees => "sof_zman_tfila_mga_19_8",
+            Zman::SofZmanTfilaMGA72MinutesZmanis => "sof_zman_tfila_mga_72_zmanis",
+            Zman::SofZmanTfilaMGA90Minutes => "sof_zman_tfila_mga_90",
+            Zman::SofZmanTfilaMGA90MinutesZmanis => "sof_zman_tfila_mga_90_zmanis",
+            Zman::SofZmanTfilaMGA96Minutes => "sof_zman_tfila_mga_96",
+            Zman::SofZmanTfilaMGA96MinutesZmanis => "sof_zman_tfila_mga_96_zmanis",
+            Zman::Tzais => "tzais",
+            Zman::Tzais120 => "tzais_120",
+            Zman::Tzais120Zmanis => "tzais_120_zmanis",
+            Zman::Tzais16Point1Degrees => "tzais_16_1",
+            Zman::Tzais18Degrees => "tzais_18",
+            Zman::Tzais19Point8Degrees => "tzais_19_8",
+            Zman::Tzais26Degrees => "tzais_26",
+            Zman::Tzais50 => "tzais_50",
+            Zman::Tzais60 => "tzais_60",
+            Zman::Tzais72 => "tzais_72",
+            Zman::Tzais72Zmanis => "tzais_72_zmanis",
+            Zman::Tzais90 => "tzais_90",
+            Zman::Tzais90Zmanis => "tzais_90_zmanis",
+            Zman::Tzais96 => "tzais_96",
+            Zman::Tzais96Zmanis => "tzais_96_zmanis",
+            Zman::TzaisAteretTorah => "tzais_ateret_torah",
+            Zman::TzaisGeonim3Point65Degrees => "tzais_geonim_3_65",
+            Zman::TzaisGeonim3Point676Degrees => "tzais_geonim_3_676",
+            Zman::TzaisGeonim3Point7Degrees => "tzais_geonim_3_7",
+            Zman::TzaisGeonim3Point8Degrees => "tzais_geonim_3_8",
+            Zman::TzaisGeonim4Point37Degrees => "tzais_geonim_4_37",
+            Zman::TzaisGeonim4Point61Degrees => "tzais_geonim_4_61",
+            Zman::TzaisGeonim4Point8Degrees => "tzais_geonim_4_8",
+            Zman::TzaisGeonim5Point88Degrees => "tzais_geonim_5_88",
+            Zman::TzaisGeonim5Point95Degrees => "tzais_geonim_5_95",
+            Zman::TzaisGeonim6Point45Degrees => "tzais_geonim_6_45",
+            Zman::TzaisGeonim7Point083Degrees => "tzais_geonim_7_083",
+            Zman::TzaisGeonim7Point67Degrees => "tzais_geonim_7_67",
+            Zman::TzaisGeonim9Point3Degrees => "tzais_geonim_9_3",
+            Zman::TzaisGeonim9Point75Degrees => "tzais_geonim_9_75",
+        }
+    }
+    /// Returns a description of the opinion that this zman follows and how it is calculated, in
+    /// English.
+    #[cfg(feature = "names")]
+    pub fn opinion(&self) -> &'static str {
+        match self {
+            Zman::Alos120 => "120 minutes before sunrise.",
+            Zman::Alos120Zmanis => {
+                "2 shaos zmaniyos, a twelfth of the day from sunrise to sunset each, before sunrise."
+            }
+            Zman::Alos18Degrees => "The sun 18° below the horizon in the morning.",
+            Zman::Alos19Degrees => "The sun 19° below the horizon in the morning.",
+            Zman::Alos19Point8Degrees => "The sun 19.8° below the horizon in the morning.",
+            Zman::Alos26Degrees => "The sun 26° below the horizon in the morning.",
+            Zman::Alos60 => "60 minutes before sunrise.",
+            Zman::Alos72 => "72 minutes before sunrise, the time it takes to walk 4 mil at 18 minutes a mil.",
+            Zman::Alos72Zmanis => {
+                "1.2 shaos zmaniyos, a twelfth of the day from sunrise to sunset each, before sunrise."
+            }
+            Zman::Alos90 => "90 minutes before sunrise.",
+            Zman::Alos90Zmanis => {
+                "1.5 shaos zmaniyos, a twelfth of the day from sunrise to sunset each, before sunrise."
+            }
+            Zman::Alos96 => "96 minutes before sunrise.",
+            Zman::Alos96Zmanis => {
+                "1.6 shaos zmaniyos, a twelfth of the day from sunrise to sunset each, before sunrise."
+            }
+            Zman::AlosHashachar => {
+                "The sun 16.1° below the horizon in the morning, which is 72 minutes before sunrise in Jerusalem around the equinox."
+            }
+            Zman::BainHashmashosRT13Point24Degrees => {
+                "The sun 13.24° below the horizon, the start of bain hashmashos according to Rabbeinu Tam."
+            }
+            Zman::BainHashmashosRT13Point5MinutesBefore7Point083Degrees => {
+                "13.5 minutes before the sun is 7.083° below the horizon, the start of bain hashmashos according to Rabbeinu Tam."
+            }
+            Zman::BainHashmashosRT2Stars => {
+                "Sunset plus 5/18 of the time from alos at 19.8° to sunrise, the start of bain hashmashos according to Rabbeinu Tam as calculated by the Divrei Yosef."
+            }
+            Zman::BainHashmashosRT58Point5Minutes => {
+                "58.5 minutes after sunset, 3.25 mil of 18 minutes, the start of bain hashmashos according to Rabbeinu Tam."
+            }
+            Zman::BainHashmashosYereim13Point5Minutes => {
+                "13.5 minutes before sunset, the start of bain hashmashos according to the Yereim."
+            }
+            Zman::BainHashmashosYereim16Point875Minutes => {
+                "16.875 minutes before sunset, the start of bain hashmashos according to the Yereim."
+            }
+            Zman::BainHashmashosYereim18Minutes => {
+                "18 minutes before sunset, the start of bain hashmashos according to the Yereim."
+            }
+            Zman::BainHashmashosYereim2Point1Degrees => {
+                "The sun 2.1° above the horizon before sunset, the start of bain hashmashos according to the Yereim."
+            }
+            Zman::BainHashmashosYereim2Point8Degrees => {
+                "The sun 2.8° above the horizon before sunset, the start of bain hashmashos according to the Yereim."
+            }
+            Zman::BainHashmashosYereim3Point05Degrees => {
+                "The sun 3.05° above the horizon before sunset, the start of bain hashmashos according to the Yereim."
+            }
+            Zman::CandleLighting => "The candle lighting offset of the calendar before sea level sunset.",
+            Zman::Chatzos => {
+                "Midday, halfway between sunrise and sunset, or the sun transit with astronomical chatzos."
+            }
+            Zman::ChatzosAsHalfDay => "Halfway between sea level sunrise and sea level sunset.",
+            Zman::MinchaGedola => "6.5 shaos zmaniyos into the day from sunrise to sunset, according to the GRA.",
+            Zman::MinchaGedola16Point1Degrees => "6.5 shaos zmaniyos into the day from alos to tzais at 16.1°.",
+            Zman::MinchaGedola30Minutes => "30 minutes after chatzos, when the sun has clearly begun to decline.",
+            Zman::MinchaGedolaAteretTorah => {
+                "6.5 shaos zmaniyos into the day from alos at 1.2 shaos zmaniyos before sunrise to the tzais of the Ateret Torah."
+            }
+            Zman::MinchaGedolaGreaterThan30 => {
+                "The later of mincha gedola according to the GRA and 30 minutes after chatzos."
+            }
+            Zman::MinchaKetana => "9.5 shaos zmaniyos into the day from sunrise to sunset, according to the GRA.",
+            Zman::MinchaKetana16Point1Degrees => "9.5 shaos zmaniyos into the day from alos to tzais at 16.1°.",
+            Zman::MinchaKetana72Minutes => {
+                "9.5 shaos zmaniyos into the day from 72 minutes before sunrise to 72 minutes after sunset."
+            }
+            Zman::MinchaKetanaAteretTorah => {
+                "9.5 shaos zmaniyos into the day from alos at 1.2 shaos zmaniyos before sunrise to the tzais of the Ateret Torah."
+            }
+            Zman::MinchaKetanaGRAFixedLocalChatzosToSunset => {
+                "3.5 shaos zmaniyos after fixed local chatzos, with hours of a sixth of the time from fixed local chatzos to sunset."
+            }
+            Zman::PlagHamincha => "10.75 shaos zmaniyos into the day from sunrise to sunset, according to the GRA.",
+            Zman::PlagHaminchaAteretTorah => {
+                "10.75 shaos zmaniyos into the day from alos at 1.2 shaos zmaniyos before sunrise to the tzais of the Ateret Torah."
+            }
+            Zman::SofZmanShmaAteretTorah => {
+                "3 shaos zmaniyos into the day from alos at 1.2 shaos zmaniyos before sunrise to the tzais of the Ateret Torah."
+            }
+            Zman::SofZmanShmaGRA => "3 shaos zmaniyos into the day from sunrise to sunset, according to the GRA.",
+            Zman::SofZmanShmaMGA => {
+                "3 shaos zmaniyos into the day from 72 minutes before sunrise to 72 minutes after sunset, according to the Magen Avraham."
+            }
+            Zman::SofZmanShmaMGA120Minutes => {
+                "3 shaos zmaniyos into the day from 120 minutes before sunrise to 120 minutes after sunset, according to the Magen Avraham."
+            }
+            Zman::SofZmanShmaMGA16Point1Degrees => {
+                "3 shaos zmaniyos into the day from alos to tzais at 16.1°, according to the Magen Avraham."
+            }
+            Zman::SofZmanShmaMGA18Degrees => {
+                "3 shaos zmaniyos into the day from alos to tzais at 18°, according to the Magen Avraham."
+            }
+            Zman::SofZmanShmaMGA19Point8Degrees => {
+                "3 shaos zmaniyos into the day from alos to tzais at 19.8°, according to the Magen Avraham."
+            }
+            Zman::SofZmanShmaMGA72MinutesZmanis => {
+                "3 shaos zmaniyos into the day from 1.2 shaos zmaniyos before sunrise to 1.2 shaos zmaniyos after sunset, according to the Magen Avraham."
+            }
+            Zman::SofZmanShmaMGA90Minutes => {
+                "3 shaos zmaniyos into the day from 90 minutes before sunrise to 90 minutes after sunset, according to the Magen Avraham."
+            }
+            Zman::SofZmanShmaMGA90MinutesZmanis => {
+                "3 shaos zmaniyos into the day from 1.5 shaos zmaniyos before sunrise to 1.5 shaos zmaniyos after sunset, according to the Magen Avraham."
+            }
+            Zman::SofZmanShmaMGA96Minutes => {
+                "3 shaos zmaniyos into the day from 96 minutes before sunrise to 96 minutes after sunset, according to the Magen Avraham."
+            }
+            Zman::SofZmanShmaMGA96MinutesZmanis => {
+                "3 shaos zmaniyos into the day from 1.6 shaos zmaniyos before sunrise to 1.6 shaos zmaniyos after sunset, according to the Magen Avraham."
+            }
+            Zman::SofZmanTfila2HoursBeforeChatzos => {
+                "2 hours before chatzos, based on the Magen Avraham's day of 72 minutes before sunrise to 72 minutes after sunset, which is always 2 hours after sof zman shma."
+            }
+            Zman::SofZmanTfilaAteretTorah => {
+                "4 shaos zmaniyos into the day from alos at 1.2 shaos zmaniyos before sunrise to the tzais of the Ateret Torah."
+            }
+            Zman::SofZmanTfilaGRA => "4 shaos zmaniyos into the day from sunrise to sunset, according to the GRA.",
+            Zman::SofZmanTfilaMGA => {
+                "4 shaos zmaniyos into the day from 72 minutes before sunrise to 72 minutes after sunset, according to the Magen Avraham."
+            }
+            Zman::SofZmanTfilaMGA120Minutes => {
+                "4 shaos zmaniyos into the day from 120 minutes before sunrise to 120 minutes after sunset, according to the Magen Avraham."
+            }
+            Zman::SofZmanTfilaMGA16Point1Degrees => {
+                "4 shaos zmaniyos into the day from alos to tzais at 16.1°, according to the Magen Avraham."
+            }
+            Zman::SofZmanTfilaMGA18Degrees => {
+                "4 shaos zmaniyos into the day from alos to tzais at 18°, according to the Magen Avraham."
+            }
+            Zman::SofZmanTfilaMGA19Point8Degrees => {
+                "4 shaos zmaniyos into the day from alos to tzais at 19.8°, according to the Magen Avraham."
+            }
+            Zman::SofZmanTfilaMGA72MinutesZmanis => {
+                "4 shaos zmaniyos into the day from 1.2 shaos zmaniyos before sunrise to 1.2 shaos zmaniyos after sunset, according to the Magen Avraham."
+            }
+            Zman::SofZmanTfilaMGA90Minutes => {
+                "4 shaos zmaniyos into the day from 90 minutes before sunrise to 90 minutes after sunset, according to the Magen Avraham."
+            }
+            Zman::SofZmanTfilaMGA90MinutesZmanis => {
+                "4 shaos zmaniyos into the day from 1.5 shaos zmaniyos before sunrise to 1.5 shaos zmaniyos after sunset, according to the Magen Avraham."
+            }
+            Zman::SofZmanTfilaMGA96Minutes => {
+                "4 shaos zmaniyos into the day from 96 minutes before sunrise to 96 minutes after sunset, according to the Magen Avraham."
+            }
+            Zman::SofZmanTfilaMGA96MinutesZmanis => {
+                "4 shaos zmaniyos into the day from 1.6 shaos zmaniyos before sunrise to 1.6 shaos zmaniyos after sunset, according to the Magen Avraham."
+            }
+            Zman::Tzais => {
+                "The sun 8.5° below the horizon in the evening, when 3 small stars are visible, according to the Geonim."
+            }
+            Zman::Tzais120 => "120 minutes after sunset.",
+            Zman::Tzais120Zmanis => "2 shaos zmaniyos, a twelfth of the day from sunrise to sunset each, after sunset.",
+            Zman::Tzais16Point1Degrees => "The sun 16.1° below the horizon in the evening.",
+            Zman::Tzais18Degrees => "The sun 18° below the horizon in the evening.",
+            Zman::Tzais19Point8Degrees => "The sun 19.8° below the horizon in the evening.",
+            Zman::Tzais26Degrees => "The sun 26° below the horizon in the evening.",
+            Zman::Tzais50 => "50 minutes after sunset.",
+            Zman::Tzais60 => "60 minutes after sunset.",
+            Zman::Tzais72 => "72 minutes after sunset, the time it takes to walk 4 mil, according to Rabbeinu Tam.",
+            Zman::Tzais72Zmanis => {
+                "1.2 shaos zmaniyos, a twelfth of the day from sunrise to sunset each, after sunset."
+            }
+            Zman::Tzais90 => "90 minutes after sunset.",
+            Zman::Tzais90Zmanis => {
+                "1.5 shaos zmaniyos, a twelfth of the day from sunrise to sunset each, after sunset."
+            }
+            Zman::Tzais96 => "96 minutes after sunset.",
+            Zman::Tzais96Zmanis => {
+                "1.6 shaos zmaniyos, a twelfth of the day from sunrise to sunset each, after sunset."
+            }
+            Zman::TzaisAteretTorah => {
+                "The Ateret Torah sunset offset of the calendar after sunset, 40 minutes by default, according to Chacham Yosef Harari-Raful."
+            }
+            Zman::TzaisGeonim3Point65Degrees => {
+                "The sun 3.65° below the horizon in the evening, according to the Geonim."
+            }
+            Zman::TzaisGeonim3Point676Degrees => {
+                "The sun 3.676° below the horizon in the evening, according to the Geonim."
+            }
+            Zman::TzaisGeonim3Point7Degrees => {
+                "The sun 3.7° below the horizon in the evening, according to the Geonim."
+            }
+            Zman::TzaisGeonim3Point8Degrees => {
+                "The sun 3.8° below the horizon in the evening, according to the Geonim."
+            }
+            Zman::TzaisGeonim4Point37Degrees => {
+                "The sun 4.37° below the horizon in the evening, according to the Geonim."
+            }
+            Zman::TzaisGeonim4Point61Degrees => {
+                "The sun 4.61° below the horizon in the evening, according to the Geonim."
+            }
+            Zman::TzaisGeonim4Point8Degrees => {
+                "The sun 4.8° below the horizon in the evening, according to the Geonim."
+            }
+            Zman::TzaisGeonim5Point88Degrees => {
+                "The sun 5.88° below the horizon in the evening, according to the Geonim."
+            }
+            Zman::TzaisGeonim5Point95Degrees => {
+                "The sun 5.95° below the horizon in the evening, according to the Geonim."
+            }
+            Zman::TzaisGeonim6Point45Degrees => {
+                "The sun 6.45° below the horizon in the evening, according to the Geonim."
+            }
+            Zman::TzaisGeonim7Point083Degrees => {
+                "The sun 7.083° below the horizon in the evening, according to the Geonim."
+            }
+            Zman::TzaisGeonim7Point67Degrees => {
+                "The sun 7.67° below the horizon in the evening, according to the Geonim."
+            }
+            Zman::TzaisGeonim9Point3Degrees => {
+                "The sun 9.3° below the horizon in the evening, according to the Geonim."
+            }
+            Zman::TzaisGeonim9Point75Degrees => {
+                "The sun 9.75° below the horizon in the evening, according to the Geonim."
+            }
+        }
+    }
+    pub fn values() -> [Zman; Zman::COUNT] {
         [
             Zman::PlagHamincha,
             Zman::MinchaKetana,
@@ -1130,6 +2117,167 @@ impl Zman {
             Zman::CandleLighting,
             Zman::SofZmanTfilaGRA,
             Zman::SofZmanTfilaMGA,
+            Zman::Alos120,
+            Zman::Alos120Zmanis,
+            Zman::Alos18Degrees,
+            Zman::Alos19Degrees,
+            Zman::Alos19Point8Degrees,
+            Zman::Alos26Degrees,
+            Zman::Alos60,
+            Zman::Alos72Zmanis,
+            Zman::Alos90,
+            Zman::Alos90Zmanis,
+            Zman::Alos96,
+            Zman::Alos96Zmanis,
+            Zman::BainHashmashosRT13Point24Degrees,
+            Zman::BainHashmashosRT13Point5MinutesBefore7Point083Degrees,
+            Zman::BainHashmashosRT2Stars,
+            Zman::BainHashmashosRT58Point5Minutes,
+            Zman::BainHashmashosYereim13Point5Minutes,
+            Zman::BainHashmashosYereim16Point875Minutes,
+            Zman::BainHashmashosYereim18Minutes,
+            Zman::BainHashmashosYereim2Point1Degrees,
+            Zman::BainHashmashosYereim2Point8Degrees,
+            Zman::BainHashmashosYereim3Point05Degrees,
+            Zman::MinchaGedola16Point1Degrees,
+            Zman::MinchaGedola30Minutes,
+            Zman::MinchaGedolaAteretTorah,
+            Zman::MinchaGedolaGreaterThan30,
+            Zman::MinchaKetana16Point1Degrees,
+            Zman::MinchaKetana72Minutes,
+            Zman::MinchaKetanaAteretTorah,
+            Zman::MinchaKetanaGRAFixedLocalChatzosToSunset,
+            Zman::PlagHaminchaAteretTorah,
+            Zman::SofZmanShmaAteretTorah,
+            Zman::SofZmanShmaMGA120Minutes,
+            Zman::SofZmanShmaMGA16Point1Degrees,
+            Zman::SofZmanShmaMGA18Degrees,
+            Zman::SofZmanShmaMGA19Point8Degrees,
+            Zman::SofZmanShmaMGA72MinutesZmanis,
+            Zman::SofZmanShmaMGA90Minutes,
+            Zman::SofZmanShmaMGA90MinutesZmanis,
+            Zman::SofZmanShmaMGA96Minutes,
+            Zman::SofZmanShmaMGA96MinutesZmanis,
+            Zman::SofZmanTfila2HoursBeforeChatzos,
+            Zman::SofZmanTfilaAteretTorah,
+            Zman::SofZmanTfilaMGA120Minutes,
+            Zman::SofZmanTfilaMGA16Point1Degrees,
+            Zman::SofZmanTfilaMGA18Degrees,
+            Zman::SofZmanTfilaMGA19Point8Degrees,
+            Zman::SofZmanTfilaMGA72MinutesZmanis,
+            Zman::SofZmanTfilaMGA90Minutes,
+            Zman::SofZmanTfilaMGA90MinutesZmanis,
+            Zman::SofZmanTfilaMGA96Minutes,
+            Zman::SofZmanTfilaMGA96MinutesZmanis,
+            Zman::Tzais120,
+            Zman::Tzais120Zmanis,
+            Zman::Tzais16Point1Degrees,
+            Zman::Tzais18Degrees,
+            Zman::Tzais19Point8Degrees,
+            Zman::Tzais26Degrees,
+            Zman::Tzais50,
+            Zman::Tzais60,
+            Zman::Tzais72Zmanis,
+            Zman::Tzais90,
+            Zman::Tzais90Zmanis,
+            Zman::Tzais96,
+            Zman::Tzais96Zmanis,
+            Zman::TzaisAteretTorah,
+            Zman::TzaisGeonim3Point65Degrees,
+            Zman::TzaisGeonim3Point676Degrees,
+            Zman::TzaisGeonim3Point7Degrees,
+            Zman::TzaisGeonim3Point8Degrees,
+            Zman::TzaisGeonim4Point37Degrees,
+            Zman::TzaisGeonim4Point61Degrees,
+            Zman::TzaisGeonim4Point8Degrees,
+            Zman::TzaisGeonim5Point88Degrees,
+            Zman::TzaisGeonim5Point95Degrees,
+            Zman::TzaisGeonim6Point45Degrees,
+            Zman::TzaisGeonim7Point083Degrees,
+            Zman::TzaisGeonim7Point67Degrees,
+            Zman::TzaisGeonim9Point3Degrees,
+            Zman::TzaisGeonim9Point75Degrees,
         ]
     }
 }
+
+/// The error returned when parsing a string that is not the identifier of any [`Zman`].
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[derive(Eq, Hash, PartialEq, Debug, Clone, Copy)]
+pub struct UnknownZman;
+
+impl core::fmt::Display for UnknownZman {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str("unknown zman")
+    }
+}
+
+impl core::error::Error for UnknownZman {}
+
+impl core::str::FromStr for Zman {
+    type Err = UnknownZman;
+
+    /// Parses the identifier returned by [`Zman::as_str`].
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Zman::values()
+            .into_iter()
+            .find(|zman| zman.as_str() == s)
+            .ok_or(UnknownZman)
+    }
+}
+
+/// Serializes a zman as its identifier from [`Zman::as_str`], so that renaming a variant does not
+/// change the output.
+#[cfg(feature = "serde")]
+impl serde::Serialize for Zman {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Zman {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct ZmanVisitor;
+
+        impl serde::de::Visitor<'_> for ZmanVisitor {
+            type Value = Zman;
+
+            fn expecting(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                f.write_str("the identifier of a zman")
+            }
+
+            fn visit_str<E: serde::de::Error>(self, value: &str) -> Result<Zman, E> {
+                value
+                    .parse()
+                    .map_err(|_| E::custom(format_args!("unknown zman `{value}`")))
+            }
+        }
+
+        deserializer.deserialize_str(ZmanVisitor)
+    }
+}
+
+/// A zman that has several shitos, each of which is a separate [`Zman`].
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[derive(Eq, Hash, PartialEq, Debug, Clone, Copy)]
+pub enum ZmanConcept {
+    Alos,
+    SofZmanShma,
+    SofZmanTfila,
+    Chatzos,
+    Tzais,
+}
+
+impl ZmanConcept {
+    /// Returns the zmanim of each shita of this concept.
+    pub fn shitos(&self) -> &'static [Zman] {
+        match self {
+            ZmanConcept::Alos => &[Zman::AlosHashachar, Zman::Alos72],
+            ZmanConcept::SofZmanShma => &[Zman::SofZmanShmaGRA, Zman::SofZmanShmaMGA],
+            ZmanConcept::SofZmanTfila => &[Zman::SofZmanTfilaGRA, Zman::SofZmanTfilaMGA],
+            ZmanConcept::Chatzos => &[Zman::Chatzos, Zman::ChatzosAsHalfDay],
+            ZmanConcept::Tzais => &[Zman::Tzais, Zman::Tzais72],
+        }
+    }
+}
@@ -0,0 +1,118 @@
+//! Declaring which times define the start and end of the day that the shaos zmaniyos of a zman
+//! are counted in, instead of choosing between a method for every shita.
+//!
+//! ```ignore
+//! // Sof zman shma when the day is counted from alos at 16.1° to tzais at 16.1°.
+//! let day = DayDefinition::new(DayEdge::Degrees(16.1), DayEdge::Degrees(16.1));
+//! let shma = calendar.get_sof_zman_shma_with(&day);
+//! ```
+use chrono::{DateTime, Duration, TimeZone};
+
+use crate::{
+    astronomical_calculator::AstronomicalCalculatorTrait,
+    constants::Zman,
+    geolocation::GeoLocation,
+    zenith,
+    zmanim_calendar::{ZmanimCalendar, ZmanimCalendarTrait},
+};
+
+/// The start or end of the day.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum DayEdge {
+    /// Sunrise for the start of the day, or sunset for its end, adjusted for elevation when
+    /// [`ZmanimCalendar::use_elevation`] is set.
+    Sun,
+    /// The time of a zman, such as [`Zman::Alos72`].
+    Zman(Zman),
+    /// The sun this many degrees below the horizon, before sunrise for the start of the day or
+    /// after sunset for its end.
+    Degrees(f64),
+    /// A fixed time before sunrise for the start of the day, or after sunset for its end.
+    Offset(Duration),
+}
+
+/// The start and end of the day that shaos zmaniyos are counted in.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DayDefinition {
+    pub start: DayEdge,
+    pub end: DayEdge,
+}
+
+impl DayDefinition {
+    /// The day of the GRA, from sunrise to sunset.
+    pub const GRA: Self = Self::new(DayEdge::Sun, DayEdge::Sun);
+    /// The day of the Magen Avraham, from 72 minutes before sunrise to 72 minutes after sunset.
+    pub const MGA: Self = Self::new(DayEdge::Zman(Zman::Alos72), DayEdge::Zman(Zman::Tzais72));
+
+    pub const fn new(start: DayEdge, end: DayEdge) -> Self {
+        Self { start, end }
+    }
+
+    /// Returns true if the start and end of the day are the same distance from sunrise and
+    /// sunset, so that the day is centered on chatzos. Only such days use astronomical chatzos
+    /// when [`ZmanimCalendar::use_astronomical_chatzos_for_other_zmanim`] is set.
+    pub fn is_synchronous(&self) -> bool {
+        match (self.start, self.end) {
+            (DayEdge::Sun, DayEdge::Sun) => true,
+            (DayEdge::Zman(Zman::Alos72), DayEdge::Zman(Zman::Tzais72)) => true,
+            (DayEdge::Degrees(start), DayEdge::Degrees(end)) => start == end,
+            (DayEdge::Offset(start), DayEdge::Offset(end)) => start == end,
+            _ => false,
+        }
+    }
+}
+
+impl<Tz: TimeZone, N: AstronomicalCalculatorTrait> ZmanimCalendar<Tz, GeoLocation, N> {
+    /// Returns the start and end of the day according to `day`.
+    pub fn get_day_bounds(&self, day: &DayDefinition) -> Option<(DateTime<Tz>, DateTime<Tz>)> {
+        let start = match day.start {
+            DayEdge::Sun => self.get_elevation_adjusted_sunrise()?,
+            DayEdge::Zman(zman) => self.get_zman(&zman)?,
+            DayEdge::Degrees(degrees) => self.get_sunrise_offset_by_degrees(zenith::GEOMETRIC + degrees)?,
+            DayEdge::Offset(offset) => self.get_elevation_adjusted_sunrise()? - offset,
+        };
+        let end = match day.end {
+            DayEdge::Sun => self.get_elevation_adjusted_sunset()?,
+            DayEdge::Zman(zman) => self.get_zman(&zman)?,
+            DayEdge::Degrees(degrees) => self.get_sunset_offset_by_degrees(zenith::GEOMETRIC + degrees)?,
+            DayEdge::Offset(offset) => self.get_elevation_adjusted_sunset()? + offset,
+        };
+        Some((start, end))
+    }
+
+    /// Returns a shaah zmanis, a twelfth of the day according to `day`.
+    pub fn get_shaah_zmanis_with(&self, day: &DayDefinition) -> Option<Duration> {
+        let (start, end) = self.get_day_bounds(day)?;
+        self.get_temporal_hour_from_times(&start, &end)
+    }
+
+    /// Returns sof zman shma, 3 shaos zmaniyos into the day according to `day`.
+    pub fn get_sof_zman_shma_with(&self, day: &DayDefinition) -> Option<DateTime<Tz>> {
+        let (start, end) = self.get_day_bounds(day)?;
+        self.get_sof_zman_shma_from_times(&start, Some(&end), day.is_synchronous())
+    }
+
+    /// Returns sof zman tfila, 4 shaos zmaniyos into the day according to `day`.
+    pub fn get_sof_zman_tfila_with(&self, day: &DayDefinition) -> Option<DateTime<Tz>> {
+        let (start, end) = self.get_day_bounds(day)?;
+        self.get_sof_zman_tfila_from_times(&start, Some(&end), day.is_synchronous())
+    }
+
+    /// Returns mincha gedola, 6.5 shaos zmaniyos into the day according to `day`.
+    pub fn get_mincha_gedola_with(&self, day: &DayDefinition) -> Option<DateTime<Tz>> {
+        let (start, end) = self.get_day_bounds(day)?;
+        self.get_mincha_gedola_from_times(Some(&start), &end, day.is_synchronous())
+    }
+
+    /// Returns mincha ketana, 9.5 shaos zmaniyos into the day according to `day`.
+    pub fn get_mincha_ketana_with(&self, day: &DayDefinition) -> Option<DateTime<Tz>> {
+        let (start, end) = self.get_day_bounds(day)?;
+        self.get_mincha_ketana_from_times(Some(&start), &end, day.is_synchronous())
+    }
+
+    /// Returns plag hamincha, 10.75 shaos zmaniyos into the day according to `day`.
+    pub fn get_plag_hamincha_with(&self, day: &DayDefinition) -> Option<DateTime<Tz>> {
+        let (start, end) = self.get_day_bounds(day)?;
+        self.get_plag_hamincha_from_times(Some(&start), &end, day.is_synchronous())
+    }
+}
@@ -0,0 +1,128 @@
+//! Everything that happens on a day in one value, for schedulers and cron jobs that act on
+//! zmanim and on changes in the calendar, such as the start of the omer or of vesein tal umatar,
+//! without their own rules for each. Two [`DayEvents`] can be compared to find what changed.
+use chrono::{DateTime, NaiveDate, TimeZone};
+
+use crate::{
+    astronomical_calculator::AstronomicalCalculatorTrait,
+    constants::{JewishHoliday, Zman},
+    jewish_calendar::{JewishCalendar, JewishCalendarTrait},
+    luach_day::LuachDay,
+    zmanim_calendar::ZmanimCalendarTrait,
+};
+
+/// A state of the calendar that lasts for a number of days.
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub enum DayFlag {
+    /// The omer is counted.
+    Omer,
+    /// Vesein tal umatar is said in the weekday amidah.
+    VeseinTalUmatar,
+    /// Mashiv haruach is said in the amidah.
+    MashivHaruach,
+    /// Chanukah.
+    Chanukah,
+    /// The ten days from Rosh Hashana to Yom Kippur.
+    AseresYemeiTeshuva,
+    /// Chol hamoed of Pesach or Succos.
+    CholHamoed,
+    /// Rosh chodesh.
+    RoshChodesh,
+    /// A fast day.
+    Taanis,
+    /// Shabbos or a yom tov on which melacha is forbidden.
+    AssurBemelacha,
+}
+
+impl DayFlag {
+    /// Returns every flag.
+    pub fn values() -> [DayFlag; 9] {
+        [
+            DayFlag::Omer,
+            DayFlag::VeseinTalUmatar,
+            DayFlag::MashivHaruach,
+            DayFlag::Chanukah,
+            DayFlag::AseresYemeiTeshuva,
+            DayFlag::CholHamoed,
+            DayFlag::RoshChodesh,
+            DayFlag::Taanis,
+            DayFlag::AssurBemelacha,
+        ]
+    }
+
+    /// Returns true if the flag is set on the day of `jewish_calendar`.
+    pub fn is_set<N: AstronomicalCalculatorTrait>(&self, jewish_calendar: &JewishCalendar<N>) -> bool {
+        match self {
+            DayFlag::Omer => jewish_calendar.get_day_of_omer().is_some(),
+            DayFlag::VeseinTalUmatar => jewish_calendar.is_vesein_tal_umatar_recited(),
+            DayFlag::MashivHaruach => jewish_calendar.is_mashiv_haruach_recited() == Some(true),
+            DayFlag::Chanukah => jewish_calendar.is_chanukah(),
+            DayFlag::AseresYemeiTeshuva => jewish_calendar.is_aseres_yemei_teshuva(),
+            DayFlag::CholHamoed => jewish_calendar.is_chol_hamoed(),
+            DayFlag::RoshChodesh => jewish_calendar.is_rosh_chodesh(),
+            DayFlag::Taanis => jewish_calendar.is_taanis(),
+            DayFlag::AssurBemelacha => jewish_calendar.is_assur_bemelacha(),
+        }
+    }
+}
+
+/// The zmanim of a day, in the order they occur, and the changes of the calendar around it.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DayEvents<Tz: TimeZone> {
+    /// The civil date of the day.
+    pub date: NaiveDate,
+    /// Every zman that occurs on the day, in order of time.
+    pub zmanim: Vec<(Zman, DateTime<Tz>)>,
+    /// The holidays of the day. See [`JewishCalendar::get_holidays`].
+    pub holidays: Vec<JewishHoliday>,
+    /// Candle lighting this evening. See [`LuachDay::get_candle_lighting`].
+    pub candle_lighting: Option<DateTime<Tz>>,
+    /// The end of Shabbos or yom tov this evening. See [`LuachDay::get_havdalah`].
+    pub havdalah: Option<DateTime<Tz>>,
+    /// The day of the omer counted this evening.
+    pub omer_tonight: Option<u8>,
+    /// The flags that are set on this day but were not on the day before.
+    pub started: Vec<DayFlag>,
+    /// The flags that were set on the day before but are not on this day.
+    pub ended: Vec<DayFlag>,
+    /// The flags that are set on the next day, beginning this evening, but not on this day.
+    pub starting_tonight: Vec<DayFlag>,
+    /// The flags that are set on this day but not on the next day, ending this evening.
+    pub ending_tonight: Vec<DayFlag>,
+}
+
+impl<Tz: TimeZone, N: AstronomicalCalculatorTrait> LuachDay<Tz, N> {
+    /// Returns everything that happens on this day, or `None` if the days before or after it
+    /// are out of range.
+    pub fn get_events(&self) -> Option<DayEvents<Tz>> {
+        let yesterday = self.jewish_calendar.copy_with_days_added(-1)?;
+        let today = &self.jewish_calendar;
+        let tomorrow = self.jewish_calendar.copy_with_days_added(1)?;
+        let changed = |from: &JewishCalendar<N>, to: &JewishCalendar<N>| {
+            DayFlag::values()
+                .into_iter()
+                .filter(|flag| !flag.is_set(from) && flag.is_set(to))
+                .collect()
+        };
+        let mut zmanim: Vec<_> = self
+            .zmanim_calendar
+            .get_all_zmanim()
+            .into_iter()
+            .filter_map(|(zman, time)| Some((zman, time?)))
+            .collect();
+        zmanim.sort_by(|(_, a), (_, b)| a.cmp(b));
+        Some(DayEvents {
+            date: self.zmanim_calendar.get_date_time().date_naive(),
+            zmanim,
+            holidays: today.get_holidays().collect(),
+            candle_lighting: self.get_candle_lighting(),
+            havdalah: self.get_havdalah(),
+            omer_tonight: today.get_day_of_omer_tomorrow(),
+            started: changed(&yesterday, today),
+            ended: changed(today, &yesterday),
+            starting_tonight: changed(today, &tomorrow),
+            ending_tonight: changed(&tomorrow, today),
+        })
+    }
+}
@@ -0,0 +1,200 @@
+//! A one-screen summary of a day as text, for bots and emails: the date, the holiday, parsha,
+//! daf yomi and omer, and the zmanim of the day. In plain text it reads:
+//!
+//! ```text
+//! יום שלישי, ט״ו בניסן תשפ״ד / Tuesday, Nissan 15, 5784 (2024-04-23)
+//! Pesach
+//! Daf Yomi: <tractate> <daf>
+//! Omer: <day>
+//! Alos Hashachar: <hh:mm>
+//! ...
+//! ```
+use core::fmt;
+
+use chrono::{DateTime, Duration, TimeZone, Timelike};
+
+use crate::{
+    astronomical_calculator::AstronomicalCalculatorTrait, constants::Zman, full_date::FullDateOptions,
+    jewish_calendar::JewishCalendarTrait, luach_day::LuachDay, zmanim_calendar::ZmanimCalendarTrait,
+};
+
+/// The zmanim of a digest unless others are chosen.
+pub const DEFAULT_DIGEST_ZMANIM: [Zman; 8] = [
+    Zman::AlosHashachar,
+    Zman::SofZmanShmaMGA,
+    Zman::SofZmanShmaGRA,
+    Zman::SofZmanTfilaGRA,
+    Zman::Chatzos,
+    Zman::MinchaGedola,
+    Zman::PlagHamincha,
+    Zman::Tzais,
+];
+
+/// How a digest is marked up.
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default, Hash)]
+pub enum DigestFormat {
+    /// One line per item.
+    #[default]
+    PlainText,
+    /// The date in bold, followed by a list.
+    Markdown,
+}
+
+/// Displays the digest of a [`LuachDay`]. See [`LuachDay::digest`].
+#[derive(Debug, Clone)]
+pub struct Digest<'a, Tz: TimeZone, N: AstronomicalCalculatorTrait> {
+    day: &'a LuachDay<Tz, N>,
+    zmanim: &'a [Zman],
+    format: DigestFormat,
+}
+
+impl<Tz: TimeZone, N: AstronomicalCalculatorTrait> Digest<'_, Tz, N> {
+    fn line(&self, f: &mut fmt::Formatter<'_>, label: &str, value: impl fmt::Display) -> fmt::Result {
+        if self.format == DigestFormat::Markdown {
+            write!(f, "- ")?;
+        }
+        match label.is_empty() {
+            true => writeln!(f, "{}", value),
+            false => writeln!(f, "{}: {}", label, value),
+        }
+    }
+}
+
+impl<Tz: TimeZone, N: AstronomicalCalculatorTrait> fmt::Display for Digest<'_, Tz, N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let calendar = &self.day.jewish_calendar;
+        let full_date = calendar.format_full_date(FullDateOptions::default());
+        let date = self.day.zmanim_calendar.get_date_time().date_naive();
+        match self.format {
+            DigestFormat::PlainText => writeln!(f, "{} ({})", full_date, date)?,
+            DigestFormat::Markdown => writeln!(f, "**{} ({})**\n", full_date, date)?,
+        }
+        for holiday in calendar.get_holidays() {
+            self.line(f, "", holiday.en_string())?;
+        }
+        if let Some(parsha) = calendar.get_parshah() {
+            match calendar.get_special_shabbos() {
+                Some(special) => self.line(
+                    f,
+                    "Parsha",
+                    format_args!("{} ({})", parsha.en_string(), special.en_string()),
+                )?,
+                None => self.line(f, "Parsha", parsha.en_string())?,
+            }
+        }
+        if let Some(daf) = calendar.get_daf_yomi_bavli() {
+            self.line(
+                f,
+                "Daf Yomi",
+                format_args!("{} {}", daf.tractate.en_string(), daf.daf_index),
+            )?;
+        }
+        if let Some(day) = calendar.get_day_of_omer() {
+            self.line(f, "Omer", day)?;
+        }
+        if let Some(candle_lighting) = self.day.get_candle_lighting() {
+            self.line(f, "Candle Lighting", Minute::floor(&candle_lighting))?;
+        }
+        for zman in self.zmanim {
+            let time = self.day.zmanim_calendar.get_zman(zman);
+            let label = zman.en_string();
+            match time {
+                Some(time) if is_deadline(zman) => self.line(f, label, Minute::floor(&time))?,
+                Some(time) => self.line(f, label, Minute::ceil(&time))?,
+                None => self.line(f, label, "-")?,
+            }
+        }
+        if let Some(havdalah) = self.day.get_havdalah() {
+            self.line(f, "Havdalah", Minute::ceil(&havdalah))?;
+        }
+        Ok(())
+    }
+}
+
+/// Returns true for zmanim by which something must be done, which are shown rounded down rather
+/// than up so that the minute shown is never too late.
+fn is_deadline(zman: &Zman) -> bool {
+    matches!(
+        zman,
+        Zman::SofZmanShmaAteretTorah
+            | Zman::SofZmanShmaGRA
+            | Zman::SofZmanShmaMGA
+            | Zman::SofZmanShmaMGA120Minutes
+            | Zman::SofZmanShmaMGA16Point1Degrees
+            | Zman::SofZmanShmaMGA18Degrees
+            | Zman::SofZmanShmaMGA19Point8Degrees
+            | Zman::SofZmanShmaMGA72MinutesZmanis
+            | Zman::SofZmanShmaMGA90Minutes
+            | Zman::SofZmanShmaMGA90MinutesZmanis
+            | Zman::SofZmanShmaMGA96Minutes
+            | Zman::SofZmanShmaMGA96MinutesZmanis
+            | Zman::SofZmanTfila2HoursBeforeChatzos
+            | Zman::SofZmanTfilaAteretTorah
+            | Zman::SofZmanTfilaGRA
+            | Zman::SofZmanTfilaMGA
+            | Zman::SofZmanTfilaMGA120Minutes
+            | Zman::SofZmanTfilaMGA16Point1Degrees
+            | Zman::SofZmanTfilaMGA18Degrees
+            | Zman::SofZmanTfilaMGA19Point8Degrees
+            | Zman::SofZmanTfilaMGA72MinutesZmanis
+            | Zman::SofZmanTfilaMGA90Minutes
+            | Zman::SofZmanTfilaMGA90MinutesZmanis
+            | Zman::SofZmanTfilaMGA96Minutes
+            | Zman::SofZmanTfilaMGA96MinutesZmanis
+            | Zman::Chatzos
+            | Zman::ChatzosAsHalfDay
+            | Zman::CandleLighting
+    )
+}
+
+/// A local time shown to the minute.
+struct Minute {
+    hour: u32,
+    minute: u32,
+}
+
+impl Minute {
+    fn floor<Tz: TimeZone>(time: &DateTime<Tz>) -> Self {
+        let time = time.naive_local().time();
+        Self {
+            hour: time.hour(),
+            minute: time.minute(),
+        }
+    }
+
+    fn ceil<Tz: TimeZone>(time: &DateTime<Tz>) -> Self {
+        let time = time.naive_local().time();
+        let time = match time.second() == 0 && time.nanosecond() == 0 {
+            true => time,
+            false => time + Duration::minutes(1),
+        };
+        Self {
+            hour: time.hour(),
+            minute: time.minute(),
+        }
+    }
+}
+
+impl fmt::Display for Minute {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:02}:{:02}", self.hour, self.minute)
+    }
+}
+
+impl<Tz: TimeZone, N: AstronomicalCalculatorTrait> LuachDay<Tz, N> {
+    /// Returns a summary of this day as text: the full date and the Gregorian date, the holiday,
+    /// parsha, daf yomi and day of the omer where there are any, candle lighting, the listed
+    /// `zmanim` such as [`DEFAULT_DIGEST_ZMANIM`], and havdalah.
+    ///
+    /// Times are shown to the minute in the time zone of the calendar. Deadlines such as sof zman
+    /// shma and candle lighting are rounded down and other zmanim up, as luchos print them. A zman
+    /// that does not occur on this day is shown as "-".
+    pub fn digest<'a>(&'a self, zmanim: &'a [Zman], format: DigestFormat) -> Digest<'a, Tz, N> {
+        Digest {
+            day: self,
+            zmanim,
+            format,
+        }
+    }
+}
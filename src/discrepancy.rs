@@ -0,0 +1,176 @@
+//! Comparing a published table of zmanim with the zmanim calculated for the same location, to
+//! find which shita and elevation settings the table was calculated with.
+//!
+//! The table is CSV with a date (`YYYY-MM-DD`) followed by a local time (`HH:MM` or
+//! `HH:MM:SS`) for each zman on that date. An empty field means the table has no time for that
+//! zman. Blank lines, lines starting with `#` and a header row, the first other line if it does
+//! not start with a date, are skipped.
+//!
+//! ```ignore
+//! let table = "date,shma,tzais\n2025-03-20,08:43,18:20\n";
+//! let report = calendar.compare_with_table(table, &[Zman::SofZmanShmaGRA, Zman::Tzais]).unwrap();
+//! ```
+use std::collections::BTreeMap;
+
+use chrono::{DateTime, Duration, NaiveDate, NaiveTime, TimeZone};
+
+use crate::{
+    astronomical_calculator::AstronomicalCalculatorTrait,
+    constants::Zman,
+    geolocation::GeoLocation,
+    zmanim_calendar::{ZmanimCalendar, ZmanimCalendarTrait},
+};
+
+/// The difference between a published and a calculated time of a zman on one date.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Discrepancy<Tz: TimeZone> {
+    pub date: NaiveDate,
+    pub zman: Zman,
+    pub published: NaiveTime,
+    /// The calculated time, or `None` if the zman cannot be calculated on this date.
+    pub calculated: Option<DateTime<Tz>>,
+    /// The calculated time less the published time, positive when the table is earlier.
+    pub delta: Option<Duration>,
+}
+
+/// How far the calculated times of a zman are from a published table over all of its dates.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DiscrepancySummary {
+    /// The number of dates on which both times are known.
+    pub count: usize,
+    pub mean: Duration,
+    pub mean_absolute: Duration,
+    pub min: Duration,
+    pub max: Duration,
+}
+
+/// Why a line of a published table could not be read.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TableErrorKind {
+    /// The line does not start with a `YYYY-MM-DD` date.
+    InvalidDate,
+    /// A field is not an `HH:MM` or `HH:MM:SS` time.
+    InvalidTime,
+    /// The line has more times than there are columns.
+    TooManyTimes,
+    /// The zmanim of the date cannot be calculated.
+    DateOutOfRange,
+}
+
+/// A line of a published table that could not be read.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TableError {
+    /// The 1-based line number in the table.
+    pub line: usize,
+    pub kind: TableErrorKind,
+}
+
+impl core::fmt::Display for TableError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let reason = match self.kind {
+            TableErrorKind::InvalidDate => "invalid date",
+            TableErrorKind::InvalidTime => "invalid time",
+            TableErrorKind::TooManyTimes => "more times than columns",
+            TableErrorKind::DateOutOfRange => "date out of range",
+        };
+        write!(f, "line {}: {}", self.line, reason)
+    }
+}
+
+impl core::error::Error for TableError {}
+
+/// The discrepancies of every time in a published table.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DiscrepancyReport<Tz: TimeZone> {
+    pub discrepancies: Vec<Discrepancy<Tz>>,
+}
+
+impl<Tz: TimeZone> DiscrepancyReport<Tz> {
+    /// Returns the summary of each zman in the table. Zmanim that were never calculated are
+    /// left out.
+    pub fn get_summaries(&self) -> BTreeMap<Zman, DiscrepancySummary> {
+        let mut deltas: BTreeMap<Zman, Vec<Duration>> = BTreeMap::new();
+        for discrepancy in &self.discrepancies {
+            if let Some(delta) = discrepancy.delta {
+                deltas.entry(discrepancy.zman).or_default().push(delta);
+            }
+        }
+        deltas
+            .into_iter()
+            .filter_map(|(zman, deltas)| {
+                let count = deltas.len();
+                let total: Duration = deltas.iter().copied().sum();
+                let total_absolute: Duration = deltas.iter().map(|delta| delta.abs()).sum();
+                let summary = DiscrepancySummary {
+                    count,
+                    mean: total / i32::try_from(count).ok()?,
+                    mean_absolute: total_absolute / i32::try_from(count).ok()?,
+                    min: deltas.iter().copied().min()?,
+                    max: deltas.iter().copied().max()?,
+                };
+                Some((zman, summary))
+            })
+            .collect()
+    }
+}
+
+impl<Tz: TimeZone, N: AstronomicalCalculatorTrait> ZmanimCalendar<Tz, GeoLocation, N> {
+    /// Compares the published table `csv`, whose columns after the date are the times of
+    /// `columns`, with the zmanim calculated at the location and with the settings of this
+    /// calendar. Returns an error with the line that cannot be parsed or has more times than
+    /// `columns`.
+    ///
+    /// Running this with calendars of different settings, such as with and without
+    /// [`ZmanimCalendar::use_elevation`], and comparing
+    /// [`DiscrepancySummary::mean_absolute`] shows which settings the table matches best.
+    pub fn compare_with_table(&self, csv: &str, columns: &[Zman]) -> Result<DiscrepancyReport<Tz>, TableError> {
+        let mut discrepancies = Vec::new();
+        let mut first = true;
+        for (index, line) in csv.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let error = |kind| TableError { line: index + 1, kind };
+            let is_first = core::mem::replace(&mut first, false);
+            let mut fields = line.split(',').map(str::trim);
+            let date = match NaiveDate::parse_from_str(fields.next().unwrap_or_default(), "%Y-%m-%d") {
+                Ok(date) => date,
+                // A header row.
+                Err(_) if is_first => continue,
+                Err(_) => return Err(error(TableErrorKind::InvalidDate)),
+            };
+            let fields: Vec<&str> = fields.collect();
+            if fields.len() > columns.len() {
+                return Err(error(TableErrorKind::TooManyTimes));
+            }
+            let calendar = self
+                .with_date(date)
+                .ok_or_else(|| error(TableErrorKind::DateOutOfRange))?;
+            for (field, zman) in fields.into_iter().zip(columns) {
+                if field.is_empty() {
+                    continue;
+                }
+                let published = NaiveTime::parse_from_str(field, "%H:%M:%S")
+                    .or_else(|_| NaiveTime::parse_from_str(field, "%H:%M"))
+                    .map_err(|_| error(TableErrorKind::InvalidTime))?;
+                let calculated = calendar.get_zman(zman);
+                let delta = calculated.as_ref().and_then(|calculated| {
+                    let published = calculated
+                        .timezone()
+                        .from_local_datetime(&date.and_time(published))
+                        .earliest()?;
+                    Some(calculated.clone() - published)
+                });
+                discrepancies.push(Discrepancy {
+                    date,
+                    zman: *zman,
+                    published,
+                    calculated,
+                    delta,
+                });
+            }
+        }
+        Ok(DiscrepancyReport { discrepancies })
+    }
+}
@@ -0,0 +1,106 @@
+//! Rounding and display of durations such as a shaah zmanis, so that reported values match
+//! those printed in luchos.
+use core::fmt;
+
+use chrono::Duration;
+
+/// The unit that durations are rounded to.
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DurationPrecision {
+    Milliseconds,
+    Seconds,
+    Minutes,
+}
+
+/// How durations are brought to a [`DurationPrecision`].
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Rounding {
+    /// Rounds to the nearest unit, with halves rounded away from zero.
+    Nearest,
+    /// Drops the part smaller than the unit.
+    Truncate,
+}
+
+/// A configuration for rounding and displaying durations.
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct DurationFormat {
+    pub precision: DurationPrecision,
+    pub rounding: Rounding,
+}
+
+impl Default for DurationFormat {
+    /// Rounds to the nearest minute, as most luchos print.
+    fn default() -> Self {
+        Self {
+            precision: DurationPrecision::Minutes,
+            rounding: Rounding::Nearest,
+        }
+    }
+}
+
+impl DurationFormat {
+    pub fn new(precision: DurationPrecision, rounding: Rounding) -> Self {
+        Self { precision, rounding }
+    }
+
+    /// Returns `duration` rounded to the configured precision.
+    pub fn apply(&self, duration: Duration) -> Duration {
+        let unit = match self.precision {
+            DurationPrecision::Milliseconds => 1,
+            DurationPrecision::Seconds => 1_000,
+            DurationPrecision::Minutes => 60_000,
+        };
+        let millis = duration.num_milliseconds();
+        let units = match self.rounding {
+            Rounding::Truncate => millis / unit,
+            Rounding::Nearest => (millis + millis.signum() * (unit / 2)) / unit,
+        };
+        Duration::milliseconds(units * unit)
+    }
+
+    /// Returns a [`fmt::Display`] of `duration` rounded to the configured precision, such as
+    /// `1h 12m` for minutes or `1h 12m 5s` for seconds.
+    pub fn display(&self, duration: Duration) -> FormattedDuration {
+        FormattedDuration {
+            duration: self.apply(duration),
+            precision: self.precision,
+        }
+    }
+}
+
+/// A rounded duration that displays in the style of a luach. See [`DurationFormat::display`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FormattedDuration {
+    duration: Duration,
+    precision: DurationPrecision,
+}
+
+impl FormattedDuration {
+    pub fn duration(&self) -> Duration {
+        self.duration
+    }
+}
+
+impl fmt::Display for FormattedDuration {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let millis = self.duration.num_milliseconds();
+        if millis < 0 {
+            write!(f, "-")?;
+        }
+        let millis = millis.unsigned_abs();
+        let hours = millis / 3_600_000;
+        let minutes = millis / 60_000 % 60;
+        if hours > 0 {
+            write!(f, "{}h ", hours)?;
+        }
+        write!(f, "{}m", minutes)?;
+        match self.precision {
+            DurationPrecision::Minutes => Ok(()),
+            DurationPrecision::Seconds => write!(f, " {}s", millis / 1_000 % 60),
+            DurationPrecision::Milliseconds => write!(f, " {}.{:03}s", millis / 1_000 % 60, millis % 1_000),
+        }
+    }
+}
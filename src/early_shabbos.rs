@@ -0,0 +1,93 @@
+//! Early Shabbos, accepted on Friday afternoon after plag hamincha, as many shuls do during the
+//! summer.
+use chrono::{DateTime, Datelike, Duration, NaiveDate, NaiveTime, TimeZone, Weekday};
+
+use crate::{
+    astronomical_calculator::AstronomicalCalculatorTrait,
+    constants::Zman,
+    geolocation::GeoLocation,
+    zmanim_calendar::{ZmanimCalendar, ZmanimCalendarTrait},
+};
+
+/// The times for accepting Shabbos early on a Friday.
+#[derive(Debug, Clone, PartialEq)]
+pub struct EarlyShabbos<Tz: TimeZone> {
+    /// The Friday.
+    pub date: NaiveDate,
+    /// The earliest time for mincha, which must be davened before Shabbos is accepted.
+    pub mincha_gedola: DateTime<Tz>,
+    /// The earliest time Shabbos can be accepted, and candles lit.
+    pub plag_hamincha: DateTime<Tz>,
+    pub sunset: DateTime<Tz>,
+    /// Candle lighting at the regular time before sunset.
+    pub candle_lighting: DateTime<Tz>,
+    /// Whether plag hamincha is earlier than the minimum time set for early Shabbos, in which
+    /// case there is no early minyan that week.
+    pub before_minimum: bool,
+}
+
+impl<Tz: TimeZone> EarlyShabbos<Tz> {
+    /// Returns the early Shabbos times for the first Friday on or after `date`, flagging it
+    /// when plag hamincha is before the local time `minimum`.
+    ///
+    /// Candle lighting is `candle_lighting_offset` before sunset.
+    pub fn for_week<N: AstronomicalCalculatorTrait>(
+        date: NaiveDate,
+        timezone: Tz,
+        geo_location: &GeoLocation,
+        calculator: N,
+        candle_lighting_offset: Duration,
+        minimum: NaiveTime,
+    ) -> Option<Self> {
+        let days_until_friday =
+            (Weekday::Fri.num_days_from_monday() as i64 - date.weekday().num_days_from_monday() as i64).rem_euclid(7);
+        let date = date.checked_add_signed(Duration::days(days_until_friday))?;
+        let calendar = ZmanimCalendar::new(
+            date,
+            timezone,
+            geo_location.clone(),
+            calculator,
+            false,
+            false,
+            candle_lighting_offset,
+            Duration::zero(),
+        )?;
+        let plag_hamincha = calendar.get_zman(&Zman::PlagHamincha)?;
+        Some(Self {
+            date,
+            mincha_gedola: calendar.get_zman(&Zman::MinchaGedola)?,
+            before_minimum: plag_hamincha.naive_local().time() < minimum,
+            plag_hamincha,
+            sunset: calendar.get_sunset()?,
+            candle_lighting: calendar.get_zman(&Zman::CandleLighting)?,
+        })
+    }
+
+    /// Returns the early Shabbos times for every Friday from `start` to `end` (inclusive),
+    /// skipping Fridays on which the zmanim cannot be calculated.
+    pub fn for_weeks<N: AstronomicalCalculatorTrait>(
+        start: NaiveDate,
+        end: NaiveDate,
+        timezone: Tz,
+        geo_location: &GeoLocation,
+        calculator: N,
+        candle_lighting_offset: Duration,
+        minimum: NaiveTime,
+    ) -> impl Iterator<Item = Self> + use<Tz, N> {
+        let geo_location = geo_location.clone();
+        start
+            .iter_days()
+            .take_while(move |date| *date <= end)
+            .filter(|date| date.weekday() == Weekday::Fri)
+            .filter_map(move |date| {
+                Self::for_week(
+                    date,
+                    timezone.clone(),
+                    &geo_location,
+                    calculator.clone(),
+                    candle_lighting_offset,
+                    minimum,
+                )
+            })
+    }
+}
@@ -0,0 +1,74 @@
+//! Zmanim both adjusted for the elevation of the location and at sea level, as many published
+//! tables show them side by side.
+use chrono::{DateTime, TimeZone};
+
+use crate::{
+    astronomical_calculator::AstronomicalCalculatorTrait,
+    constants::Zman,
+    geolocation::GeoLocation,
+    zmanim_calendar::{ZmanimCalendar, ZmanimCalendarTrait},
+};
+
+/// A time calculated for the elevation of the location and for sea level.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ElevationPair<Tz: TimeZone> {
+    pub elevated: Option<DateTime<Tz>>,
+    pub sea_level: Option<DateTime<Tz>>,
+}
+
+impl<Tz: TimeZone, N: AstronomicalCalculatorTrait> ZmanimCalendar<Tz, GeoLocation, N> {
+    /// Returns the elevation adjusted and sea level sunrise.
+    pub fn get_sunrise_pair(&self) -> ElevationPair<Tz> {
+        ElevationPair {
+            elevated: self.get_sunrise(),
+            sea_level: self.get_sea_level_sunrise(),
+        }
+    }
+
+    /// Returns the elevation adjusted and sea level sunset.
+    pub fn get_sunset_pair(&self) -> ElevationPair<Tz> {
+        ElevationPair {
+            elevated: self.get_sunset(),
+            sea_level: self.get_sea_level_sunset(),
+        }
+    }
+
+    /// Returns the time the sun reaches `offset_zenith` in the morning at the elevation of the
+    /// location and at sea level. Elevation only affects the geometric zenith, so the two are
+    /// the same for any zenith below the horizon.
+    pub fn get_sunrise_offset_by_degrees_pair(&self, offset_zenith: f64) -> ElevationPair<Tz> {
+        ElevationPair {
+            elevated: self.get_sunrise_offset_by_degrees(offset_zenith),
+            sea_level: self
+                .copy_at_sea_level()
+                .and_then(|calendar| calendar.get_sunrise_offset_by_degrees(offset_zenith)),
+        }
+    }
+
+    /// Returns the time the sun reaches `offset_zenith` in the evening at the elevation of the
+    /// location and at sea level. See [`ZmanimCalendar::get_sunrise_offset_by_degrees_pair`].
+    pub fn get_sunset_offset_by_degrees_pair(&self, offset_zenith: f64) -> ElevationPair<Tz> {
+        ElevationPair {
+            elevated: self.get_sunset_offset_by_degrees(offset_zenith),
+            sea_level: self
+                .copy_at_sea_level()
+                .and_then(|calendar| calendar.get_sunset_offset_by_degrees(offset_zenith)),
+        }
+    }
+
+    /// Returns `zman` calculated at the elevation of the location and at sea level.
+    pub fn get_zman_pair(&self, zman: &Zman) -> ElevationPair<Tz> {
+        ElevationPair {
+            elevated: self.get_zman(zman),
+            sea_level: self.copy_at_sea_level().and_then(|calendar| calendar.get_zman(zman)),
+        }
+    }
+
+    fn copy_at_sea_level(&self) -> Option<Self> {
+        let mut calendar = self.with_date(self.get_date_time().date_naive())?;
+        let mut geo_location = self.get_geo_location().clone();
+        geo_location.elevation = 0.0;
+        calendar.set_geo_location(geo_location);
+        Some(calendar)
+    }
+}
@@ -0,0 +1,407 @@
+//! The full date line of a luach, the day of the week with the Jewish date in Hebrew letters and
+//! transliterated, such as "יום שלישי, ט״ו בניסן תשפ״ד / Tuesday, Nissan 15, 5784", and
+//! parsing dates written that way back into a [`JewishDate`].
+//!
+//! ```
+//! use jew_sdk::{full_date::FullDateOptions, prelude::*};
+//!
+//! let calendar =
+//!     JewishCalendar::from_hebrew_date(5784, JewishMonth::Nissan, 15, false, false, false, NOAACalculator).unwrap();
+//! assert_eq!(
+//!     calendar.format_full_date(FullDateOptions::default()).to_string(),
+//!     "יום שלישי, ט״ו בניסן תשפ״ד / Tuesday, Nissan 15, 5784"
+//! );
+//! ```
+use core::fmt;
+
+use chrono::Weekday;
+
+use crate::{
+    astronomical_calculator::AstronomicalCalculatorTrait,
+    constants::{JewishMonth, WeekdayStrings},
+    jewish_calendar::{JewishCalendar, JewishCalendarTrait},
+    jewish_year,
+};
+
+const GERESH: char = '׳';
+const GERSHAYIM: char = '״';
+
+/// Displays a number in Hebrew letters, such as ט״ו for 15 or תשפ״ד for 784. Thousands are
+/// left out, as is usual for years. Zero displays as nothing.
+///
+/// This does not allocate, so it is available without the `std` feature.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct HebrewNumeral(pub u32);
+
+const HUNDREDS: [&str; 10] = ["", "ק", "ר", "ש", "ת", "תק", "תר", "תש", "תת", "תתק"];
+const TENS: [&str; 10] = ["", "י", "כ", "ל", "מ", "נ", "ס", "ע", "פ", "צ"];
+const ONES: [&str; 10] = ["", "א", "ב", "ג", "ד", "ה", "ו", "ז", "ח", "ט"];
+
+impl HebrewNumeral {
+    /// Parses a number in Hebrew letters, the inverse of displaying it. The geresh and
+    /// gershayim may be written as `'` and `"` or left out, and a geresh after the first letter
+    /// marks thousands, as in ה׳תשפ״ד. Returns `None` if the letters are not in the usual order,
+    /// as in a word rather than a number.
+    ///
+    /// ```
+    /// use jew_sdk::full_date::HebrewNumeral;
+    ///
+    /// assert_eq!(HebrewNumeral::parse("ט\"ו"), Some(HebrewNumeral(15)));
+    /// assert_eq!(HebrewNumeral::parse("ה׳תשפ״ד"), Some(HebrewNumeral(5784)));
+    /// assert_eq!(HebrewNumeral::parse("שבט"), None);
+    /// ```
+    pub fn parse(text: &str) -> Option<Self> {
+        let (thousands, units) = match text.split_once([GERESH, '\'']) {
+            Some((thousands, units)) if !units.is_empty() => (parse_letters(thousands)?, units),
+            _ => (0, text),
+        };
+        Some(HebrewNumeral(thousands * 1000 + parse_letters(units)?))
+    }
+}
+
+fn letter_value(letter: char) -> Option<u32> {
+    [(&ONES, 1), (&TENS, 10), (&HUNDREDS, 100)]
+        .into_iter()
+        .find_map(|(letters, place)| {
+            let index = letters.iter().position(|letters| {
+                let mut chars = letters.chars();
+                chars.next() == Some(letter) && chars.next().is_none()
+            })?;
+            Some(index as u32 * place)
+        })
+}
+
+fn parse_letters(text: &str) -> Option<u32> {
+    let place = |value: u32| value.ilog10();
+    let mut total = 0;
+    let mut previous: Option<u32> = None;
+    for letter in text.chars().filter(|c| !matches!(*c, GERESH | GERSHAYIM | '\'' | '"')) {
+        let value = letter_value(letter)?;
+        // Each place has one letter, from the largest down, except that ת repeats as in תת for
+        // 800, and 15 and 16 are written ט״ו and ט״ז.
+        let in_order = match previous {
+            None => true,
+            Some(previous) => {
+                place(value) < place(previous)
+                    || (previous == 400 && place(value) == 2)
+                    || (previous == 9 && matches!(value, 6 | 7))
+            }
+        };
+        if !in_order {
+            return None;
+        }
+        total += value;
+        previous = Some(value);
+    }
+    previous.map(|_| total)
+}
+
+impl fmt::Display for HebrewNumeral {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let number = self.0 % 1000;
+        let (tens, ones) = match number % 100 {
+            // Written ט״ו and ט״ז to avoid spelling the name of Hashem.
+            15 => ("ט", "ו"),
+            16 => ("ט", "ז"),
+            rest => (TENS[(rest / 10) as usize], ONES[(rest % 10) as usize]),
+        };
+        let letters = HUNDREDS[(number / 100) as usize]
+            .chars()
+            .chain(tens.chars())
+            .chain(ones.chars());
+        let count = letters.clone().count();
+        for (index, letter) in letters.enumerate() {
+            if count > 1 && index == count - 1 {
+                write!(f, "{}", GERSHAYIM)?;
+            }
+            write!(f, "{}", letter)?;
+        }
+        if count == 1 {
+            write!(f, "{}", GERESH)?;
+        }
+        Ok(())
+    }
+}
+
+/// The language of the part of a full date in Hebrew letters.
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default, Hash)]
+pub enum FullDateLocale {
+    /// "יום שלישי, ט״ו בניסן תשפ״ד"
+    #[default]
+    Hebrew,
+    /// "דינסטיק, ט״ו ניסן תשפ״ד"
+    Yiddish,
+}
+
+/// The order of the transliterated part of a full date.
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default, Hash)]
+pub enum TransliterationStyle {
+    /// "Tuesday, Nissan 15, 5784"
+    #[default]
+    MonthFirst,
+    /// "Tuesday, 15 Nissan 5784"
+    DayFirst,
+}
+
+/// What a full date includes. The default is both parts, in Hebrew and month first.
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub struct FullDateOptions {
+    /// The language of the date in Hebrew letters, or `None` to leave it out.
+    pub locale: Option<FullDateLocale>,
+    /// The order of the date transliterated into English, or `None` to leave it out.
+    pub transliteration: Option<TransliterationStyle>,
+}
+
+impl Default for FullDateOptions {
+    fn default() -> Self {
+        Self {
+            locale: Some(FullDateLocale::Hebrew),
+            transliteration: Some(TransliterationStyle::MonthFirst),
+        }
+    }
+}
+
+/// Displays the full date of a [`JewishCalendar`]. See [`JewishCalendar::format_full_date`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FullDate {
+    weekday: Weekday,
+    year: i32,
+    month: JewishMonth,
+    day: u8,
+    is_leap_year: bool,
+    options: FullDateOptions,
+}
+
+impl fmt::Display for FullDate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let day = HebrewNumeral(u32::from(self.day));
+        let year = HebrewNumeral(self.year.unsigned_abs());
+        match self.options.locale {
+            Some(FullDateLocale::Hebrew) => {
+                match self.weekday {
+                    Weekday::Sat => write!(f, "{}", self.weekday.he_string())?,
+                    weekday => write!(f, "יום {}", weekday.he_string())?,
+                }
+                write!(f, ", {} ב{} {}", day, self.month.he_string(self.is_leap_year), year)?;
+            }
+            Some(FullDateLocale::Yiddish) => write!(
+                f,
+                "{}, {} {} {}",
+                self.weekday.yi_string(),
+                day,
+                self.month.yi_string(self.is_leap_year),
+                year
+            )?,
+            None => {}
+        }
+        if self.options.locale.is_some() && self.options.transliteration.is_some() {
+            write!(f, " / ")?;
+        }
+        let weekday = self.weekday.en_string();
+        let month = self.month.en_string(self.is_leap_year);
+        match self.options.transliteration {
+            Some(TransliterationStyle::MonthFirst) => {
+                write!(f, "{}, {} {}, {}", weekday, month, self.day, self.year)
+            }
+            Some(TransliterationStyle::DayFirst) => write!(f, "{}, {} {} {}", weekday, self.day, month, self.year),
+            None => Ok(()),
+        }
+    }
+}
+
+impl<N: AstronomicalCalculatorTrait> JewishCalendar<N> {
+    /// Returns the day of the week and the Jewish date in Hebrew letters and transliterated, such
+    /// as "יום שלישי, ט״ו בניסן תשפ״ד / Tuesday, Nissan 15, 5784".
+    pub fn format_full_date(&self, options: FullDateOptions) -> FullDate {
+        FullDate {
+            weekday: self.get_day_of_week(),
+            year: self.get_jewish_year(),
+            month: self.get_jewish_month(),
+            day: self.get_jewish_day_of_month(),
+            is_leap_year: self.is_jewish_leap_year(),
+            options,
+        }
+    }
+}
+
+/// A Jewish date without a calendar's settings, as parsed from text by [`JewishDate::parse`].
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct JewishDate {
+    pub year: i32,
+    pub month: JewishMonth,
+    pub day: u8,
+}
+
+/// Spellings of the months, lowercase and without punctuation. Hebrew names may also be written
+/// with the prefix ב, as in בשבט.
+const MONTH_NAMES: [(&str, JewishMonth); 40] = [
+    ("nissan", JewishMonth::Nissan),
+    ("nisan", JewishMonth::Nissan),
+    ("ניסן", JewishMonth::Nissan),
+    ("iyar", JewishMonth::Iyar),
+    ("iyyar", JewishMonth::Iyar),
+    ("אייר", JewishMonth::Iyar),
+    ("איר", JewishMonth::Iyar),
+    ("sivan", JewishMonth::Sivan),
+    ("סיון", JewishMonth::Sivan),
+    ("סיוון", JewishMonth::Sivan),
+    ("tammuz", JewishMonth::Tammuz),
+    ("tamuz", JewishMonth::Tammuz),
+    ("תמוז", JewishMonth::Tammuz),
+    ("av", JewishMonth::Av),
+    ("אב", JewishMonth::Av),
+    ("elul", JewishMonth::Elul),
+    ("אלול", JewishMonth::Elul),
+    ("tishrei", JewishMonth::Tishrei),
+    ("tishri", JewishMonth::Tishrei),
+    ("תשרי", JewishMonth::Tishrei),
+    ("cheshvan", JewishMonth::Cheshvan),
+    ("heshvan", JewishMonth::Cheshvan),
+    ("marcheshvan", JewishMonth::Cheshvan),
+    ("חשון", JewishMonth::Cheshvan),
+    ("חשוון", JewishMonth::Cheshvan),
+    ("מרחשון", JewishMonth::Cheshvan),
+    ("מרחשוון", JewishMonth::Cheshvan),
+    ("kislev", JewishMonth::Kislev),
+    ("כסלו", JewishMonth::Kislev),
+    ("כסליו", JewishMonth::Kislev),
+    ("teves", JewishMonth::Teves),
+    ("tevet", JewishMonth::Teves),
+    ("טבת", JewishMonth::Teves),
+    ("shevat", JewishMonth::Shevat),
+    ("shvat", JewishMonth::Shevat),
+    ("שבט", JewishMonth::Shevat),
+    ("adar", JewishMonth::Adar),
+    ("אדר", JewishMonth::Adar),
+    ("adarii", JewishMonth::AdarII),
+    ("אדרב", JewishMonth::AdarII),
+];
+
+/// The words after Adar that tell Adar I from Adar II.
+const ADAR_NAMES: [(&str, JewishMonth); 12] = [
+    ("i", JewishMonth::Adar),
+    ("aleph", JewishMonth::Adar),
+    ("alef", JewishMonth::Adar),
+    ("rishon", JewishMonth::Adar),
+    ("א", JewishMonth::Adar),
+    ("ראשון", JewishMonth::Adar),
+    ("ii", JewishMonth::AdarII),
+    ("bet", JewishMonth::AdarII),
+    ("beis", JewishMonth::AdarII),
+    ("sheni", JewishMonth::AdarII),
+    ("ב", JewishMonth::AdarII),
+    ("שני", JewishMonth::AdarII),
+];
+
+/// Words that may appear in a date but say nothing about it, besides the names of the days of
+/// the week.
+const IGNORED_WORDS: [&str; 6] = ["יום", "saturday", "shabbat", "menachem", "מנחם", "במנחם"];
+
+fn folded_eq(token: &str, name: &str) -> bool {
+    token
+        .chars()
+        .filter(|c| c.is_alphanumeric())
+        .map(|c| c.to_ascii_lowercase())
+        .eq(name.chars().map(|c| c.to_ascii_lowercase()))
+}
+
+fn find_name(token: &str, names: &[(&str, JewishMonth)]) -> Option<JewishMonth> {
+    names
+        .iter()
+        .find(|(name, _)| folded_eq(token, name))
+        .map(|(_, month)| *month)
+}
+
+fn is_ignored(token: &str) -> bool {
+    let weekdays = [
+        Weekday::Sun,
+        Weekday::Mon,
+        Weekday::Tue,
+        Weekday::Wed,
+        Weekday::Thu,
+        Weekday::Fri,
+        Weekday::Sat,
+    ];
+    IGNORED_WORDS.iter().any(|word| folded_eq(token, word))
+        || weekdays.iter().any(|weekday| {
+            folded_eq(token, weekday.en_string())
+                || folded_eq(token, weekday.he_string())
+                || folded_eq(token, weekday.yi_string())
+        })
+}
+
+impl JewishDate {
+    /// Parses a Jewish date in Hebrew letters or transliterated, such as "ט״ו בשבט תשפ״ד",
+    /// "15 Shevat 5784" or "Shevat 15, 5784", the inverse of [`JewishCalendar::format_full_date`].
+    /// The day comes before the year, and a day of the week is allowed and ignored. Only the part
+    /// before a `/` is read, so a full date with both parts parses too.
+    ///
+    /// Months are matched ignoring case and punctuation, with common alternative spellings such
+    /// as Tevet, Heshvan and Sh'vat. A year of under 1000, such as תשפ״ד, is taken to be in the
+    /// sixth millennium.
+    ///
+    /// Returns `None` if the text is not a date, or if the day does not occur in the month that
+    /// year, including Adar II in a year that is not a leap year.
+    ///
+    /// ```
+    /// use jew_sdk::{full_date::JewishDate, prelude::JewishMonth};
+    ///
+    /// let date = JewishDate { year: 5784, month: JewishMonth::Shevat, day: 15 };
+    /// assert_eq!(JewishDate::parse("ט\"ו בשבט תשפ\"ד"), Some(date));
+    /// assert_eq!(JewishDate::parse("15 Shevat 5784"), Some(date));
+    /// ```
+    pub fn parse(text: &str) -> Option<Self> {
+        let text = text.split('/').next()?;
+        let mut tokens = text
+            .split(|c: char| c.is_whitespace() || c == ',')
+            .filter(|token| !token.is_empty())
+            .peekable();
+        let mut month = None;
+        let mut numbers = [0u32; 2];
+        let mut count = 0;
+        while let Some(token) = tokens.next() {
+            if is_ignored(token) {
+                continue;
+            }
+            let number = match token.parse::<u32>() {
+                Ok(number) => Some(number),
+                Err(_) => HebrewNumeral::parse(token).map(|numeral| numeral.0),
+            };
+            if let Some(number) = number {
+                *numbers.get_mut(count)? = number;
+                count += 1;
+                continue;
+            }
+            let found = find_name(token, &MONTH_NAMES).or_else(|| find_name(token.strip_prefix('ב')?, &MONTH_NAMES))?;
+            if month.is_some() {
+                return None;
+            }
+            let adar = tokens.peek().and_then(|token| find_name(token, &ADAR_NAMES));
+            month = match (found, adar) {
+                (JewishMonth::Adar, Some(adar)) => {
+                    tokens.next();
+                    Some(adar)
+                }
+                (found, _) => Some(found),
+            };
+        }
+        let [day, year] = numbers;
+        if count != 2 {
+            return None;
+        }
+        let month = month?;
+        let year = i32::try_from(if year < 1000 { year + 5000 } else { year }).ok()?;
+        let day = u8::try_from(day).ok()?;
+        if month == JewishMonth::AdarII && !jewish_year::is_jewish_leap_year(year) {
+            return None;
+        }
+        if day == 0 || day > jewish_year::get_days_in_jewish_month(month, year) {
+            return None;
+        }
+        Some(JewishDate { year, month, day })
+    }
+}
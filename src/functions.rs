@@ -0,0 +1,96 @@
+//! Stateless functions that take a location, date and settings and return zmanim, for callers
+//! such as request handlers that have no use for keeping a [`ZmanimCalendar`] around.
+use chrono::{DateTime, Duration, NaiveDate, TimeZone};
+
+use crate::{
+    astronomical_calculator::{AstronomicalCalculatorTrait, ErasedCalculator},
+    constants::Zman,
+    geolocation::GeoLocation,
+    zmanim_calendar::{ZmanimCalendar, ZmanimCalendarTrait, ZmanimCore},
+};
+
+/// The settings of a [`ZmanimCalendar`] that affect its zmanim.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ZmanimSettings {
+    pub use_astronomical_chatzos: bool,
+    pub use_astronomical_chatzos_for_other_zmanim: bool,
+    pub candle_lighting_offset: Duration,
+    pub ateret_torah_sunset_offset: Duration,
+    pub use_elevation: bool,
+    pub havdalah_zman: Zman,
+}
+
+impl Default for ZmanimSettings {
+    /// Chatzos as the midpoint of the day, candle lighting 18 minutes before sunset, the Ateret
+    /// Torah's tzais 40 minutes after sunset, elevation adjusted sunrise and sunset, and havdalah
+    /// at [`Zman::Tzais`].
+    fn default() -> Self {
+        Self {
+            use_astronomical_chatzos: false,
+            use_astronomical_chatzos_for_other_zmanim: false,
+            candle_lighting_offset: Duration::minutes(18),
+            ateret_torah_sunset_offset: Duration::minutes(40),
+            use_elevation: true,
+            havdalah_zman: Zman::Tzais,
+        }
+    }
+}
+
+/// Returns `zman` on `date` at `geo_location`, in `timezone`.
+pub fn get_zman<Tz: TimeZone, N: AstronomicalCalculatorTrait>(
+    date: NaiveDate,
+    timezone: Tz,
+    geo_location: &GeoLocation,
+    calculator: N,
+    settings: &ZmanimSettings,
+    zman: &Zman,
+) -> Option<DateTime<Tz>> {
+    let date_time = start_of_day(date, &timezone)?;
+    let core = ZmanimCore::new(&date_time, geo_location, ErasedCalculator(&calculator), *settings);
+    Some(core.get_zman(zman)?.with_timezone(&timezone))
+}
+
+/// Returns each of `zmanim` on `date` at `geo_location`, in `timezone`, in the same order.
+/// Returns `None` if the start of `date` does not exist in the time zone.
+pub fn get_zmanim<'a, Tz: TimeZone, N: AstronomicalCalculatorTrait>(
+    date: NaiveDate,
+    timezone: Tz,
+    geo_location: &'a GeoLocation,
+    calculator: N,
+    settings: &ZmanimSettings,
+    zmanim: &'a [Zman],
+) -> Option<impl Iterator<Item = (Zman, Option<DateTime<Tz>>)> + use<'a, Tz, N>> {
+    let date_time = start_of_day(date, &timezone)?;
+    let settings = *settings;
+    Some(zmanim.iter().map(move |zman| {
+        let core = ZmanimCore::new(&date_time, geo_location, ErasedCalculator(&calculator), settings);
+        (*zman, core.get_zman(zman).map(|time| time.with_timezone(&timezone)))
+    }))
+}
+
+/// Returns midnight at the start of `date` in `timezone`, or `None` if it does not exist there.
+fn start_of_day<Tz: TimeZone>(date: NaiveDate, timezone: &Tz) -> Option<DateTime<Tz>> {
+    timezone.from_local_datetime(&date.and_hms_opt(0, 0, 0)?).single()
+}
+
+pub(crate) fn calendar<Tz: TimeZone, N: AstronomicalCalculatorTrait>(
+    date: NaiveDate,
+    timezone: Tz,
+    geo_location: &GeoLocation,
+    calculator: N,
+    settings: &ZmanimSettings,
+) -> Option<ZmanimCalendar<Tz, GeoLocation, N>> {
+    let mut calendar = ZmanimCalendar::new(
+        date,
+        timezone,
+        geo_location.clone(),
+        calculator,
+        settings.use_astronomical_chatzos,
+        settings.use_astronomical_chatzos_for_other_zmanim,
+        settings.candle_lighting_offset,
+        settings.ateret_torah_sunset_offset,
+    )?;
+    calendar.set_use_elevation(settings.use_elevation);
+    calendar.havdalah_zman = settings.havdalah_zman;
+    Some(calendar)
+}
@@ -1,5 +1,6 @@
-use crate::constants::{_Formula, _MINUTE_MILLIS};
-use chrono::{DateTime, Duration, Offset, TimeZone};
+use crate::constants::{_EARTH_RADIUS, _Formula, _MINUTE_MILLIS};
+use chrono::{DateTime, Duration, NaiveDate, Offset, TimeZone};
+use core::cmp::Ordering;
 use core::f64::consts::PI;
 #[allow(unused_imports)]
 use core_maths::CoreFloat;
@@ -14,14 +15,75 @@ pub trait GeoLocationTrait {
     fn get_geodesic_distance(&self, location: &Self) -> Option<f64>;
     fn get_local_mean_time_offset<Tz: TimeZone>(&self, date: &DateTime<Tz>) -> Duration;
     fn get_antimeridian_adjustment<Tz: TimeZone>(&self, date: &DateTime<Tz>) -> i8;
+    /// The earth model used for rhumb line distances and the elevation adjustment of sunrise and sunset.
+    fn get_earth_model(&self) -> EarthModel {
+        EarthModel::default()
+    }
 }
 
+/// The model of the earth used for rhumb line distances and for the elevation adjustment
+/// of sunrise and sunset. Geodesic calculations always use the WGS84 ellipsoid.
 #[cfg_attr(feature = "defmt", derive(defmt::Format))]
-#[derive(Debug, Clone, PartialEq, Default, PartialOrd)]
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum EarthModel {
+    /// The radii used by KosherJava: 6356.9 km for the elevation adjustment and the
+    /// WGS84 equatorial radius of 6378.137 km for rhumb line distances.
+    #[default]
+    KosherJava,
+    /// A sphere with the WGS84 mean radius of 6371.0088 km.
+    Wgs84Mean,
+    /// A sphere with a custom radius in kilometers.
+    Spherical(f64),
+}
+
+impl EarthModel {
+    /// The radius in kilometers used for the elevation adjustment.
+    pub fn elevation_radius_km(&self) -> f64 {
+        match self {
+            EarthModel::KosherJava => _EARTH_RADIUS,
+            EarthModel::Wgs84Mean => 6371.0088,
+            EarthModel::Spherical(radius) => *radius,
+        }
+    }
+
+    /// The radius in meters used for rhumb line distances.
+    pub fn distance_radius_meters(&self) -> f64 {
+        match self {
+            EarthModel::KosherJava => 6378137.0,
+            EarthModel::Wgs84Mean => 6371008.8,
+            EarthModel::Spherical(radius) => radius * 1000.0,
+        }
+    }
+}
+
+/// The largest deviation of a time zone from the local mean time of a location that
+/// [`GeoLocation::is_time_zone_plausible`] accepts. Western China, several hours from the
+/// meridian of its single time zone, is the largest deviation in common use.
+pub const MAX_TIME_ZONE_DEVIATION_HOURS: i64 = 4;
+
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[derive(Debug, Clone, PartialEq, Default)]
 pub struct GeoLocation {
     pub latitude: f64,
     pub longitude: f64,
     pub elevation: f64,
+    /// [`GeoLocation::with_earth_model`] checks that a custom radius is valid.
+    pub earth_model: EarthModel,
+}
+
+/// Locations are ordered by latitude, longitude and elevation. Locations that differ only in
+/// their earth model are not comparable.
+impl PartialOrd for GeoLocation {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        match (self.latitude, self.longitude, self.elevation).partial_cmp(&(
+            other.latitude,
+            other.longitude,
+            other.elevation,
+        )) {
+            Some(Ordering::Equal) if self.earth_model != other.earth_model => None,
+            ordering => ordering,
+        }
+    }
 }
 impl GeoLocation {
     pub fn new(latitude: f64, longitude: f64, elevation: f64) -> Option<Self> {
@@ -41,8 +103,52 @@ impl GeoLocation {
             latitude,
             longitude,
             elevation,
+            earth_model: EarthModel::default(),
         })
     }
+
+    /// Returns this location using the given earth model.
+    /// Returns `None` if a custom radius is not a positive, finite number.
+    pub fn with_earth_model(self, earth_model: EarthModel) -> Option<Self> {
+        if matches!(earth_model, EarthModel::Spherical(radius) if !radius.is_finite() || radius <= 0.0) {
+            return None;
+        }
+        Some(Self { earth_model, ..self })
+    }
+
+    /// Returns a location with the coordinates, elevation and earth model of `geo_location`.
+    pub(crate) fn from_location<G: GeoLocationTrait>(geo_location: &G) -> Self {
+        Self {
+            latitude: geo_location.get_latitude(),
+            longitude: geo_location.get_longitude(),
+            elevation: geo_location.get_elevation(),
+            earth_model: geo_location.get_earth_model(),
+        }
+    }
+
+    /// Returns how far the standard UTC offset of `timezone` in `year` is from the local mean
+    /// time of this location, between -12 and 12 hours. Locations a time zone is actually used in
+    /// are within a few hours of it, so a larger deviation usually means the wrong time zone was
+    /// given, such as New York coordinates with `Asia/Tokyo`. Returns `None` if the year is out
+    /// of range.
+    pub fn get_time_zone_deviation<Tz: TimeZone>(&self, timezone: &Tz, year: i32) -> Option<Duration> {
+        // The smaller of the winter and summer offsets, to leave out daylight saving time.
+        let offset_at = |month| -> Option<i32> {
+            let instant = NaiveDate::from_ymd_opt(year, month, 1)?.and_hms_opt(12, 0, 0)?;
+            Some(timezone.offset_from_utc_datetime(&instant).fix().local_minus_utc())
+        };
+        let standard_offset = offset_at(1)?.min(offset_at(7)?);
+        let local_mean_time_offset = (self.longitude * 240.0).round() as i32;
+        let deviation = (standard_offset - local_mean_time_offset + 12 * 3600).rem_euclid(24 * 3600) - 12 * 3600;
+        Some(Duration::seconds(deviation.into()))
+    }
+
+    /// Returns true if `timezone` is within [`MAX_TIME_ZONE_DEVIATION_HOURS`] of the local mean
+    /// time of this location. See [`GeoLocation::get_time_zone_deviation`].
+    pub fn is_time_zone_plausible<Tz: TimeZone>(&self, timezone: &Tz, year: i32) -> bool {
+        self.get_time_zone_deviation(timezone, year)
+            .is_some_and(|deviation| deviation.num_seconds().abs() <= MAX_TIME_ZONE_DEVIATION_HOURS * 3600)
+    }
     fn vincenty_inverse_formula(&self, location: &impl GeoLocationTrait, formula: _Formula) -> Option<f64> {
         let major_semi_axis = 6378137.0;
         let minor_semi_axis = 6356752.3142;
@@ -165,7 +271,7 @@ impl GeoLocationTrait for GeoLocation {
     }
 
     fn get_rhumb_line_distance(&self, location: &Self) -> f64 {
-        let earth_radius = 6378137.0;
+        let earth_radius = self.get_earth_model().distance_radius_meters();
         let d_lat = location.get_latitude().to_radians() - self.get_latitude().to_radians();
         let mut d_lon = (location.get_longitude().to_radians() - self.get_longitude().to_radians()).abs();
         let d_phi = ((location.get_latitude().to_radians() / 2.0 + PI / 4.0).tan()).ln()
@@ -212,4 +318,8 @@ impl GeoLocationTrait for GeoLocation {
         }
         0
     }
+
+    fn get_earth_model(&self) -> EarthModel {
+        self.earth_model
+    }
 }
@@ -0,0 +1,86 @@
+//! Mapping an instant to the Jewish date it belongs to. The Jewish date begins at nightfall,
+//! so an instant in the evening belongs to the next civil day's Jewish date.
+use chrono::{DateTime, Datelike, Duration, TimeZone};
+
+use crate::{
+    astronomical_calculator::AstronomicalCalculatorTrait,
+    constants::Zman,
+    geolocation::GeoLocation,
+    jewish_calendar::JewishCalendar,
+    zmanim_calendar::{ZmanimCalendar, ZmanimCalendarTrait},
+};
+
+/// The moment at which the Jewish date changes.
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default, Hash)]
+pub enum DayBoundary {
+    /// The date changes at sunset.
+    #[default]
+    Sunset,
+    /// The date changes at tzais, when the sun is 8.5° below the horizon. If the sun does not
+    /// reach that depth on a given day, sunset is used instead.
+    Tzais,
+    /// The date changes at the given zman, such as [`Zman::Tzais72`]. If the zman does not occur
+    /// on a given day, sunset is used instead.
+    Zman(Zman),
+}
+
+/// An instant together with the Jewish date it belongs to at a given location.
+#[derive(Debug, Clone, PartialEq)]
+pub struct HalachicDay<Tz: TimeZone, N: AstronomicalCalculatorTrait> {
+    pub date_time: DateTime<Tz>,
+    pub jewish_calendar: JewishCalendar<N>,
+    /// Whether the instant is after the day boundary, i.e. on the evening before the civil date
+    /// of `jewish_calendar`.
+    pub is_evening: bool,
+}
+
+impl<Tz: TimeZone, N: AstronomicalCalculatorTrait> HalachicDay<Tz, N> {
+    /// Determines the Jewish date of `date_time` at `geo_location`, switching to the next date
+    /// at the given `boundary`. If the sun does not set on that day, the civil date is used.
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        date_time: DateTime<Tz>,
+        geo_location: &GeoLocation,
+        boundary: DayBoundary,
+        in_israel: bool,
+        is_mukaf_choma: bool,
+        use_modern_holidays: bool,
+        calculator: N,
+    ) -> Option<Self> {
+        let date = date_time.date_naive();
+        let zmanim_calendar = ZmanimCalendar::new(
+            date,
+            date_time.timezone(),
+            geo_location.clone(),
+            calculator.clone(),
+            false,
+            false,
+            Duration::zero(),
+            Duration::zero(),
+        )?;
+        let boundary_time = match boundary {
+            DayBoundary::Sunset => zmanim_calendar.get_sunset(),
+            DayBoundary::Tzais => zmanim_calendar
+                .get_zman(&Zman::Tzais)
+                .or_else(|| zmanim_calendar.get_sunset()),
+            DayBoundary::Zman(zman) => zmanim_calendar.get_zman(&zman).or_else(|| zmanim_calendar.get_sunset()),
+        };
+        let is_evening = boundary_time.is_some_and(|boundary_time| date_time >= boundary_time);
+        let date = if is_evening { date.succ_opt()? } else { date };
+        let jewish_calendar = JewishCalendar::from_gregorian_date(
+            date.year(),
+            date.month() as u8,
+            date.day() as u8,
+            in_israel,
+            is_mukaf_choma,
+            use_modern_holidays,
+            calculator,
+        )?;
+        Some(Self {
+            date_time,
+            jewish_calendar,
+            is_evening,
+        })
+    }
+}
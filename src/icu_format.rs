@@ -0,0 +1,54 @@
+//! Locale-aware formatting of Jewish dates using CLDR patterns from ICU4X, enabled by the
+//! `icu-format` feature.
+//!
+//! ```
+//! use icu_locale_core::locale;
+//! use jew_sdk::{icu_format::{FormatLength, JewishDateFormatter}, prelude::*};
+//!
+//! let calendar =
+//!     JewishCalendar::from_hebrew_date(5784, JewishMonth::Nissan, 15, false, false, false, NOAACalculator).unwrap();
+//! let formatter = JewishDateFormatter::try_new(&locale!("en"), FormatLength::Long).unwrap();
+//! let formatted = formatter.format(&calendar).to_string();
+//! assert!(formatted.contains("Nisan") && formatted.contains("5784"));
+//! ```
+use core::fmt::Display;
+
+use icu_calendar::cal::Hebrew;
+use icu_datetime::{FixedCalendarDateTimeFormatter, fieldsets::YMD};
+use icu_locale_core::Locale;
+
+use crate::{astronomical_calculator::AstronomicalCalculatorTrait, jewish_calendar::JewishCalendar};
+
+/// The length of the formatted date, following the CLDR lengths.
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default, Hash)]
+pub enum FormatLength {
+    #[default]
+    Long,
+    Medium,
+    Short,
+}
+
+/// Formats Jewish dates for a locale, e.g. "15 Nisan 5784" or "Nisan 15, 5784 AM".
+#[derive(Debug)]
+pub struct JewishDateFormatter {
+    formatter: FixedCalendarDateTimeFormatter<Hebrew, YMD>,
+}
+
+impl JewishDateFormatter {
+    /// Creates a formatter for `locale`, returning `None` if no data is available for it.
+    pub fn try_new(locale: &Locale, length: FormatLength) -> Option<Self> {
+        let field_set = match length {
+            FormatLength::Long => YMD::long(),
+            FormatLength::Medium => YMD::medium(),
+            FormatLength::Short => YMD::short(),
+        };
+        let formatter = FixedCalendarDateTimeFormatter::try_new(locale.into(), field_set).ok()?;
+        Some(Self { formatter })
+    }
+
+    /// Formats the date of `calendar`.
+    pub fn format<'a, N: AstronomicalCalculatorTrait>(&'a self, calendar: &'a JewishCalendar<N>) -> impl Display + 'a {
+        self.formatter.format(&calendar.hebrew_date)
+    }
+}
@@ -0,0 +1,72 @@
+//! RFC 3339 (ISO 8601) formatting of zmanim, which is what most web services expect.
+use core::fmt;
+
+use chrono::{DateTime, Datelike, Offset, TimeZone, Timelike};
+
+#[cfg(feature = "std")]
+use crate::{
+    astronomical_calculator::AstronomicalCalculatorTrait,
+    constants::Zman,
+    geolocation::GeoLocation,
+    zmanim_calendar::{ZmanimCalendar, ZmanimCalendarTrait},
+};
+
+/// Displays a time as an RFC 3339 string, such as `2025-03-13T11:43:27+02:00`. Milliseconds
+/// are included only when they are not zero.
+///
+/// This does not allocate, so it is available without the `std` feature.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Iso8601<Tz: TimeZone>(pub DateTime<Tz>);
+
+impl<Tz: TimeZone> fmt::Display for Iso8601<Tz> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let local = self.0.naive_local();
+        write!(
+            f,
+            "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}",
+            local.year(),
+            local.month(),
+            local.day(),
+            local.hour(),
+            local.minute(),
+            local.second()
+        )?;
+        let millis = self.0.timestamp_subsec_millis();
+        if millis != 0 {
+            write!(f, ".{:03}", millis)?;
+        }
+        let offset = self.0.offset().fix().local_minus_utc();
+        let sign = if offset < 0 { '-' } else { '+' };
+        let offset = offset.unsigned_abs();
+        write!(f, "{}{:02}:{:02}", sign, offset / 3600, offset / 60 % 60)
+    }
+}
+
+/// Converts the result of any zmanim method to an [`Iso8601`] adapter, so that results can be
+/// formatted without matching on the `Option` first.
+pub trait ToIso8601 {
+    type Output;
+    fn iso8601(self) -> Self::Output;
+}
+
+impl<Tz: TimeZone> ToIso8601 for DateTime<Tz> {
+    type Output = Iso8601<Tz>;
+    fn iso8601(self) -> Self::Output {
+        Iso8601(self)
+    }
+}
+
+impl<Tz: TimeZone> ToIso8601 for Option<DateTime<Tz>> {
+    type Output = Option<Iso8601<Tz>>;
+    fn iso8601(self) -> Self::Output {
+        self.map(Iso8601)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<Tz: TimeZone, N: AstronomicalCalculatorTrait> ZmanimCalendar<Tz, GeoLocation, N> {
+    /// Returns `zman` as an RFC 3339 string.
+    pub fn get_zman_iso(&self, zman: &Zman) -> Option<String> {
+        self.get_zman(zman).iso8601().map(|time| time.to_string())
+    }
+}
@@ -1,3 +1,4 @@
+use core::cmp::Ordering;
 use core::fmt::Debug;
 
 use chrono::DateTime;
@@ -19,6 +20,8 @@ use crate::astronomical_calculator::AstronomicalCalculatorTrait;
 use crate::astronomical_calculator::get_julian_day;
 use crate::constants::*;
 use crate::daf::*;
+use crate::jewish_year;
+use crate::molad;
 // use crate:::InternalJewishCalendarTrait;
 // use crate:::JewishCalendar;
 // use crate:::JewishCalendarTrait;
@@ -32,7 +35,11 @@ pub trait JewishCalendarTrait {
     fn get_jewish_month(&self) -> JewishMonth;
     fn get_jewish_day_of_month(&self) -> u8;
     fn get_gregorian_year(&self) -> i32;
+    /// Returns the Gregorian month, counting from 0 for January as in KosherJava.
+    #[deprecated(note = "the month is 0-based, use `get_gregorian_month_1_based`")]
     fn get_gregorian_month(&self) -> u8;
+    /// Returns the Gregorian month, counting from 1 for January as in chrono.
+    fn get_gregorian_month_1_based(&self) -> u8;
     fn get_gregorian_day_of_month(&self) -> u8;
     fn get_molad_as_date(&self) -> Option<DateTime<Utc>>;
     fn get_molad_as_calendar(&self) -> Option<impl JewishCalendarTrait>;
@@ -109,7 +116,92 @@ pub struct MoladData {
     pub chalakim: i64,
 }
 
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq)]
+impl MoladData {
+    /// Creates a molad from the number of chalakim since the start of its day at 18:00 the
+    /// previous evening. Returns `None` if `chalakim` is not within a single day.
+    pub fn from_total_chalakim(chalakim: i64) -> Option<Self> {
+        if !(0.._CHALAKIM_PER_DAY).contains(&chalakim) {
+            return None;
+        }
+        Some(Self::from_chalakim_of_day(chalakim))
+    }
+
+    fn from_chalakim_of_day(chalakim: i64) -> Self {
+        let hours = chalakim / _CHALAKIM_PER_HOUR;
+        let remainder = chalakim % _CHALAKIM_PER_HOUR;
+        MoladData {
+            hours: (hours + 18) % 24,
+            minutes: remainder / _CHALAKIM_PER_MINUTE,
+            chalakim: remainder % _CHALAKIM_PER_MINUTE,
+        }
+    }
+
+    /// Returns the number of chalakim since the start of the molad's day at 18:00 the previous
+    /// evening.
+    pub fn total_chalakim(&self) -> i64 {
+        let hours_since_day_start = (self.hours + 6) % 24;
+        hours_since_day_start * _CHALAKIM_PER_HOUR + self.minutes * _CHALAKIM_PER_MINUTE + self.chalakim
+    }
+
+    /// Returns the time since the start of the molad's day at 18:00 the previous evening, or
+    /// `None` if the fields are too large to convert. A chelek is 3⅓ seconds.
+    pub fn duration_since_day_start(&self) -> Option<chrono::Duration> {
+        molad::chalakim_to_duration(self.total_chalakim())
+    }
+
+    /// Returns the time of day of the molad `months` months later (or earlier, if negative),
+    /// found by adding the mean length of a month of 29 days, 12 hours and 793 chalakim.
+    /// Returns `None` if the number of chalakim overflows.
+    pub fn add_months(&self, months: i64) -> Option<Self> {
+        let chalakim = self
+            .total_chalakim()
+            .checked_add(months.checked_mul(_CHALAKIM_PER_MONTH)?)?;
+        Some(Self::from_chalakim_of_day(chalakim.rem_euclid(_CHALAKIM_PER_DAY)))
+    }
+}
+
+impl core::fmt::Display for MoladData {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}h {}m {}cp", self.hours, self.minutes, self.chalakim)
+    }
+}
+
+/// The day or days of a Rosh Chodesh. Rosh Chodesh is two days when the previous month has 30
+/// days, the 30th of the previous month being the first day.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RoshChodeshDays {
+    pub month: JewishMonth,
+    pub first: NaiveDate,
+    /// The 1st of the month when Rosh Chodesh is two days.
+    pub second: Option<NaiveDate>,
+}
+
+impl RoshChodeshDays {
+    pub fn is_two_days(&self) -> bool {
+        self.second.is_some()
+    }
+
+    /// Returns the last day of Rosh Chodesh, which is always the 1st of the month.
+    pub fn last(&self) -> NaiveDate {
+        self.second.unwrap_or(self.first)
+    }
+}
+
+/// A Jewish date, ordered by date alone, for sorting and ranging calendars regardless of their
+/// settings. See [`JewishCalendar::get_date_key`].
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct JewishDateKey {
+    year: i32,
+    /// The month counted from Tishrei, so that the months of a year are in order.
+    month_ordinal: u8,
+    day: u8,
+}
+
+/// Calendars are ordered by their dates. Calendars of the same date with different settings
+/// are unordered, since they are not equal; use [`JewishCalendar::cmp_date`] to ignore the
+/// settings.
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct JewishCalendar<N: AstronomicalCalculatorTrait> {
     pub(crate) hebrew_date: Date<Hebrew>,
     pub in_israel: bool,
@@ -118,49 +210,42 @@ pub struct JewishCalendar<N: AstronomicalCalculatorTrait> {
     pub(crate) calculator: N,
 }
 
+impl<N: AstronomicalCalculatorTrait> PartialOrd for JewishCalendar<N> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        match self.cmp_date(other) {
+            Ordering::Equal if self != other => None,
+            ordering => Some(ordering),
+        }
+    }
+}
+
 impl<N: AstronomicalCalculatorTrait> JewishCalendar<N> {
+    /// Returns the date of this calendar as a key that orders by date alone.
+    pub fn get_date_key(&self) -> JewishDateKey {
+        JewishDateKey {
+            year: self.hebrew_date.era_year().year,
+            month_ordinal: self.hebrew_date.month().ordinal,
+            day: self.hebrew_date.day_of_month().0,
+        }
+    }
+    /// Compares the dates of two calendars, ignoring their settings.
+    pub fn cmp_date<M: AstronomicalCalculatorTrait>(&self, other: &JewishCalendar<M>) -> Ordering {
+        self.get_date_key().cmp(&other.get_date_key())
+    }
     pub(crate) fn get_gregorian_date(&self) -> Date<Gregorian> {
         self.get_hebrew_date().to_calendar(Gregorian)
     }
+    /// See [`jewish_year::get_days_in_jewish_month`].
     pub fn get_days_in_jewish_month_static(month: JewishMonth, year: i32) -> u8 {
-        match month {
-            JewishMonth::Iyar | JewishMonth::Tammuz | JewishMonth::Elul | JewishMonth::Teves => 29,
-            JewishMonth::Cheshvan => {
-                if JewishCalendar::<N>::is_cheshvan_long_static(year) {
-                    30
-                } else {
-                    29
-                }
-            }
-            JewishMonth::Kislev => {
-                if JewishCalendar::<N>::is_kislev_short_static(year) {
-                    29
-                } else {
-                    30
-                }
-            }
-            JewishMonth::Adar => {
-                if JewishCalendar::<N>::is_jewish_leap_year_static(year) {
-                    30
-                } else {
-                    29
-                }
-            }
-            JewishMonth::AdarII => 29,
-            _ => 30,
-        }
+        jewish_year::get_days_in_jewish_month(month, year)
     }
+    /// See [`jewish_year::get_days_in_jewish_year`].
     pub fn get_days_in_jewish_year_static(year: i32) -> i32 {
-        JewishCalendar::<N>::get_jewish_calendar_elapsed_days(year + 1)
-            - JewishCalendar::<N>::get_jewish_calendar_elapsed_days(year)
+        jewish_year::get_days_in_jewish_year(year)
     }
+    /// See [`jewish_year::get_jewish_calendar_elapsed_days`].
     pub fn get_jewish_calendar_elapsed_days(year: i32) -> i32 {
-        let chalakim_since =
-            JewishCalendar::<N>::get_chalakim_since_molad_tohu_static(year, JewishMonth::Tishrei.into());
-        let molad_day = chalakim_since / _CHALAKIM_PER_DAY;
-        let molad_parts = chalakim_since - molad_day * _CHALAKIM_PER_DAY;
-
-        JewishCalendar::<N>::add_dechiyos(year, molad_day, molad_parts)
+        jewish_year::get_jewish_calendar_elapsed_days(year)
     }
     pub fn get_last_day_of_gregorian_month(month: u8, year: i32) -> u8 {
         match month {
@@ -176,9 +261,9 @@ impl<N: AstronomicalCalculatorTrait> JewishCalendar<N> {
         }
     }
 
+    /// See [`jewish_year::is_jewish_leap_year`].
     pub fn is_jewish_leap_year_static(year: i32) -> bool {
-        let year_in_cycle = ((year - 1) % 19) + 1;
-        matches!(year_in_cycle, 3 | 6 | 8 | 11 | 14 | 17 | 19)
+        jewish_year::is_jewish_leap_year(year)
     }
     pub fn from_hebrew_date(
         year: i32,
@@ -189,6 +274,9 @@ impl<N: AstronomicalCalculatorTrait> JewishCalendar<N> {
         use_modern_holidays: bool,
         calculator: N,
     ) -> Option<Self> {
+        if !jewish_year::is_supported_jewish_year(year) {
+            return None;
+        }
         let is_leap_year = Date::try_new_from_codes(Some("am"), year, MonthCode("M01".parse().ok()?), 1, Hebrew)
             .ok()?
             .is_in_leap_year();
@@ -255,9 +343,13 @@ impl<N: AstronomicalCalculatorTrait> JewishCalendar<N> {
         calculator: N,
     ) -> Option<Self> {
         let gregorian_date = Date::try_new_iso(year, month, day).ok()?;
+        let hebrew_date = gregorian_date.to_calendar(Hebrew);
+        if !jewish_year::is_supported_jewish_year(hebrew_date.era_year().year) {
+            return None;
+        }
 
         Some(JewishCalendar {
-            hebrew_date: gregorian_date.to_calendar(Hebrew),
+            hebrew_date,
             in_israel,
             is_mukaf_choma,
             use_modern_holidays,
@@ -273,6 +365,17 @@ impl<N: AstronomicalCalculatorTrait> JewishCalendar<N> {
             calculator: self.calculator.clone(),
         }
     }
+    /// Returns a copy of this calendar that borrows the calculator, so that the many copies made
+    /// while scanning for a date do not clone it.
+    pub(crate) fn with_borrowed_calculator(&self) -> JewishCalendar<&N> {
+        JewishCalendar {
+            hebrew_date: self.hebrew_date.clone(),
+            in_israel: self.in_israel,
+            is_mukaf_choma: self.is_mukaf_choma,
+            use_modern_holidays: self.use_modern_holidays,
+            calculator: &self.calculator,
+        }
+    }
     pub(crate) fn copy_with_hebrew_ymd(&self, year: i32, month: JewishMonth, day: u8) -> Option<Self> {
         Self::from_hebrew_date(
             year,
@@ -296,6 +399,17 @@ impl<N: AstronomicalCalculatorTrait> JewishCalendar<N> {
         )
     }
 
+    /// Returns the Gregorian date of this date.
+    pub fn get_gregorian_naive_date(&self) -> Option<NaiveDate> {
+        Some(icu_to_naive(&self.get_gregorian_date())?.date_naive())
+    }
+
+    pub(crate) fn copy_with_days_added(&self, days: i64) -> Option<Self> {
+        let date = self.get_gregorian_naive_date()?;
+        let date = date.checked_add_signed(chrono::TimeDelta::try_days(days)?)?;
+        self.copy_with_gregorian_ymd(date.year(), date.month() as u8, date.day() as u8)
+    }
+
     fn get_hebrew_date(&self) -> &Date<Hebrew> {
         &self.hebrew_date
     }
@@ -326,51 +440,6 @@ impl<N: AstronomicalCalculatorTrait> JewishCalendar<N> {
         ))
     }
 
-    fn get_chalakim_since_molad_tohu_static(year: i32, month: u8) -> i64 {
-        let month_of_year = JewishCalendar::<N>::get_jewish_month_of_year(year, month);
-        let months_elapsed = (235 * ((year - 1) / 19))
-            + (12 * ((year - 1) % 19))
-            + ((7 * ((year - 1) % 19) + 1) / 19)
-            + (month_of_year as i32 - 1);
-
-        _CHALAKIM_MOLAD_TOHU + (_CHALAKIM_PER_MONTH * months_elapsed as i64)
-    }
-
-    fn get_jewish_month_of_year(year: i32, month: u8) -> u8 {
-        let is_leap_year = JewishCalendar::<N>::is_jewish_leap_year_static(year);
-        (month + if is_leap_year { 6 } else { 5 }) % if is_leap_year { 13 } else { 12 } + 1
-    }
-
-    fn add_dechiyos(year: i32, molad_day: i64, molad_parts: i64) -> i32 {
-        let mut rosh_hashana_day = molad_day;
-
-        if (molad_parts >= 19440)
-            || (((molad_day % 7) == 2)
-                && (molad_parts >= 9924)
-                && !JewishCalendar::<N>::is_jewish_leap_year_static(year))
-            || (((molad_day % 7) == 1)
-                && (molad_parts >= 16789)
-                && (JewishCalendar::<N>::is_jewish_leap_year_static(year - 1)))
-        {
-            rosh_hashana_day += 1;
-        }
-
-        if ((rosh_hashana_day % 7) == 0) || ((rosh_hashana_day % 7) == 3) || ((rosh_hashana_day % 7) == 5) {
-            rosh_hashana_day += 1;
-        }
-
-        rosh_hashana_day as i32
-    }
-
-    fn is_cheshvan_long_static(year: i32) -> bool {
-        JewishCalendar::<N>::get_days_in_jewish_year_static(year) % 10 == 5
-    }
-
-    fn is_kislev_short_static(year: i32) -> bool {
-        JewishCalendar::<N>::get_days_in_jewish_year_static(year) % 10 == 3
-    }
-
-
     fn molad_to_abs_date(chalakim: i64) -> i64 {
         _JEWISH_EPOCH + (chalakim / _CHALAKIM_PER_DAY)
     }
@@ -384,7 +453,7 @@ impl<N: AstronomicalCalculatorTrait> JewishCalendar<N> {
     }
 
     fn abs_date_to_date(abs_date: i64) -> Option<Date<Gregorian>> {
-        let mut year = (abs_date / 366) as i32;
+        let mut year = i32::try_from(abs_date / 366).ok()?;
         while abs_date >= JewishCalendar::<N>::gregorian_date_to_abs_date(year + 1, 1, 1) {
             year += 1;
         }
@@ -398,7 +467,8 @@ impl<N: AstronomicalCalculatorTrait> JewishCalendar<N> {
         {
             month += 1;
         }
-        let day_of_month: u8 = (abs_date - JewishCalendar::<N>::gregorian_date_to_abs_date(year, month, 1) + 1) as u8;
+        let day_of_month =
+            u8::try_from(abs_date - JewishCalendar::<N>::gregorian_date_to_abs_date(year, month, 1) + 1).ok()?;
         Date::try_new_gregorian(year, month, day_of_month).ok()
     }
 
@@ -411,10 +481,11 @@ impl<N: AstronomicalCalculatorTrait> JewishCalendar<N> {
             .get_jewish_year();
 
         let mut special_days = 0u64;
+        let borrowed = self.with_borrowed_calculator();
         for i in start_year..=end_year {
             // Create new calendar instances for each year
-            let yom_kippur_date = self.copy_with_hebrew_ymd(i, JewishMonth::Tishrei, 10)?;
-            let tisha_beav_date = self.copy_with_hebrew_ymd(i, JewishMonth::Av, 9)?;
+            let yom_kippur_date = borrowed.copy_with_hebrew_ymd(i, JewishMonth::Tishrei, 10)?;
+            let tisha_beav_date = borrowed.copy_with_hebrew_ymd(i, JewishMonth::Av, 9)?;
 
             // Get Gregorian dates and convert to DateTime<Utc>
             let yom_kippur_dt = icu_to_naive(&yom_kippur_date.get_gregorian_date())?;
@@ -583,6 +654,10 @@ impl<N: AstronomicalCalculatorTrait> JewishCalendarTrait for JewishCalendar<N> {
         self.get_gregorian_date().month().ordinal - 1
     }
 
+    fn get_gregorian_month_1_based(&self) -> u8 {
+        self.get_gregorian_date().month().ordinal
+    }
+
     fn get_gregorian_day_of_month(&self) -> u8 {
         self.get_gregorian_date().day_of_month().0
     }
@@ -613,24 +688,15 @@ impl<N: AstronomicalCalculatorTrait> JewishCalendarTrait for JewishCalendar<N> {
     }
 
     fn is_cheshvan_long(&self) -> bool {
-        JewishCalendar::<N>::is_cheshvan_long_static(self.get_jewish_year())
+        jewish_year::is_cheshvan_long(self.get_jewish_year())
     }
 
     fn is_kislev_short(&self) -> bool {
-        JewishCalendar::<N>::is_kislev_short_static(self.get_jewish_year())
+        jewish_year::is_kislev_short(self.get_jewish_year())
     }
 
     fn get_cheshvan_kislev_kviah(&self) -> YearLengthType {
-        let year = self.get_jewish_year();
-        if JewishCalendar::<N>::is_cheshvan_long_static(year) && !JewishCalendar::<N>::is_kislev_short_static(year) {
-            YearLengthType::Shelaimim
-        } else if !JewishCalendar::<N>::is_cheshvan_long_static(year)
-            && JewishCalendar::<N>::is_kislev_short_static(year)
-        {
-            YearLengthType::Chaserim
-        } else {
-            YearLengthType::Kesidran
-        }
+        jewish_year::get_cheshvan_kislev_kviah(self.get_jewish_year())
     }
 
     fn get_days_since_start_of_jewish_year(&self) -> i32 {
@@ -639,20 +705,17 @@ impl<N: AstronomicalCalculatorTrait> JewishCalendarTrait for JewishCalendar<N> {
         let day = self.get_jewish_day_of_month();
 
         let is_leap_year = self.is_jewish_leap_year();
-        let mut elapsed_days: i32 = day as i32;
-        let mut start = JewishMonth::Tishrei;
-        while start != current_month {
-            elapsed_days += JewishCalendar::<N>::get_days_in_jewish_month_static(start, year) as i32;
-            start = start.next(is_leap_year)
-        }
+        let elapsed_days: i32 = JewishMonth::range(JewishMonth::Tishrei, current_month, is_leap_year)
+            .map(|month| JewishCalendar::<N>::get_days_in_jewish_month_static(month, year) as i32)
+            .sum();
 
-        elapsed_days
+        elapsed_days + day as i32
     }
 
     fn get_chalakim_since_molad_tohu(&self) -> i64 {
         let year = self.get_jewish_year();
         let month = self.get_jewish_month();
-        JewishCalendar::<N>::get_chalakim_since_molad_tohu_static(year, month.into())
+        molad::get_chalakim_since_molad_tohu(year, month)
     }
 
     fn get_molad(&self) -> Option<MoladData> {
@@ -1315,7 +1378,8 @@ impl<N: AstronomicalCalculatorTrait> JewishCalendarTrait for JewishCalendar<N> {
         }
 
         // Get parshah for that date
-        let upcoming_calendar = self.copy_with_hebrew_ymd(upcoming_year, upcoming_month, upcoming_day)?;
+        let borrowed = self.with_borrowed_calculator();
+        let upcoming_calendar = borrowed.copy_with_hebrew_ymd(upcoming_year, upcoming_month, upcoming_day)?;
 
         let mut parshah = upcoming_calendar.get_parshah();
 
@@ -1344,7 +1408,7 @@ impl<N: AstronomicalCalculatorTrait> JewishCalendarTrait for JewishCalendar<N> {
                     }
                 };
             }
-            let temp_calendar = self.copy_with_hebrew_ymd(temp_year, temp_month, temp_day)?;
+            let temp_calendar = borrowed.copy_with_hebrew_ymd(temp_year, temp_month, temp_day)?;
 
             parshah = temp_calendar.get_parshah();
         }
@@ -1431,7 +1495,7 @@ impl<N: AstronomicalCalculatorTrait> JewishCalendarTrait for JewishCalendar<N> {
 
         // Get the Gregorian date components from molad JewishCalendar
         let year = molad.get_gregorian_year();
-        let month = (molad.get_gregorian_month() + 1) as u32; // Convert from 0-based to 1-based
+        let month = molad.get_gregorian_month_1_based() as u32;
         let day = molad.get_gregorian_day_of_month() as u32;
 
         let molad_seconds = molad_data.chalakim as f64 * 10.0 / 3.0;
@@ -1589,6 +1653,217 @@ impl<N: AstronomicalCalculatorTrait> JewishCalendarTrait for JewishCalendar<N> {
     }
 }
 
+impl<N: AstronomicalCalculatorTrait> JewishCalendar<N> {
+    /// Returns the holiday of [`JewishCalendarTrait::get_yom_tov_index`] followed by the days that
+    /// it does not return, which can fall on another holiday: Rosh Chodesh, such as on Rosh Chodesh
+    /// Teves during Chanukah, Yom Kippur Katan and Behab.
+    pub fn get_holidays(&self) -> impl Iterator<Item = JewishHoliday> + use<N> {
+        let yom_tov = self.get_yom_tov_index();
+        let others = [
+            (JewishHoliday::RoshChodesh, self.is_rosh_chodesh()),
+            (JewishHoliday::YomKippurKatan, self.is_yom_kippur_katan()),
+            (JewishHoliday::Behab, self.is_be_hab()),
+        ];
+        yom_tov.into_iter().chain(
+            others
+                .into_iter()
+                .filter_map(move |(holiday, is_today)| (is_today && yom_tov != Some(holiday)).then_some(holiday)),
+        )
+    }
+
+    /// Returns the next day.
+    pub fn tomorrow(&self) -> Option<Self> {
+        self.copy_with_days_added(1)
+    }
+
+    /// Returns the holiday of the next day. Days at the end of the supported range have no next
+    /// day, so the queries about tomorrow return `None` or `false` for them.
+    pub fn get_yom_tov_index_tomorrow(&self) -> Option<JewishHoliday> {
+        self.with_borrowed_calculator().tomorrow()?.get_yom_tov_index()
+    }
+
+    /// Returns true if the next day is Rosh Chodesh.
+    pub fn is_tomorrow_rosh_chodesh(&self) -> bool {
+        self.is_tomorrow(|tomorrow| tomorrow.is_rosh_chodesh())
+    }
+
+    /// Returns true if the next day is a yom tov, including minor holidays.
+    pub fn is_tomorrow_yom_tov(&self) -> bool {
+        self.is_tomorrow(|tomorrow| tomorrow.is_yom_tov())
+    }
+
+    /// Returns true if melacha is forbidden on the next day, because it is Shabbos or a yom tov.
+    pub fn is_tomorrow_assur_bemelacha(&self) -> bool {
+        self.is_tomorrow(|tomorrow| tomorrow.is_assur_bemelacha())
+    }
+
+    /// Returns true if the next day is a fast day.
+    pub fn is_tomorrow_taanis(&self) -> bool {
+        self.is_tomorrow(|tomorrow| tomorrow.is_taanis())
+    }
+
+    /// Returns the day of Chanukah of the next day, which is the number of candles lit tonight.
+    pub fn get_day_of_chanukah_tomorrow(&self) -> Option<u8> {
+        self.with_borrowed_calculator().tomorrow()?.get_day_of_chanukah()
+    }
+
+    /// Returns the day of the omer of the next day, which is the day counted tonight.
+    pub fn get_day_of_omer_tomorrow(&self) -> Option<u8> {
+        self.with_borrowed_calculator().tomorrow()?.get_day_of_omer()
+    }
+
+    /// Returns the month blessed on this date if it is Shabbos Mevorchim.
+    pub fn get_month_being_blessed(&self) -> Option<JewishMonth> {
+        if !self.is_shabbos_mevorchim() {
+            return None;
+        }
+        Some(self.with_borrowed_calculator().get_first_of_next_month()?.get_jewish_month())
+    }
+
+    /// Returns the molad of the month blessed on this date if it is Shabbos Mevorchim, which is
+    /// announced along with the day(s) of Rosh Chodesh.
+    pub fn get_molad_of_month_being_blessed(&self) -> Option<MoladData> {
+        if !self.is_shabbos_mevorchim() {
+            return None;
+        }
+        self.with_borrowed_calculator().get_first_of_next_month()?.get_molad()
+    }
+
+    /// Returns the time of the molad of the month blessed on this date if it is Shabbos
+    /// Mevorchim. See [`JewishCalendarTrait::get_molad_as_date`].
+    pub fn get_molad_date_of_month_being_blessed(&self) -> Option<DateTime<Utc>> {
+        if !self.is_shabbos_mevorchim() {
+            return None;
+        }
+        self.with_borrowed_calculator().get_first_of_next_month()?.get_molad_as_date()
+    }
+
+    /// Returns the days of the upcoming Rosh Chodesh of `month`, the first one that has not yet
+    /// ended on this date. Returns `None` for Tishrei, whose first day is Rosh Hashana.
+    pub fn get_rosh_chodesh_days(&self, month: JewishMonth) -> Option<RoshChodeshDays> {
+        if month == JewishMonth::Tishrei {
+            return None;
+        }
+        let today = self.get_gregorian_naive_date()?;
+        // Adar II only occurs in leap years, which are at most three years apart.
+        (self.get_jewish_year()..=self.get_jewish_year() + 3)
+            .filter_map(|year| self.with_borrowed_calculator().copy_with_hebrew_ymd(year, month, 1))
+            .find_map(|first_of_month| {
+                let date = first_of_month.get_gregorian_naive_date()?;
+                if date < today {
+                    return None;
+                }
+                let previous_day = first_of_month.copy_with_days_added(-1)?;
+                Some(match previous_day.get_jewish_day_of_month() {
+                    30 => RoshChodeshDays {
+                        month,
+                        first: previous_day.get_gregorian_naive_date()?,
+                        second: Some(date),
+                    },
+                    _ => RoshChodeshDays {
+                        month,
+                        first: date,
+                        second: None,
+                    },
+                })
+            })
+    }
+
+    fn get_first_of_next_month(&self) -> Option<Self> {
+        let days_left = self.get_days_in_jewish_month() - self.get_jewish_day_of_month();
+        self.copy_with_days_added(days_left as i64 + 1)
+    }
+
+    fn is_tomorrow(&self, predicate: impl FnOnce(&JewishCalendar<&N>) -> bool) -> bool {
+        self.with_borrowed_calculator()
+            .tomorrow()
+            .is_some_and(|tomorrow| predicate(&tomorrow))
+    }
+}
+
+impl<N: AstronomicalCalculatorTrait> JewishCalendar<N> {
+    fn get_erev_pesach(&self) -> Option<Self> {
+        self.copy_with_hebrew_ymd(self.get_jewish_year(), JewishMonth::Nissan, 14)
+    }
+
+    /// Returns true if Erev Pesach (14 Nissan) of this Jewish year falls on Shabbos.
+    pub fn is_erev_pesach_on_shabbos(&self) -> bool {
+        self.get_erev_pesach()
+            .is_some_and(|erev_pesach| erev_pesach.get_day_of_week() == Weekday::Sat)
+    }
+
+    /// Returns true if this date is Shabbos Hagadol that falls on Erev Pesach.
+    pub fn is_shabbos_hagadol_erev_pesach(&self) -> bool {
+        self.get_jewish_month() == JewishMonth::Nissan
+            && self.get_jewish_day_of_month() == 14
+            && self.get_day_of_week() == Weekday::Sat
+    }
+
+    /// Returns the date of this Jewish year on which chametz is burned. This is Erev Pesach,
+    /// unless Erev Pesach falls on Shabbos, in which case the chametz is burned on Friday.
+    pub fn get_biur_chametz_date(&self) -> Option<Self> {
+        match self.is_erev_pesach_on_shabbos() {
+            true => self.copy_with_hebrew_ymd(self.get_jewish_year(), JewishMonth::Nissan, 13),
+            false => self.get_erev_pesach(),
+        }
+    }
+
+    /// Returns the date of this Jewish year on whose evening the search for chametz is made. This
+    /// is 13 Nissan, the night of Erev Pesach, unless Erev Pesach falls on Shabbos, in which case
+    /// the search is made on Thursday night.
+    pub fn get_bedikas_chametz_date(&self) -> Option<Self> {
+        let day = match self.is_erev_pesach_on_shabbos() {
+            true => 12,
+            false => 13,
+        };
+        self.copy_with_hebrew_ymd(self.get_jewish_year(), JewishMonth::Nissan, day)
+    }
+
+    /// Returns the last date of this Jewish year on which chametz may be eaten. This is always
+    /// Erev Pesach, even when it falls on Shabbos (the remaining chametz is eaten at the morning meal).
+    pub fn get_sof_zman_achilas_chametz_date(&self) -> Option<Self> {
+        self.get_erev_pesach()
+    }
+
+    /// Returns the date of Taanis Bechoros in this Jewish year. This is Erev Pesach, unless
+    /// Erev Pesach falls on Shabbos, in which case the fast is moved back to Thursday.
+    pub fn get_taanis_bechoros_date(&self) -> Option<Self> {
+        match self.is_erev_pesach_on_shabbos() {
+            true => self.copy_with_hebrew_ymd(self.get_jewish_year(), JewishMonth::Nissan, 12),
+            false => self.get_erev_pesach(),
+        }
+    }
+
+    /// Returns the date in the Jewish year of this date on which Vesein Tal Umatar is first
+    /// recited, starting from Maariv of the previous evening. In Israel this is 7 Cheshvan.
+    /// Outside Israel it is 60 days after tekufas Tishrei, moved to Sunday when it falls on
+    /// Shabbos. In this era that is December 5, or December 6 before a civil leap year.
+    pub fn get_vesein_tal_umatar_start_date(&self) -> Option<Self> {
+        let year = self.get_jewish_year();
+        if self.in_israel {
+            return self.copy_with_hebrew_ymd(year, JewishMonth::Cheshvan, 7);
+        }
+        let rosh_hashana = self.copy_with_hebrew_ymd(year, JewishMonth::Tishrei, 1)?;
+        let start = rosh_hashana.copy_with_days_added(47 - rosh_hashana.get_tekufas_tishrei_elapsed_days())?;
+        match start.get_day_of_week() {
+            Weekday::Sat => start.copy_with_days_added(1),
+            _ => Some(start),
+        }
+    }
+
+    /// Returns the civil date in December of the Gregorian `year` on which Vesein Tal Umatar is
+    /// first recited outside Israel, starting from Maariv of the previous evening.
+    pub fn get_vesein_tal_umatar_start_date_in_gregorian_year(&self, year: i32) -> Option<NaiveDate> {
+        let calendar = self.copy_with_gregorian_ymd(year, 12, 31)?;
+        let calendar = JewishCalendar {
+            in_israel: false,
+            ..calendar
+        };
+        let start = calendar.get_vesein_tal_umatar_start_date()?;
+        start.get_gregorian_naive_date()
+    }
+}
+
 const BLATT_PER_YERUSHALMI_TRACTATE: [u64; 39] = [
     68, 37, 34, 44, 31, 59, 26, 33, 28, 20, 13, 92, 65, 71, 22, 22, 42, 26, 26, 33, 34, 22, 19, 85, 72, 47, 40, 47, 54,
     48, 44, 37, 34, 44, 9, 57, 37, 19, 13,
@@ -0,0 +1,248 @@
+//! Functions describing the structure of a Jewish year (leap years, month lengths, and the
+//! dechiyos that fix the day of Rosh Hashana). These need neither a calendar instance nor
+//! an astronomical calculator.
+//!
+//! ```
+//! use jew_sdk::{constants::JewishMonth, jewish_year};
+//!
+//! assert!(jewish_year::is_jewish_leap_year(5784));
+//! assert_eq!(jewish_year::get_days_in_jewish_year(5784), 383);
+//! assert_eq!(jewish_year::get_days_in_jewish_month(JewishMonth::Adar, 5784), 30);
+//! ```
+//!
+//! The functions that take a year and do not return an `Option` give meaningless results for
+//! years outside [`SUPPORTED_JEWISH_YEARS`]; check [`is_supported_jewish_year`] first for years
+//! that come from user input.
+use core::ops::RangeInclusive;
+
+use chrono::{Datelike, NaiveDate, Weekday};
+
+use crate::{constants::*, molad};
+
+/// The first supported Jewish year, in which the Gregorian year 1 begins. The conversion from
+/// Gregorian dates counts days from January 1 of the year 1, as KosherJava does.
+pub const MIN_JEWISH_YEAR: i32 = 3761;
+
+/// The last supported Jewish year, the last to end within the range of [`NaiveDate`].
+pub const MAX_JEWISH_YEAR: i32 = 265_899;
+
+/// The Jewish years for which calendars can be created. Constructors of
+/// [`JewishCalendar`](crate::jewish_calendar::JewishCalendar) and the functions here that return
+/// an `Option` return `None` for dates outside this range rather than a wrong result.
+pub const SUPPORTED_JEWISH_YEARS: RangeInclusive<i32> = MIN_JEWISH_YEAR..=MAX_JEWISH_YEAR;
+
+/// Returns true if the Jewish `year` is within [`SUPPORTED_JEWISH_YEARS`].
+pub fn is_supported_jewish_year(year: i32) -> bool {
+    SUPPORTED_JEWISH_YEARS.contains(&year)
+}
+
+/// Returns true if the Jewish `year` is a leap year (has Adar I and Adar II).
+pub fn is_jewish_leap_year(year: i32) -> bool {
+    let year_in_cycle = ((year - 1) % 19) + 1;
+    matches!(year_in_cycle, 3 | 6 | 8 | 11 | 14 | 17 | 19)
+}
+
+/// Returns the number of days from the Jewish epoch to Rosh Hashana of the Jewish `year`.
+pub fn get_jewish_calendar_elapsed_days(year: i32) -> i32 {
+    get_rosh_hashana_dechiyos(year).rosh_hashana_day
+}
+
+/// A rule postponing Rosh Hashana from the day of the molad of Tishrei.
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub enum Dechiya {
+    /// Molad zaken: the molad is at or after noon, 18 hours into its day.
+    MoladZaken,
+    /// GaTaRaD: in a common year, the molad is on Tuesday at or after 9 hours and 204 chalakim
+    /// (3:11:20 am). Rosh Hashana on Thursday would make the year after too long.
+    Gatarad,
+    /// BeTUTaKPaT: in the year after a leap year, the molad is on Monday at or after 15 hours
+    /// and 589 chalakim (9:32:43 1/3 am). Rosh Hashana on Tuesday would make the leap year before
+    /// too short.
+    Betutakpat,
+    /// Lo ADU Rosh: Rosh Hashana does not fall on Sunday, Wednesday or Friday.
+    LoAdu,
+}
+
+/// How the day of Rosh Hashana of a year follows from the molad of Tishrei.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub struct RoshHashanaDechiyos {
+    /// The number of days from the Jewish epoch to the day of the molad.
+    pub molad_day: i64,
+    /// The chalakim of the molad since the start of its day at 6 pm.
+    pub molad_parts: i64,
+    pub molad_weekday: Weekday,
+    /// The postponement for the time of the molad, if any. The day it postpones to can then
+    /// be postponed again by [`Dechiya::LoAdu`].
+    pub postponement: Option<Dechiya>,
+    /// Whether Rosh Hashana was postponed by [`Dechiya::LoAdu`].
+    pub lo_adu: bool,
+    /// The number of days from the Jewish epoch to Rosh Hashana, as returned by
+    /// [`get_jewish_calendar_elapsed_days`].
+    pub rosh_hashana_day: i32,
+}
+
+impl RoshHashanaDechiyos {
+    /// Returns the dechiyos applied, in order.
+    pub fn get_dechiyos(&self) -> impl Iterator<Item = Dechiya> {
+        self.postponement
+            .into_iter()
+            .chain(self.lo_adu.then_some(Dechiya::LoAdu))
+    }
+
+    /// Returns the number of days (0-2) Rosh Hashana was postponed from the day of the molad.
+    pub fn get_days_postponed(&self) -> i64 {
+        i64::from(self.rosh_hashana_day) - self.molad_day
+    }
+}
+
+/// Returns the molad of Tishrei of the Jewish `year` and the dechiyos that fix Rosh Hashana
+/// from it.
+pub fn get_rosh_hashana_dechiyos(year: i32) -> RoshHashanaDechiyos {
+    let chalakim_since = molad::get_chalakim_since_molad_tohu(year, JewishMonth::Tishrei);
+    let molad_day = chalakim_since / _CHALAKIM_PER_DAY;
+    let molad_parts = chalakim_since - molad_day * _CHALAKIM_PER_DAY;
+
+    let postponement = if molad_parts >= 19440 {
+        Some(Dechiya::MoladZaken)
+    } else if (molad_day % 7) == 2 && molad_parts >= 9924 && !is_jewish_leap_year(year) {
+        Some(Dechiya::Gatarad)
+    } else if (molad_day % 7) == 1 && molad_parts >= 16789 && is_jewish_leap_year(year - 1) {
+        Some(Dechiya::Betutakpat)
+    } else {
+        None
+    };
+    let mut rosh_hashana_day = molad_day + i64::from(postponement.is_some());
+    let lo_adu = matches!(rosh_hashana_day % 7, 0 | 3 | 5);
+    if lo_adu {
+        rosh_hashana_day += 1;
+    }
+
+    RoshHashanaDechiyos {
+        molad_day,
+        molad_parts,
+        molad_weekday: weekday_of_day(molad_day),
+        postponement,
+        lo_adu,
+        rosh_hashana_day: rosh_hashana_day as i32,
+    }
+}
+
+/// Returns the day of the week of a number of days from the Jewish epoch, which is a multiple
+/// of 7 on Sunday.
+fn weekday_of_day(day: i64) -> Weekday {
+    match day.rem_euclid(7) {
+        0 => Weekday::Sun,
+        1 => Weekday::Mon,
+        2 => Weekday::Tue,
+        3 => Weekday::Wed,
+        4 => Weekday::Thu,
+        5 => Weekday::Fri,
+        _ => Weekday::Sat,
+    }
+}
+
+/// Returns the number of days in the Jewish `year` (353-355 or 383-385).
+pub fn get_days_in_jewish_year(year: i32) -> i32 {
+    get_jewish_calendar_elapsed_days(year + 1) - get_jewish_calendar_elapsed_days(year)
+}
+
+/// Returns true if Cheshvan has 30 days in the Jewish `year`.
+pub fn is_cheshvan_long(year: i32) -> bool {
+    get_days_in_jewish_year(year) % 10 == 5
+}
+
+/// Returns true if Kislev has 29 days in the Jewish `year`.
+pub fn is_kislev_short(year: i32) -> bool {
+    get_days_in_jewish_year(year) % 10 == 3
+}
+
+/// Returns whether the Jewish `year` is chaserim, kesidran or shelaimim.
+pub fn get_cheshvan_kislev_kviah(year: i32) -> YearLengthType {
+    match (is_cheshvan_long(year), is_kislev_short(year)) {
+        (true, false) => YearLengthType::Shelaimim,
+        (false, true) => YearLengthType::Chaserim,
+        _ => YearLengthType::Kesidran,
+    }
+}
+
+/// Returns the number of days in `month` of the Jewish `year`.
+pub fn get_days_in_jewish_month(month: JewishMonth, year: i32) -> u8 {
+    match month {
+        JewishMonth::Iyar | JewishMonth::Tammuz | JewishMonth::Elul | JewishMonth::Teves => 29,
+        JewishMonth::Cheshvan => {
+            if is_cheshvan_long(year) {
+                30
+            } else {
+                29
+            }
+        }
+        JewishMonth::Kislev => {
+            if is_kislev_short(year) {
+                29
+            } else {
+                30
+            }
+        }
+        JewishMonth::Adar => {
+            if is_jewish_leap_year(year) {
+                30
+            } else {
+                29
+            }
+        }
+        JewishMonth::AdarII => 29,
+        _ => 30,
+    }
+}
+
+/// Returns the number of days from December 31 of the Gregorian year 0 to Rosh Hashana of the
+/// Jewish `year`, as counted by [`NaiveDate::from_num_days_from_ce_opt`].
+fn get_rosh_hashana_days_from_ce(year: i32) -> i64 {
+    i64::from(get_jewish_calendar_elapsed_days(year)) + _JEWISH_EPOCH + 1
+}
+
+/// Returns the Gregorian date of Rosh Hashana of the Jewish `year`, or `None` if the year is not
+/// supported.
+pub fn get_rosh_hashana_date(year: i32) -> Option<NaiveDate> {
+    if !is_supported_jewish_year(year) {
+        return None;
+    }
+    NaiveDate::from_num_days_from_ce_opt(i32::try_from(get_rosh_hashana_days_from_ce(year)).ok()?)
+}
+
+/// Returns the Gregorian dates of the first and last days of the Jewish `year`, Rosh Hashana
+/// and Erev Rosh Hashana of the year after, or `None` if the year is not supported.
+pub fn get_gregorian_span(year: i32) -> Option<(NaiveDate, NaiveDate)> {
+    let first = get_rosh_hashana_date(year)?;
+    let last = get_rosh_hashana_days_from_ce(year + 1) - 1;
+    Some((first, NaiveDate::from_num_days_from_ce_opt(i32::try_from(last).ok()?)?))
+}
+
+/// The two Jewish years that overlap a Gregorian year.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub struct JewishYearSpan {
+    /// The Jewish year on January 1.
+    pub first_year: i32,
+    /// The Jewish year on December 31, which begins on [`JewishYearSpan::rosh_hashana`].
+    pub second_year: i32,
+    pub rosh_hashana: NaiveDate,
+}
+
+/// Returns the Jewish years that overlap the Gregorian `year` and the date of Rosh Hashana
+/// between them, which is in September or October in the present era.
+///
+/// Returns `None` if the years are not supported, or in the far future when the calendar has
+/// drifted so far that Rosh Hashana no longer falls in the same Gregorian year.
+pub fn get_jewish_years_in_gregorian_year(year: i32) -> Option<JewishYearSpan> {
+    let second_year = year.checked_add(3761)?;
+    let rosh_hashana = get_rosh_hashana_date(second_year)?;
+    if rosh_hashana.year() != year || !is_supported_jewish_year(second_year - 1) {
+        return None;
+    }
+    Some(JewishYearSpan {
+        first_year: second_year - 1,
+        second_year,
+        rosh_hashana,
+    })
+}
@@ -0,0 +1,176 @@
+//! Spanish names, enabled by the `lang-es` feature.
+use crate::constants::{JewishHoliday, JewishMonth, Zman};
+
+impl JewishHoliday {
+    pub fn es_string(&self) -> &str {
+        match self {
+            JewishHoliday::ErevPesach => "Víspera de Pésaj",
+            JewishHoliday::Pesach => "Pésaj",
+            JewishHoliday::CholHamoedPesach => "Jol Hamoed Pésaj",
+            JewishHoliday::PesachSheni => "Pésaj Shení",
+            JewishHoliday::ErevShavuos => "Víspera de Shavuot",
+            JewishHoliday::Shavuos => "Shavuot",
+            JewishHoliday::SeventeenthOfTammuz => "17 de Tamuz",
+            JewishHoliday::TishahBav => "9 de Av",
+            JewishHoliday::TuBav => "15 de Av",
+            JewishHoliday::ErevRoshHashana => "Víspera de Rosh Hashaná",
+            JewishHoliday::RoshHashana => "Rosh Hashaná",
+            JewishHoliday::FastOfGedalyah => "Ayuno de Guedalia",
+            JewishHoliday::ErevYomKippur => "Víspera de Yom Kipur",
+            JewishHoliday::YomKippur => "Yom Kipur",
+            JewishHoliday::ErevSuccos => "Víspera de Sucot",
+            JewishHoliday::Succos => "Sucot",
+            JewishHoliday::CholHamoedSuccos => "Jol Hamoed Sucot",
+            JewishHoliday::HoshanaRabbah => "Hoshaná Rabá",
+            JewishHoliday::SheminiAtzeres => "Sheminí Atzeret",
+            JewishHoliday::SimchasTorah => "Simjat Torá",
+            JewishHoliday::ErevChanukah => "Víspera de Janucá",
+            JewishHoliday::Chanukah => "Janucá",
+            JewishHoliday::TenthOfTeves => "10 de Tevet",
+            JewishHoliday::TuBshvat => "Tu BiShvat",
+            JewishHoliday::FastOfEsther => "Ayuno de Ester",
+            JewishHoliday::Purim => "Purim",
+            JewishHoliday::ShushanPurim => "Shushán Purim",
+            JewishHoliday::PurimKatan => "Purim Katán",
+            JewishHoliday::RoshChodesh => "Rosh Jodesh",
+            JewishHoliday::YomHaShoah => "Yom HaShoá",
+            JewishHoliday::YomHazikaron => "Yom HaZikarón",
+            JewishHoliday::YomHaatzmaut => "Yom HaAtzmaut",
+            JewishHoliday::YomYerushalayim => "Yom Yerushaláim",
+            JewishHoliday::LagBomer => "Lag BaÓmer",
+            JewishHoliday::ShushanPurimKatan => "Shushán Purim Katán",
+            JewishHoliday::IsruChag => "Isrú Jag",
+            JewishHoliday::YomKippurKatan => "Yom Kipur Katán",
+            JewishHoliday::Behab => "Behab",
+        }
+    }
+}
+
+impl JewishMonth {
+    pub fn es_string(&self, is_leap_year: bool) -> &str {
+        match self {
+            JewishMonth::Nissan => "Nisán",
+            JewishMonth::Iyar => "Iyar",
+            JewishMonth::Sivan => "Siván",
+            JewishMonth::Tammuz => "Tamuz",
+            JewishMonth::Av => "Av",
+            JewishMonth::Elul => "Elul",
+            JewishMonth::Tishrei => "Tishrei",
+            JewishMonth::Cheshvan => "Jeshván",
+            JewishMonth::Kislev => "Kislev",
+            JewishMonth::Teves => "Tevet",
+            JewishMonth::Shevat => "Shvat",
+            JewishMonth::Adar => {
+                if is_leap_year {
+                    "Adar I"
+                } else {
+                    "Adar"
+                }
+            }
+            JewishMonth::AdarII => "Adar II",
+        }
+    }
+}
+
+impl Zman {
+    pub fn es_string(&self) -> &str {
+        match self {
+            Zman::Alos120 => "Alot Hashajar (120 minutos)",
+            Zman::Alos120Zmanis => "Alot Hashajar (120 minutos zmaniyot)",
+            Zman::Alos18Degrees => "Alot Hashajar (18°)",
+            Zman::Alos19Degrees => "Alot Hashajar (19°)",
+            Zman::Alos19Point8Degrees => "Alot Hashajar (19,8°)",
+            Zman::Alos26Degrees => "Alot Hashajar (26°)",
+            Zman::Alos60 => "Alot Hashajar (60 minutos)",
+            Zman::Alos72 => "Alot Hashajar (72 minutos)",
+            Zman::Alos72Zmanis => "Alot Hashajar (72 minutos zmaniyot)",
+            Zman::Alos90 => "Alot Hashajar (90 minutos)",
+            Zman::Alos90Zmanis => "Alot Hashajar (90 minutos zmaniyot)",
+            Zman::Alos96 => "Alot Hashajar (96 minutos)",
+            Zman::Alos96Zmanis => "Alot Hashajar (96 minutos zmaniyot)",
+            Zman::AlosHashachar => "Alot Hashajar",
+            Zman::BainHashmashosRT13Point24Degrees => "Bein Hashmashot (Rabenu Tam, 13,24°)",
+            Zman::BainHashmashosRT13Point5MinutesBefore7Point083Degrees => {
+                "Bein Hashmashot (Rabenu Tam, 13,5 minutos antes de 7,083°)"
+            }
+            Zman::BainHashmashosRT2Stars => "Bein Hashmashot (Rabenu Tam, 2 estrellas)",
+            Zman::BainHashmashosRT58Point5Minutes => "Bein Hashmashot (Rabenu Tam, 58,5 minutos)",
+            Zman::BainHashmashosYereim13Point5Minutes => "Bein Hashmashot (Yereim, 13,5 minutos)",
+            Zman::BainHashmashosYereim16Point875Minutes => "Bein Hashmashot (Yereim, 16,875 minutos)",
+            Zman::BainHashmashosYereim18Minutes => "Bein Hashmashot (Yereim, 18 minutos)",
+            Zman::BainHashmashosYereim2Point1Degrees => "Bein Hashmashot (Yereim, 2,1°)",
+            Zman::BainHashmashosYereim2Point8Degrees => "Bein Hashmashot (Yereim, 2,8°)",
+            Zman::BainHashmashosYereim3Point05Degrees => "Bein Hashmashot (Yereim, 3,05°)",
+            Zman::CandleLighting => "Encendido de velas",
+            Zman::Chatzos => "Jatzot",
+            Zman::ChatzosAsHalfDay => "Jatzot (mitad del día)",
+            Zman::MinchaGedola => "Minjá Guedolá",
+            Zman::MinchaGedola16Point1Degrees => "Minjá Guedolá (16,1°)",
+            Zman::MinchaGedola30Minutes => "Minjá Guedolá (30 minutos)",
+            Zman::MinchaGedolaAteretTorah => "Minjá Guedolá (Ateret Torá)",
+            Zman::MinchaGedolaGreaterThan30 => "Minjá Guedolá (al menos 30 minutos)",
+            Zman::MinchaKetana => "Minjá Ketaná",
+            Zman::MinchaKetana16Point1Degrees => "Minjá Ketaná (16,1°)",
+            Zman::MinchaKetana72Minutes => "Minjá Ketaná (72 minutos)",
+            Zman::MinchaKetanaAteretTorah => "Minjá Ketaná (Ateret Torá)",
+            Zman::MinchaKetanaGRAFixedLocalChatzosToSunset => "Minjá Ketaná (Gra, de jatzot fijo a la puesta del sol)",
+            Zman::PlagHamincha => "Plag Haminjá",
+            Zman::PlagHaminchaAteretTorah => "Plag Haminjá (Ateret Torá)",
+            Zman::SofZmanShmaAteretTorah => "Fin del Shemá (Ateret Torá)",
+            Zman::SofZmanShmaGRA => "Fin del Shemá (Gra)",
+            Zman::SofZmanShmaMGA => "Fin del Shemá (Maguén Abraham)",
+            Zman::SofZmanShmaMGA120Minutes => "Fin del Shemá (Maguén Abraham, 120 minutos)",
+            Zman::SofZmanShmaMGA16Point1Degrees => "Fin del Shemá (Maguén Abraham, 16,1°)",
+            Zman::SofZmanShmaMGA18Degrees => "Fin del Shemá (Maguén Abraham, 18°)",
+            Zman::SofZmanShmaMGA19Point8Degrees => "Fin del Shemá (Maguén Abraham, 19,8°)",
+            Zman::SofZmanShmaMGA72MinutesZmanis => "Fin del Shemá (Maguén Abraham, 72 minutos zmaniyot)",
+            Zman::SofZmanShmaMGA90Minutes => "Fin del Shemá (Maguén Abraham, 90 minutos)",
+            Zman::SofZmanShmaMGA90MinutesZmanis => "Fin del Shemá (Maguén Abraham, 90 minutos zmaniyot)",
+            Zman::SofZmanShmaMGA96Minutes => "Fin del Shemá (Maguén Abraham, 96 minutos)",
+            Zman::SofZmanShmaMGA96MinutesZmanis => "Fin del Shemá (Maguén Abraham, 96 minutos zmaniyot)",
+            Zman::SofZmanTfila2HoursBeforeChatzos => "Fin de la Tefilá (2 horas antes de jatzot)",
+            Zman::SofZmanTfilaAteretTorah => "Fin de la Tefilá (Ateret Torá)",
+            Zman::SofZmanTfilaGRA => "Fin de la Tefilá (Gra)",
+            Zman::SofZmanTfilaMGA => "Fin de la Tefilá (Maguén Abraham)",
+            Zman::SofZmanTfilaMGA120Minutes => "Fin de la Tefilá (Maguén Abraham, 120 minutos)",
+            Zman::SofZmanTfilaMGA16Point1Degrees => "Fin de la Tefilá (Maguén Abraham, 16,1°)",
+            Zman::SofZmanTfilaMGA18Degrees => "Fin de la Tefilá (Maguén Abraham, 18°)",
+            Zman::SofZmanTfilaMGA19Point8Degrees => "Fin de la Tefilá (Maguén Abraham, 19,8°)",
+            Zman::SofZmanTfilaMGA72MinutesZmanis => "Fin de la Tefilá (Maguén Abraham, 72 minutos zmaniyot)",
+            Zman::SofZmanTfilaMGA90Minutes => "Fin de la Tefilá (Maguén Abraham, 90 minutos)",
+            Zman::SofZmanTfilaMGA90MinutesZmanis => "Fin de la Tefilá (Maguén Abraham, 90 minutos zmaniyot)",
+            Zman::SofZmanTfilaMGA96Minutes => "Fin de la Tefilá (Maguén Abraham, 96 minutos)",
+            Zman::SofZmanTfilaMGA96MinutesZmanis => "Fin de la Tefilá (Maguén Abraham, 96 minutos zmaniyot)",
+            Zman::Tzais => "Tzet Hakojavim",
+            Zman::Tzais120 => "Tzet Hakojavim (120 minutos)",
+            Zman::Tzais120Zmanis => "Tzet Hakojavim (120 minutos zmaniyot)",
+            Zman::Tzais16Point1Degrees => "Tzet Hakojavim (16,1°)",
+            Zman::Tzais18Degrees => "Tzet Hakojavim (18°)",
+            Zman::Tzais19Point8Degrees => "Tzet Hakojavim (19,8°)",
+            Zman::Tzais26Degrees => "Tzet Hakojavim (26°)",
+            Zman::Tzais50 => "Tzet Hakojavim (50 minutos)",
+            Zman::Tzais60 => "Tzet Hakojavim (60 minutos)",
+            Zman::Tzais72 => "Tzet Hakojavim (72 minutos)",
+            Zman::Tzais72Zmanis => "Tzet Hakojavim (72 minutos zmaniyot)",
+            Zman::Tzais90 => "Tzet Hakojavim (90 minutos)",
+            Zman::Tzais90Zmanis => "Tzet Hakojavim (90 minutos zmaniyot)",
+            Zman::Tzais96 => "Tzet Hakojavim (96 minutos)",
+            Zman::Tzais96Zmanis => "Tzet Hakojavim (96 minutos zmaniyot)",
+            Zman::TzaisAteretTorah => "Tzet Hakojavim (Ateret Torá)",
+            Zman::TzaisGeonim3Point65Degrees => "Tzet Hakojavim (Gueonim, 3,65°)",
+            Zman::TzaisGeonim3Point676Degrees => "Tzet Hakojavim (Gueonim, 3,676°)",
+            Zman::TzaisGeonim3Point7Degrees => "Tzet Hakojavim (Gueonim, 3,7°)",
+            Zman::TzaisGeonim3Point8Degrees => "Tzet Hakojavim (Gueonim, 3,8°)",
+            Zman::TzaisGeonim4Point37Degrees => "Tzet Hakojavim (Gueonim, 4,37°)",
+            Zman::TzaisGeonim4Point61Degrees => "Tzet Hakojavim (Gueonim, 4,61°)",
+            Zman::TzaisGeonim4Point8Degrees => "Tzet Hakojavim (Gueonim, 4,8°)",
+            Zman::TzaisGeonim5Point88Degrees => "Tzet Hakojavim (Gueonim, 5,88°)",
+            Zman::TzaisGeonim5Point95Degrees => "Tzet Hakojavim (Gueonim, 5,95°)",
+            Zman::TzaisGeonim6Point45Degrees => "Tzet Hakojavim (Gueonim, 6,45°)",
+            Zman::TzaisGeonim7Point083Degrees => "Tzet Hakojavim (Gueonim, 7,083°)",
+            Zman::TzaisGeonim7Point67Degrees => "Tzet Hakojavim (Gueonim, 7,67°)",
+            Zman::TzaisGeonim9Point3Degrees => "Tzet Hakojavim (Gueonim, 9,3°)",
+            Zman::TzaisGeonim9Point75Degrees => "Tzet Hakojavim (Gueonim, 9,75°)",
+        }
+    }
+}
@@ -0,0 +1,176 @@
+//! French names, enabled by the `lang-fr` feature.
+use crate::constants::{JewishHoliday, JewishMonth, Zman};
+
+impl JewishHoliday {
+    pub fn fr_string(&self) -> &str {
+        match self {
+            JewishHoliday::ErevPesach => "Veille de Pessah",
+            JewishHoliday::Pesach => "Pessah",
+            JewishHoliday::CholHamoedPesach => "Hol Hamoèd Pessah",
+            JewishHoliday::PesachSheni => "Pessah Chéni",
+            JewishHoliday::ErevShavuos => "Veille de Chavouot",
+            JewishHoliday::Shavuos => "Chavouot",
+            JewishHoliday::SeventeenthOfTammuz => "17 Tamouz",
+            JewishHoliday::TishahBav => "9 Av",
+            JewishHoliday::TuBav => "15 Av",
+            JewishHoliday::ErevRoshHashana => "Veille de Roch Hachana",
+            JewishHoliday::RoshHashana => "Roch Hachana",
+            JewishHoliday::FastOfGedalyah => "Jeûne de Guedalia",
+            JewishHoliday::ErevYomKippur => "Veille de Yom Kippour",
+            JewishHoliday::YomKippur => "Yom Kippour",
+            JewishHoliday::ErevSuccos => "Veille de Souccot",
+            JewishHoliday::Succos => "Souccot",
+            JewishHoliday::CholHamoedSuccos => "Hol Hamoèd Souccot",
+            JewishHoliday::HoshanaRabbah => "Hochaana Rabba",
+            JewishHoliday::SheminiAtzeres => "Chemini Atseret",
+            JewishHoliday::SimchasTorah => "Simhat Torah",
+            JewishHoliday::ErevChanukah => "Veille de Hanoucca",
+            JewishHoliday::Chanukah => "Hanoucca",
+            JewishHoliday::TenthOfTeves => "10 Tévet",
+            JewishHoliday::TuBshvat => "Tou Bichvat",
+            JewishHoliday::FastOfEsther => "Jeûne d'Esther",
+            JewishHoliday::Purim => "Pourim",
+            JewishHoliday::ShushanPurim => "Chouchan Pourim",
+            JewishHoliday::PurimKatan => "Pourim Katan",
+            JewishHoliday::RoshChodesh => "Roch Hodech",
+            JewishHoliday::YomHaShoah => "Yom HaChoah",
+            JewishHoliday::YomHazikaron => "Yom HaZikaron",
+            JewishHoliday::YomHaatzmaut => "Yom HaAtsmaout",
+            JewishHoliday::YomYerushalayim => "Yom Yerouchalayim",
+            JewishHoliday::LagBomer => "Lag Baomer",
+            JewishHoliday::ShushanPurimKatan => "Chouchan Pourim Katan",
+            JewishHoliday::IsruChag => "Issrou Hag",
+            JewishHoliday::YomKippurKatan => "Yom Kippour Katan",
+            JewishHoliday::Behab => "Béhab",
+        }
+    }
+}
+
+impl JewishMonth {
+    pub fn fr_string(&self, is_leap_year: bool) -> &str {
+        match self {
+            JewishMonth::Nissan => "Nissan",
+            JewishMonth::Iyar => "Iyar",
+            JewishMonth::Sivan => "Sivan",
+            JewishMonth::Tammuz => "Tamouz",
+            JewishMonth::Av => "Av",
+            JewishMonth::Elul => "Eloul",
+            JewishMonth::Tishrei => "Tichri",
+            JewishMonth::Cheshvan => "Hechvan",
+            JewishMonth::Kislev => "Kislev",
+            JewishMonth::Teves => "Tévet",
+            JewishMonth::Shevat => "Chevat",
+            JewishMonth::Adar => {
+                if is_leap_year {
+                    "Adar I"
+                } else {
+                    "Adar"
+                }
+            }
+            JewishMonth::AdarII => "Adar II",
+        }
+    }
+}
+
+impl Zman {
+    pub fn fr_string(&self) -> &str {
+        match self {
+            Zman::Alos120 => "Alot hachahar (120 minutes)",
+            Zman::Alos120Zmanis => "Alot hachahar (120 minutes zmaniyot)",
+            Zman::Alos18Degrees => "Alot hachahar (18°)",
+            Zman::Alos19Degrees => "Alot hachahar (19°)",
+            Zman::Alos19Point8Degrees => "Alot hachahar (19,8°)",
+            Zman::Alos26Degrees => "Alot hachahar (26°)",
+            Zman::Alos60 => "Alot hachahar (60 minutes)",
+            Zman::Alos72 => "Alot hachahar (72 minutes)",
+            Zman::Alos72Zmanis => "Alot hachahar (72 minutes zmaniyot)",
+            Zman::Alos90 => "Alot hachahar (90 minutes)",
+            Zman::Alos90Zmanis => "Alot hachahar (90 minutes zmaniyot)",
+            Zman::Alos96 => "Alot hachahar (96 minutes)",
+            Zman::Alos96Zmanis => "Alot hachahar (96 minutes zmaniyot)",
+            Zman::AlosHashachar => "Alot hachahar",
+            Zman::BainHashmashosRT13Point24Degrees => "Ben hachmachot (Rabbénou Tam, 13,24°)",
+            Zman::BainHashmashosRT13Point5MinutesBefore7Point083Degrees => {
+                "Ben hachmachot (Rabbénou Tam, 13,5 minutes avant 7,083°)"
+            }
+            Zman::BainHashmashosRT2Stars => "Ben hachmachot (Rabbénou Tam, 2 étoiles)",
+            Zman::BainHashmashosRT58Point5Minutes => "Ben hachmachot (Rabbénou Tam, 58,5 minutes)",
+            Zman::BainHashmashosYereim13Point5Minutes => "Ben hachmachot (Yeréim, 13,5 minutes)",
+            Zman::BainHashmashosYereim16Point875Minutes => "Ben hachmachot (Yeréim, 16,875 minutes)",
+            Zman::BainHashmashosYereim18Minutes => "Ben hachmachot (Yeréim, 18 minutes)",
+            Zman::BainHashmashosYereim2Point1Degrees => "Ben hachmachot (Yeréim, 2,1°)",
+            Zman::BainHashmashosYereim2Point8Degrees => "Ben hachmachot (Yeréim, 2,8°)",
+            Zman::BainHashmashosYereim3Point05Degrees => "Ben hachmachot (Yeréim, 3,05°)",
+            Zman::CandleLighting => "Allumage des bougies",
+            Zman::Chatzos => "Hatsot",
+            Zman::ChatzosAsHalfDay => "Hatsot (milieu du jour)",
+            Zman::MinchaGedola => "Minha guedola",
+            Zman::MinchaGedola16Point1Degrees => "Minha guedola (16,1°)",
+            Zman::MinchaGedola30Minutes => "Minha guedola (30 minutes)",
+            Zman::MinchaGedolaAteretTorah => "Minha guedola (Ateret Torah)",
+            Zman::MinchaGedolaGreaterThan30 => "Minha guedola (au moins 30 minutes)",
+            Zman::MinchaKetana => "Minha ketana",
+            Zman::MinchaKetana16Point1Degrees => "Minha ketana (16,1°)",
+            Zman::MinchaKetana72Minutes => "Minha ketana (72 minutes)",
+            Zman::MinchaKetanaAteretTorah => "Minha ketana (Ateret Torah)",
+            Zman::MinchaKetanaGRAFixedLocalChatzosToSunset => "Minha ketana (Gra, de hatsot fixe au coucher du soleil)",
+            Zman::PlagHamincha => "Plag haminha",
+            Zman::PlagHaminchaAteretTorah => "Plag haminha (Ateret Torah)",
+            Zman::SofZmanShmaAteretTorah => "Fin du Chéma (Ateret Torah)",
+            Zman::SofZmanShmaGRA => "Fin du Chéma (Gra)",
+            Zman::SofZmanShmaMGA => "Fin du Chéma (Maguen Avraham)",
+            Zman::SofZmanShmaMGA120Minutes => "Fin du Chéma (Maguen Avraham, 120 minutes)",
+            Zman::SofZmanShmaMGA16Point1Degrees => "Fin du Chéma (Maguen Avraham, 16,1°)",
+            Zman::SofZmanShmaMGA18Degrees => "Fin du Chéma (Maguen Avraham, 18°)",
+            Zman::SofZmanShmaMGA19Point8Degrees => "Fin du Chéma (Maguen Avraham, 19,8°)",
+            Zman::SofZmanShmaMGA72MinutesZmanis => "Fin du Chéma (Maguen Avraham, 72 minutes zmaniyot)",
+            Zman::SofZmanShmaMGA90Minutes => "Fin du Chéma (Maguen Avraham, 90 minutes)",
+            Zman::SofZmanShmaMGA90MinutesZmanis => "Fin du Chéma (Maguen Avraham, 90 minutes zmaniyot)",
+            Zman::SofZmanShmaMGA96Minutes => "Fin du Chéma (Maguen Avraham, 96 minutes)",
+            Zman::SofZmanShmaMGA96MinutesZmanis => "Fin du Chéma (Maguen Avraham, 96 minutes zmaniyot)",
+            Zman::SofZmanTfila2HoursBeforeChatzos => "Fin de la Téfila (2 heures avant hatsot)",
+            Zman::SofZmanTfilaAteretTorah => "Fin de la Téfila (Ateret Torah)",
+            Zman::SofZmanTfilaGRA => "Fin de la Téfila (Gra)",
+            Zman::SofZmanTfilaMGA => "Fin de la Téfila (Maguen Avraham)",
+            Zman::SofZmanTfilaMGA120Minutes => "Fin de la Téfila (Maguen Avraham, 120 minutes)",
+            Zman::SofZmanTfilaMGA16Point1Degrees => "Fin de la Téfila (Maguen Avraham, 16,1°)",
+            Zman::SofZmanTfilaMGA18Degrees => "Fin de la Téfila (Maguen Avraham, 18°)",
+            Zman::SofZmanTfilaMGA19Point8Degrees => "Fin de la Téfila (Maguen Avraham, 19,8°)",
+            Zman::SofZmanTfilaMGA72MinutesZmanis => "Fin de la Téfila (Maguen Avraham, 72 minutes zmaniyot)",
+            Zman::SofZmanTfilaMGA90Minutes => "Fin de la Téfila (Maguen Avraham, 90 minutes)",
+            Zman::SofZmanTfilaMGA90MinutesZmanis => "Fin de la Téfila (Maguen Avraham, 90 minutes zmaniyot)",
+            Zman::SofZmanTfilaMGA96Minutes => "Fin de la Téfila (Maguen Avraham, 96 minutes)",
+            Zman::SofZmanTfilaMGA96MinutesZmanis => "Fin de la Téfila (Maguen Avraham, 96 minutes zmaniyot)",
+            Zman::Tzais => "Tsét hakokhavim",
+            Zman::Tzais120 => "Tsét hakokhavim (120 minutes)",
+            Zman::Tzais120Zmanis => "Tsét hakokhavim (120 minutes zmaniyot)",
+            Zman::Tzais16Point1Degrees => "Tsét hakokhavim (16,1°)",
+            Zman::Tzais18Degrees => "Tsét hakokhavim (18°)",
+            Zman::Tzais19Point8Degrees => "Tsét hakokhavim (19,8°)",
+            Zman::Tzais26Degrees => "Tsét hakokhavim (26°)",
+            Zman::Tzais50 => "Tsét hakokhavim (50 minutes)",
+            Zman::Tzais60 => "Tsét hakokhavim (60 minutes)",
+            Zman::Tzais72 => "Tsét hakokhavim (72 minutes)",
+            Zman::Tzais72Zmanis => "Tsét hakokhavim (72 minutes zmaniyot)",
+            Zman::Tzais90 => "Tsét hakokhavim (90 minutes)",
+            Zman::Tzais90Zmanis => "Tsét hakokhavim (90 minutes zmaniyot)",
+            Zman::Tzais96 => "Tsét hakokhavim (96 minutes)",
+            Zman::Tzais96Zmanis => "Tsét hakokhavim (96 minutes zmaniyot)",
+            Zman::TzaisAteretTorah => "Tsét hakokhavim (Ateret Torah)",
+            Zman::TzaisGeonim3Point65Degrees => "Tsét hakokhavim (Guéonim, 3,65°)",
+            Zman::TzaisGeonim3Point676Degrees => "Tsét hakokhavim (Guéonim, 3,676°)",
+            Zman::TzaisGeonim3Point7Degrees => "Tsét hakokhavim (Guéonim, 3,7°)",
+            Zman::TzaisGeonim3Point8Degrees => "Tsét hakokhavim (Guéonim, 3,8°)",
+            Zman::TzaisGeonim4Point37Degrees => "Tsét hakokhavim (Guéonim, 4,37°)",
+            Zman::TzaisGeonim4Point61Degrees => "Tsét hakokhavim (Guéonim, 4,61°)",
+            Zman::TzaisGeonim4Point8Degrees => "Tsét hakokhavim (Guéonim, 4,8°)",
+            Zman::TzaisGeonim5Point88Degrees => "Tsét hakokhavim (Guéonim, 5,88°)",
+            Zman::TzaisGeonim5Point95Degrees => "Tsét hakokhavim (Guéonim, 5,95°)",
+            Zman::TzaisGeonim6Point45Degrees => "Tsét hakokhavim (Guéonim, 6,45°)",
+            Zman::TzaisGeonim7Point083Degrees => "Tsét hakokhavim (Guéonim, 7,083°)",
+            Zman::TzaisGeonim7Point67Degrees => "Tsét hakokhavim (Guéonim, 7,67°)",
+            Zman::TzaisGeonim9Point3Degrees => "Tsét hakokhavim (Guéonim, 9,3°)",
+            Zman::TzaisGeonim9Point75Degrees => "Tsét hakokhavim (Guéonim, 9,75°)",
+        }
+    }
+}
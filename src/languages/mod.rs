@@ -0,0 +1,8 @@
+//! Optional name tables for languages other than English, Hebrew and Yiddish. Each language is
+//! behind its own `lang-*` feature so that unused tables are not compiled in.
+#[cfg(feature = "lang-es")]
+mod es;
+#[cfg(feature = "lang-fr")]
+mod fr;
+#[cfg(feature = "lang-ru")]
+mod ru;
@@ -0,0 +1,176 @@
+//! Russian names, enabled by the `lang-ru` feature.
+use crate::constants::{JewishHoliday, JewishMonth, Zman};
+
+impl JewishHoliday {
+    pub fn ru_string(&self) -> &str {
+        match self {
+            JewishHoliday::ErevPesach => "Канун Песаха",
+            JewishHoliday::Pesach => "Песах",
+            JewishHoliday::CholHamoedPesach => "Холь а-моэд Песах",
+            JewishHoliday::PesachSheni => "Песах шени",
+            JewishHoliday::ErevShavuos => "Канун Шавуота",
+            JewishHoliday::Shavuos => "Шавуот",
+            JewishHoliday::SeventeenthOfTammuz => "17 Таммуза",
+            JewishHoliday::TishahBav => "9 Ава",
+            JewishHoliday::TuBav => "15 Ава",
+            JewishHoliday::ErevRoshHashana => "Канун Рош а-Шана",
+            JewishHoliday::RoshHashana => "Рош а-Шана",
+            JewishHoliday::FastOfGedalyah => "Пост Гедальи",
+            JewishHoliday::ErevYomKippur => "Канун Йом Кипура",
+            JewishHoliday::YomKippur => "Йом Кипур",
+            JewishHoliday::ErevSuccos => "Канун Суккота",
+            JewishHoliday::Succos => "Суккот",
+            JewishHoliday::CholHamoedSuccos => "Холь а-моэд Суккот",
+            JewishHoliday::HoshanaRabbah => "Гошана Раба",
+            JewishHoliday::SheminiAtzeres => "Шмини Ацерет",
+            JewishHoliday::SimchasTorah => "Симхат Тора",
+            JewishHoliday::ErevChanukah => "Канун Хануки",
+            JewishHoliday::Chanukah => "Ханука",
+            JewishHoliday::TenthOfTeves => "10 Тевета",
+            JewishHoliday::TuBshvat => "Ту би-Шват",
+            JewishHoliday::FastOfEsther => "Пост Эстер",
+            JewishHoliday::Purim => "Пурим",
+            JewishHoliday::ShushanPurim => "Шушан Пурим",
+            JewishHoliday::PurimKatan => "Пурим катан",
+            JewishHoliday::RoshChodesh => "Рош Ходеш",
+            JewishHoliday::YomHaShoah => "Йом а-Шоа",
+            JewishHoliday::YomHazikaron => "Йом а-Зикарон",
+            JewishHoliday::YomHaatzmaut => "Йом а-Ацмаут",
+            JewishHoliday::YomYerushalayim => "Йом Иерушалаим",
+            JewishHoliday::LagBomer => "Лаг ба-Омер",
+            JewishHoliday::ShushanPurimKatan => "Шушан Пурим катан",
+            JewishHoliday::IsruChag => "Исру хаг",
+            JewishHoliday::YomKippurKatan => "Йом Кипур катан",
+            JewishHoliday::Behab => "Бахаб",
+        }
+    }
+}
+
+impl JewishMonth {
+    pub fn ru_string(&self, is_leap_year: bool) -> &str {
+        match self {
+            JewishMonth::Nissan => "Нисан",
+            JewishMonth::Iyar => "Ияр",
+            JewishMonth::Sivan => "Сиван",
+            JewishMonth::Tammuz => "Таммуз",
+            JewishMonth::Av => "Ав",
+            JewishMonth::Elul => "Элул",
+            JewishMonth::Tishrei => "Тишрей",
+            JewishMonth::Cheshvan => "Хешван",
+            JewishMonth::Kislev => "Кислев",
+            JewishMonth::Teves => "Тевет",
+            JewishMonth::Shevat => "Шват",
+            JewishMonth::Adar => {
+                if is_leap_year {
+                    "Адар I"
+                } else {
+                    "Адар"
+                }
+            }
+            JewishMonth::AdarII => "Адар II",
+        }
+    }
+}
+
+impl Zman {
+    pub fn ru_string(&self) -> &str {
+        match self {
+            Zman::Alos120 => "Алот а-шахар (120 минут)",
+            Zman::Alos120Zmanis => "Алот а-шахар (120 временных минут)",
+            Zman::Alos18Degrees => "Алот а-шахар (18°)",
+            Zman::Alos19Degrees => "Алот а-шахар (19°)",
+            Zman::Alos19Point8Degrees => "Алот а-шахар (19,8°)",
+            Zman::Alos26Degrees => "Алот а-шахар (26°)",
+            Zman::Alos60 => "Алот а-шахар (60 минут)",
+            Zman::Alos72 => "Алот а-шахар (72 минуты)",
+            Zman::Alos72Zmanis => "Алот а-шахар (72 временных минут)",
+            Zman::Alos90 => "Алот а-шахар (90 минут)",
+            Zman::Alos90Zmanis => "Алот а-шахар (90 временных минут)",
+            Zman::Alos96 => "Алот а-шахар (96 минут)",
+            Zman::Alos96Zmanis => "Алот а-шахар (96 временных минут)",
+            Zman::AlosHashachar => "Алот а-шахар",
+            Zman::BainHashmashosRT13Point24Degrees => "Бейн а-шмашот (Рабейну Там, 13,24°)",
+            Zman::BainHashmashosRT13Point5MinutesBefore7Point083Degrees => {
+                "Бейн а-шмашот (Рабейну Там, за 13,5 минуты до 7,083°)"
+            }
+            Zman::BainHashmashosRT2Stars => "Бейн а-шмашот (Рабейну Там, 2 звезды)",
+            Zman::BainHashmashosRT58Point5Minutes => "Бейн а-шмашот (Рабейну Там, 58,5 минуты)",
+            Zman::BainHashmashosYereim13Point5Minutes => "Бейн а-шмашот (Йереим, 13,5 минуты)",
+            Zman::BainHashmashosYereim16Point875Minutes => "Бейн а-шмашот (Йереим, 16,875 минуты)",
+            Zman::BainHashmashosYereim18Minutes => "Бейн а-шмашот (Йереим, 18 минут)",
+            Zman::BainHashmashosYereim2Point1Degrees => "Бейн а-шмашот (Йереим, 2,1°)",
+            Zman::BainHashmashosYereim2Point8Degrees => "Бейн а-шмашот (Йереим, 2,8°)",
+            Zman::BainHashmashosYereim3Point05Degrees => "Бейн а-шмашот (Йереим, 3,05°)",
+            Zman::CandleLighting => "Зажигание свечей",
+            Zman::Chatzos => "Хацот",
+            Zman::ChatzosAsHalfDay => "Хацот (середина дня)",
+            Zman::MinchaGedola => "Минха гедола",
+            Zman::MinchaGedola16Point1Degrees => "Минха гедола (16,1°)",
+            Zman::MinchaGedola30Minutes => "Минха гедола (30 минут)",
+            Zman::MinchaGedolaAteretTorah => "Минха гедола (Атерет Тора)",
+            Zman::MinchaGedolaGreaterThan30 => "Минха гедола (не менее 30 минут)",
+            Zman::MinchaKetana => "Минха ктана",
+            Zman::MinchaKetana16Point1Degrees => "Минха ктана (16,1°)",
+            Zman::MinchaKetana72Minutes => "Минха ктана (72 минуты)",
+            Zman::MinchaKetanaAteretTorah => "Минха ктана (Атерет Тора)",
+            Zman::MinchaKetanaGRAFixedLocalChatzosToSunset => "Минха ктана (Гра, от постоянного хацота до заката)",
+            Zman::PlagHamincha => "Плаг а-минха",
+            Zman::PlagHaminchaAteretTorah => "Плаг а-минха (Атерет Тора)",
+            Zman::SofZmanShmaAteretTorah => "Конец времени Шма (Атерет Тора)",
+            Zman::SofZmanShmaGRA => "Конец времени Шма (Гра)",
+            Zman::SofZmanShmaMGA => "Конец времени Шма (Маген Авраам)",
+            Zman::SofZmanShmaMGA120Minutes => "Конец времени Шма (Маген Авраам, 120 минут)",
+            Zman::SofZmanShmaMGA16Point1Degrees => "Конец времени Шма (Маген Авраам, 16,1°)",
+            Zman::SofZmanShmaMGA18Degrees => "Конец времени Шма (Маген Авраам, 18°)",
+            Zman::SofZmanShmaMGA19Point8Degrees => "Конец времени Шма (Маген Авраам, 19,8°)",
+            Zman::SofZmanShmaMGA72MinutesZmanis => "Конец времени Шма (Маген Авраам, 72 временных минут)",
+            Zman::SofZmanShmaMGA90Minutes => "Конец времени Шма (Маген Авраам, 90 минут)",
+            Zman::SofZmanShmaMGA90MinutesZmanis => "Конец времени Шма (Маген Авраам, 90 временных минут)",
+            Zman::SofZmanShmaMGA96Minutes => "Конец времени Шма (Маген Авраам, 96 минут)",
+            Zman::SofZmanShmaMGA96MinutesZmanis => "Конец времени Шма (Маген Авраам, 96 временных минут)",
+            Zman::SofZmanTfila2HoursBeforeChatzos => "Конец времени молитвы (за 2 часа до хацота)",
+            Zman::SofZmanTfilaAteretTorah => "Конец времени молитвы (Атерет Тора)",
+            Zman::SofZmanTfilaGRA => "Конец времени молитвы (Гра)",
+            Zman::SofZmanTfilaMGA => "Конец времени молитвы (Маген Авраам)",
+            Zman::SofZmanTfilaMGA120Minutes => "Конец времени молитвы (Маген Авраам, 120 минут)",
+            Zman::SofZmanTfilaMGA16Point1Degrees => "Конец времени молитвы (Маген Авраам, 16,1°)",
+            Zman::SofZmanTfilaMGA18Degrees => "Конец времени молитвы (Маген Авраам, 18°)",
+            Zman::SofZmanTfilaMGA19Point8Degrees => "Конец времени молитвы (Маген Авраам, 19,8°)",
+            Zman::SofZmanTfilaMGA72MinutesZmanis => "Конец времени молитвы (Маген Авраам, 72 временных минут)",
+            Zman::SofZmanTfilaMGA90Minutes => "Конец времени молитвы (Маген Авраам, 90 минут)",
+            Zman::SofZmanTfilaMGA90MinutesZmanis => "Конец времени молитвы (Маген Авраам, 90 временных минут)",
+            Zman::SofZmanTfilaMGA96Minutes => "Конец времени молитвы (Маген Авраам, 96 минут)",
+            Zman::SofZmanTfilaMGA96MinutesZmanis => "Конец времени молитвы (Маген Авраам, 96 временных минут)",
+            Zman::Tzais => "Цет а-кохавим",
+            Zman::Tzais120 => "Цет а-кохавим (120 минут)",
+            Zman::Tzais120Zmanis => "Цет а-кохавим (120 временных минут)",
+            Zman::Tzais16Point1Degrees => "Цет а-кохавим (16,1°)",
+            Zman::Tzais18Degrees => "Цет а-кохавим (18°)",
+            Zman::Tzais19Point8Degrees => "Цет а-кохавим (19,8°)",
+            Zman::Tzais26Degrees => "Цет а-кохавим (26°)",
+            Zman::Tzais50 => "Цет а-кохавим (50 минут)",
+            Zman::Tzais60 => "Цет а-кохавим (60 минут)",
+            Zman::Tzais72 => "Цет а-кохавим (72 минуты)",
+            Zman::Tzais72Zmanis => "Цет а-кохавим (72 временных минут)",
+            Zman::Tzais90 => "Цет а-кохавим (90 минут)",
+            Zman::Tzais90Zmanis => "Цет а-кохавим (90 временных минут)",
+            Zman::Tzais96 => "Цет а-кохавим (96 минут)",
+            Zman::Tzais96Zmanis => "Цет а-кохавим (96 временных минут)",
+            Zman::TzaisAteretTorah => "Цет а-кохавим (Атерет Тора)",
+            Zman::TzaisGeonim3Point65Degrees => "Цет а-кохавим (Гаоним, 3,65°)",
+            Zman::TzaisGeonim3Point676Degrees => "Цет а-кохавим (Гаоним, 3,676°)",
+            Zman::TzaisGeonim3Point7Degrees => "Цет а-кохавим (Гаоним, 3,7°)",
+            Zman::TzaisGeonim3Point8Degrees => "Цет а-кохавим (Гаоним, 3,8°)",
+            Zman::TzaisGeonim4Point37Degrees => "Цет а-кохавим (Гаоним, 4,37°)",
+            Zman::TzaisGeonim4Point61Degrees => "Цет а-кохавим (Гаоним, 4,61°)",
+            Zman::TzaisGeonim4Point8Degrees => "Цет а-кохавим (Гаоним, 4,8°)",
+            Zman::TzaisGeonim5Point88Degrees => "Цет а-кохавим (Гаоним, 5,88°)",
+            Zman::TzaisGeonim5Point95Degrees => "Цет а-кохавим (Гаоним, 5,95°)",
+            Zman::TzaisGeonim6Point45Degrees => "Цет а-кохавим (Гаоним, 6,45°)",
+            Zman::TzaisGeonim7Point083Degrees => "Цет а-кохавим (Гаоним, 7,083°)",
+            Zman::TzaisGeonim7Point67Degrees => "Цет а-кохавим (Гаоним, 7,67°)",
+            Zman::TzaisGeonim9Point3Degrees => "Цет а-кохавим (Гаоним, 9,3°)",
+            Zman::TzaisGeonim9Point75Degrees => "Цет а-кохавим (Гаоним, 9,75°)",
+        }
+    }
+}
@@ -0,0 +1,174 @@
+//! Daily and weekly learning cycles.
+//!
+//! Cycles that depend on large schedules (Mishna Yomis, Nach Yomi, Amud Yomi and the daily
+//! Rambam) are not yet supported. Calendars that assign a portion to each date of the Jewish
+//! year, such as the daily Mishna Berura of Dirshu, the daily Chofetz Chaim and Shmiras
+//! Halashon, are not included either, but can be looked up from a [`StudySchedule`] built from
+//! the published calendar.
+use chrono::Weekday;
+
+use crate::{
+    anniversary::AdarPolicy,
+    astronomical_calculator::AstronomicalCalculatorTrait,
+    constants::JewishMonth,
+    daf::{BavliDaf, YerushalmiDaf},
+    jewish_calendar::{JewishCalendar, JewishCalendarTrait},
+};
+
+/// A range of chapters, both ends inclusive.
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[derive(Debug, PartialEq, Eq, Clone, Copy, PartialOrd, Ord, Hash)]
+pub struct ChapterRange {
+    pub first_chapter: u8,
+    pub last_chapter: u8,
+}
+
+/// The first chapter read on each day of the month in the monthly Tehillim cycle.
+/// Days 25 and 26 each read half of chapter 119 (verses 1-96 and 97-176).
+const TEHILLIM_MONTHLY_START: [u8; 30] = [
+    1, 10, 18, 23, 29, 35, 39, 44, 49, 55, 60, 66, 69, 72, 77, 79, 83, 88, 90, 97, 104, 106, 108, 113, 119, 119, 120,
+    135, 140, 145,
+];
+
+/// Everything learned on a single day, as returned by [`JewishCalendar::get_daily_learning`].
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct DailyLearning {
+    pub daf_yomi_bavli: Option<BavliDaf>,
+    pub daf_yomi_yerushalmi: Option<YerushalmiDaf>,
+    pub tehillim: ChapterRange,
+    /// Only set on Shabbos afternoons between Pesach and Rosh Hashana.
+    pub pirkei_avos: Option<ChapterRange>,
+}
+
+/// A study calendar that assigns portions to dates of the Jewish year, given as the month, the
+/// day of the month and the portion. A date may have several entries.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct StudySchedule<'a, T> {
+    /// The portions of a year without Adar II.
+    pub entries: &'a [(JewishMonth, u8, T)],
+    /// The portions of a leap year, for calendars that publish one. Without them, leap years use
+    /// `entries`, with the portions of Adar learned in the Adar(s) chosen by `adar_policy`.
+    pub leap_year_entries: Option<&'a [(JewishMonth, u8, T)]>,
+    pub adar_policy: AdarPolicy,
+}
+
+impl<N: AstronomicalCalculatorTrait> JewishCalendar<N> {
+    /// Returns the portions of `schedule` learned today. As with the monthly Tehillim, in a 29
+    /// day month the portions of the 30th are learned on the 29th.
+    pub fn get_scheduled_learning<'a, T>(
+        &self,
+        schedule: &StudySchedule<'a, T>,
+    ) -> impl Iterator<Item = &'a T> + use<'a, T, N> {
+        let month = self.get_jewish_month();
+        let (entries, month) = match (self.is_jewish_leap_year(), schedule.leap_year_entries) {
+            (true, Some(entries)) => (entries, Some(month)),
+            (true, None) => {
+                let month = match (month, schedule.adar_policy) {
+                    (JewishMonth::Adar, AdarPolicy::AdarII) | (JewishMonth::AdarII, AdarPolicy::AdarI) => None,
+                    (JewishMonth::AdarII, _) => Some(JewishMonth::Adar),
+                    (month, _) => Some(month),
+                };
+                (schedule.entries, month)
+            }
+            (false, _) => (schedule.entries, Some(month)),
+        };
+        let day = self.get_jewish_day_of_month();
+        let last_day = if day == 29 && self.get_days_in_jewish_month() == 29 {
+            30
+        } else {
+            day
+        };
+        entries
+            .iter()
+            .filter(move |(entry_month, entry_day, _)| {
+                Some(*entry_month) == month && (day..=last_day).contains(entry_day)
+            })
+            .map(|(_, _, portion)| portion)
+    }
+
+    /// Returns the chapters of Tehillim read today in the monthly cycle. In a 29 day month the
+    /// portions of the 29th and 30th are both read on the 29th.
+    pub fn get_tehillim_monthly(&self) -> ChapterRange {
+        let day = self.get_jewish_day_of_month();
+        let first_chapter = TEHILLIM_MONTHLY_START.get(day as usize - 1).copied().unwrap_or(145);
+        let last_chapter = if day >= self.get_days_in_jewish_month() {
+            150
+        } else {
+            TEHILLIM_MONTHLY_START
+                .get(day as usize)
+                .map_or(150, |next| if *next == first_chapter { *next } else { next - 1 })
+        };
+        ChapterRange {
+            first_chapter,
+            last_chapter,
+        }
+    }
+
+    /// Returns the chapter(s) of Pirkei Avos read this Shabbos afternoon, or `None` if this is not
+    /// a Shabbos between Pesach and Rosh Hashana.
+    ///
+    /// Reading starts on the Shabbos after Pesach, one chapter a week, and ends on the Shabbos
+    /// before Rosh Hashana. Shabbos Shavuos and Shabbos Tisha B'Av are skipped. When the weeks do not
+    /// divide evenly, chapters are combined in the final round so that it ends with chapter 6.
+    pub fn get_pirkei_avos(&self) -> Option<ChapterRange> {
+        if self.get_day_of_week() != Weekday::Sat {
+            return None;
+        }
+        let year = self.get_jewish_year();
+        let borrowed = self.with_borrowed_calculator();
+        let last_day_of_pesach =
+            borrowed.copy_with_hebrew_ymd(year, JewishMonth::Nissan, if self.in_israel { 21 } else { 22 })?;
+        let rosh_hashana = borrowed.copy_with_hebrew_ymd(year + 1, JewishMonth::Tishrei, 1)?;
+        if self.hebrew_date <= last_day_of_pesach.hebrew_date || self.hebrew_date >= rosh_hashana.hebrew_date {
+            return None;
+        }
+
+        let days_to_shabbos = 7 - (last_day_of_pesach.get_day_of_week().num_days_from_sunday() as i64 + 1) % 7;
+        let mut shabbos = last_day_of_pesach.copy_with_days_added(days_to_shabbos)?;
+        let mut weeks: u8 = 0;
+        let mut week = None;
+        while shabbos.hebrew_date < rosh_hashana.hebrew_date {
+            let is_skipped = shabbos.is_shavuos()
+                || (shabbos.get_jewish_month() == JewishMonth::Av && shabbos.get_jewish_day_of_month() == 9);
+            if !is_skipped {
+                if shabbos.hebrew_date == self.hebrew_date {
+                    week = Some(weeks);
+                }
+                weeks += 1;
+            }
+            shabbos = shabbos.copy_with_days_added(7)?;
+        }
+        let week = week?;
+
+        let remainder = weeks % 6;
+        let last_round_start = weeks - remainder;
+        if remainder == 0 || week < last_round_start {
+            let chapter = week % 6 + 1;
+            return Some(ChapterRange {
+                first_chapter: chapter,
+                last_chapter: chapter,
+            });
+        }
+        // Spread the six chapters over the remaining weeks, doubling up at the end.
+        let index = week - last_round_start;
+        let per_week = 6 / remainder;
+        let extra = 6 % remainder;
+        let first_chapter = index * per_week + index.saturating_sub(remainder - extra) + 1;
+        let length = per_week + if index >= remainder - extra { 1 } else { 0 };
+        Some(ChapterRange {
+            first_chapter,
+            last_chapter: first_chapter + length - 1,
+        })
+    }
+
+    /// Returns all supported learning cycles for this date.
+    pub fn get_daily_learning(&self) -> DailyLearning {
+        DailyLearning {
+            daf_yomi_bavli: self.get_daf_yomi_bavli(),
+            daf_yomi_yerushalmi: self.get_daf_yomi_yerushalmi(),
+            tehillim: self.get_tehillim_monthly(),
+            pirkei_avos: self.get_pirkei_avos(),
+        }
+    }
+}
@@ -1,18 +1,80 @@
 #![cfg_attr(not(feature = "std"), no_std)]
+pub mod anniversary;
 pub mod astronomical_calculator;
+pub mod behab;
+pub mod birkas_hachamah;
+#[cfg(feature = "std")]
+pub mod bulk;
+pub mod chanukah;
+pub mod clock_change;
 pub mod constants;
 pub mod daf;
+pub mod day_definition;
+#[cfg(feature = "std")]
+pub mod day_events;
+#[cfg(feature = "names")]
+pub mod digest;
+#[cfg(feature = "std")]
+pub mod discrepancy;
+pub mod duration_format;
+pub mod early_shabbos;
+pub mod elevation_pair;
+#[cfg(feature = "names")]
+pub mod full_date;
+pub mod functions;
 pub mod geolocation;
+pub mod halachic_day;
+#[cfg(feature = "icu-format")]
+pub mod icu_format;
+pub mod iso8601;
 pub mod jewish_calendar;
+pub mod jewish_year;
+mod languages;
+pub mod learning;
+pub mod luach_day;
+pub mod melacha;
+pub mod molad;
+pub mod next_zman;
+#[cfg(feature = "std")]
+pub mod now;
+pub mod omer;
 pub mod parshas;
+pub mod periods;
+pub mod recurrence;
+pub mod shadow;
+pub mod siyum;
+pub mod solar_transit;
+pub mod sun_table;
+#[cfg(feature = "names")]
+pub mod table_header;
 pub mod tefila_rules;
+pub mod teshuva;
 #[cfg(test)]
 pub mod tests;
+pub mod timezone;
+pub mod unix_time;
+pub mod utc_window;
+pub mod week;
+pub mod zenith;
+pub mod zman_alarm;
+pub mod zman_bounds;
+pub mod zman_drift;
+pub mod zman_inputs;
+pub mod zman_interval;
+pub mod zman_precision;
 pub mod zmanim_calendar;
+#[cfg(feature = "serde")]
+pub mod zmanim_day;
+#[cfg(feature = "tokio")]
+pub mod zmanim_stream;
 
 pub mod prelude {
     pub use crate::{
-        astronomical_calculator::*, constants::*, daf::*, geolocation::*, jewish_calendar::*, parshas::*,
-        tefila_rules::*, zmanim_calendar::*,
+        anniversary::*, astronomical_calculator::*, behab::*, birkas_hachamah::*, chanukah::*, clock_change::*,
+        constants::*, daf::*, day_definition::*, duration_format::*, early_shabbos::*, elevation_pair::*,
+        geolocation::*, halachic_day::*, iso8601::*, jewish_calendar::*, learning::*, luach_day::*, next_zman::*,
+        omer::*, parshas::*, periods::*, recurrence::*, siyum::*, solar_transit::*, sun_table::*, tefila_rules::*,
+        teshuva::*, timezone::*, unix_time::*, utc_window::*, week::*, zman_alarm::*, zman_bounds::*, zman_drift::*,
+        zman_inputs::*, zman_interval::*, zman_precision::*, zmanim_calendar::*,
     };
 }
@@ -0,0 +1,144 @@
+//! A day of a luach: the Jewish date together with the zmanim of that date at a location, for
+//! the queries that need both.
+use chrono::{DateTime, TimeZone, Weekday};
+
+use crate::{
+    astronomical_calculator::AstronomicalCalculatorTrait,
+    constants::Zman,
+    geolocation::GeoLocation,
+    jewish_calendar::{JewishCalendar, JewishCalendarTrait},
+    zmanim_calendar::{ZmanimCalendar, ZmanimCalendarTrait},
+};
+
+/// How candles are lit for Shabbos or yom tov.
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub enum CandleLightingKind {
+    /// Before sunset on a weekday.
+    BeforeSunset,
+    /// Before sunset on a yom tov that falls on Friday, from an existing flame, for the Shabbos
+    /// that follows.
+    BeforeShabbosFromExistingFlame,
+    /// After nightfall, from an existing flame, once Shabbos or the first day of yom tov ends
+    /// and another day of yom tov begins. There is no havdalah before.
+    AfterNightfallFromExistingFlame,
+}
+
+/// The candle lighting of one evening.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CandleLightingEvent<Tz: TimeZone> {
+    pub time: DateTime<Tz>,
+    pub kind: CandleLightingKind,
+    /// Which of the consecutive days of Shabbos and yom tov begins this evening, from 1.
+    pub night: u8,
+    /// The number of consecutive days of Shabbos and yom tov, after the last of which is
+    /// havdalah.
+    pub nights: u8,
+}
+
+/// The Jewish date and the zmanim of one civil date.
+#[derive(Debug, Clone, PartialEq)]
+pub struct LuachDay<Tz: TimeZone, N: AstronomicalCalculatorTrait> {
+    pub jewish_calendar: JewishCalendar<N>,
+    pub zmanim_calendar: ZmanimCalendar<Tz, GeoLocation, N>,
+}
+
+impl<Tz: TimeZone, N: AstronomicalCalculatorTrait> LuachDay<Tz, N> {
+    /// Combines `jewish_calendar` with the zmanim of its date, calculated with the location and
+    /// settings of `zmanim_calendar`.
+    pub fn new(
+        jewish_calendar: JewishCalendar<N>,
+        zmanim_calendar: &ZmanimCalendar<Tz, GeoLocation, N>,
+    ) -> Option<Self> {
+        let zmanim_calendar = zmanim_calendar.with_date(jewish_calendar.get_gregorian_naive_date()?)?;
+        Some(Self {
+            jewish_calendar,
+            zmanim_calendar,
+        })
+    }
+
+    /// Returns the time candles are lit for Shabbos or yom tov this evening, or `None` if they
+    /// are not. Candles lit on Shabbos or yom tov for the day that follows are lit after it ends,
+    /// at [`ZmanimCalendar::havdalah_zman`], except on yom tov before Shabbos, when they are lit
+    /// before sunset as on any Erev Shabbos.
+    pub fn get_candle_lighting(&self) -> Option<DateTime<Tz>> {
+        Some(self.get_candle_lighting_event()?.time)
+    }
+
+    /// Returns the candle lighting of this evening, annotated with how the candles are lit and
+    /// where the evening falls in a sequence of Shabbos and yom tov days, such as the second
+    /// night of a yom tov that runs from Thursday until Shabbos. Returns `None` if candles are
+    /// not lit this evening.
+    pub fn get_candle_lighting_event(&self) -> Option<CandleLightingEvent<Tz>> {
+        if !self.jewish_calendar.has_candle_lighting() {
+            return None;
+        }
+        let (kind, time) = match (
+            self.jewish_calendar.is_assur_bemelacha(),
+            self.jewish_calendar.get_day_of_week(),
+        ) {
+            (false, _) => (
+                CandleLightingKind::BeforeSunset,
+                self.zmanim_calendar.get_zman(&Zman::CandleLighting)?,
+            ),
+            (true, Weekday::Fri) => (
+                CandleLightingKind::BeforeShabbosFromExistingFlame,
+                self.zmanim_calendar.get_zman(&Zman::CandleLighting)?,
+            ),
+            (true, _) => (
+                CandleLightingKind::AfterNightfallFromExistingFlame,
+                self.zmanim_calendar.get_havdalah()?,
+            ),
+        };
+        // A sequence is at most three days long, yom tov on Thursday and Friday followed by
+        // Shabbos, or Shabbos followed by two days of yom tov.
+        let days_before = (1..=2)
+            .take_while(|&days| {
+                self.jewish_calendar
+                    .copy_with_days_added(1 - days)
+                    .is_some_and(|day| day.is_assur_bemelacha())
+            })
+            .count() as u8;
+        let days_after = (1..=2)
+            .take_while(|&days| {
+                self.jewish_calendar
+                    .copy_with_days_added(days)
+                    .is_some_and(|day| day.has_candle_lighting())
+            })
+            .count() as u8;
+        Some(CandleLightingEvent {
+            time,
+            kind,
+            night: days_before + 1,
+            nights: days_before + days_after + 1,
+        })
+    }
+
+    /// Returns the end of Shabbos or yom tov at [`ZmanimCalendar::havdalah_zman`], or `None` if
+    /// melacha is permitted today or the day that follows is also Shabbos or yom tov.
+    pub fn get_havdalah(&self) -> Option<DateTime<Tz>> {
+        if !self.jewish_calendar.is_assur_bemelacha() || self.jewish_calendar.has_candle_lighting() {
+            return None;
+        }
+        self.zmanim_calendar.get_havdalah()
+    }
+
+    /// Returns the end of the fast according to the `tzais` shita, or `None` if this is not a
+    /// fast day. Yom Kippur ends at [`ZmanimCalendar::havdalah_zman`] instead.
+    pub fn get_fast_end(&self, tzais: &Zman) -> Option<DateTime<Tz>> {
+        if !self.jewish_calendar.is_taanis() {
+            return None;
+        }
+        match self.jewish_calendar.is_yom_kippur() {
+            true => self.zmanim_calendar.get_havdalah(),
+            false => self.zmanim_calendar.get_zman(tzais),
+        }
+    }
+
+    /// Returns the day of the omer counted this evening and the time from which it is counted,
+    /// nightfall according to the `tzais` shita, or `None` if the omer is not counted tonight.
+    pub fn get_omer_count_at_nightfall(&self, tzais: &Zman) -> Option<(u8, DateTime<Tz>)> {
+        let day = self.jewish_calendar.get_day_of_omer_tomorrow()?;
+        Some((day, self.zmanim_calendar.get_zman(tzais)?))
+    }
+}
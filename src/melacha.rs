@@ -0,0 +1,77 @@
+//! The intervals of time during which melacha is prohibited, for scheduling that must avoid
+//! Shabbos and yom tov rather than only know whether a given day is one.
+use core::ops::Range;
+
+use chrono::{DateTime, Datelike, Duration, TimeZone};
+
+use crate::{
+    astronomical_calculator::AstronomicalCalculatorTrait,
+    constants::Zman,
+    geolocation::GeoLocation,
+    jewish_calendar::{JewishCalendar, JewishCalendarTrait},
+    zmanim_calendar::{ZmanimCalendar, ZmanimCalendarTrait},
+};
+
+/// The longest run of consecutive days of Shabbos and yom tov, yom tov on Thursday and Friday
+/// followed by Shabbos.
+const MAX_CONSECUTIVE_DAYS: i64 = 3;
+
+impl<Tz: TimeZone, N: AstronomicalCalculatorTrait> ZmanimCalendar<Tz, GeoLocation, N> {
+    /// Returns the intervals between `start` and `end` during which melacha is prohibited at the
+    /// location of this calendar, in order. Each Shabbos or yom tov, or run of consecutive days
+    /// of them, begins at `begins` on the evening before, such as [`Zman::CandleLighting`], and
+    /// ends at `ends` on its last day, such as [`ZmanimCalendar::havdalah_zman`]. Intervals are
+    /// clipped to `start` and `end`.
+    ///
+    /// The iterator stops early on a date whose zmanim cannot be calculated.
+    pub fn get_assur_bemelacha_intervals(
+        &self,
+        start: &DateTime<Tz>,
+        end: &DateTime<Tz>,
+        in_israel: bool,
+        begins: Zman,
+        ends: Zman,
+    ) -> impl Iterator<Item = Range<DateTime<Tz>>> + use<Tz, N> {
+        let calendar = self.clone();
+        let (start, end) = (start.clone(), end.clone());
+        let last = end.naive_local().date();
+        // An interval in progress at `start` began up to three days before.
+        let mut date = start
+            .naive_local()
+            .date()
+            .checked_sub_signed(Duration::days(MAX_CONSECUTIVE_DAYS));
+        let mut begin: Option<DateTime<Tz>> = None;
+        core::iter::from_fn(move || {
+            loop {
+                let today = date.filter(|date| *date <= last);
+                let Some(today) = today else {
+                    date = None;
+                    let begin = begin.take()?.max(start.clone());
+                    return (begin < end).then(|| begin..end.clone());
+                };
+                date = today.succ_opt();
+                let jewish_calendar = JewishCalendar::from_gregorian_date(
+                    today.year(),
+                    today.month() as u8,
+                    today.day() as u8,
+                    in_israel,
+                    false,
+                    false,
+                    calendar.get_calculator().clone(),
+                )?;
+                let zmanim_calendar = calendar.with_date(today)?;
+                let assur_bemelacha = jewish_calendar.is_assur_bemelacha();
+                let has_candle_lighting = jewish_calendar.has_candle_lighting();
+                if begin.is_none() && has_candle_lighting && !assur_bemelacha {
+                    begin = Some(zmanim_calendar.get_zman(&begins)?);
+                } else if begin.is_some() && assur_bemelacha && !has_candle_lighting {
+                    let finish = zmanim_calendar.get_zman(&ends)?.min(end.clone());
+                    let begin = begin.take()?.max(start.clone());
+                    if begin < finish {
+                        return Some(begin..finish);
+                    }
+                }
+            }
+        })
+    }
+}
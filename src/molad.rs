@@ -0,0 +1,69 @@
+//! Chalakim arithmetic for the molad (mean lunar conjunction), as used by the calendar.
+//!
+//! An hour is divided into 1080 chalakim, so a chelek is 3⅓ seconds. Chalakim are counted
+//! from molad tohu, the theoretical molad of Tishrei before creation, on the evening that
+//! began Monday, 5 hours and 204 chalakim after 18:00.
+//!
+//! ```
+//! use jew_sdk::{constants::JewishMonth, molad};
+//!
+//! assert_eq!(molad::get_chalakim_since_molad_tohu(1, JewishMonth::Tishrei), molad::CHALAKIM_MOLAD_TOHU);
+//! assert_eq!(molad::get_months_elapsed(2, JewishMonth::Tishrei), 12);
+//! assert_eq!(molad::chalakim_to_seconds(molad::CHALAKIM_PER_HOUR), 3600.0);
+//! ```
+use chrono::Duration;
+
+use crate::{constants::JewishMonth, jewish_year::is_jewish_leap_year};
+
+pub const CHALAKIM_PER_MINUTE: i64 = 18;
+pub const CHALAKIM_PER_HOUR: i64 = 1080;
+pub const CHALAKIM_PER_DAY: i64 = 25920;
+/// The mean length of a lunar month: 29 days, 12 hours and 793 chalakim.
+pub const CHALAKIM_PER_MONTH: i64 = 765433;
+/// The chalakim from the start of the calendar to molad tohu: 1 day, 5 hours and 204 chalakim.
+pub const CHALAKIM_MOLAD_TOHU: i64 = 31524;
+
+/// Converts chalakim to seconds.
+pub fn chalakim_to_seconds(chalakim: i64) -> f64 {
+    chalakim as f64 * 10.0 / 3.0
+}
+
+/// Converts chalakim to minutes.
+pub fn chalakim_to_minutes(chalakim: i64) -> f64 {
+    chalakim as f64 / CHALAKIM_PER_MINUTE as f64
+}
+
+/// Converts chalakim to a duration, exact to the nanosecond.
+pub fn chalakim_to_duration(chalakim: i64) -> Option<Duration> {
+    Some(Duration::nanoseconds(chalakim.checked_mul(10_000_000_000)? / 3))
+}
+
+/// Converts a duration to whole chalakim, rounding towards zero.
+pub fn duration_to_chalakim(duration: Duration) -> Option<i64> {
+    Some(duration.num_milliseconds().checked_mul(3)? / 10_000)
+}
+
+/// Converts minutes to chalakim.
+pub fn minutes_to_chalakim(minutes: i64) -> i64 {
+    minutes * CHALAKIM_PER_MINUTE
+}
+
+/// Returns the number of months from molad tohu to the start of `month` in the Jewish `year`.
+pub fn get_months_elapsed(year: i32, month: JewishMonth) -> i32 {
+    let month_of_year = get_jewish_month_of_year(year, month.into());
+    (235 * ((year - 1) / 19))
+        + (12 * ((year - 1) % 19))
+        + ((7 * ((year - 1) % 19) + 1) / 19)
+        + (month_of_year as i32 - 1)
+}
+
+/// Returns the number of chalakim from the start of the calendar to the molad of `month` in
+/// the Jewish `year`.
+pub fn get_chalakim_since_molad_tohu(year: i32, month: JewishMonth) -> i64 {
+    CHALAKIM_MOLAD_TOHU + (CHALAKIM_PER_MONTH * get_months_elapsed(year, month) as i64)
+}
+
+fn get_jewish_month_of_year(year: i32, month: u8) -> u8 {
+    let is_leap_year = is_jewish_leap_year(year);
+    (month + if is_leap_year { 6 } else { 5 }) % if is_leap_year { 13 } else { 12 } + 1
+}
@@ -0,0 +1,66 @@
+//! The next or previous zman from a given time, searching the following or preceding days when
+//! none of the zmanim remain on the same day, as in the evening after the last zman or during
+//! the polar night.
+use chrono::{DateTime, Duration, NaiveDate, TimeZone};
+
+use crate::{
+    astronomical_calculator::AstronomicalCalculatorTrait,
+    constants::Zman,
+    geolocation::GeoLocation,
+    zmanim_calendar::{ZmanimCalendar, ZmanimCalendarTrait},
+};
+
+/// The number of days to search, after which there is taken to be no next or previous zman.
+const MAX_DAYS: i64 = 366;
+
+impl<Tz: TimeZone, N: AstronomicalCalculatorTrait> ZmanimCalendar<Tz, GeoLocation, N> {
+    /// Returns the first of `zmanim` that occurs after `time`, on any day, calculated with the
+    /// location and settings of this calendar. When several occur at the same time, the first of
+    /// them in `zmanim` is returned. Returns `None` if none of `zmanim` occur within a year.
+    pub fn get_next_zman(&self, time: &DateTime<Tz>, zmanim: &[Zman]) -> Option<(Zman, DateTime<Tz>)> {
+        self.find_zman(time, zmanim, true)
+    }
+
+    /// Returns the last of `zmanim` that occurs before `time`, on any day. See
+    /// [`ZmanimCalendar::get_next_zman`].
+    pub fn get_previous_zman(&self, time: &DateTime<Tz>, zmanim: &[Zman]) -> Option<(Zman, DateTime<Tz>)> {
+        self.find_zman(time, zmanim, false)
+    }
+
+    fn find_zman(&self, time: &DateTime<Tz>, zmanim: &[Zman], next: bool) -> Option<(Zman, DateTime<Tz>)> {
+        let date = time.with_timezone(&self.get_date_time().timezone()).date_naive();
+        let mut best: Option<(Zman, DateTime<Tz>)> = None;
+        // The zmanim of the day before can fall after midnight, and those of the day after before
+        // it, so the search starts a day on the other side of the date of `time`, and does not
+        // stop before that date has been checked.
+        for step in -1..=MAX_DAYS {
+            let day = if next { step } else { -step };
+            if let Some(calendar) = self.with_offset_date(date, day) {
+                for zman in zmanim {
+                    let Some(candidate) = calendar.get_zman(zman) else {
+                        continue;
+                    };
+                    let found = match next {
+                        true => candidate > *time,
+                        false => candidate < *time,
+                    };
+                    let better = best.as_ref().is_none_or(|(_, best)| match next {
+                        true => candidate < *best,
+                        false => candidate > *best,
+                    });
+                    if found && better {
+                        best = Some((*zman, candidate));
+                    }
+                }
+            }
+            if best.is_some() && step >= 0 {
+                break;
+            }
+        }
+        best
+    }
+
+    fn with_offset_date(&self, date: NaiveDate, days: i64) -> Option<Self> {
+        self.with_date(date.checked_add_signed(Duration::days(days))?)
+    }
+}
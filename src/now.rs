@@ -0,0 +1,68 @@
+//! Calendars for the current date, read from the system clock.
+//!
+//! ```
+//! use jew_sdk::{functions::ZmanimSettings, prelude::*};
+//!
+//! let geo_location = GeoLocation::new(31.778, 35.2354, 754.0).unwrap();
+//! let offset = chrono::FixedOffset::east_opt(2 * 3600).unwrap();
+//! let today = JewishCalendar::now_at(&offset, &geo_location, DayBoundary::Sunset, true, NOAACalculator).unwrap();
+//! let zmanim = ZmanimCalendar::now(offset, geo_location, NOAACalculator, &ZmanimSettings::default()).unwrap();
+//! ```
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use chrono::{DateTime, Datelike, TimeZone, Utc};
+
+use crate::{
+    astronomical_calculator::AstronomicalCalculatorTrait,
+    functions::{self, ZmanimSettings},
+    geolocation::GeoLocation,
+    halachic_day::{DayBoundary, HalachicDay},
+    jewish_calendar::JewishCalendar,
+    zmanim_calendar::ZmanimCalendar,
+};
+
+/// Returns the current time in `timezone`, or `None` if the system clock is before 1970.
+pub fn get_now<Tz: TimeZone>(timezone: &Tz) -> Option<DateTime<Tz>> {
+    let since_epoch = SystemTime::now().duration_since(UNIX_EPOCH).ok()?;
+    let now = DateTime::<Utc>::from_timestamp_millis(i64::try_from(since_epoch.as_millis()).ok()?)?;
+    Some(now.with_timezone(timezone))
+}
+
+impl<N: AstronomicalCalculatorTrait> JewishCalendar<N> {
+    /// Returns the calendar of the current civil date in `timezone`. The Jewish date does not
+    /// change in the evening; use [`JewishCalendar::now_at`] for that.
+    pub fn now<Tz: TimeZone>(timezone: &Tz, in_israel: bool, calculator: N) -> Option<Self> {
+        let date = get_now(timezone)?.date_naive();
+        JewishCalendar::from_gregorian_date(
+            date.year(),
+            date.month() as u8,
+            date.day() as u8,
+            in_israel,
+            false,
+            false,
+            calculator,
+        )
+    }
+
+    /// Returns the calendar of the current Jewish date at `geo_location`, which changes to the
+    /// next date at `boundary` in the evening. See [`HalachicDay`].
+    pub fn now_at<Tz: TimeZone>(
+        timezone: &Tz,
+        geo_location: &GeoLocation,
+        boundary: DayBoundary,
+        in_israel: bool,
+        calculator: N,
+    ) -> Option<Self> {
+        let now = get_now(timezone)?;
+        let day = HalachicDay::new(now, geo_location, boundary, in_israel, false, false, calculator)?;
+        Some(day.jewish_calendar)
+    }
+}
+
+impl<Tz: TimeZone, N: AstronomicalCalculatorTrait> ZmanimCalendar<Tz, GeoLocation, N> {
+    /// Returns the zmanim of the current civil date in `timezone` at `geo_location`.
+    pub fn now(timezone: Tz, geo_location: GeoLocation, calculator: N, settings: &ZmanimSettings) -> Option<Self> {
+        let date = get_now(&timezone)?.date_naive();
+        functions::calendar(date, timezone, &geo_location, calculator, settings)
+    }
+}
@@ -0,0 +1,98 @@
+//! Tracking whether the omer is counted with a bracha. Someone who misses a day entirely, neither
+//! at night nor during the following day, continues counting without a bracha. Someone who
+//! forgot at night but counted during the day counts without a bracha that day and continues
+//! with one.
+use chrono::{DateTime, TimeZone};
+
+use crate::{
+    astronomical_calculator::AstronomicalCalculatorTrait,
+    geolocation::GeoLocation,
+    halachic_day::{DayBoundary, HalachicDay},
+    jewish_calendar::JewishCalendarTrait,
+};
+
+/// When a day of the omer was counted.
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub enum OmerCountTime {
+    /// The night the day begins, when the omer is counted with a bracha.
+    Night,
+    /// The following day, after the count was forgotten at night. This is counted without a
+    /// bracha, but keeps the count unbroken.
+    Day,
+}
+
+/// The state of one person's count of the omer.
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash, Default)]
+pub struct OmerCounter {
+    last_counted: Option<u8>,
+    broken: bool,
+}
+
+impl OmerCounter {
+    /// Creates a counter for someone who has not counted yet.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a counter for someone whose last count was of `last_counted` (1-49). `missed`
+    /// is whether any earlier day was missed entirely. Returns `None` if `last_counted` is not
+    /// a day of the omer.
+    pub fn resume(last_counted: u8, missed: bool) -> Option<Self> {
+        if !(1..=49).contains(&last_counted) {
+            return None;
+        }
+        Some(Self {
+            last_counted: Some(last_counted),
+            broken: missed,
+        })
+    }
+
+    /// Returns the last day counted, if any.
+    pub fn get_last_counted(&self) -> Option<u8> {
+        self.last_counted
+    }
+
+    /// Returns true if a day was missed entirely, so that the remaining days are counted without
+    /// a bracha.
+    pub fn is_broken(&self) -> bool {
+        self.broken
+    }
+
+    /// Returns whether counting `day` at night would be with a bracha. The days from the last
+    /// count up to `day` would be missed.
+    pub fn is_bracha_recited(&self, day: u8) -> bool {
+        !self.broken && day == self.get_last_counted().unwrap_or(0) + 1
+    }
+
+    /// Records counting `day` (1-49) at `time`, and returns whether it is counted with a bracha.
+    /// Returns `None`, leaving the counter unchanged, if `day` is not a day of the omer or is
+    /// not after the last day counted.
+    pub fn count(&mut self, day: u8, time: OmerCountTime) -> Option<bool> {
+        if !(1..=49).contains(&day) || self.last_counted.is_some_and(|last| day <= last) {
+            return None;
+        }
+        let bracha = time == OmerCountTime::Night && self.is_bracha_recited(day);
+        if day != self.get_last_counted().unwrap_or(0) + 1 {
+            self.broken = true;
+        }
+        self.last_counted = Some(day);
+        Some(bracha)
+    }
+}
+
+/// Returns the day of the omer to count at `date_time` at `geo_location`: the count of the
+/// coming night once `nightfall` has passed, and the count of the current day before it, which
+/// someone who forgot at night counts without a bracha. Returns `None` outside the omer.
+pub fn get_omer_day_to_count<Tz: TimeZone, N: AstronomicalCalculatorTrait>(
+    date_time: DateTime<Tz>,
+    geo_location: &GeoLocation,
+    nightfall: DayBoundary,
+    in_israel: bool,
+    calculator: N,
+) -> Option<u8> {
+    HalachicDay::new(date_time, geo_location, nightfall, in_israel, false, false, calculator)?
+        .jewish_calendar
+        .get_day_of_omer()
+}
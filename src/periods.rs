@@ -0,0 +1,81 @@
+//! Periods of the year with customs of mourning: the Three Weeks before Tisha B'Av and the days
+//! of the omer.
+use chrono::Weekday;
+
+use crate::{
+    astronomical_calculator::AstronomicalCalculatorTrait,
+    constants::JewishMonth,
+    jewish_calendar::{JewishCalendar, JewishCalendarTrait},
+};
+
+/// The custom for which days of the omer are kept as days of mourning.
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub enum SefirahMinhag {
+    /// From Pesach until Lag BaOmer, the 33rd day of the omer.
+    UntilLagBaOmer,
+    /// From 1 Iyar until the three days of preparation before Shavuos, except for Lag BaOmer.
+    FromRoshChodeshIyar,
+    /// The entire omer until Erev Shavuos, except for Lag BaOmer.
+    EntireSefirah,
+}
+
+impl<N: AstronomicalCalculatorTrait> JewishCalendar<N> {
+    /// Returns true if this date is in the Three Weeks, from the fast of 17 Tammuz through
+    /// Tisha B'Av. When Tisha B'Av falls on Shabbos the period ends with the postponed fast on
+    /// Sunday.
+    pub fn is_bein_hametzarim(&self) -> bool {
+        self.is_mourning_period_before_tisha_beav(JewishMonth::Tammuz, 17)
+    }
+
+    /// Returns true if this date is in the Nine Days, from Rosh Chodesh Av through Tisha B'Av.
+    /// When Tisha B'Av falls on Shabbos the period ends with the postponed fast on Sunday.
+    pub fn is_nine_days(&self) -> bool {
+        self.is_mourning_period_before_tisha_beav(JewishMonth::Av, 1)
+    }
+
+    /// Returns true if this date is in the week in which Tisha B'Av falls, from Sunday until the
+    /// day before the fast. There is no such week when the fast is on Sunday.
+    pub fn is_shavua_shechal_bo(&self) -> bool {
+        let Some(tisha_beav) = self.get_tisha_beav_fast() else {
+            return false;
+        };
+        let days_before = tisha_beav.get_day_of_week().num_days_from_sunday();
+        tisha_beav
+            .copy_with_days_added(-(days_before as i64))
+            .is_some_and(|sunday| sunday.hebrew_date <= self.hebrew_date && self.hebrew_date < tisha_beav.hebrew_date)
+    }
+
+    /// Returns true if this date is a day of mourning during the omer according to `minhag`.
+    pub fn is_sefirah_mourning(&self, minhag: SefirahMinhag) -> bool {
+        let Some(day) = self.get_day_of_omer() else {
+            return false;
+        };
+        match minhag {
+            SefirahMinhag::UntilLagBaOmer => day < 33,
+            SefirahMinhag::FromRoshChodeshIyar => (16..=46).contains(&day) && day != 33,
+            SefirahMinhag::EntireSefirah => day <= 48 && day != 33,
+        }
+    }
+
+    fn is_mourning_period_before_tisha_beav(&self, month: JewishMonth, day: u8) -> bool {
+        let calendar = self.with_borrowed_calculator();
+        let Some(start) = calendar.copy_with_hebrew_ymd(self.get_jewish_year(), month, day) else {
+            return false;
+        };
+        self.get_tisha_beav_fast()
+            .is_some_and(|end| start.hebrew_date <= self.hebrew_date && self.hebrew_date <= end.hebrew_date)
+    }
+
+    /// Returns the day of the fast of Tisha B'Av in this Jewish year, which is postponed to
+    /// Sunday when 9 Av falls on Shabbos.
+    fn get_tisha_beav_fast(&self) -> Option<JewishCalendar<&N>> {
+        let tisha_beav =
+            self.with_borrowed_calculator()
+                .copy_with_hebrew_ymd(self.get_jewish_year(), JewishMonth::Av, 9)?;
+        match tisha_beav.get_day_of_week() {
+            Weekday::Sat => tisha_beav.copy_with_days_added(1),
+            _ => Some(tisha_beav),
+        }
+    }
+}
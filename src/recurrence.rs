@@ -0,0 +1,86 @@
+//! Events that recur on the Jewish calendar, such as reminders for a yahrzeit or a meeting held
+//! every Rosh Chodesh.
+use chrono::{NaiveDate, Weekday};
+
+use crate::{
+    anniversary::AdarPolicy,
+    astronomical_calculator::AstronomicalCalculatorTrait,
+    constants::{JewishHoliday, JewishMonth},
+    jewish_calendar::{JewishCalendar, JewishCalendarTrait},
+};
+
+/// A rule for the days on which an event recurs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Recurrence {
+    /// The same Hebrew date every year, such as 7 Adar. Years in which the month has no such
+    /// day (30 Cheshvan, 30 Kislev, 30 Adar) are skipped.
+    HebrewDate {
+        month: JewishMonth,
+        day: u8,
+        /// Which Adar a date in Adar falls in during a leap year.
+        adar_policy: AdarPolicy,
+    },
+    /// Every day of Rosh Chodesh.
+    RoshChodesh,
+    /// The same day of the week. For an event at night, such as "every Thursday night", this
+    /// is the civil date whose evening it is.
+    Weekly(Weekday),
+    /// Every day of a holiday.
+    Holiday(JewishHoliday),
+    /// The civil date on whose evening the given night of Chanukah (1-8) begins.
+    NightOfChanukah(u8),
+}
+
+impl Recurrence {
+    /// Returns whether the event occurs on `date`.
+    pub fn matches<N: AstronomicalCalculatorTrait>(&self, date: &JewishCalendar<N>) -> bool {
+        match self {
+            Recurrence::HebrewDate {
+                month,
+                day,
+                adar_policy,
+            } => {
+                let (first, second) = observed_months(*month, date.is_jewish_leap_year(), *adar_policy);
+                let date_month = date.get_jewish_month();
+                date.get_jewish_day_of_month() == *day && (date_month == first || Some(date_month) == second)
+            }
+            Recurrence::RoshChodesh => date.is_rosh_chodesh(),
+            Recurrence::Weekly(weekday) => date.get_day_of_week() == *weekday,
+            Recurrence::Holiday(holiday) => date.get_yom_tov_index() == Some(*holiday),
+            Recurrence::NightOfChanukah(night) => date
+                .with_borrowed_calculator()
+                .copy_with_days_added(1)
+                .is_some_and(|tomorrow| tomorrow.get_day_of_chanukah() == Some(*night)),
+        }
+    }
+
+    /// Returns the dates from `start` to the civil date `end` (inclusive) on which the event
+    /// occurs.
+    pub fn occurrences<N: AstronomicalCalculatorTrait>(
+        &self,
+        start: &JewishCalendar<N>,
+        end: NaiveDate,
+    ) -> impl Iterator<Item = JewishCalendar<N>> + use<N> {
+        let recurrence = *self;
+        core::iter::successors(Some(start.clone()), |date| date.copy_with_days_added(1))
+            .take_while(move |date| date.get_gregorian_naive_date().is_some_and(|date| date <= end))
+            .filter(move |date| recurrence.matches(date))
+    }
+}
+
+/// Returns the month(s) in a year on which a date in `month` is observed.
+fn observed_months(
+    month: JewishMonth,
+    is_leap_year: bool,
+    adar_policy: AdarPolicy,
+) -> (JewishMonth, Option<JewishMonth>) {
+    match month {
+        JewishMonth::AdarII if !is_leap_year => (JewishMonth::Adar, None),
+        JewishMonth::Adar if is_leap_year => match adar_policy {
+            AdarPolicy::AdarI => (JewishMonth::Adar, None),
+            AdarPolicy::AdarII => (JewishMonth::AdarII, None),
+            AdarPolicy::Both => (JewishMonth::Adar, Some(JewishMonth::AdarII)),
+        },
+        month => (month, None),
+    }
+}
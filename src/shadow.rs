@@ -0,0 +1,36 @@
+//! The altitude of the sun and the length of shadows, which several of the classical discussions
+//! of zmanim are framed in, such as mincha gedola from when shadows begin to lengthen after
+//! chatzos.
+use chrono::{DateTime, TimeZone};
+#[allow(unused_imports)]
+use core_maths::CoreFloat;
+
+use crate::{
+    astronomical_calculator::AstronomicalCalculatorTrait,
+    geolocation::GeoLocation,
+    zmanim_calendar::{ZmanimCalendar, ZmanimCalendarTrait},
+};
+
+impl<Tz: TimeZone, N: AstronomicalCalculatorTrait> ZmanimCalendar<Tz, GeoLocation, N> {
+    /// Returns the altitude of the sun above the horizon at `date_time` in degrees, negative when
+    /// it is below. No adjustment is made for refraction.
+    pub fn get_sun_altitude(&self, date_time: &DateTime<Tz>) -> f64 {
+        -self.get_degrees_below_horizon(date_time)
+    }
+
+    /// Returns the altitude of the sun in degrees at its highest, when it crosses the meridian.
+    pub fn get_sun_altitude_at_transit(&self) -> Option<f64> {
+        Some(self.get_sun_altitude(&self.get_sun_transit()?))
+    }
+
+    /// Returns the length of the shadow of an upright object on level ground at `date_time`, as
+    /// a multiple of the height of the object. Returns `None` when the sun is not above the
+    /// horizon.
+    pub fn get_shadow_length_ratio(&self, date_time: &DateTime<Tz>) -> Option<f64> {
+        let altitude = self.get_sun_altitude(date_time);
+        if altitude <= 0.0 {
+            return None;
+        }
+        Some(1.0 / altitude.to_radians().tan())
+    }
+}
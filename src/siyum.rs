@@ -0,0 +1,87 @@
+//! The dates on which the Daf Yomi learners complete the current masechta or the whole cycle.
+use chrono::NaiveDate;
+
+use crate::{
+    astronomical_calculator::AstronomicalCalculatorTrait,
+    constants::{BavliTractate, YerushalmiTractate},
+    jewish_calendar::{JewishCalendar, JewishCalendarTrait},
+};
+
+/// The number of days searched for a siyum, which is longer than a cycle of either the Bavli
+/// (2,711 days) or the Yerushalmi (1,554 days, not counting the days it is not learnt).
+const MAX_SIYUM_SEARCH_DAYS: i64 = 3000;
+
+/// The completion of a masechta or a cycle of Daf Yomi.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Siyum<T> {
+    /// The masechta completed. For a cycle this is the last masechta.
+    pub tractate: T,
+    /// The date on which the last daf is learnt.
+    pub date: NaiveDate,
+    /// The number of days from the date the siyum was calculated from, which is 0 if the siyum
+    /// is on that date.
+    pub days_until: i64,
+}
+
+impl<N: AstronomicalCalculatorTrait> JewishCalendar<N> {
+    /// Returns the siyum of the masechta of the Daf Yomi Bavli learnt on this date.
+    pub fn get_daf_yomi_bavli_masechta_siyum(&self) -> Option<Siyum<BavliTractate>> {
+        self.find_siyum(
+            |day| Some(day.get_daf_yomi_bavli()?.tractate),
+            |learnt, next| learnt != next,
+        )
+    }
+
+    /// Returns the siyum of the current cycle of the Daf Yomi Bavli.
+    pub fn get_daf_yomi_bavli_cycle_siyum(&self) -> Option<Siyum<BavliTractate>> {
+        self.find_siyum(
+            |day| Some(day.get_daf_yomi_bavli()?.tractate),
+            |learnt, next| next < learnt,
+        )
+    }
+
+    /// Returns the siyum of the masechta of the Daf Yomi Yerushalmi learnt on this date, or on
+    /// the next day it is learnt if it is not learnt on this date.
+    pub fn get_daf_yomi_yerushalmi_masechta_siyum(&self) -> Option<Siyum<YerushalmiTractate>> {
+        self.find_siyum(
+            |day| Some(day.get_daf_yomi_yerushalmi()?.tractate),
+            |learnt, next| learnt != next,
+        )
+    }
+
+    /// Returns the siyum of the current cycle of the Daf Yomi Yerushalmi.
+    pub fn get_daf_yomi_yerushalmi_cycle_siyum(&self) -> Option<Siyum<YerushalmiTractate>> {
+        self.find_siyum(
+            |day| Some(day.get_daf_yomi_yerushalmi()?.tractate),
+            |learnt, next| next < learnt,
+        )
+    }
+
+    /// Finds the last day learnt before `ends` returns true for the masechta learnt on that day
+    /// and the masechta learnt on the next day that has a daf.
+    fn find_siyum<T: Copy + Ord>(
+        &self,
+        tractate_of: impl Fn(&JewishCalendar<&N>) -> Option<T>,
+        ends: impl Fn(T, T) -> bool,
+    ) -> Option<Siyum<T>> {
+        let calendar = self.with_borrowed_calculator();
+        let start = calendar.get_gregorian_naive_date()?;
+        let mut learnt: Option<(T, JewishCalendar<&N>)> = None;
+        for days in 0..=MAX_SIYUM_SEARCH_DAYS {
+            let day = calendar.copy_with_days_added(days)?;
+            let Some(tractate) = tractate_of(&day) else {
+                continue;
+            };
+            if let Some((last_tractate, last_day)) = learnt.as_ref().filter(|(last, _)| ends(*last, tractate)) {
+                let date = last_day.get_gregorian_naive_date()?;
+                return Some(Siyum {
+                    tractate: *last_tractate,
+                    date,
+                    days_until: (date - start).num_days(),
+                });
+            }
+            learnt = Some((tractate, day));
+        }
+        None
+    }
+}
@@ -0,0 +1,40 @@
+//! The upper and lower transits of the sun. [`ZmanimCalendarTrait::get_solar_midnight`] returns
+//! the lower transit that follows solar noon, in the night after the date of the calendar, while
+//! a night is often thought of as belonging to the day that it precedes. These name the transit
+//! and the night explicitly.
+use chrono::{DateTime, TimeZone};
+
+use crate::{
+    astronomical_calculator::AstronomicalCalculatorTrait,
+    geolocation::GeoLocation,
+    zmanim_calendar::{ZmanimCalendar, ZmanimCalendarTrait},
+};
+
+/// One of the two nights adjoining the date of a calendar.
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub enum Night {
+    /// The night before the date, from sunset on the previous day to sunrise.
+    Preceding,
+    /// The night after the date, from sunset to sunrise on the next day.
+    Following,
+}
+
+impl<Tz: TimeZone, N: AstronomicalCalculatorTrait> ZmanimCalendar<Tz, GeoLocation, N> {
+    /// Returns the upper transit of the sun, when it crosses the meridian at solar noon. This is
+    /// the same as [`ZmanimCalendarTrait::get_sun_transit`].
+    pub fn get_upper_transit(&self) -> Option<DateTime<Tz>> {
+        self.get_sun_transit()
+    }
+
+    /// Returns the lower transit of the sun, solar midnight, in `night`. The lower transit of the
+    /// following night is the same as [`ZmanimCalendarTrait::get_solar_midnight`].
+    pub fn get_lower_transit(&self, night: Night) -> Option<DateTime<Tz>> {
+        match night {
+            Night::Preceding => self
+                .with_date(self.get_date_time().date_naive().pred_opt()?)?
+                .get_solar_midnight(),
+            Night::Following => self.get_solar_midnight(),
+        }
+    }
+}
@@ -0,0 +1,153 @@
+//! A precomputed table of sunrise and sunset for every day of a year at one location, for
+//! microcontrollers too slow to run the solar calculations on demand. The table is generated
+//! ahead of time, such as at build time or on a companion device, and zmanim based on sunrise
+//! and sunset are then interpolated from it.
+use chrono::{DateTime, Datelike, Duration, FixedOffset, NaiveDate, NaiveTime, TimeZone};
+
+use crate::{
+    astronomical_calculator::AstronomicalCalculatorTrait,
+    constants::Zman,
+    geolocation::GeoLocation,
+    zmanim_calendar::{ZmanimCalendar, ZmanimCalendarTrait},
+};
+
+/// Marks a day on which the sun does not rise or set.
+pub const NO_EVENT: u16 = u16::MAX;
+
+/// Sunrise and sunset on one day, in minutes after midnight at the start of the day, or
+/// [`NO_EVENT`].
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub struct SunTableDay {
+    pub sunrise: u16,
+    pub sunset: u16,
+}
+
+impl SunTableDay {
+    const EMPTY: Self = Self {
+        sunrise: NO_EVENT,
+        sunset: NO_EVENT,
+    };
+}
+
+/// Sunrise and sunset for every day of a Gregorian year, in a fixed UTC offset. Times are rounded
+/// to the minute, so zmanim read from the table may differ from calculated ones by a minute.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct SunTable {
+    /// The offset the minutes of the table are counted in. Adjusting for daylight saving time is
+    /// left to the caller.
+    pub offset: FixedOffset,
+    pub year: i32,
+    /// One entry per day from January 1, the last of which is unused outside leap years.
+    pub days: [SunTableDay; 366],
+}
+
+impl SunTable {
+    /// Calculates the table for `year` with the location and settings of `calendar`, including
+    /// whether sunrise and sunset are adjusted for elevation. Returns `None` if the year is out
+    /// of range.
+    pub fn generate<N: AstronomicalCalculatorTrait>(
+        calendar: &ZmanimCalendar<FixedOffset, GeoLocation, N>,
+        year: i32,
+    ) -> Option<Self> {
+        let offset = calendar.get_date_time().timezone();
+        let mut days = [SunTableDay::EMPTY; 366];
+        let mut date = NaiveDate::from_ymd_opt(year, 1, 1)?;
+        let mut calendar = calendar.with_date(date)?;
+        for day in days.iter_mut() {
+            if date.year() != year {
+                break;
+            }
+            calendar.set_date(date)?;
+            let minutes = |time: Option<DateTime<FixedOffset>>| {
+                time.and_then(|time| {
+                    let seconds = (time - calendar.get_date_time()).num_seconds();
+                    u16::try_from((seconds + 30).div_euclid(60)).ok()
+                })
+                .filter(|minutes| *minutes != NO_EVENT)
+                .unwrap_or(NO_EVENT)
+            };
+            *day = SunTableDay {
+                sunrise: minutes(calendar.get_elevation_adjusted_sunrise()),
+                sunset: minutes(calendar.get_elevation_adjusted_sunset()),
+            };
+            date = date.succ_opt()?;
+        }
+        Some(Self { offset, year, days })
+    }
+
+    /// Returns the entry for `date`, or `None` if it is not in the year of the table.
+    pub fn get_day(&self, date: NaiveDate) -> Option<SunTableDay> {
+        if date.year() != self.year {
+            return None;
+        }
+        self.days.get(date.ordinal0() as usize).copied()
+    }
+
+    /// Returns sunrise on `date`.
+    pub fn get_sunrise(&self, date: NaiveDate) -> Option<DateTime<FixedOffset>> {
+        self.get_time(date, self.get_day(date)?.sunrise)
+    }
+
+    /// Returns sunset on `date`.
+    pub fn get_sunset(&self, date: NaiveDate) -> Option<DateTime<FixedOffset>> {
+        self.get_time(date, self.get_day(date)?.sunset)
+    }
+
+    /// Returns `zman` on `date`, interpolated between sunrise and sunset, with chatzos as the
+    /// midpoint of the day and the Magen Avraham's day from 72 minutes before sunrise to 72
+    /// minutes after sunset. Returns `None` for zmanim that depend on the depth of the sun
+    /// below the horizon, on an offset in shaos zmaniyos or on a setting of the calendar, and for
+    /// candle lighting, which uses sea level sunset.
+    pub fn get_zman(&self, date: NaiveDate, zman: &Zman) -> Option<DateTime<FixedOffset>> {
+        let sunrise = self.get_sunrise(date)?;
+        let sunset = self.get_sunset(date)?;
+        let mga = Duration::minutes(72);
+        // Shaos zmaniyos are counted in quarters so that plag hamincha is whole.
+        let at = |start: DateTime<FixedOffset>, end: DateTime<FixedOffset>, quarters: i32| {
+            Some(start + (end - start) * quarters / 48)
+        };
+        match zman {
+            Zman::Chatzos | Zman::ChatzosAsHalfDay => at(sunrise, sunset, 24),
+            Zman::SofZmanShmaGRA => at(sunrise, sunset, 12),
+            Zman::SofZmanTfilaGRA => at(sunrise, sunset, 16),
+            Zman::MinchaGedola => at(sunrise, sunset, 26),
+            Zman::MinchaKetana => at(sunrise, sunset, 38),
+            Zman::PlagHamincha => at(sunrise, sunset, 43),
+            Zman::SofZmanShmaMGA => at(sunrise - mga, sunset + mga, 12),
+            Zman::SofZmanTfilaMGA => at(sunrise - mga, sunset + mga, 16),
+            Zman::Alos72 => Some(sunrise - mga),
+            Zman::Tzais72 => Some(sunset + mga),
+            Zman::Alos120 => Some(sunrise - Duration::minutes(120)),
+            Zman::Alos60 => Some(sunrise - Duration::minutes(60)),
+            Zman::Alos90 => Some(sunrise - Duration::minutes(90)),
+            Zman::Alos96 => Some(sunrise - Duration::minutes(96)),
+            Zman::MinchaKetana72Minutes => at(sunrise - mga, sunset + mga, 38),
+            Zman::SofZmanShmaMGA120Minutes => at(sunrise - Duration::minutes(120), sunset + Duration::minutes(120), 12),
+            Zman::SofZmanShmaMGA90Minutes => at(sunrise - Duration::minutes(90), sunset + Duration::minutes(90), 12),
+            Zman::SofZmanShmaMGA96Minutes => at(sunrise - Duration::minutes(96), sunset + Duration::minutes(96), 12),
+            Zman::SofZmanTfilaMGA120Minutes => {
+                at(sunrise - Duration::minutes(120), sunset + Duration::minutes(120), 16)
+            }
+            Zman::SofZmanTfilaMGA90Minutes => at(sunrise - Duration::minutes(90), sunset + Duration::minutes(90), 16),
+            Zman::SofZmanTfilaMGA96Minutes => at(sunrise - Duration::minutes(96), sunset + Duration::minutes(96), 16),
+            Zman::Tzais120 => Some(sunset + Duration::minutes(120)),
+            Zman::Tzais50 => Some(sunset + Duration::minutes(50)),
+            Zman::Tzais60 => Some(sunset + Duration::minutes(60)),
+            Zman::Tzais90 => Some(sunset + Duration::minutes(90)),
+            Zman::Tzais96 => Some(sunset + Duration::minutes(96)),
+            _ => None,
+        }
+    }
+
+    fn get_time(&self, date: NaiveDate, minutes: u16) -> Option<DateTime<FixedOffset>> {
+        if minutes == NO_EVENT {
+            return None;
+        }
+        let midnight = self
+            .offset
+            .from_local_datetime(&date.and_time(NaiveTime::MIN))
+            .single()?;
+        Some(midnight + Duration::minutes(minutes.into()))
+    }
+}
@@ -0,0 +1,124 @@
+//! Column headers for tables of zmanim, in English, Hebrew or Yiddish, so that an exported table
+//! can be printed as is. The English names can follow the Ashkenazi transliteration of the rest of
+//! the crate, "Sof Zman Shma", or the Sephardi one, "Sof Zman Shema". A header row reads:
+//!
+//! ```text
+//! Date,Alos Hashachar,Sof Zman Shma GRA,Tzais
+//! ```
+//!
+//! which is also the layout [`crate::zmanim_calendar::ZmanimCalendar::compare_with_table`] reads.
+use core::fmt;
+
+use crate::constants::Zman;
+
+/// The words that are transliterated differently in the Sephardi scheme, and how.
+const SEPHARDI_WORDS: [(&str, &str); 8] = [
+    ("Alos", "Alot"),
+    ("Bain", "Ben"),
+    ("Chatzos", "Chatzot"),
+    ("Hashmashos", "Hashemashot"),
+    ("Shma", "Shema"),
+    ("Tfila", "Tefila"),
+    ("Tzais", "Tzeit"),
+    ("Zmaniyos", "Zmaniyot"),
+];
+
+/// The language of the headers of a table.
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default, Hash)]
+pub enum HeaderLanguage {
+    /// "Sof Zman Shma GRA"
+    #[default]
+    English,
+    /// "סוף זמן שמע גר״א"
+    Hebrew,
+    /// The Hebrew names, with the Yiddish "דאַטע" for the date.
+    Yiddish,
+}
+
+/// How the English names are transliterated.
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default, Hash)]
+pub enum TransliterationScheme {
+    /// "Tzais", "Chatzos", as in [`Zman::en_string`].
+    #[default]
+    Ashkenazi,
+    /// "Tzeit", "Chatzot".
+    Sephardi,
+}
+
+/// The language and transliteration of the headers of a table. The default is English with the
+/// Ashkenazi transliteration.
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default, Hash)]
+pub struct HeaderOptions {
+    pub language: HeaderLanguage,
+    /// Used only for [`HeaderLanguage::English`].
+    pub scheme: TransliterationScheme,
+}
+
+/// Displays the header of the column of one zman. See [`Zman::header`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ZmanHeader {
+    zman: Zman,
+    options: HeaderOptions,
+}
+
+impl fmt::Display for ZmanHeader {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match (self.options.language, self.options.scheme) {
+            (HeaderLanguage::Hebrew, _) => write!(f, "{}", self.zman.he_string()),
+            (HeaderLanguage::Yiddish, _) => write!(f, "{}", self.zman.yi_string()),
+            (HeaderLanguage::English, TransliterationScheme::Ashkenazi) => write!(f, "{}", self.zman.en_string()),
+            (HeaderLanguage::English, TransliterationScheme::Sephardi) => {
+                for (index, word) in self.zman.en_string().split(' ').enumerate() {
+                    if index > 0 {
+                        write!(f, " ")?;
+                    }
+                    let word = SEPHARDI_WORDS
+                        .iter()
+                        .find(|(ashkenazi, _)| *ashkenazi == word)
+                        .map_or(word, |(_, sephardi)| sephardi);
+                    write!(f, "{}", word)?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+impl Zman {
+    /// Returns the header of a column of this zman in a table.
+    pub fn header(&self, options: HeaderOptions) -> ZmanHeader {
+        ZmanHeader { zman: *self, options }
+    }
+}
+
+/// Displays the header row of a CSV table whose first column is the date, followed by a column
+/// for each of the zmanim. See [`table_header`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TableHeader<'a> {
+    columns: &'a [Zman],
+    options: HeaderOptions,
+}
+
+impl fmt::Display for TableHeader<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let date = match self.options.language {
+            HeaderLanguage::English => "Date",
+            HeaderLanguage::Hebrew => "תאריך",
+            HeaderLanguage::Yiddish => "דאַטע",
+        };
+        write!(f, "{}", date)?;
+        for zman in self.columns {
+            // None of the names contain a comma or a quote, so they are never quoted.
+            write!(f, ",{}", zman.header(self.options))?;
+        }
+        Ok(())
+    }
+}
+
+/// Returns the header row of a CSV table of `columns`, without a line ending.
+pub fn table_header(columns: &[Zman], options: HeaderOptions) -> TableHeader<'_> {
+    TableHeader { columns, options }
+}
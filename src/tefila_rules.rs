@@ -314,3 +314,50 @@ impl<N: AstronomicalCalculatorTrait> TefilaRulesTrait<JewishCalendar<N>> for Tef
         jewish_calendar.is_morid_hatal_recited()
     }
 }
+
+/// How a community observes Yom Kippur Katan.
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct YomKippurKatanPractice {
+    /// Whether the community fasts, rather than only reciting the tefillos.
+    pub fast: bool,
+    /// Whether the community holds the special mincha with selichos.
+    pub mincha_with_selichos: bool,
+}
+
+impl Default for YomKippurKatanPractice {
+    fn default() -> Self {
+        Self {
+            fast: true,
+            mincha_with_selichos: true,
+        }
+    }
+}
+
+/// What is observed on a Yom Kippur Katan.
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct YomKippurKatanObservance {
+    pub fast: bool,
+    /// Whether the mincha with selichos is held. Selichos are not said on a day that tachanun
+    /// is not recited.
+    pub mincha_with_selichos: bool,
+}
+
+impl TefilaRules {
+    /// Returns what is observed on `jewish_calendar` if it is Yom Kippur Katan, according to
+    /// the community's `practice`.
+    pub fn get_yom_kippur_katan_observance<N: AstronomicalCalculatorTrait>(
+        &self,
+        jewish_calendar: &JewishCalendar<N>,
+        practice: &YomKippurKatanPractice,
+    ) -> Option<YomKippurKatanObservance> {
+        if !jewish_calendar.is_yom_kippur_katan() {
+            return None;
+        }
+        Some(YomKippurKatanObservance {
+            fast: practice.fast,
+            mincha_with_selichos: practice.mincha_with_selichos && self.is_tachanun_recited_shacharis(jewish_calendar),
+        })
+    }
+}
@@ -0,0 +1,52 @@
+//! The days of the Aseres Yemei Teshuva, and the selichos recited before them.
+use chrono::Weekday;
+
+use crate::{
+    astronomical_calculator::AstronomicalCalculatorTrait,
+    constants::{JewishHoliday, JewishMonth, Parsha},
+    jewish_calendar::{JewishCalendar, JewishCalendarTrait},
+};
+
+impl<N: AstronomicalCalculatorTrait> JewishCalendar<N> {
+    /// Returns the date of Tzom Gedalyah in this Jewish year. The fast is on 3 Tishrei, unless
+    /// that falls on Shabbos, in which case it is postponed to Sunday.
+    pub fn get_tzom_gedalyah_date(&self) -> Option<Self> {
+        let third = self.copy_with_hebrew_ymd(self.get_jewish_year(), JewishMonth::Tishrei, 3)?;
+        match third.get_day_of_week() {
+            Weekday::Sat => third.copy_with_days_added(1),
+            _ => Some(third),
+        }
+    }
+
+    /// Returns true if this date is the observed fast of Tzom Gedalyah.
+    pub fn is_tzom_gedalyah(&self) -> bool {
+        self.get_yom_tov_index() == Some(JewishHoliday::FastOfGedalyah)
+    }
+
+    /// Returns true if this date is Shabbos Shuva, the Shabbos of the Aseres Yemei Teshuva.
+    pub fn is_shabbos_shuva(&self) -> bool {
+        self.get_special_shabbos() == Some(Parsha::Shuva)
+    }
+
+    /// Returns true if this date is Erev Yom Kippur, on which kaparos are done, tachanun is not
+    /// said, and the fast begins at sunset after the seudah hamafsekes.
+    pub fn is_erev_yom_kippur(&self) -> bool {
+        self.get_yom_tov_index() == Some(JewishHoliday::ErevYomKippur)
+    }
+
+    /// Returns the Shabbos in Elul of this Jewish year on whose night Ashkenazim begin selichos,
+    /// before Rosh Hashana of the following year. Selichos begin on the Motzai Shabbos before
+    /// Rosh Hashana, or a week earlier if that would leave fewer than four days of selichos.
+    pub fn get_selichos_start_date(&self) -> Option<Self> {
+        let rosh_hashana = self.copy_with_hebrew_ymd(self.get_jewish_year() + 1, JewishMonth::Tishrei, 1)?;
+        let days_before = match rosh_hashana.get_day_of_week() {
+            // The Motzai Shabbos before a Monday or Tuesday Rosh Hashana is too close.
+            Weekday::Mon => 9,
+            Weekday::Tue => 10,
+            Weekday::Thu => 5,
+            // Rosh Hashana is never on Sunday, Wednesday or Friday.
+            _ => 7,
+        };
+        rosh_hashana.copy_with_days_added(-days_before)
+    }
+}
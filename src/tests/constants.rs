@@ -0,0 +1,175 @@
+use crate::prelude::*;
+
+#[test]
+#[cfg(feature = "std")]
+fn test_parsha_sequence_navigation() {
+    let sequence: Vec<Parsha> = Parsha::standard_sequence().collect();
+    assert_eq!(sequence.len(), 54);
+    for pair in sequence.windows(2) {
+        assert_eq!(pair[0].next(), Some(pair[1]));
+        assert_eq!(pair[1].prev(), Some(pair[0]));
+    }
+    assert_eq!(Parsha::VezosHabracha.next(), Some(Parsha::Bereshis));
+    assert_eq!(Parsha::Bereshis.prev(), Some(Parsha::VezosHabracha));
+    assert_eq!(Parsha::VayakhelPekudei.next(), Some(Parsha::Vayikra));
+    assert_eq!(Parsha::VayakhelPekudei.prev(), Some(Parsha::KiSisa));
+    assert_eq!(Parsha::Zachor.next(), None);
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn test_jewish_month_ranges() {
+    for is_leap_year in [false, true] {
+        let months: Vec<JewishMonth> = JewishMonth::months_of_year(is_leap_year).collect();
+        assert_eq!(months.len(), if is_leap_year { 13 } else { 12 });
+        for (index, month) in months.iter().enumerate() {
+            assert_eq!(month.position_in_year(is_leap_year), Some(index as u8 + 1));
+        }
+        let reversed: Vec<JewishMonth> = JewishMonth::months_of_year(is_leap_year).rev().collect();
+        assert_eq!(reversed, months.iter().rev().copied().collect::<Vec<_>>());
+    }
+    assert_eq!(
+        JewishMonth::range(JewishMonth::Tishrei, JewishMonth::Tishrei, true).count(),
+        0
+    );
+    assert_eq!(
+        JewishMonth::range(JewishMonth::Nissan, JewishMonth::Tishrei, true).count(),
+        0
+    );
+    assert_eq!(
+        JewishMonth::range_inclusive(JewishMonth::AdarII, JewishMonth::Nissan, false).count(),
+        0
+    );
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn test_holiday_classification() {
+    let holidays: Vec<JewishHoliday> = (0..=37u8).filter_map(|i| JewishHoliday::try_from(i).ok()).collect();
+    assert_eq!(holidays.len(), 38);
+    let yom_tov = holidays.iter().filter(|h| h.is_yom_tov_assur_bemelacha()).count();
+    assert_eq!(yom_tov, 7);
+    assert!(JewishHoliday::YomKippur.is_fast_day());
+    assert!(!JewishHoliday::YomKippur.is_rabbinic());
+    assert!(JewishHoliday::TishahBav.is_fast_day() && JewishHoliday::TishahBav.is_rabbinic());
+    assert!(JewishHoliday::Chanukah.is_rabbinic());
+    assert!(JewishHoliday::Behab.is_fast_day() && JewishHoliday::Behab.is_custom());
+    assert!(!JewishHoliday::YomKippurKatan.is_rabbinic());
+    assert!(!JewishHoliday::RoshChodesh.is_rabbinic() && !JewishHoliday::RoshChodesh.is_custom());
+    for holiday in holidays {
+        assert_eq!(holiday.is_modern(), holiday.category() == HolidayCategory::Modern);
+    }
+}
+
+#[test]
+fn test_zman_from_str() {
+    for zman in Zman::values() {
+        assert_eq!(zman.as_str().parse(), Ok(zman));
+    }
+    assert_eq!(
+        "sof_zman_shma_mga_16_1".parse(),
+        Ok(Zman::SofZmanShmaMGA16Point1Degrees)
+    );
+    assert_eq!("SofZmanShmaMGA".parse::<Zman>(), Err(UnknownZman));
+    assert_eq!("".parse::<Zman>(), Err(UnknownZman));
+}
+
+#[test]
+#[cfg(feature = "serde")]
+fn test_zman_serde() {
+    for zman in Zman::values() {
+        let json = serde_json::to_value(zman).unwrap();
+        assert_eq!(json, zman.as_str());
+        assert_eq!(serde_json::from_value::<Zman>(json).unwrap(), zman);
+    }
+    assert!(serde_json::from_str::<Zman>("\"SofZmanShmaMGA\"").is_err());
+}
+
+#[test]
+#[cfg(all(feature = "std", feature = "names"))]
+fn test_zman_metadata() {
+    let zmanim = Zman::values();
+    let unique: std::collections::HashSet<_> = zmanim.iter().collect();
+    assert_eq!(unique.len(), zmanim.len());
+    let keys: std::collections::HashSet<_> = zmanim.iter().map(|zman| zman.as_str()).collect();
+    assert_eq!(keys.len(), zmanim.len());
+    for zman in zmanim {
+        assert!(
+            zman.as_str()
+                .bytes()
+                .all(|b| b.is_ascii_lowercase() || b.is_ascii_digit() || b == b'_')
+        );
+        assert!(!zman.en_string().is_empty() && !zman.he_string().is_empty());
+        assert!(zman.opinion().ends_with('.'), "zman: {:?}", zman);
+    }
+    assert_eq!(Zman::SofZmanShmaMGA16Point1Degrees.as_str(), "sof_zman_shma_mga_16_1");
+    assert_eq!(Zman::TzaisGeonim3Point7Degrees.en_string(), "Tzais Geonim 3.7°");
+    assert_eq!(Zman::Alos72Zmanis.he_string(), "עלות השחר 72 דקות זמניות");
+}
+
+#[test]
+#[cfg(feature = "names")]
+fn test_yiddish_strings() {
+    for holiday in (0..=37u8).filter_map(|i| JewishHoliday::try_from(i).ok()) {
+        assert!(!holiday.yi_string().is_empty());
+    }
+    assert_eq!(JewishMonth::Cheshvan.yi_string(false), "מרחשון");
+    assert_eq!(JewishMonth::Adar.yi_string(true), "אדר ראשון");
+    assert_eq!(Parsha::Bereshis.yi_string(), Parsha::Bereshis.he_string());
+    assert_eq!(chrono::Weekday::Fri.yi_string(), "פרייטיק");
+    assert_eq!(chrono::Weekday::Sat.he_string(), "שבת");
+}
+
+#[test]
+#[cfg(all(feature = "lang-es", feature = "lang-fr", feature = "lang-ru"))]
+fn test_language_packs() {
+    assert_eq!(JewishHoliday::Pesach.fr_string(), "Pessah");
+    assert_eq!(JewishHoliday::Pesach.es_string(), "Pésaj");
+    assert_eq!(JewishHoliday::Pesach.ru_string(), "Песах");
+    assert_eq!(JewishMonth::Adar.ru_string(true), "Адар I");
+    assert_eq!(JewishMonth::Adar.fr_string(false), "Adar");
+    for zman in Zman::values() {
+        assert!(!zman.es_string().is_empty());
+    }
+}
+
+#[test]
+#[cfg(all(feature = "std", feature = "names"))]
+fn test_table_header() {
+    use crate::table_header::{HeaderLanguage, HeaderOptions, TransliterationScheme, table_header};
+
+    let columns = [Zman::AlosHashachar, Zman::SofZmanShmaGRA, Zman::Tzais];
+    assert_eq!(
+        table_header(&columns, HeaderOptions::default()).to_string(),
+        "Date,Alos Hashachar,Sof Zman Shma GRA,Tzais"
+    );
+    let sephardi = HeaderOptions {
+        language: HeaderLanguage::English,
+        scheme: TransliterationScheme::Sephardi,
+    };
+    assert_eq!(
+        table_header(&columns, sephardi).to_string(),
+        "Date,Alot Hashachar,Sof Zman Shema GRA,Tzeit"
+    );
+    assert_eq!(
+        Zman::BainHashmashosRT13Point24Degrees.header(sephardi).to_string(),
+        "Ben Hashemashot Rabbeinu Tam 13.24°"
+    );
+    let hebrew = HeaderOptions {
+        language: HeaderLanguage::Hebrew,
+        ..HeaderOptions::default()
+    };
+    assert_eq!(
+        table_header(&columns[1..2], hebrew).to_string(),
+        format!("תאריך,{}", Zman::SofZmanShmaGRA.he_string())
+    );
+    let yiddish = HeaderOptions {
+        language: HeaderLanguage::Yiddish,
+        ..HeaderOptions::default()
+    };
+    assert!(table_header(&columns, yiddish).to_string().starts_with("דאַטע,"));
+    for zman in Zman::values() {
+        assert!(!zman.header(sephardi).to_string().contains(','));
+        assert!(!zman.header(hebrew).to_string().contains(','));
+    }
+}
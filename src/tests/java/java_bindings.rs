@@ -14,7 +14,7 @@ use chrono::{DateTime, TimeZone, Weekday};
 use chrono::{Duration, Utc};
 use j4rs::{Instance, InvocationArg, Jvm, Null};
 
-use crate::{astronomical_calculator::NOAACalculator, constants::_SolarEvent, zmanim_calendar::ZmanimCalendarTrait};
+use crate::{astronomical_calculator::NOAACalculator, constants::SolarEvent, zmanim_calendar::ZmanimCalendarTrait};
 pub struct JavaGeoLocation<'a> {
     pub jvm: &'a Jvm,
     pub instance: Instance,
@@ -407,6 +407,7 @@ impl<'a, Tz: TimeZone> JavaZmanimCalendar<'a, Tz> {
         use_astronomical_chatzos: bool,
         use_astronomical_chatzos_for_other_zmanim: bool,
         ateret_torah_sunset_offset: Duration,
+        use_elevation: bool,
     ) -> Self {
         let java_geolocation = geolocation_to_java_geolocation(jvm, &geo_location, timezone_id).unwrap();
         let java_date_time = dt_to_java_calendar(jvm, &date_time, timezone_id).unwrap();
@@ -426,7 +427,10 @@ impl<'a, Tz: TimeZone> JavaZmanimCalendar<'a, Tz> {
         jvm.invoke(
             &java_zmanim_calendar,
             "setUseElevation",
-            &[InvocationArg::try_from(true).unwrap().into_primitive().unwrap()],
+            &[InvocationArg::try_from(use_elevation)
+                .unwrap()
+                .into_primitive()
+                .unwrap()],
         )
         .unwrap();
         jvm.invoke(
@@ -501,301 +505,1242 @@ impl<'a, Tz: TimeZone> ZmanimCalendarTrait<Tz, GeoLocation, NOAACalculator> for
         todo!()
     }
     // These methods are not used in the tests, but we need to implement them for the trait.
-    fn get_date_from_time(&self, _calculated_time: f64, _solar_event: _SolarEvent) -> Option<DateTime<Tz>> {
+    fn get_date_from_time(&self, _calculated_time: f64, _solar_event: SolarEvent) -> Option<DateTime<Tz>> {
+        todo!()
+    }
+
+    fn get_sunrise(&self) -> Option<DateTime<Tz>> {
+        let java_result = self
+            .jvm
+            .invoke(&self.instance, "getSunrise", InvocationArg::empty())
+            .ok()?;
+        self.java_date_to_rust_datetime(&java_result)
+    }
+
+    fn get_sea_level_sunrise(&self) -> Option<DateTime<Tz>> {
+        let java_result = self
+            .jvm
+            .invoke(&self.instance, "getSeaLevelSunrise", InvocationArg::empty())
+            .ok()?;
+
+        self.java_date_to_rust_datetime(&java_result)
+    }
+
+    fn get_begin_civil_twilight(&self) -> Option<DateTime<Tz>> {
+        let java_result = self
+            .jvm
+            .invoke(&self.instance, "getBeginCivilTwilight", InvocationArg::empty())
+            .ok()?;
+        self.java_date_to_rust_datetime(&java_result)
+    }
+
+    fn get_begin_nautical_twilight(&self) -> Option<DateTime<Tz>> {
+        let java_result = self
+            .jvm
+            .invoke(&self.instance, "getBeginNauticalTwilight", InvocationArg::empty())
+            .ok()?;
+        self.java_date_to_rust_datetime(&java_result)
+    }
+
+    fn get_begin_astronomical_twilight(&self) -> Option<DateTime<Tz>> {
+        let java_result = self
+            .jvm
+            .invoke(&self.instance, "getBeginAstronomicalTwilight", InvocationArg::empty())
+            .ok()?;
+        self.java_date_to_rust_datetime(&java_result)
+    }
+
+    fn get_sunset(&self) -> Option<DateTime<Tz>> {
+        let java_result = self
+            .jvm
+            .invoke(&self.instance, "getSunset", InvocationArg::empty())
+            .ok()?;
+
+        self.java_date_to_rust_datetime(&java_result)
+    }
+
+    fn get_sea_level_sunset(&self) -> Option<DateTime<Tz>> {
+        let java_result = self
+            .jvm
+            .invoke(&self.instance, "getSeaLevelSunset", InvocationArg::empty())
+            .ok()?;
+        self.java_date_to_rust_datetime(&java_result)
+    }
+
+    fn get_end_civil_twilight(&self) -> Option<DateTime<Tz>> {
+        let java_result = self
+            .jvm
+            .invoke(&self.instance, "getEndCivilTwilight", InvocationArg::empty())
+            .ok()?;
+        self.java_date_to_rust_datetime(&java_result)
+    }
+
+    fn get_end_nautical_twilight(&self) -> Option<DateTime<Tz>> {
+        let java_result = self
+            .jvm
+            .invoke(&self.instance, "getEndNauticalTwilight", InvocationArg::empty())
+            .ok()?;
+        self.java_date_to_rust_datetime(&java_result)
+    }
+
+    fn get_end_astronomical_twilight(&self) -> Option<DateTime<Tz>> {
+        let java_result = self
+            .jvm
+            .invoke(&self.instance, "getEndAstronomicalTwilight", InvocationArg::empty())
+            .ok()?;
+        self.java_date_to_rust_datetime(&java_result)
+    }
+
+    fn get_sunrise_offset_by_degrees(&self, offset_zenith: f64) -> Option<DateTime<Tz>> {
+        let java_result = self
+            .jvm
+            .invoke(
+                &self.instance,
+                "getSunriseOffsetByDegrees",
+                &[InvocationArg::try_from(offset_zenith)
+                    .unwrap()
+                    .into_primitive()
+                    .unwrap()],
+            )
+            .ok()?;
+        self.java_date_to_rust_datetime(&java_result)
+    }
+
+    fn get_sunset_offset_by_degrees(&self, offset_zenith: f64) -> Option<DateTime<Tz>> {
+        let java_result = self
+            .jvm
+            .invoke(
+                &self.instance,
+                "getSunsetOffsetByDegrees",
+                &[InvocationArg::try_from(offset_zenith)
+                    .unwrap()
+                    .into_primitive()
+                    .unwrap()],
+            )
+            .ok()?;
+        self.java_date_to_rust_datetime(&java_result)
+    }
+
+    fn get_utc_sunrise(&self, zenith: f64) -> Option<f64> {
+        let java_result = self
+            .jvm
+            .invoke(
+                &self.instance,
+                "getUTCSunrise",
+                &[InvocationArg::try_from(zenith).unwrap().into_primitive().unwrap()],
+            )
+            .ok()?;
+        let result = self.jvm.to_rust::<f64>(java_result).ok()?;
+        if result.is_nan() { None } else { Some(result) }
+    }
+
+    // These methods are not used in the tests, but we need to implement them for the trait.
+    fn get_elevation_adjusted_sunrise(&self) -> Option<DateTime<Tz>> {
+        todo!()
+    }
+    // These methods are not used in the tests, but we need to implement them for the trait.
+    fn get_elevation_adjusted_sunset(&self) -> Option<DateTime<Tz>> {
+        todo!()
+    }
+    // These methods are not used in the tests, but we need to implement them for the trait.
+    fn get_degrees_below_horizon(&self, _date_time: &DateTime<Tz>) -> f64 {
+        todo!()
+    }
+    // These methods are not used in the tests, but we need to implement them for the trait.
+    fn get_utc_noon(&self) -> Option<f64> {
+        todo!()
+    }
+    // These methods are not used in the tests, but we need to implement them for the trait.
+    fn get_utc_midnight(&self) -> Option<f64> {
         todo!()
     }
 
-    fn get_sunrise(&self) -> Option<DateTime<Tz>> {
+    fn get_utc_sea_level_sunrise(&self, zenith: f64) -> Option<f64> {
+        let java_result = self
+            .jvm
+            .invoke(
+                &self.instance,
+                "getUTCSeaLevelSunrise",
+                &[InvocationArg::try_from(zenith).unwrap().into_primitive().unwrap()],
+            )
+            .ok()?;
+        let result = self.jvm.to_rust::<f64>(java_result).ok()?;
+        if result.is_nan() { None } else { Some(result) }
+    }
+
+    fn get_utc_sunset(&self, zenith: f64) -> Option<f64> {
+        let java_result = self
+            .jvm
+            .invoke(
+                &self.instance,
+                "getUTCSunset",
+                &[InvocationArg::try_from(zenith).unwrap().into_primitive().unwrap()],
+            )
+            .ok()?;
+        let result = self.jvm.to_rust::<f64>(java_result).ok()?;
+        if result.is_nan() { None } else { Some(result) }
+    }
+
+    fn get_utc_sea_level_sunset(&self, zenith: f64) -> Option<f64> {
+        let java_result = self
+            .jvm
+            .invoke(
+                &self.instance,
+                "getUTCSeaLevelSunset",
+                &[InvocationArg::try_from(zenith).unwrap().into_primitive().unwrap()],
+            )
+            .ok()?;
+        let result = self.jvm.to_rust::<f64>(java_result).ok()?;
+        if result.is_nan() { None } else { Some(result) }
+    }
+
+    fn get_temporal_hour(&self) -> Option<Duration> {
+        let java_result = self
+            .jvm
+            .invoke(&self.instance, "getTemporalHour", InvocationArg::empty())
+            .ok()?;
+        let millis = self.jvm.to_rust::<i64>(java_result).ok()?;
+        // DIFF: Java returns Long.MIN_VALUE (-9223372036854775808) to indicate null/None
+        if millis == -9223372036854775808i64 {
+            None
+        } else {
+            Some(Duration::milliseconds(millis))
+        }
+    }
+
+    fn get_temporal_hour_from_times(&self, start_of_day: &DateTime<Tz>, end_of_day: &DateTime<Tz>) -> Option<Duration> {
+        let java_start = self
+            .jvm
+            .create_instance(
+                "java.util.Date",
+                &[InvocationArg::try_from(start_of_day.timestamp_millis())
+                    .unwrap()
+                    .into_primitive()
+                    .unwrap()],
+            )
+            .ok()?;
+        let java_end = self
+            .jvm
+            .create_instance(
+                "java.util.Date",
+                &[InvocationArg::try_from(end_of_day.timestamp_millis())
+                    .unwrap()
+                    .into_primitive()
+                    .unwrap()],
+            )
+            .ok()?;
+        let java_result = self
+            .jvm
+            .invoke(
+                &self.instance,
+                "getTemporalHour",
+                &[InvocationArg::from(java_start), InvocationArg::from(java_end)],
+            )
+            .ok()?;
+        let millis = self.jvm.to_rust::<i64>(java_result).ok()?;
+        // DIFF: Java returns Long.MIN_VALUE (-9223372036854775808) to indicate null/None
+        if millis == -9223372036854775808i64 {
+            None
+        } else {
+            Some(Duration::milliseconds(millis))
+        }
+    }
+
+    fn get_sun_transit(&self) -> Option<DateTime<Tz>> {
+        let java_result = self
+            .jvm
+            .invoke(&self.instance, "getSunTransit", InvocationArg::empty())
+            .ok()?;
+        self.java_date_to_rust_datetime(&java_result)
+    }
+
+    fn get_solar_midnight(&self) -> Option<DateTime<Tz>> {
+        let java_result = self
+            .jvm
+            .invoke(&self.instance, "getSolarMidnight", InvocationArg::empty())
+            .ok()?;
+        self.java_date_to_rust_datetime(&java_result)
+    }
+
+    fn get_sun_transit_from_times(
+        &self,
+        start_of_day: &DateTime<Tz>,
+        end_of_day: &DateTime<Tz>,
+    ) -> Option<DateTime<Tz>> {
+        let java_start = dt_to_java_date(self.jvm, start_of_day);
+        let java_end = dt_to_java_date(self.jvm, end_of_day);
+        let java_result = self
+            .jvm
+            .invoke(
+                &self.instance,
+                "getSunTransit",
+                &[InvocationArg::from(java_start), InvocationArg::from(java_end)],
+            )
+            .ok()?;
+        self.java_date_to_rust_datetime(&java_result)
+    }
+
+    fn get_local_mean_time(&self, hours: f64) -> Option<DateTime<Tz>> {
+        let java_result = self
+            .jvm
+            .invoke(
+                &self.instance,
+                "getLocalMeanTime",
+                &[InvocationArg::try_from(hours).unwrap().into_primitive().unwrap()],
+            )
+            .ok()?;
+        self.java_date_to_rust_datetime(&java_result)
+    }
+    fn get_percent_of_shaah_zmanis_from_degrees(&self, degrees: f64, sunset: bool) -> Option<f64> {
+        let java_result = self
+            .jvm
+            .invoke(
+                &self.instance,
+                "getPercentOfShaahZmanisFromDegrees",
+                &[
+                    InvocationArg::try_from(degrees).unwrap().into_primitive().unwrap(),
+                    InvocationArg::try_from(sunset).unwrap().into_primitive().unwrap(),
+                ],
+            )
+            .ok()?;
+        let result = self.jvm.to_rust::<f64>(java_result).ok()?;
+        if result == 5e-324 { None } else { Some(result) }
+    }
+
+    fn get_shaah_zmanis_gra(&self) -> Option<Duration> {
+        let java_result = self
+            .jvm
+            .invoke(&self.instance, "getShaahZmanisGra", InvocationArg::empty())
+            .ok()?;
+        self.get_java_duration_millis(java_result).map(Duration::milliseconds)
+    }
+
+    fn get_shaah_zmanis_mga(&self) -> Option<Duration> {
+        let java_result = self
+            .jvm
+            .invoke(&self.instance, "getShaahZmanisMGA", InvocationArg::empty())
+            .ok()?;
+        self.get_java_duration_millis(java_result).map(Duration::milliseconds)
+    }
+
+    fn get_shaah_zmanis_16_point_1_degrees(&self) -> Option<Duration> {
+        let java_result = self
+            .jvm
+            .invoke(&self.instance, "getShaahZmanis16Point1Degrees", InvocationArg::empty())
+            .ok()?;
+        self.get_java_duration_millis(java_result).map(Duration::milliseconds)
+    }
+
+    fn get_shaah_zmanis_18_degrees(&self) -> Option<Duration> {
+        let java_result = self
+            .jvm
+            .invoke(&self.instance, "getShaahZmanis18Degrees", InvocationArg::empty())
+            .ok()?;
+        self.get_java_duration_millis(java_result).map(Duration::milliseconds)
+    }
+
+    fn get_shaah_zmanis_19_point_8_degrees(&self) -> Option<Duration> {
+        let java_result = self
+            .jvm
+            .invoke(&self.instance, "getShaahZmanis19Point8Degrees", InvocationArg::empty())
+            .ok()?;
+        self.get_java_duration_millis(java_result).map(Duration::milliseconds)
+    }
+
+    fn get_shaah_zmanis_26_degrees(&self) -> Option<Duration> {
+        let java_result = self
+            .jvm
+            .invoke(&self.instance, "getShaahZmanis26Degrees", InvocationArg::empty())
+            .ok()?;
+        self.get_java_duration_millis(java_result).map(Duration::milliseconds)
+    }
+
+    fn get_shaah_zmanis_60_minutes(&self) -> Option<Duration> {
+        let java_result = self
+            .jvm
+            .invoke(&self.instance, "getShaahZmanis60Minutes", InvocationArg::empty())
+            .ok()?;
+        self.get_java_duration_millis(java_result).map(Duration::milliseconds)
+    }
+
+    fn get_shaah_zmanis_72_minutes(&self) -> Option<Duration> {
+        let java_result = self
+            .jvm
+            .invoke(&self.instance, "getShaahZmanis72Minutes", InvocationArg::empty())
+            .ok()?;
+        self.get_java_duration_millis(java_result).map(Duration::milliseconds)
+    }
+
+    fn get_shaah_zmanis_72_minutes_zmanis(&self) -> Option<Duration> {
+        let java_result = self
+            .jvm
+            .invoke(&self.instance, "getShaahZmanis72MinutesZmanis", InvocationArg::empty())
+            .ok()?;
+        self.get_java_duration_millis(java_result).map(Duration::milliseconds)
+    }
+
+    fn get_shaah_zmanis_90_minutes(&self) -> Option<Duration> {
+        let java_result = self
+            .jvm
+            .invoke(&self.instance, "getShaahZmanis90Minutes", InvocationArg::empty())
+            .ok()?;
+        self.get_java_duration_millis(java_result).map(Duration::milliseconds)
+    }
+
+    fn get_shaah_zmanis_90_minutes_zmanis(&self) -> Option<Duration> {
+        let java_result = self
+            .jvm
+            .invoke(&self.instance, "getShaahZmanis90MinutesZmanis", InvocationArg::empty())
+            .ok()?;
+        self.get_java_duration_millis(java_result).map(Duration::milliseconds)
+    }
+
+    fn get_shaah_zmanis_96_minutes(&self) -> Option<Duration> {
+        let java_result = self
+            .jvm
+            .invoke(&self.instance, "getShaahZmanis96Minutes", InvocationArg::empty())
+            .ok()?;
+        self.get_java_duration_millis(java_result).map(Duration::milliseconds)
+    }
+
+    fn get_shaah_zmanis_96_minutes_zmanis(&self) -> Option<Duration> {
+        let java_result = self
+            .jvm
+            .invoke(&self.instance, "getShaahZmanis96MinutesZmanis", InvocationArg::empty())
+            .ok()?;
+        self.get_java_duration_millis(java_result).map(Duration::milliseconds)
+    }
+
+    fn get_shaah_zmanis_120_minutes(&self) -> Option<Duration> {
+        let java_result = self
+            .jvm
+            .invoke(&self.instance, "getShaahZmanis120Minutes", InvocationArg::empty())
+            .ok()?;
+        self.get_java_duration_millis(java_result).map(Duration::milliseconds)
+    }
+
+    fn get_shaah_zmanis_120_minutes_zmanis(&self) -> Option<Duration> {
+        let java_result = self
+            .jvm
+            .invoke(&self.instance, "getShaahZmanis120MinutesZmanis", InvocationArg::empty())
+            .ok()?;
+        self.get_java_duration_millis(java_result).map(Duration::milliseconds)
+    }
+
+    fn get_shaah_zmanis_ateret_torah(&self) -> Option<Duration> {
+        let java_result = self
+            .jvm
+            .invoke(&self.instance, "getShaahZmanisAteretTorah", InvocationArg::empty())
+            .ok()?;
+        self.get_java_duration_millis(java_result).map(Duration::milliseconds)
+    }
+
+    fn get_shaah_zmanis_alos_16_point_1_to_tzais_3_point_7(&self) -> Option<Duration> {
+        let java_result = self
+            .jvm
+            .invoke(
+                &self.instance,
+                "getShaahZmanisAlos16Point1ToTzais3Point7",
+                InvocationArg::empty(),
+            )
+            .ok()?;
+        self.get_java_duration_millis(java_result).map(Duration::milliseconds)
+    }
+
+    fn get_shaah_zmanis_alos_16_point_1_to_tzais_3_point_8(&self) -> Option<Duration> {
+        let java_result = self
+            .jvm
+            .invoke(
+                &self.instance,
+                "getShaahZmanisAlos16Point1ToTzais3Point8",
+                InvocationArg::empty(),
+            )
+            .ok()?;
+        self.get_java_duration_millis(java_result).map(Duration::milliseconds)
+    }
+
+    fn get_zmanis_based_offset(&self, hours: f64) -> Option<DateTime<Tz>> {
+        let java_result = self
+            .jvm
+            .invoke(
+                &self.instance,
+                "getZmanisBasedOffset",
+                &[InvocationArg::try_from(hours).unwrap().into_primitive().unwrap()],
+            )
+            .ok()?;
+        self.java_date_to_rust_datetime(&java_result)
+    }
+
+    fn get_alos_16_point_1_degrees(&self) -> Option<DateTime<Tz>> {
+        let java_result = self
+            .jvm
+            .invoke(&self.instance, "getAlos16Point1Degrees", InvocationArg::empty())
+            .ok()?;
+        self.java_date_to_rust_datetime(&java_result)
+    }
+
+    fn get_alos_18_degrees(&self) -> Option<DateTime<Tz>> {
+        let java_result = self
+            .jvm
+            .invoke(&self.instance, "getAlos18Degrees", InvocationArg::empty())
+            .ok()?;
+        self.java_date_to_rust_datetime(&java_result)
+    }
+
+    fn get_alos_19_degrees(&self) -> Option<DateTime<Tz>> {
+        let java_result = self
+            .jvm
+            .invoke(&self.instance, "getAlos19Degrees", InvocationArg::empty())
+            .ok()?;
+        self.java_date_to_rust_datetime(&java_result)
+    }
+
+    fn get_alos_19_point_8_degrees(&self) -> Option<DateTime<Tz>> {
+        let java_result = self
+            .jvm
+            .invoke(&self.instance, "getAlos19Point8Degrees", InvocationArg::empty())
+            .ok()?;
+        self.java_date_to_rust_datetime(&java_result)
+    }
+
+    fn get_alos_26_degrees(&self) -> Option<DateTime<Tz>> {
+        let java_result = self
+            .jvm
+            .invoke(&self.instance, "getAlos26Degrees", InvocationArg::empty())
+            .ok()?;
+        self.java_date_to_rust_datetime(&java_result)
+    }
+
+    fn get_alos_60(&self) -> Option<DateTime<Tz>> {
+        let java_result = self
+            .jvm
+            .invoke(&self.instance, "getAlos60", InvocationArg::empty())
+            .ok()?;
+        self.java_date_to_rust_datetime(&java_result)
+    }
+
+    fn get_alos_72(&self) -> Option<DateTime<Tz>> {
+        let java_result = self
+            .jvm
+            .invoke(&self.instance, "getAlos72", InvocationArg::empty())
+            .ok()?;
+        self.java_date_to_rust_datetime(&java_result)
+    }
+
+    fn get_alos_90(&self) -> Option<DateTime<Tz>> {
+        let java_result = self
+            .jvm
+            .invoke(&self.instance, "getAlos90", InvocationArg::empty())
+            .ok()?;
+        self.java_date_to_rust_datetime(&java_result)
+    }
+
+    fn get_alos_96(&self) -> Option<DateTime<Tz>> {
+        let java_result = self
+            .jvm
+            .invoke(&self.instance, "getAlos96", InvocationArg::empty())
+            .ok()?;
+        self.java_date_to_rust_datetime(&java_result)
+    }
+
+    fn get_alos_120(&self) -> Option<DateTime<Tz>> {
+        let java_result = self
+            .jvm
+            .invoke(&self.instance, "getAlos120", InvocationArg::empty())
+            .ok()?;
+        self.java_date_to_rust_datetime(&java_result)
+    }
+
+    fn get_alos_72_zmanis(&self) -> Option<DateTime<Tz>> {
+        let java_result = self
+            .jvm
+            .invoke(&self.instance, "getAlos72Zmanis", InvocationArg::empty())
+            .ok()?;
+        self.java_date_to_rust_datetime(&java_result)
+    }
+
+    fn get_alos_90_zmanis(&self) -> Option<DateTime<Tz>> {
+        let java_result = self
+            .jvm
+            .invoke(&self.instance, "getAlos90Zmanis", InvocationArg::empty())
+            .ok()?;
+        self.java_date_to_rust_datetime(&java_result)
+    }
+
+    fn get_alos_96_zmanis(&self) -> Option<DateTime<Tz>> {
+        let java_result = self
+            .jvm
+            .invoke(&self.instance, "getAlos96Zmanis", InvocationArg::empty())
+            .ok()?;
+        self.java_date_to_rust_datetime(&java_result)
+    }
+
+    fn get_alos_120_zmanis(&self) -> Option<DateTime<Tz>> {
+        let java_result = self
+            .jvm
+            .invoke(&self.instance, "getAlos120Zmanis", InvocationArg::empty())
+            .ok()?;
+        self.java_date_to_rust_datetime(&java_result)
+    }
+
+    fn get_tzais_16_point_1_degrees(&self) -> Option<DateTime<Tz>> {
+        let java_result = self
+            .jvm
+            .invoke(&self.instance, "getTzais16Point1Degrees", InvocationArg::empty())
+            .ok()?;
+        self.java_date_to_rust_datetime(&java_result)
+    }
+
+    fn get_tzais_18_degrees(&self) -> Option<DateTime<Tz>> {
+        let java_result = self
+            .jvm
+            .invoke(&self.instance, "getTzais18Degrees", InvocationArg::empty())
+            .ok()?;
+        self.java_date_to_rust_datetime(&java_result)
+    }
+
+    fn get_tzais_19_point_8_degrees(&self) -> Option<DateTime<Tz>> {
+        let java_result = self
+            .jvm
+            .invoke(&self.instance, "getTzais19Point8Degrees", InvocationArg::empty())
+            .ok()?;
+        self.java_date_to_rust_datetime(&java_result)
+    }
+
+    fn get_tzais_26_degrees(&self) -> Option<DateTime<Tz>> {
+        let java_result = self
+            .jvm
+            .invoke(&self.instance, "getTzais26Degrees", InvocationArg::empty())
+            .ok()?;
+        self.java_date_to_rust_datetime(&java_result)
+    }
+
+    fn get_tzais_50(&self) -> Option<DateTime<Tz>> {
+        let java_result = self
+            .jvm
+            .invoke(&self.instance, "getTzais50", InvocationArg::empty())
+            .ok()?;
+        self.java_date_to_rust_datetime(&java_result)
+    }
+
+    fn get_tzais_60(&self) -> Option<DateTime<Tz>> {
+        let java_result = self
+            .jvm
+            .invoke(&self.instance, "getTzais60", InvocationArg::empty())
+            .ok()?;
+        self.java_date_to_rust_datetime(&java_result)
+    }
+
+    fn get_tzais_72(&self) -> Option<DateTime<Tz>> {
+        let java_result = self
+            .jvm
+            .invoke(&self.instance, "getTzais72", InvocationArg::empty())
+            .ok()?;
+        self.java_date_to_rust_datetime(&java_result)
+    }
+
+    fn get_tzais_90(&self) -> Option<DateTime<Tz>> {
+        let java_result = self
+            .jvm
+            .invoke(&self.instance, "getTzais90", InvocationArg::empty())
+            .ok()?;
+        self.java_date_to_rust_datetime(&java_result)
+    }
+
+    fn get_tzais_96(&self) -> Option<DateTime<Tz>> {
+        let java_result = self
+            .jvm
+            .invoke(&self.instance, "getTzais96", InvocationArg::empty())
+            .ok()?;
+        self.java_date_to_rust_datetime(&java_result)
+    }
+
+    fn get_tzais_120(&self) -> Option<DateTime<Tz>> {
+        let java_result = self
+            .jvm
+            .invoke(&self.instance, "getTzais120", InvocationArg::empty())
+            .ok()?;
+        self.java_date_to_rust_datetime(&java_result)
+    }
+
+    fn get_tzais_72_zmanis(&self) -> Option<DateTime<Tz>> {
+        let java_result = self
+            .jvm
+            .invoke(&self.instance, "getTzais72Zmanis", InvocationArg::empty())
+            .ok()?;
+        self.java_date_to_rust_datetime(&java_result)
+    }
+
+    fn get_tzais_90_zmanis(&self) -> Option<DateTime<Tz>> {
+        let java_result = self
+            .jvm
+            .invoke(&self.instance, "getTzais90Zmanis", InvocationArg::empty())
+            .ok()?;
+        self.java_date_to_rust_datetime(&java_result)
+    }
+
+    fn get_tzais_96_zmanis(&self) -> Option<DateTime<Tz>> {
+        let java_result = self
+            .jvm
+            .invoke(&self.instance, "getTzais96Zmanis", InvocationArg::empty())
+            .ok()?;
+        self.java_date_to_rust_datetime(&java_result)
+    }
+
+    fn get_tzais_120_zmanis(&self) -> Option<DateTime<Tz>> {
+        let java_result = self
+            .jvm
+            .invoke(&self.instance, "getTzais120Zmanis", InvocationArg::empty())
+            .ok()?;
+        self.java_date_to_rust_datetime(&java_result)
+    }
+
+    fn get_sof_zman_shma_mga_16_point_1_degrees(&self) -> Option<DateTime<Tz>> {
+        let java_result = self
+            .jvm
+            .invoke(
+                &self.instance,
+                "getSofZmanShmaMGA16Point1Degrees",
+                InvocationArg::empty(),
+            )
+            .ok()?;
+        self.java_date_to_rust_datetime(&java_result)
+    }
+
+    fn get_sof_zman_shma_mga_18_degrees(&self) -> Option<DateTime<Tz>> {
+        let java_result = self
+            .jvm
+            .invoke(&self.instance, "getSofZmanShmaMGA18Degrees", InvocationArg::empty())
+            .ok()?;
+        self.java_date_to_rust_datetime(&java_result)
+    }
+
+    fn get_sof_zman_shma_mga_19_point_8_degrees(&self) -> Option<DateTime<Tz>> {
+        let java_result = self
+            .jvm
+            .invoke(
+                &self.instance,
+                "getSofZmanShmaMGA19Point8Degrees",
+                InvocationArg::empty(),
+            )
+            .ok()?;
+        self.java_date_to_rust_datetime(&java_result)
+    }
+
+    fn get_sof_zman_shma_mga_72_minutes(&self) -> Option<DateTime<Tz>> {
+        let java_result = self
+            .jvm
+            .invoke(&self.instance, "getSofZmanShmaMGA72Minutes", InvocationArg::empty())
+            .ok()?;
+        self.java_date_to_rust_datetime(&java_result)
+    }
+
+    fn get_sof_zman_shma_mga_72_minutes_zmanis(&self) -> Option<DateTime<Tz>> {
+        let java_result = self
+            .jvm
+            .invoke(
+                &self.instance,
+                "getSofZmanShmaMGA72MinutesZmanis",
+                InvocationArg::empty(),
+            )
+            .ok()?;
+        self.java_date_to_rust_datetime(&java_result)
+    }
+
+    fn get_sof_zman_shma_mga_90_minutes(&self) -> Option<DateTime<Tz>> {
+        let java_result = self
+            .jvm
+            .invoke(&self.instance, "getSofZmanShmaMGA90Minutes", InvocationArg::empty())
+            .ok()?;
+        self.java_date_to_rust_datetime(&java_result)
+    }
+
+    fn get_sof_zman_shma_mga_90_minutes_zmanis(&self) -> Option<DateTime<Tz>> {
+        let java_result = self
+            .jvm
+            .invoke(
+                &self.instance,
+                "getSofZmanShmaMGA90MinutesZmanis",
+                InvocationArg::empty(),
+            )
+            .ok()?;
+        self.java_date_to_rust_datetime(&java_result)
+    }
+
+    fn get_sof_zman_shma_mga_96_minutes(&self) -> Option<DateTime<Tz>> {
+        let java_result = self
+            .jvm
+            .invoke(&self.instance, "getSofZmanShmaMGA96Minutes", InvocationArg::empty())
+            .ok()?;
+        self.java_date_to_rust_datetime(&java_result)
+    }
+
+    fn get_sof_zman_shma_mga_96_minutes_zmanis(&self) -> Option<DateTime<Tz>> {
+        let java_result = self
+            .jvm
+            .invoke(
+                &self.instance,
+                "getSofZmanShmaMGA96MinutesZmanis",
+                InvocationArg::empty(),
+            )
+            .ok()?;
+        self.java_date_to_rust_datetime(&java_result)
+    }
+
+    fn get_sof_zman_shma_mga_120_minutes(&self) -> Option<DateTime<Tz>> {
+        let java_result = self
+            .jvm
+            .invoke(&self.instance, "getSofZmanShmaMGA120Minutes", InvocationArg::empty())
+            .ok()?;
+        self.java_date_to_rust_datetime(&java_result)
+    }
+
+    fn get_sof_zman_tfila_mga_16_point_1_degrees(&self) -> Option<DateTime<Tz>> {
+        let java_result = self
+            .jvm
+            .invoke(
+                &self.instance,
+                "getSofZmanTfilaMGA16Point1Degrees",
+                InvocationArg::empty(),
+            )
+            .ok()?;
+        self.java_date_to_rust_datetime(&java_result)
+    }
+
+    fn get_sof_zman_tfila_mga_18_degrees(&self) -> Option<DateTime<Tz>> {
+        let java_result = self
+            .jvm
+            .invoke(&self.instance, "getSofZmanTfilaMGA18Degrees", InvocationArg::empty())
+            .ok()?;
+        self.java_date_to_rust_datetime(&java_result)
+    }
+
+    fn get_sof_zman_tfila_mga_19_point_8_degrees(&self) -> Option<DateTime<Tz>> {
+        let java_result = self
+            .jvm
+            .invoke(
+                &self.instance,
+                "getSofZmanTfilaMGA19Point8Degrees",
+                InvocationArg::empty(),
+            )
+            .ok()?;
+        self.java_date_to_rust_datetime(&java_result)
+    }
+
+    fn get_sof_zman_tfila_mga_72_minutes(&self) -> Option<DateTime<Tz>> {
+        let java_result = self
+            .jvm
+            .invoke(&self.instance, "getSofZmanTfilaMGA72Minutes", InvocationArg::empty())
+            .ok()?;
+        self.java_date_to_rust_datetime(&java_result)
+    }
+
+    fn get_sof_zman_tfila_mga_72_minutes_zmanis(&self) -> Option<DateTime<Tz>> {
+        let java_result = self
+            .jvm
+            .invoke(
+                &self.instance,
+                "getSofZmanTfilaMGA72MinutesZmanis",
+                InvocationArg::empty(),
+            )
+            .ok()?;
+        self.java_date_to_rust_datetime(&java_result)
+    }
+
+    fn get_sof_zman_tfila_mga_90_minutes(&self) -> Option<DateTime<Tz>> {
+        let java_result = self
+            .jvm
+            .invoke(&self.instance, "getSofZmanTfilaMGA90Minutes", InvocationArg::empty())
+            .ok()?;
+        self.java_date_to_rust_datetime(&java_result)
+    }
+
+    fn get_sof_zman_tfila_mga_90_minutes_zmanis(&self) -> Option<DateTime<Tz>> {
+        let java_result = self
+            .jvm
+            .invoke(
+                &self.instance,
+                "getSofZmanTfilaMGA90MinutesZmanis",
+                InvocationArg::empty(),
+            )
+            .ok()?;
+        self.java_date_to_rust_datetime(&java_result)
+    }
+
+    fn get_sof_zman_tfila_mga_96_minutes(&self) -> Option<DateTime<Tz>> {
+        let java_result = self
+            .jvm
+            .invoke(&self.instance, "getSofZmanTfilaMGA96Minutes", InvocationArg::empty())
+            .ok()?;
+        self.java_date_to_rust_datetime(&java_result)
+    }
+
+    fn get_sof_zman_tfila_mga_96_minutes_zmanis(&self) -> Option<DateTime<Tz>> {
+        let java_result = self
+            .jvm
+            .invoke(
+                &self.instance,
+                "getSofZmanTfilaMGA96MinutesZmanis",
+                InvocationArg::empty(),
+            )
+            .ok()?;
+        self.java_date_to_rust_datetime(&java_result)
+    }
+
+    fn get_sof_zman_tfila_mga_120_minutes(&self) -> Option<DateTime<Tz>> {
         let java_result = self
             .jvm
-            .invoke(&self.instance, "getSunrise", InvocationArg::empty())
+            .invoke(&self.instance, "getSofZmanTfilaMGA120Minutes", InvocationArg::empty())
             .ok()?;
         self.java_date_to_rust_datetime(&java_result)
     }
 
-    fn get_sea_level_sunrise(&self) -> Option<DateTime<Tz>> {
+    fn get_sof_zman_tfila_2_hours_before_chatzos(&self) -> Option<DateTime<Tz>> {
         let java_result = self
             .jvm
-            .invoke(&self.instance, "getSeaLevelSunrise", InvocationArg::empty())
+            .invoke(
+                &self.instance,
+                "getSofZmanTfila2HoursBeforeChatzos",
+                InvocationArg::empty(),
+            )
             .ok()?;
+        self.java_date_to_rust_datetime(&java_result)
+    }
 
+    fn get_mincha_gedola_30_minutes(&self) -> Option<DateTime<Tz>> {
+        let java_result = self
+            .jvm
+            .invoke(&self.instance, "getMinchaGedola30Minutes", InvocationArg::empty())
+            .ok()?;
         self.java_date_to_rust_datetime(&java_result)
     }
 
-    fn get_begin_civil_twilight(&self) -> Option<DateTime<Tz>> {
+    fn get_mincha_gedola_16_point_1_degrees(&self) -> Option<DateTime<Tz>> {
         let java_result = self
             .jvm
-            .invoke(&self.instance, "getBeginCivilTwilight", InvocationArg::empty())
+            .invoke(&self.instance, "getMinchaGedola16Point1Degrees", InvocationArg::empty())
             .ok()?;
         self.java_date_to_rust_datetime(&java_result)
     }
 
-    fn get_begin_nautical_twilight(&self) -> Option<DateTime<Tz>> {
+    fn get_mincha_gedola_greater_than_30(&self) -> Option<DateTime<Tz>> {
         let java_result = self
             .jvm
-            .invoke(&self.instance, "getBeginNauticalTwilight", InvocationArg::empty())
+            .invoke(&self.instance, "getMinchaGedolaGreaterThan30", InvocationArg::empty())
             .ok()?;
         self.java_date_to_rust_datetime(&java_result)
     }
 
-    fn get_begin_astronomical_twilight(&self) -> Option<DateTime<Tz>> {
+    fn get_mincha_ketana_16_point_1_degrees(&self) -> Option<DateTime<Tz>> {
         let java_result = self
             .jvm
-            .invoke(&self.instance, "getBeginAstronomicalTwilight", InvocationArg::empty())
+            .invoke(&self.instance, "getMinchaKetana16Point1Degrees", InvocationArg::empty())
             .ok()?;
         self.java_date_to_rust_datetime(&java_result)
     }
 
-    fn get_sunset(&self) -> Option<DateTime<Tz>> {
+    fn get_mincha_ketana_72_minutes(&self) -> Option<DateTime<Tz>> {
         let java_result = self
             .jvm
-            .invoke(&self.instance, "getSunset", InvocationArg::empty())
+            .invoke(&self.instance, "getMinchaKetana72Minutes", InvocationArg::empty())
             .ok()?;
+        self.java_date_to_rust_datetime(&java_result)
+    }
 
+    fn get_mincha_ketana_gra_fixed_local_chatzos_to_sunset(&self) -> Option<DateTime<Tz>> {
+        let java_result = self
+            .jvm
+            .invoke(
+                &self.instance,
+                "getMinchaKetanaGRAFixedLocalChatzosToSunset",
+                InvocationArg::empty(),
+            )
+            .ok()?;
         self.java_date_to_rust_datetime(&java_result)
     }
 
-    fn get_sea_level_sunset(&self) -> Option<DateTime<Tz>> {
+    fn get_bain_hashmashos_rt_13_point_24_degrees(&self) -> Option<DateTime<Tz>> {
         let java_result = self
             .jvm
-            .invoke(&self.instance, "getSeaLevelSunset", InvocationArg::empty())
+            .invoke(
+                &self.instance,
+                "getBainHashmashosRT13Point24Degrees",
+                InvocationArg::empty(),
+            )
             .ok()?;
         self.java_date_to_rust_datetime(&java_result)
     }
 
-    fn get_end_civil_twilight(&self) -> Option<DateTime<Tz>> {
+    fn get_bain_hashmashos_rt_58_point_5_minutes(&self) -> Option<DateTime<Tz>> {
         let java_result = self
             .jvm
-            .invoke(&self.instance, "getEndCivilTwilight", InvocationArg::empty())
+            .invoke(
+                &self.instance,
+                "getBainHashmashosRT58Point5Minutes",
+                InvocationArg::empty(),
+            )
             .ok()?;
         self.java_date_to_rust_datetime(&java_result)
     }
 
-    fn get_end_nautical_twilight(&self) -> Option<DateTime<Tz>> {
+    fn get_bain_hashmashos_rt_13_point_5_minutes_before_7_point_083_degrees(&self) -> Option<DateTime<Tz>> {
         let java_result = self
             .jvm
-            .invoke(&self.instance, "getEndNauticalTwilight", InvocationArg::empty())
+            .invoke(
+                &self.instance,
+                "getBainHashmashosRT13Point5MinutesBefore7Point083Degrees",
+                InvocationArg::empty(),
+            )
             .ok()?;
         self.java_date_to_rust_datetime(&java_result)
     }
 
-    fn get_end_astronomical_twilight(&self) -> Option<DateTime<Tz>> {
+    fn get_bain_hashmashos_rt_2_stars(&self) -> Option<DateTime<Tz>> {
         let java_result = self
             .jvm
-            .invoke(&self.instance, "getEndAstronomicalTwilight", InvocationArg::empty())
+            .invoke(&self.instance, "getBainHashmashosRT2Stars", InvocationArg::empty())
             .ok()?;
         self.java_date_to_rust_datetime(&java_result)
     }
 
-    fn get_sunrise_offset_by_degrees(&self, offset_zenith: f64) -> Option<DateTime<Tz>> {
+    fn get_bain_hashmashos_yereim_18_minutes(&self) -> Option<DateTime<Tz>> {
         let java_result = self
             .jvm
             .invoke(
                 &self.instance,
-                "getSunriseOffsetByDegrees",
-                &[InvocationArg::try_from(offset_zenith)
-                    .unwrap()
-                    .into_primitive()
-                    .unwrap()],
+                "getBainHashmashosYereim18Minutes",
+                InvocationArg::empty(),
             )
             .ok()?;
         self.java_date_to_rust_datetime(&java_result)
     }
 
-    fn get_sunset_offset_by_degrees(&self, offset_zenith: f64) -> Option<DateTime<Tz>> {
+    fn get_bain_hashmashos_yereim_3_point_05_degrees(&self) -> Option<DateTime<Tz>> {
         let java_result = self
             .jvm
             .invoke(
                 &self.instance,
-                "getSunsetOffsetByDegrees",
-                &[InvocationArg::try_from(offset_zenith)
-                    .unwrap()
-                    .into_primitive()
-                    .unwrap()],
+                "getBainHashmashosYereim3Point05Degrees",
+                InvocationArg::empty(),
             )
             .ok()?;
         self.java_date_to_rust_datetime(&java_result)
     }
 
-    fn get_utc_sunrise(&self, zenith: f64) -> Option<f64> {
+    fn get_bain_hashmashos_yereim_16_point_875_minutes(&self) -> Option<DateTime<Tz>> {
         let java_result = self
             .jvm
             .invoke(
                 &self.instance,
-                "getUTCSunrise",
-                &[InvocationArg::try_from(zenith).unwrap().into_primitive().unwrap()],
+                "getBainHashmashosYereim16Point875Minutes",
+                InvocationArg::empty(),
             )
             .ok()?;
-        let result = self.jvm.to_rust::<f64>(java_result).ok()?;
-        if result.is_nan() { None } else { Some(result) }
+        self.java_date_to_rust_datetime(&java_result)
     }
 
-    fn get_utc_sea_level_sunrise(&self, zenith: f64) -> Option<f64> {
+    fn get_bain_hashmashos_yereim_2_point_8_degrees(&self) -> Option<DateTime<Tz>> {
         let java_result = self
             .jvm
             .invoke(
                 &self.instance,
-                "getUTCSeaLevelSunrise",
-                &[InvocationArg::try_from(zenith).unwrap().into_primitive().unwrap()],
+                "getBainHashmashosYereim2Point8Degrees",
+                InvocationArg::empty(),
             )
             .ok()?;
-        let result = self.jvm.to_rust::<f64>(java_result).ok()?;
-        if result.is_nan() { None } else { Some(result) }
+        self.java_date_to_rust_datetime(&java_result)
     }
 
-    fn get_utc_sunset(&self, zenith: f64) -> Option<f64> {
+    fn get_bain_hashmashos_yereim_13_point_5_minutes(&self) -> Option<DateTime<Tz>> {
         let java_result = self
             .jvm
             .invoke(
                 &self.instance,
-                "getUTCSunset",
-                &[InvocationArg::try_from(zenith).unwrap().into_primitive().unwrap()],
+                "getBainHashmashosYereim13Point5Minutes",
+                InvocationArg::empty(),
             )
             .ok()?;
-        let result = self.jvm.to_rust::<f64>(java_result).ok()?;
-        if result.is_nan() { None } else { Some(result) }
+        self.java_date_to_rust_datetime(&java_result)
     }
 
-    fn get_utc_sea_level_sunset(&self, zenith: f64) -> Option<f64> {
+    fn get_bain_hashmashos_yereim_2_point_1_degrees(&self) -> Option<DateTime<Tz>> {
         let java_result = self
             .jvm
             .invoke(
                 &self.instance,
-                "getUTCSeaLevelSunset",
-                &[InvocationArg::try_from(zenith).unwrap().into_primitive().unwrap()],
+                "getBainHashmashosYereim2Point1Degrees",
+                InvocationArg::empty(),
             )
             .ok()?;
-        let result = self.jvm.to_rust::<f64>(java_result).ok()?;
-        if result.is_nan() { None } else { Some(result) }
+        self.java_date_to_rust_datetime(&java_result)
     }
 
-    fn get_temporal_hour(&self) -> Option<Duration> {
+    fn get_tzais_geonim_3_point_65_degrees(&self) -> Option<DateTime<Tz>> {
         let java_result = self
             .jvm
-            .invoke(&self.instance, "getTemporalHour", InvocationArg::empty())
+            .invoke(&self.instance, "getTzaisGeonim3Point65Degrees", InvocationArg::empty())
             .ok()?;
-        let millis = self.jvm.to_rust::<i64>(java_result).ok()?;
-        // DIFF: Java returns Long.MIN_VALUE (-9223372036854775808) to indicate null/None
-        if millis == -9223372036854775808i64 {
-            None
-        } else {
-            Some(Duration::milliseconds(millis))
-        }
+        self.java_date_to_rust_datetime(&java_result)
     }
 
-    fn get_temporal_hour_from_times(&self, start_of_day: &DateTime<Tz>, end_of_day: &DateTime<Tz>) -> Option<Duration> {
-        let java_start = self
+    fn get_tzais_geonim_3_point_676_degrees(&self) -> Option<DateTime<Tz>> {
+        let java_result = self
             .jvm
-            .create_instance(
-                "java.util.Date",
-                &[InvocationArg::try_from(start_of_day.timestamp_millis())
-                    .unwrap()
-                    .into_primitive()
-                    .unwrap()],
-            )
+            .invoke(&self.instance, "getTzaisGeonim3Point676Degrees", InvocationArg::empty())
             .ok()?;
-        let java_end = self
+        self.java_date_to_rust_datetime(&java_result)
+    }
+
+    fn get_tzais_geonim_3_point_7_degrees(&self) -> Option<DateTime<Tz>> {
+        let java_result = self
             .jvm
-            .create_instance(
-                "java.util.Date",
-                &[InvocationArg::try_from(end_of_day.timestamp_millis())
-                    .unwrap()
-                    .into_primitive()
-                    .unwrap()],
-            )
+            .invoke(&self.instance, "getTzaisGeonim3Point7Degrees", InvocationArg::empty())
             .ok()?;
+        self.java_date_to_rust_datetime(&java_result)
+    }
+
+    fn get_tzais_geonim_3_point_8_degrees(&self) -> Option<DateTime<Tz>> {
         let java_result = self
             .jvm
-            .invoke(
-                &self.instance,
-                "getTemporalHour",
-                &[InvocationArg::from(java_start), InvocationArg::from(java_end)],
-            )
+            .invoke(&self.instance, "getTzaisGeonim3Point8Degrees", InvocationArg::empty())
             .ok()?;
-        let millis = self.jvm.to_rust::<i64>(java_result).ok()?;
-        // DIFF: Java returns Long.MIN_VALUE (-9223372036854775808) to indicate null/None
-        if millis == -9223372036854775808i64 {
-            None
-        } else {
-            Some(Duration::milliseconds(millis))
-        }
+        self.java_date_to_rust_datetime(&java_result)
     }
 
-    fn get_sun_transit(&self) -> Option<DateTime<Tz>> {
+    fn get_tzais_geonim_4_point_37_degrees(&self) -> Option<DateTime<Tz>> {
         let java_result = self
             .jvm
-            .invoke(&self.instance, "getSunTransit", InvocationArg::empty())
+            .invoke(&self.instance, "getTzaisGeonim4Point37Degrees", InvocationArg::empty())
             .ok()?;
         self.java_date_to_rust_datetime(&java_result)
     }
 
-    fn get_solar_midnight(&self) -> Option<DateTime<Tz>> {
+    fn get_tzais_geonim_4_point_61_degrees(&self) -> Option<DateTime<Tz>> {
         let java_result = self
             .jvm
-            .invoke(&self.instance, "getSolarMidnight", InvocationArg::empty())
+            .invoke(&self.instance, "getTzaisGeonim4Point61Degrees", InvocationArg::empty())
             .ok()?;
         self.java_date_to_rust_datetime(&java_result)
     }
 
-    fn get_sun_transit_from_times(
-        &self,
-        start_of_day: &DateTime<Tz>,
-        end_of_day: &DateTime<Tz>,
-    ) -> Option<DateTime<Tz>> {
-        let java_start = dt_to_java_date(self.jvm, start_of_day);
-        let java_end = dt_to_java_date(self.jvm, end_of_day);
+    fn get_tzais_geonim_4_point_8_degrees(&self) -> Option<DateTime<Tz>> {
         let java_result = self
             .jvm
-            .invoke(
-                &self.instance,
-                "getSunTransit",
-                &[InvocationArg::from(java_start), InvocationArg::from(java_end)],
-            )
+            .invoke(&self.instance, "getTzaisGeonim4Point8Degrees", InvocationArg::empty())
             .ok()?;
         self.java_date_to_rust_datetime(&java_result)
     }
 
-    fn get_local_mean_time(&self, hours: f64) -> Option<DateTime<Tz>> {
+    fn get_tzais_geonim_5_point_88_degrees(&self) -> Option<DateTime<Tz>> {
         let java_result = self
             .jvm
-            .invoke(
-                &self.instance,
-                "getLocalMeanTime",
-                &[InvocationArg::try_from(hours).unwrap().into_primitive().unwrap()],
-            )
+            .invoke(&self.instance, "getTzaisGeonim5Point88Degrees", InvocationArg::empty())
             .ok()?;
         self.java_date_to_rust_datetime(&java_result)
     }
-    fn get_percent_of_shaah_zmanis_from_degrees(&self, degrees: f64, sunset: bool) -> Option<f64> {
+
+    fn get_tzais_geonim_5_point_95_degrees(&self) -> Option<DateTime<Tz>> {
         let java_result = self
             .jvm
-            .invoke(
-                &self.instance,
-                "getPercentOfShaahZmanisFromDegrees",
-                &[
-                    InvocationArg::try_from(degrees).unwrap().into_primitive().unwrap(),
-                    InvocationArg::try_from(sunset).unwrap().into_primitive().unwrap(),
-                ],
-            )
+            .invoke(&self.instance, "getTzaisGeonim5Point95Degrees", InvocationArg::empty())
             .ok()?;
-        let result = self.jvm.to_rust::<f64>(java_result).ok()?;
-        if result == 5e-324 { None } else { Some(result) }
+        self.java_date_to_rust_datetime(&java_result)
     }
 
-    fn get_shaah_zmanis_gra(&self) -> Option<Duration> {
+    fn get_tzais_geonim_6_point_45_degrees(&self) -> Option<DateTime<Tz>> {
         let java_result = self
             .jvm
-            .invoke(&self.instance, "getShaahZmanisGra", InvocationArg::empty())
+            .invoke(&self.instance, "getTzaisGeonim6Point45Degrees", InvocationArg::empty())
             .ok()?;
-        self.get_java_duration_millis(java_result).map(Duration::milliseconds)
+        self.java_date_to_rust_datetime(&java_result)
     }
 
-    fn get_shaah_zmanis_mga(&self) -> Option<Duration> {
+    fn get_tzais_geonim_7_point_083_degrees(&self) -> Option<DateTime<Tz>> {
         let java_result = self
             .jvm
-            .invoke(&self.instance, "getShaahZmanisMGA", InvocationArg::empty())
+            .invoke(&self.instance, "getTzaisGeonim7Point083Degrees", InvocationArg::empty())
             .ok()?;
-        self.get_java_duration_millis(java_result).map(Duration::milliseconds)
+        self.java_date_to_rust_datetime(&java_result)
+    }
+
+    fn get_tzais_geonim_7_point_67_degrees(&self) -> Option<DateTime<Tz>> {
+        let java_result = self
+            .jvm
+            .invoke(&self.instance, "getTzaisGeonim7Point67Degrees", InvocationArg::empty())
+            .ok()?;
+        self.java_date_to_rust_datetime(&java_result)
+    }
+
+    fn get_tzais_geonim_8_point_5_degrees(&self) -> Option<DateTime<Tz>> {
+        let java_result = self
+            .jvm
+            .invoke(&self.instance, "getTzaisGeonim8Point5Degrees", InvocationArg::empty())
+            .ok()?;
+        self.java_date_to_rust_datetime(&java_result)
+    }
+
+    fn get_tzais_geonim_9_point_3_degrees(&self) -> Option<DateTime<Tz>> {
+        let java_result = self
+            .jvm
+            .invoke(&self.instance, "getTzaisGeonim9Point3Degrees", InvocationArg::empty())
+            .ok()?;
+        self.java_date_to_rust_datetime(&java_result)
+    }
+
+    fn get_tzais_geonim_9_point_75_degrees(&self) -> Option<DateTime<Tz>> {
+        let java_result = self
+            .jvm
+            .invoke(&self.instance, "getTzaisGeonim9Point75Degrees", InvocationArg::empty())
+            .ok()?;
+        self.java_date_to_rust_datetime(&java_result)
+    }
+
+    fn get_tzais_ateret_torah(&self) -> Option<DateTime<Tz>> {
+        let java_result = self
+            .jvm
+            .invoke(&self.instance, "getTzaisAteretTorah", InvocationArg::empty())
+            .ok()?;
+        self.java_date_to_rust_datetime(&java_result)
+    }
+
+    fn get_sof_zman_shma_ateret_torah(&self) -> Option<DateTime<Tz>> {
+        let java_result = self
+            .jvm
+            .invoke(&self.instance, "getSofZmanShmaAteretTorah", InvocationArg::empty())
+            .ok()?;
+        self.java_date_to_rust_datetime(&java_result)
+    }
+
+    fn get_sof_zman_tfila_ateret_torah(&self) -> Option<DateTime<Tz>> {
+        let java_result = self
+            .jvm
+            .invoke(&self.instance, "getSofZmanTfilahAteretTorah", InvocationArg::empty())
+            .ok()?;
+        self.java_date_to_rust_datetime(&java_result)
+    }
+
+    fn get_mincha_gedola_ateret_torah(&self) -> Option<DateTime<Tz>> {
+        let java_result = self
+            .jvm
+            .invoke(&self.instance, "getMinchaGedolaAteretTorah", InvocationArg::empty())
+            .ok()?;
+        self.java_date_to_rust_datetime(&java_result)
+    }
+
+    fn get_mincha_ketana_ateret_torah(&self) -> Option<DateTime<Tz>> {
+        let java_result = self
+            .jvm
+            .invoke(&self.instance, "getMinchaKetanaAteretTorah", InvocationArg::empty())
+            .ok()?;
+        self.java_date_to_rust_datetime(&java_result)
+    }
+
+    fn get_plag_hamincha_ateret_torah(&self) -> Option<DateTime<Tz>> {
+        let java_result = self
+            .jvm
+            .invoke(&self.instance, "getPlagHaminchaAteretTorah", InvocationArg::empty())
+            .ok()?;
+        self.java_date_to_rust_datetime(&java_result)
     }
 
     fn get_half_day_based_zman_from_times(
@@ -1122,6 +2067,88 @@ impl<'a, Tz: TimeZone> ZmanimCalendarTrait<Tz, GeoLocation, NOAACalculator> for
             Zman::Tzais => "getTzais",
             Zman::Tzais72 => "getTzais72",
             Zman::CandleLighting => "getCandleLighting",
+            Zman::Alos120 => "getAlos120",
+            Zman::Alos120Zmanis => "getAlos120Zmanis",
+            Zman::Alos18Degrees => "getAlos18Degrees",
+            Zman::Alos19Degrees => "getAlos19Degrees",
+            Zman::Alos19Point8Degrees => "getAlos19Point8Degrees",
+            Zman::Alos26Degrees => "getAlos26Degrees",
+            Zman::Alos60 => "getAlos60",
+            Zman::Alos72Zmanis => "getAlos72Zmanis",
+            Zman::Alos90 => "getAlos90",
+            Zman::Alos90Zmanis => "getAlos90Zmanis",
+            Zman::Alos96 => "getAlos96",
+            Zman::Alos96Zmanis => "getAlos96Zmanis",
+            Zman::BainHashmashosRT13Point24Degrees => "getBainHashmashosRT13Point24Degrees",
+            Zman::BainHashmashosRT13Point5MinutesBefore7Point083Degrees => {
+                "getBainHashmashosRT13Point5MinutesBefore7Point083Degrees"
+            }
+            Zman::BainHashmashosRT2Stars => "getBainHashmashosRT2Stars",
+            Zman::BainHashmashosRT58Point5Minutes => "getBainHashmashosRT58Point5Minutes",
+            Zman::BainHashmashosYereim13Point5Minutes => "getBainHashmashosYereim13Point5Minutes",
+            Zman::BainHashmashosYereim16Point875Minutes => "getBainHashmashosYereim16Point875Minutes",
+            Zman::BainHashmashosYereim18Minutes => "getBainHashmashosYereim18Minutes",
+            Zman::BainHashmashosYereim2Point1Degrees => "getBainHashmashosYereim2Point1Degrees",
+            Zman::BainHashmashosYereim2Point8Degrees => "getBainHashmashosYereim2Point8Degrees",
+            Zman::BainHashmashosYereim3Point05Degrees => "getBainHashmashosYereim3Point05Degrees",
+            Zman::MinchaGedola16Point1Degrees => "getMinchaGedola16Point1Degrees",
+            Zman::MinchaGedola30Minutes => "getMinchaGedola30Minutes",
+            Zman::MinchaGedolaAteretTorah => "getMinchaGedolaAteretTorah",
+            Zman::MinchaGedolaGreaterThan30 => "getMinchaGedolaGreaterThan30",
+            Zman::MinchaKetana16Point1Degrees => "getMinchaKetana16Point1Degrees",
+            Zman::MinchaKetana72Minutes => "getMinchaKetana72Minutes",
+            Zman::MinchaKetanaAteretTorah => "getMinchaKetanaAteretTorah",
+            Zman::MinchaKetanaGRAFixedLocalChatzosToSunset => "getMinchaKetanaGRAFixedLocalChatzosToSunset",
+            Zman::PlagHaminchaAteretTorah => "getPlagHaminchaAteretTorah",
+            Zman::SofZmanShmaAteretTorah => "getSofZmanShmaAteretTorah",
+            Zman::SofZmanShmaMGA120Minutes => "getSofZmanShmaMGA120Minutes",
+            Zman::SofZmanShmaMGA16Point1Degrees => "getSofZmanShmaMGA16Point1Degrees",
+            Zman::SofZmanShmaMGA18Degrees => "getSofZmanShmaMGA18Degrees",
+            Zman::SofZmanShmaMGA19Point8Degrees => "getSofZmanShmaMGA19Point8Degrees",
+            Zman::SofZmanShmaMGA72MinutesZmanis => "getSofZmanShmaMGA72MinutesZmanis",
+            Zman::SofZmanShmaMGA90Minutes => "getSofZmanShmaMGA90Minutes",
+            Zman::SofZmanShmaMGA90MinutesZmanis => "getSofZmanShmaMGA90MinutesZmanis",
+            Zman::SofZmanShmaMGA96Minutes => "getSofZmanShmaMGA96Minutes",
+            Zman::SofZmanShmaMGA96MinutesZmanis => "getSofZmanShmaMGA96MinutesZmanis",
+            Zman::SofZmanTfila2HoursBeforeChatzos => "getSofZmanTfila2HoursBeforeChatzos",
+            Zman::SofZmanTfilaAteretTorah => "getSofZmanTfilahAteretTorah",
+            Zman::SofZmanTfilaMGA120Minutes => "getSofZmanTfilaMGA120Minutes",
+            Zman::SofZmanTfilaMGA16Point1Degrees => "getSofZmanTfilaMGA16Point1Degrees",
+            Zman::SofZmanTfilaMGA18Degrees => "getSofZmanTfilaMGA18Degrees",
+            Zman::SofZmanTfilaMGA19Point8Degrees => "getSofZmanTfilaMGA19Point8Degrees",
+            Zman::SofZmanTfilaMGA72MinutesZmanis => "getSofZmanTfilaMGA72MinutesZmanis",
+            Zman::SofZmanTfilaMGA90Minutes => "getSofZmanTfilaMGA90Minutes",
+            Zman::SofZmanTfilaMGA90MinutesZmanis => "getSofZmanTfilaMGA90MinutesZmanis",
+            Zman::SofZmanTfilaMGA96Minutes => "getSofZmanTfilaMGA96Minutes",
+            Zman::SofZmanTfilaMGA96MinutesZmanis => "getSofZmanTfilaMGA96MinutesZmanis",
+            Zman::Tzais120 => "getTzais120",
+            Zman::Tzais120Zmanis => "getTzais120Zmanis",
+            Zman::Tzais16Point1Degrees => "getTzais16Point1Degrees",
+            Zman::Tzais18Degrees => "getTzais18Degrees",
+            Zman::Tzais19Point8Degrees => "getTzais19Point8Degrees",
+            Zman::Tzais26Degrees => "getTzais26Degrees",
+            Zman::Tzais50 => "getTzais50",
+            Zman::Tzais60 => "getTzais60",
+            Zman::Tzais72Zmanis => "getTzais72Zmanis",
+            Zman::Tzais90 => "getTzais90",
+            Zman::Tzais90Zmanis => "getTzais90Zmanis",
+            Zman::Tzais96 => "getTzais96",
+            Zman::Tzais96Zmanis => "getTzais96Zmanis",
+            Zman::TzaisAteretTorah => "getTzaisAteretTorah",
+            Zman::TzaisGeonim3Point65Degrees => "getTzaisGeonim3Point65Degrees",
+            Zman::TzaisGeonim3Point676Degrees => "getTzaisGeonim3Point676Degrees",
+            Zman::TzaisGeonim3Point7Degrees => "getTzaisGeonim3Point7Degrees",
+            Zman::TzaisGeonim3Point8Degrees => "getTzaisGeonim3Point8Degrees",
+            Zman::TzaisGeonim4Point37Degrees => "getTzaisGeonim4Point37Degrees",
+            Zman::TzaisGeonim4Point61Degrees => "getTzaisGeonim4Point61Degrees",
+            Zman::TzaisGeonim4Point8Degrees => "getTzaisGeonim4Point8Degrees",
+            Zman::TzaisGeonim5Point88Degrees => "getTzaisGeonim5Point88Degrees",
+            Zman::TzaisGeonim5Point95Degrees => "getTzaisGeonim5Point95Degrees",
+            Zman::TzaisGeonim6Point45Degrees => "getTzaisGeonim6Point45Degrees",
+            Zman::TzaisGeonim7Point083Degrees => "getTzaisGeonim7Point083Degrees",
+            Zman::TzaisGeonim7Point67Degrees => "getTzaisGeonim7Point67Degrees",
+            Zman::TzaisGeonim9Point3Degrees => "getTzaisGeonim9Point3Degrees",
+            Zman::TzaisGeonim9Point75Degrees => "getTzaisGeonim9Point75Degrees",
         };
         let java_result = self
             .jvm
@@ -1546,6 +2573,14 @@ impl<'a> JewishCalendarTrait for JavaJewishCalendar<'a> {
 
         self.jvm.to_rust::<u8>(java_result).unwrap()
     }
+    fn get_gregorian_month_1_based(&self) -> u8 {
+        let java_result = self
+            .jvm
+            .invoke(&self.instance, "getGregorianMonth", InvocationArg::empty())
+            .unwrap();
+
+        self.jvm.to_rust::<u8>(java_result).unwrap() + 1
+    }
 
     fn get_gregorian_day_of_month(&self) -> u8 {
         let java_result = self
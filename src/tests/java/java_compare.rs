@@ -395,6 +395,530 @@ pub fn compare_zmanim_calendars<'a, Tz: TimeZone + std::fmt::Debug>(
         &format!("get_shaah_zmanis_mga against java with calendar {:?}", rust_calendar),
     );
 
+    let shaah_zmanis_variants = [
+        (
+            "get_shaah_zmanis_16_point_1_degrees",
+            rust_calendar.get_shaah_zmanis_16_point_1_degrees(),
+            java_calendar.get_shaah_zmanis_16_point_1_degrees(),
+        ),
+        (
+            "get_shaah_zmanis_18_degrees",
+            rust_calendar.get_shaah_zmanis_18_degrees(),
+            java_calendar.get_shaah_zmanis_18_degrees(),
+        ),
+        (
+            "get_shaah_zmanis_19_point_8_degrees",
+            rust_calendar.get_shaah_zmanis_19_point_8_degrees(),
+            java_calendar.get_shaah_zmanis_19_point_8_degrees(),
+        ),
+        (
+            "get_shaah_zmanis_26_degrees",
+            rust_calendar.get_shaah_zmanis_26_degrees(),
+            java_calendar.get_shaah_zmanis_26_degrees(),
+        ),
+        (
+            "get_shaah_zmanis_60_minutes",
+            rust_calendar.get_shaah_zmanis_60_minutes(),
+            java_calendar.get_shaah_zmanis_60_minutes(),
+        ),
+        (
+            "get_shaah_zmanis_72_minutes",
+            rust_calendar.get_shaah_zmanis_72_minutes(),
+            java_calendar.get_shaah_zmanis_72_minutes(),
+        ),
+        (
+            "get_shaah_zmanis_72_minutes_zmanis",
+            rust_calendar.get_shaah_zmanis_72_minutes_zmanis(),
+            java_calendar.get_shaah_zmanis_72_minutes_zmanis(),
+        ),
+        (
+            "get_shaah_zmanis_90_minutes",
+            rust_calendar.get_shaah_zmanis_90_minutes(),
+            java_calendar.get_shaah_zmanis_90_minutes(),
+        ),
+        (
+            "get_shaah_zmanis_90_minutes_zmanis",
+            rust_calendar.get_shaah_zmanis_90_minutes_zmanis(),
+            java_calendar.get_shaah_zmanis_90_minutes_zmanis(),
+        ),
+        (
+            "get_shaah_zmanis_96_minutes",
+            rust_calendar.get_shaah_zmanis_96_minutes(),
+            java_calendar.get_shaah_zmanis_96_minutes(),
+        ),
+        (
+            "get_shaah_zmanis_96_minutes_zmanis",
+            rust_calendar.get_shaah_zmanis_96_minutes_zmanis(),
+            java_calendar.get_shaah_zmanis_96_minutes_zmanis(),
+        ),
+        (
+            "get_shaah_zmanis_120_minutes",
+            rust_calendar.get_shaah_zmanis_120_minutes(),
+            java_calendar.get_shaah_zmanis_120_minutes(),
+        ),
+        (
+            "get_shaah_zmanis_120_minutes_zmanis",
+            rust_calendar.get_shaah_zmanis_120_minutes_zmanis(),
+            java_calendar.get_shaah_zmanis_120_minutes_zmanis(),
+        ),
+        (
+            "get_shaah_zmanis_ateret_torah",
+            rust_calendar.get_shaah_zmanis_ateret_torah(),
+            java_calendar.get_shaah_zmanis_ateret_torah(),
+        ),
+        (
+            "get_shaah_zmanis_alos_16_point_1_to_tzais_3_point_7",
+            rust_calendar.get_shaah_zmanis_alos_16_point_1_to_tzais_3_point_7(),
+            java_calendar.get_shaah_zmanis_alos_16_point_1_to_tzais_3_point_7(),
+        ),
+        (
+            "get_shaah_zmanis_alos_16_point_1_to_tzais_3_point_8",
+            rust_calendar.get_shaah_zmanis_alos_16_point_1_to_tzais_3_point_8(),
+            java_calendar.get_shaah_zmanis_alos_16_point_1_to_tzais_3_point_8(),
+        ),
+    ];
+    for (name, result, java_result) in shaah_zmanis_variants {
+        assert_almost_equal_duration_option(
+            &result,
+            &java_result,
+            &format!("{} against java with calendar {:?}", name, rust_calendar),
+        );
+    }
+
+    let result = rust_calendar.get_zmanis_based_offset(hours);
+    let java_result = java_calendar.get_zmanis_based_offset(hours);
+    assert_almost_equal_datetime_option(
+        &result,
+        &java_result,
+        &format!(
+            "get_zmanis_based_offset({}) against java with calendar {:?}",
+            hours, rust_calendar
+        ),
+    );
+    let variants = [
+        (
+            "get_alos_16_point_1_degrees",
+            rust_calendar.get_alos_16_point_1_degrees(),
+            java_calendar.get_alos_16_point_1_degrees(),
+        ),
+        (
+            "get_alos_18_degrees",
+            rust_calendar.get_alos_18_degrees(),
+            java_calendar.get_alos_18_degrees(),
+        ),
+        (
+            "get_alos_19_degrees",
+            rust_calendar.get_alos_19_degrees(),
+            java_calendar.get_alos_19_degrees(),
+        ),
+        (
+            "get_alos_19_point_8_degrees",
+            rust_calendar.get_alos_19_point_8_degrees(),
+            java_calendar.get_alos_19_point_8_degrees(),
+        ),
+        (
+            "get_alos_26_degrees",
+            rust_calendar.get_alos_26_degrees(),
+            java_calendar.get_alos_26_degrees(),
+        ),
+        ("get_alos_60", rust_calendar.get_alos_60(), java_calendar.get_alos_60()),
+        ("get_alos_72", rust_calendar.get_alos_72(), java_calendar.get_alos_72()),
+        ("get_alos_90", rust_calendar.get_alos_90(), java_calendar.get_alos_90()),
+        ("get_alos_96", rust_calendar.get_alos_96(), java_calendar.get_alos_96()),
+        (
+            "get_alos_120",
+            rust_calendar.get_alos_120(),
+            java_calendar.get_alos_120(),
+        ),
+        (
+            "get_alos_72_zmanis",
+            rust_calendar.get_alos_72_zmanis(),
+            java_calendar.get_alos_72_zmanis(),
+        ),
+        (
+            "get_alos_90_zmanis",
+            rust_calendar.get_alos_90_zmanis(),
+            java_calendar.get_alos_90_zmanis(),
+        ),
+        (
+            "get_alos_96_zmanis",
+            rust_calendar.get_alos_96_zmanis(),
+            java_calendar.get_alos_96_zmanis(),
+        ),
+        (
+            "get_alos_120_zmanis",
+            rust_calendar.get_alos_120_zmanis(),
+            java_calendar.get_alos_120_zmanis(),
+        ),
+        (
+            "get_tzais_16_point_1_degrees",
+            rust_calendar.get_tzais_16_point_1_degrees(),
+            java_calendar.get_tzais_16_point_1_degrees(),
+        ),
+        (
+            "get_tzais_18_degrees",
+            rust_calendar.get_tzais_18_degrees(),
+            java_calendar.get_tzais_18_degrees(),
+        ),
+        (
+            "get_tzais_19_point_8_degrees",
+            rust_calendar.get_tzais_19_point_8_degrees(),
+            java_calendar.get_tzais_19_point_8_degrees(),
+        ),
+        (
+            "get_tzais_26_degrees",
+            rust_calendar.get_tzais_26_degrees(),
+            java_calendar.get_tzais_26_degrees(),
+        ),
+        (
+            "get_tzais_50",
+            rust_calendar.get_tzais_50(),
+            java_calendar.get_tzais_50(),
+        ),
+        (
+            "get_tzais_60",
+            rust_calendar.get_tzais_60(),
+            java_calendar.get_tzais_60(),
+        ),
+        (
+            "get_tzais_72",
+            rust_calendar.get_tzais_72(),
+            java_calendar.get_tzais_72(),
+        ),
+        (
+            "get_tzais_90",
+            rust_calendar.get_tzais_90(),
+            java_calendar.get_tzais_90(),
+        ),
+        (
+            "get_tzais_96",
+            rust_calendar.get_tzais_96(),
+            java_calendar.get_tzais_96(),
+        ),
+        (
+            "get_tzais_120",
+            rust_calendar.get_tzais_120(),
+            java_calendar.get_tzais_120(),
+        ),
+        (
+            "get_tzais_72_zmanis",
+            rust_calendar.get_tzais_72_zmanis(),
+            java_calendar.get_tzais_72_zmanis(),
+        ),
+        (
+            "get_tzais_90_zmanis",
+            rust_calendar.get_tzais_90_zmanis(),
+            java_calendar.get_tzais_90_zmanis(),
+        ),
+        (
+            "get_tzais_96_zmanis",
+            rust_calendar.get_tzais_96_zmanis(),
+            java_calendar.get_tzais_96_zmanis(),
+        ),
+        (
+            "get_tzais_120_zmanis",
+            rust_calendar.get_tzais_120_zmanis(),
+            java_calendar.get_tzais_120_zmanis(),
+        ),
+        (
+            "get_sof_zman_shma_mga_16_point_1_degrees",
+            rust_calendar.get_sof_zman_shma_mga_16_point_1_degrees(),
+            java_calendar.get_sof_zman_shma_mga_16_point_1_degrees(),
+        ),
+        (
+            "get_sof_zman_shma_mga_18_degrees",
+            rust_calendar.get_sof_zman_shma_mga_18_degrees(),
+            java_calendar.get_sof_zman_shma_mga_18_degrees(),
+        ),
+        (
+            "get_sof_zman_shma_mga_19_point_8_degrees",
+            rust_calendar.get_sof_zman_shma_mga_19_point_8_degrees(),
+            java_calendar.get_sof_zman_shma_mga_19_point_8_degrees(),
+        ),
+        (
+            "get_sof_zman_shma_mga_72_minutes",
+            rust_calendar.get_sof_zman_shma_mga_72_minutes(),
+            java_calendar.get_sof_zman_shma_mga_72_minutes(),
+        ),
+        (
+            "get_sof_zman_shma_mga_72_minutes_zmanis",
+            rust_calendar.get_sof_zman_shma_mga_72_minutes_zmanis(),
+            java_calendar.get_sof_zman_shma_mga_72_minutes_zmanis(),
+        ),
+        (
+            "get_sof_zman_shma_mga_90_minutes",
+            rust_calendar.get_sof_zman_shma_mga_90_minutes(),
+            java_calendar.get_sof_zman_shma_mga_90_minutes(),
+        ),
+        (
+            "get_sof_zman_shma_mga_90_minutes_zmanis",
+            rust_calendar.get_sof_zman_shma_mga_90_minutes_zmanis(),
+            java_calendar.get_sof_zman_shma_mga_90_minutes_zmanis(),
+        ),
+        (
+            "get_sof_zman_shma_mga_96_minutes",
+            rust_calendar.get_sof_zman_shma_mga_96_minutes(),
+            java_calendar.get_sof_zman_shma_mga_96_minutes(),
+        ),
+        (
+            "get_sof_zman_shma_mga_96_minutes_zmanis",
+            rust_calendar.get_sof_zman_shma_mga_96_minutes_zmanis(),
+            java_calendar.get_sof_zman_shma_mga_96_minutes_zmanis(),
+        ),
+        (
+            "get_sof_zman_shma_mga_120_minutes",
+            rust_calendar.get_sof_zman_shma_mga_120_minutes(),
+            java_calendar.get_sof_zman_shma_mga_120_minutes(),
+        ),
+        (
+            "get_sof_zman_tfila_mga_16_point_1_degrees",
+            rust_calendar.get_sof_zman_tfila_mga_16_point_1_degrees(),
+            java_calendar.get_sof_zman_tfila_mga_16_point_1_degrees(),
+        ),
+        (
+            "get_sof_zman_tfila_mga_18_degrees",
+            rust_calendar.get_sof_zman_tfila_mga_18_degrees(),
+            java_calendar.get_sof_zman_tfila_mga_18_degrees(),
+        ),
+        (
+            "get_sof_zman_tfila_mga_19_point_8_degrees",
+            rust_calendar.get_sof_zman_tfila_mga_19_point_8_degrees(),
+            java_calendar.get_sof_zman_tfila_mga_19_point_8_degrees(),
+        ),
+        (
+            "get_sof_zman_tfila_mga_72_minutes",
+            rust_calendar.get_sof_zman_tfila_mga_72_minutes(),
+            java_calendar.get_sof_zman_tfila_mga_72_minutes(),
+        ),
+        (
+            "get_sof_zman_tfila_mga_72_minutes_zmanis",
+            rust_calendar.get_sof_zman_tfila_mga_72_minutes_zmanis(),
+            java_calendar.get_sof_zman_tfila_mga_72_minutes_zmanis(),
+        ),
+        (
+            "get_sof_zman_tfila_mga_90_minutes",
+            rust_calendar.get_sof_zman_tfila_mga_90_minutes(),
+            java_calendar.get_sof_zman_tfila_mga_90_minutes(),
+        ),
+        (
+            "get_sof_zman_tfila_mga_90_minutes_zmanis",
+            rust_calendar.get_sof_zman_tfila_mga_90_minutes_zmanis(),
+            java_calendar.get_sof_zman_tfila_mga_90_minutes_zmanis(),
+        ),
+        (
+            "get_sof_zman_tfila_mga_96_minutes",
+            rust_calendar.get_sof_zman_tfila_mga_96_minutes(),
+            java_calendar.get_sof_zman_tfila_mga_96_minutes(),
+        ),
+        (
+            "get_sof_zman_tfila_mga_96_minutes_zmanis",
+            rust_calendar.get_sof_zman_tfila_mga_96_minutes_zmanis(),
+            java_calendar.get_sof_zman_tfila_mga_96_minutes_zmanis(),
+        ),
+        (
+            "get_sof_zman_tfila_mga_120_minutes",
+            rust_calendar.get_sof_zman_tfila_mga_120_minutes(),
+            java_calendar.get_sof_zman_tfila_mga_120_minutes(),
+        ),
+        (
+            "get_sof_zman_tfila_2_hours_before_chatzos",
+            rust_calendar.get_sof_zman_tfila_2_hours_before_chatzos(),
+            java_calendar.get_sof_zman_tfila_2_hours_before_chatzos(),
+        ),
+        (
+            "get_mincha_gedola_30_minutes",
+            rust_calendar.get_mincha_gedola_30_minutes(),
+            java_calendar.get_mincha_gedola_30_minutes(),
+        ),
+        (
+            "get_mincha_gedola_16_point_1_degrees",
+            rust_calendar.get_mincha_gedola_16_point_1_degrees(),
+            java_calendar.get_mincha_gedola_16_point_1_degrees(),
+        ),
+        (
+            "get_mincha_gedola_greater_than_30",
+            rust_calendar.get_mincha_gedola_greater_than_30(),
+            java_calendar.get_mincha_gedola_greater_than_30(),
+        ),
+        (
+            "get_mincha_ketana_16_point_1_degrees",
+            rust_calendar.get_mincha_ketana_16_point_1_degrees(),
+            java_calendar.get_mincha_ketana_16_point_1_degrees(),
+        ),
+        (
+            "get_mincha_ketana_72_minutes",
+            rust_calendar.get_mincha_ketana_72_minutes(),
+            java_calendar.get_mincha_ketana_72_minutes(),
+        ),
+        (
+            "get_mincha_ketana_gra_fixed_local_chatzos_to_sunset",
+            rust_calendar.get_mincha_ketana_gra_fixed_local_chatzos_to_sunset(),
+            java_calendar.get_mincha_ketana_gra_fixed_local_chatzos_to_sunset(),
+        ),
+        (
+            "get_bain_hashmashos_rt_13_point_24_degrees",
+            rust_calendar.get_bain_hashmashos_rt_13_point_24_degrees(),
+            java_calendar.get_bain_hashmashos_rt_13_point_24_degrees(),
+        ),
+        (
+            "get_bain_hashmashos_rt_58_point_5_minutes",
+            rust_calendar.get_bain_hashmashos_rt_58_point_5_minutes(),
+            java_calendar.get_bain_hashmashos_rt_58_point_5_minutes(),
+        ),
+        (
+            "get_bain_hashmashos_rt_13_point_5_minutes_before_7_point_083_degrees",
+            rust_calendar.get_bain_hashmashos_rt_13_point_5_minutes_before_7_point_083_degrees(),
+            java_calendar.get_bain_hashmashos_rt_13_point_5_minutes_before_7_point_083_degrees(),
+        ),
+        (
+            "get_bain_hashmashos_rt_2_stars",
+            rust_calendar.get_bain_hashmashos_rt_2_stars(),
+            java_calendar.get_bain_hashmashos_rt_2_stars(),
+        ),
+        (
+            "get_bain_hashmashos_yereim_18_minutes",
+            rust_calendar.get_bain_hashmashos_yereim_18_minutes(),
+            java_calendar.get_bain_hashmashos_yereim_18_minutes(),
+        ),
+        (
+            "get_bain_hashmashos_yereim_3_point_05_degrees",
+            rust_calendar.get_bain_hashmashos_yereim_3_point_05_degrees(),
+            java_calendar.get_bain_hashmashos_yereim_3_point_05_degrees(),
+        ),
+        (
+            "get_bain_hashmashos_yereim_16_point_875_minutes",
+            rust_calendar.get_bain_hashmashos_yereim_16_point_875_minutes(),
+            java_calendar.get_bain_hashmashos_yereim_16_point_875_minutes(),
+        ),
+        (
+            "get_bain_hashmashos_yereim_2_point_8_degrees",
+            rust_calendar.get_bain_hashmashos_yereim_2_point_8_degrees(),
+            java_calendar.get_bain_hashmashos_yereim_2_point_8_degrees(),
+        ),
+        (
+            "get_bain_hashmashos_yereim_13_point_5_minutes",
+            rust_calendar.get_bain_hashmashos_yereim_13_point_5_minutes(),
+            java_calendar.get_bain_hashmashos_yereim_13_point_5_minutes(),
+        ),
+        (
+            "get_bain_hashmashos_yereim_2_point_1_degrees",
+            rust_calendar.get_bain_hashmashos_yereim_2_point_1_degrees(),
+            java_calendar.get_bain_hashmashos_yereim_2_point_1_degrees(),
+        ),
+        (
+            "get_tzais_geonim_3_point_65_degrees",
+            rust_calendar.get_tzais_geonim_3_point_65_degrees(),
+            java_calendar.get_tzais_geonim_3_point_65_degrees(),
+        ),
+        (
+            "get_tzais_geonim_3_point_676_degrees",
+            rust_calendar.get_tzais_geonim_3_point_676_degrees(),
+            java_calendar.get_tzais_geonim_3_point_676_degrees(),
+        ),
+        (
+            "get_tzais_geonim_3_point_7_degrees",
+            rust_calendar.get_tzais_geonim_3_point_7_degrees(),
+            java_calendar.get_tzais_geonim_3_point_7_degrees(),
+        ),
+        (
+            "get_tzais_geonim_3_point_8_degrees",
+            rust_calendar.get_tzais_geonim_3_point_8_degrees(),
+            java_calendar.get_tzais_geonim_3_point_8_degrees(),
+        ),
+        (
+            "get_tzais_geonim_4_point_37_degrees",
+            rust_calendar.get_tzais_geonim_4_point_37_degrees(),
+            java_calendar.get_tzais_geonim_4_point_37_degrees(),
+        ),
+        (
+            "get_tzais_geonim_4_point_61_degrees",
+            rust_calendar.get_tzais_geonim_4_point_61_degrees(),
+            java_calendar.get_tzais_geonim_4_point_61_degrees(),
+        ),
+        (
+            "get_tzais_geonim_4_point_8_degrees",
+            rust_calendar.get_tzais_geonim_4_point_8_degrees(),
+            java_calendar.get_tzais_geonim_4_point_8_degrees(),
+        ),
+        (
+            "get_tzais_geonim_5_point_88_degrees",
+            rust_calendar.get_tzais_geonim_5_point_88_degrees(),
+            java_calendar.get_tzais_geonim_5_point_88_degrees(),
+        ),
+        (
+            "get_tzais_geonim_5_point_95_degrees",
+            rust_calendar.get_tzais_geonim_5_point_95_degrees(),
+            java_calendar.get_tzais_geonim_5_point_95_degrees(),
+        ),
+        (
+            "get_tzais_geonim_6_point_45_degrees",
+            rust_calendar.get_tzais_geonim_6_point_45_degrees(),
+            java_calendar.get_tzais_geonim_6_point_45_degrees(),
+        ),
+        (
+            "get_tzais_geonim_7_point_083_degrees",
+            rust_calendar.get_tzais_geonim_7_point_083_degrees(),
+            java_calendar.get_tzais_geonim_7_point_083_degrees(),
+        ),
+        (
+            "get_tzais_geonim_7_point_67_degrees",
+            rust_calendar.get_tzais_geonim_7_point_67_degrees(),
+            java_calendar.get_tzais_geonim_7_point_67_degrees(),
+        ),
+        (
+            "get_tzais_geonim_8_point_5_degrees",
+            rust_calendar.get_tzais_geonim_8_point_5_degrees(),
+            java_calendar.get_tzais_geonim_8_point_5_degrees(),
+        ),
+        (
+            "get_tzais_geonim_9_point_3_degrees",
+            rust_calendar.get_tzais_geonim_9_point_3_degrees(),
+            java_calendar.get_tzais_geonim_9_point_3_degrees(),
+        ),
+        (
+            "get_tzais_geonim_9_point_75_degrees",
+            rust_calendar.get_tzais_geonim_9_point_75_degrees(),
+            java_calendar.get_tzais_geonim_9_point_75_degrees(),
+        ),
+        (
+            "get_tzais_ateret_torah",
+            rust_calendar.get_tzais_ateret_torah(),
+            java_calendar.get_tzais_ateret_torah(),
+        ),
+        (
+            "get_sof_zman_shma_ateret_torah",
+            rust_calendar.get_sof_zman_shma_ateret_torah(),
+            java_calendar.get_sof_zman_shma_ateret_torah(),
+        ),
+        (
+            "get_sof_zman_tfila_ateret_torah",
+            rust_calendar.get_sof_zman_tfila_ateret_torah(),
+            java_calendar.get_sof_zman_tfila_ateret_torah(),
+        ),
+        (
+            "get_mincha_gedola_ateret_torah",
+            rust_calendar.get_mincha_gedola_ateret_torah(),
+            java_calendar.get_mincha_gedola_ateret_torah(),
+        ),
+        (
+            "get_mincha_ketana_ateret_torah",
+            rust_calendar.get_mincha_ketana_ateret_torah(),
+            java_calendar.get_mincha_ketana_ateret_torah(),
+        ),
+        (
+            "get_plag_hamincha_ateret_torah",
+            rust_calendar.get_plag_hamincha_ateret_torah(),
+            java_calendar.get_plag_hamincha_ateret_torah(),
+        ),
+    ];
+    for (name, result, java_result) in variants {
+        assert_almost_equal_datetime_option(
+            &result,
+            &java_result,
+            &format!("{} against java with calendar {:?}", name, rust_calendar),
+        );
+    }
+
     let result = rust_calendar.get_half_day_based_zman_from_times(start_of_half_day, end_of_half_day, hours);
     let java_result = java_calendar.get_half_day_based_zman_from_times(start_of_half_day, end_of_half_day, hours);
     assert_almost_equal_datetime_option(
@@ -795,7 +1319,14 @@ pub fn compare_jewish_calendars(
         java_calendar.get_jewish_day_of_month()
     );
     assert_eq!(rust_calendar.get_gregorian_year(), java_calendar.get_gregorian_year());
-    assert_eq!(rust_calendar.get_gregorian_month(), java_calendar.get_gregorian_month());
+    #[allow(deprecated)]
+    {
+        assert_eq!(rust_calendar.get_gregorian_month(), java_calendar.get_gregorian_month());
+    }
+    assert_eq!(
+        rust_calendar.get_gregorian_month_1_based(),
+        java_calendar.get_gregorian_month_1_based()
+    );
     assert_eq!(
         rust_calendar.get_gregorian_day_of_month(),
         java_calendar.get_gregorian_day_of_month()
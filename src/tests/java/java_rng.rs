@@ -61,8 +61,9 @@ pub fn create_zmanim_calendars<'a>(
     let use_astronomical_chatzos = rng.gen_bool(0.5);
     let use_astronomical_chatzos_for_other_zmanim = rng.gen_bool(0.5);
     let ateret_torah_sunset_offset = Duration::minutes(rng.gen_range(0..=60));
+    let use_elevation = rng.gen_bool(0.5);
 
-    let rust_calendar = ZmanimCalendar::new(
+    let mut rust_calendar = ZmanimCalendar::new(
         date_time.naive_local().date(),
         date_time.timezone(),
         geo_location.clone(),
@@ -72,6 +73,7 @@ pub fn create_zmanim_calendars<'a>(
         candle_lighting_offset,
         ateret_torah_sunset_offset,
     )?;
+    rust_calendar.set_use_elevation(use_elevation);
     let java_calendar = JavaZmanimCalendar::new(
         jvm,
         date_time,
@@ -81,6 +83,7 @@ pub fn create_zmanim_calendars<'a>(
         use_astronomical_chatzos,
         use_astronomical_chatzos_for_other_zmanim,
         ateret_torah_sunset_offset,
+        use_elevation,
     );
 
     Some((rust_calendar, java_calendar))
@@ -105,8 +108,9 @@ pub fn create_zmanim_calendars_naive<'a>(
     let use_astronomical_chatzos = rng.gen_bool(0.5);
     let use_astronomical_chatzos_for_other_zmanim = rng.gen_bool(0.5);
     let ateret_torah_sunset_offset = Duration::minutes(rng.gen_range(0..=60));
+    let use_elevation = rng.gen_bool(0.5);
 
-    let rust_calendar = ZmanimCalendar::naive(
+    let mut rust_calendar = ZmanimCalendar::naive(
         date_time.naive_local().date(),
         geo_location.clone(),
         NOAACalculator,
@@ -115,6 +119,7 @@ pub fn create_zmanim_calendars_naive<'a>(
         candle_lighting_offset,
         ateret_torah_sunset_offset,
     )?;
+    rust_calendar.set_use_elevation(use_elevation);
     let java_calendar = JavaZmanimCalendar::new(
         jvm,
         date_time,
@@ -124,6 +129,7 @@ pub fn create_zmanim_calendars_naive<'a>(
         use_astronomical_chatzos,
         use_astronomical_chatzos_for_other_zmanim,
         ateret_torah_sunset_offset,
+        use_elevation,
     );
 
     Some((rust_calendar, java_calendar))
@@ -272,12 +272,21 @@ mod java_tests {
                     iteration
                 );
                 eprintln!("Calendar configuration:");
-                eprintln!("  date_time: {:?}", calendar.date_time);
+                eprintln!("  date_time: {:?}", calendar.get_date_time());
                 eprintln!("  timezone: {}", timezone_id);
-                eprintln!("  geo_location.latitude: {}", calendar.geo_location.get_latitude());
-                eprintln!("  geo_location.longitude: {}", calendar.geo_location.get_longitude());
-                eprintln!("  geo_location.elevation: {}", calendar.geo_location.get_elevation());
-                eprintln!("  use_astronomical_chatzos: {}", calendar.use_astronomical_chatzos);
+                eprintln!(
+                    "  geo_location.latitude: {}",
+                    calendar.get_geo_location().get_latitude()
+                );
+                eprintln!(
+                    "  geo_location.longitude: {}",
+                    calendar.get_geo_location().get_longitude()
+                );
+                eprintln!(
+                    "  geo_location.elevation: {}",
+                    calendar.get_geo_location().get_elevation()
+                );
+                eprintln!("  use_astronomical_chatzos: {}", calendar.use_astronomical_chatzos());
                 eprintln!(
                     "  use_astronomical_chatzos_for_other_zmanim: {}",
                     calendar.use_astronomical_chatzos_for_other_zmanim
@@ -318,30 +327,32 @@ mod java_tests {
                 eprintln!("    let timezone_id = \"{}\";", timezone_id);
                 eprintln!(
                     "    let geo_location = GeoLocation::new({}, {}, {}).unwrap();",
-                    calendar.geo_location.get_latitude(),
-                    calendar.geo_location.get_longitude(),
-                    calendar.geo_location.get_elevation()
+                    calendar.get_geo_location().get_latitude(),
+                    calendar.get_geo_location().get_longitude(),
+                    calendar.get_geo_location().get_elevation()
                 );
                 eprintln!(
                     "    let date = NaiveDate::from_ymd_opt({}, {}, {}).unwrap();",
-                    calendar.date_time.year(),
-                    calendar.date_time.month(),
-                    calendar.date_time.day()
+                    calendar.get_date_time().year(),
+                    calendar.get_date_time().month(),
+                    calendar.get_date_time().day()
                 );
                 eprintln!("    let date_time = tz.from_local_datetime(&date.and_hms_opt(0, 0, 0).unwrap()).unwrap();");
                 eprintln!(
-                    "    let calendar = ZmanimCalendar::new(date, tz, geo_location.clone(), NOAACalculator, {}, {}, Duration::seconds({}), Duration::seconds({})).unwrap();",
-                    calendar.use_astronomical_chatzos,
+                    "    let mut calendar = ZmanimCalendar::new(date, tz, geo_location.clone(), NOAACalculator, {}, {}, Duration::seconds({}), Duration::seconds({})).unwrap();",
+                    calendar.use_astronomical_chatzos(),
                     calendar.use_astronomical_chatzos_for_other_zmanim,
                     calendar.candle_lighting_offset.num_seconds(),
                     calendar.ateret_torah_sunset_offset.num_seconds()
                 );
+                eprintln!("    calendar.set_use_elevation({});", calendar.use_elevation());
                 eprintln!(
-                    "    let java_calendar = JavaZmanimCalendar::new(&jvm, date_time, timezone_id, geo_location, Duration::seconds({}), {}, {}, Duration::seconds({}));",
+                    "    let java_calendar = JavaZmanimCalendar::new(&jvm, date_time, timezone_id, geo_location, Duration::seconds({}), {}, {}, Duration::seconds({}), {});",
                     calendar.candle_lighting_offset.num_seconds(),
-                    calendar.use_astronomical_chatzos,
+                    calendar.use_astronomical_chatzos(),
                     calendar.use_astronomical_chatzos_for_other_zmanim,
-                    calendar.ateret_torah_sunset_offset.num_seconds()
+                    calendar.ateret_torah_sunset_offset.num_seconds(),
+                    calendar.use_elevation()
                 );
 
                 // Generate code for all the test parameters
@@ -498,23 +509,23 @@ mod java_tests {
                     iteration
                 );
                 eprintln!("Calendar configuration:");
-                eprintln!("  date_time: {:?}", calendar_naive.date_time);
+                eprintln!("  date_time: {:?}", calendar_naive.get_date_time());
                 eprintln!("  timezone: UTC (naive)");
                 eprintln!(
                     "  geo_location.latitude: {}",
-                    calendar_naive.geo_location.get_latitude()
+                    calendar_naive.get_geo_location().get_latitude()
                 );
                 eprintln!(
                     "  geo_location.longitude: {}",
-                    calendar_naive.geo_location.get_longitude()
+                    calendar_naive.get_geo_location().get_longitude()
                 );
                 eprintln!(
                     "  geo_location.elevation: {}",
-                    calendar_naive.geo_location.get_elevation()
+                    calendar_naive.get_geo_location().get_elevation()
                 );
                 eprintln!(
                     "  use_astronomical_chatzos: {}",
-                    calendar_naive.use_astronomical_chatzos
+                    calendar_naive.use_astronomical_chatzos()
                 );
                 eprintln!(
                     "  use_astronomical_chatzos_for_other_zmanim: {}",
@@ -0,0 +1,744 @@
+use chrono::Weekday;
+
+use crate::prelude::*;
+
+fn hebrew(year: i32, month: JewishMonth, day: u8) -> JewishCalendar<NOAACalculator> {
+    JewishCalendar::from_hebrew_date(year, month, day, false, false, false, NOAACalculator).unwrap()
+}
+
+#[test]
+#[allow(deprecated)]
+fn test_erev_pesach_on_shabbos() {
+    // Erev Pesach 5785 fell on Shabbos, April 12 2025.
+    let calendar = hebrew(5785, JewishMonth::Tishrei, 1);
+    assert!(calendar.is_erev_pesach_on_shabbos());
+    let biur = calendar.get_biur_chametz_date().unwrap();
+    assert_eq!(biur.get_day_of_week(), Weekday::Fri);
+    assert_eq!((biur.get_gregorian_month(), biur.get_gregorian_day_of_month()), (3, 11));
+    let taanis_bechoros = calendar.get_taanis_bechoros_date().unwrap();
+    assert_eq!(taanis_bechoros.get_day_of_week(), Weekday::Thu);
+    assert!(taanis_bechoros.is_taanis_bechoros());
+    assert!(hebrew(5785, JewishMonth::Nissan, 14).is_shabbos_hagadol_erev_pesach());
+
+    let calendar = hebrew(5786, JewishMonth::Tishrei, 1);
+    assert!(!calendar.is_erev_pesach_on_shabbos());
+    assert_eq!(
+        calendar.get_biur_chametz_date().unwrap(),
+        hebrew(5786, JewishMonth::Nissan, 14)
+    );
+}
+
+#[test]
+#[allow(deprecated)]
+fn test_gregorian_month_1_based() {
+    let biur = hebrew(5785, JewishMonth::Tishrei, 1).get_biur_chametz_date().unwrap();
+    assert_eq!(biur.get_gregorian_month_1_based(), 4);
+    assert_eq!(biur.get_gregorian_month_1_based(), biur.get_gregorian_month() + 1);
+    assert_eq!(hebrew(5785, JewishMonth::Teves, 1).get_gregorian_month_1_based(), 1);
+    assert_eq!(hebrew(5785, JewishMonth::Kislev, 29).get_gregorian_month_1_based(), 12);
+}
+
+#[test]
+fn test_get_holidays() {
+    let rosh_chodesh_teves = hebrew(5785, JewishMonth::Teves, 1);
+    assert!(
+        rosh_chodesh_teves
+            .get_holidays()
+            .eq([JewishHoliday::Chanukah, JewishHoliday::RoshChodesh])
+    );
+    assert_eq!(hebrew(5785, JewishMonth::Teves, 12).get_holidays().next(), None);
+}
+
+#[test]
+fn test_adar_anniversaries() {
+    // 5785 is a common year, 5784 and 5787 are leap years.
+    let purim = hebrew(5785, JewishMonth::Adar, 14);
+    let dates = purim.get_birthday_in_year(5787, AdarPolicy::AdarII).unwrap();
+    assert_eq!(dates.first, hebrew(5787, JewishMonth::AdarII, 14));
+    assert_eq!(dates.second, None);
+    let dates = purim.get_birthday_in_year(5787, AdarPolicy::AdarI).unwrap();
+    assert_eq!(dates.first, hebrew(5787, JewishMonth::Adar, 14));
+    let dates = purim.get_yahrzeit_in_year(5787, AdarPolicy::Both).unwrap();
+    assert_eq!(dates.iter().count(), 2);
+    assert_eq!(dates.second, Some(hebrew(5787, JewishMonth::AdarII, 14)));
+
+    let adar_ii = hebrew(5784, JewishMonth::AdarII, 10);
+    let dates = adar_ii.get_birthday_in_year(5785, AdarPolicy::AdarI).unwrap();
+    assert_eq!(dates.first, hebrew(5785, JewishMonth::Adar, 10));
+
+    let adar_i_30 = hebrew(5784, JewishMonth::Adar, 30);
+    let dates = adar_i_30.get_birthday_in_year(5785, AdarPolicy::default()).unwrap();
+    assert_eq!(dates.first, hebrew(5785, JewishMonth::Nissan, 1));
+    let dates = adar_i_30.get_yahrzeit_in_year(5785, AdarPolicy::default()).unwrap();
+    assert_eq!(dates.first, hebrew(5785, JewishMonth::Shevat, 30));
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn test_adar_candidates() {
+    let purim = hebrew(5785, JewishMonth::Adar, 14);
+    let candidates = purim.get_adar_candidates(5787, AdarEvent::Purim).unwrap();
+    assert_eq!(candidates.adar_i.get_yom_tov_index(), Some(JewishHoliday::PurimKatan));
+    assert_eq!(candidates.adar_ii, Some(hebrew(5787, JewishMonth::AdarII, 14)));
+    let observed: Vec<_> = candidates.observed().collect();
+    assert_eq!(observed, [&hebrew(5787, JewishMonth::AdarII, 14)]);
+    assert_eq!(candidates.observed_with(AdarPolicy::Both).count(), 2);
+
+    let seven_adar = hebrew(5787, JewishMonth::AdarII, 7);
+    let candidates = seven_adar.get_adar_candidates(5787, AdarEvent::SevenAdar).unwrap();
+    assert_eq!(candidates.policy, AdarPolicy::AdarI);
+    let observed: Vec<_> = candidates.observed().collect();
+    assert_eq!(observed, [&hebrew(5787, JewishMonth::Adar, 7)]);
+    let candidates = seven_adar.get_adar_candidates(5785, AdarEvent::Yahrzeit).unwrap();
+    assert_eq!(candidates.adar_ii, None);
+    assert_eq!(candidates.observed_with(AdarPolicy::AdarII).count(), 1);
+
+    assert!(
+        hebrew(5785, JewishMonth::Nissan, 7)
+            .get_adar_candidates(5787, AdarEvent::Birthday)
+            .is_none()
+    );
+}
+
+#[test]
+fn test_event_date_time_after_sunset() {
+    use chrono::TimeZone;
+
+    let jerusalem = GeoLocation::new(31.778, 35.2354, 0.0).unwrap();
+    let tz = chrono_tz::Asia::Jerusalem;
+    // 13 Adar 5785 was Thursday, March 13 2025.
+    let noon = tz.with_ymd_and_hms(2025, 3, 13, 12, 0, 0).unwrap();
+    let evening = tz.with_ymd_and_hms(2025, 3, 13, 20, 0, 0).unwrap();
+    let calendar = JewishCalendar::from_event_date_time(&noon, &jerusalem, true, false, false, NOAACalculator).unwrap();
+    assert_eq!(calendar.get_jewish_day_of_month(), 13);
+    let calendar =
+        JewishCalendar::from_event_date_time(&evening, &jerusalem, true, false, false, NOAACalculator).unwrap();
+    assert_eq!(calendar.get_jewish_month(), JewishMonth::Adar);
+    assert_eq!(calendar.get_jewish_day_of_month(), 14);
+}
+
+#[test]
+fn test_halachic_day_boundaries() {
+    use chrono::TimeZone;
+
+    let jerusalem = GeoLocation::new(31.778, 35.2354, 0.0).unwrap();
+    // Between sunset (~17:47) and tzais (~18:20) in Jerusalem.
+    let bein_hashmashos = chrono_tz::Asia::Jerusalem
+        .with_ymd_and_hms(2025, 3, 13, 18, 0, 0)
+        .unwrap();
+    let day = HalachicDay::new(
+        bein_hashmashos,
+        &jerusalem,
+        DayBoundary::Sunset,
+        true,
+        false,
+        false,
+        NOAACalculator,
+    )
+    .unwrap();
+    assert!(day.is_evening);
+    assert_eq!(day.jewish_calendar.get_jewish_day_of_month(), 14);
+    let day = HalachicDay::new(
+        bein_hashmashos,
+        &jerusalem,
+        DayBoundary::Tzais,
+        true,
+        false,
+        false,
+        NOAACalculator,
+    )
+    .unwrap();
+    assert!(!day.is_evening);
+    assert_eq!(day.jewish_calendar.get_jewish_day_of_month(), 13);
+}
+
+#[test]
+fn test_pirkei_avos_cycle_ends_before_rosh_hashana() {
+    for year in 5780..5800 {
+        for in_israel in [false, true] {
+            let mut day = JewishCalendar::from_hebrew_date(
+                year,
+                JewishMonth::Nissan,
+                15,
+                in_israel,
+                false,
+                false,
+                NOAACalculator,
+            )
+            .unwrap();
+            let mut previous: Option<ChapterRange> = None;
+            while day.get_jewish_year() == year {
+                if let Some(portion) = day.get_pirkei_avos() {
+                    let expected_first = previous.map_or(1, |previous| previous.last_chapter % 6 + 1);
+                    assert_eq!(portion.first_chapter, expected_first, "year: {}", year);
+                    previous = Some(portion);
+                }
+                day = day.copy_with_days_added(1).unwrap();
+            }
+            assert_eq!(previous.unwrap().last_chapter, 6, "year: {}", year);
+        }
+    }
+}
+
+#[test]
+fn test_tehillim_monthly() {
+    let portion = hebrew(5785, JewishMonth::Tishrei, 25).get_tehillim_monthly();
+    assert_eq!((portion.first_chapter, portion.last_chapter), (119, 119));
+    // Cheshvan 5785 has 29 days.
+    let portion = hebrew(5785, JewishMonth::Cheshvan, 29).get_tehillim_monthly();
+    assert_eq!((portion.first_chapter, portion.last_chapter), (140, 150));
+    let portion = hebrew(5785, JewishMonth::Tishrei, 29).get_tehillim_monthly();
+    assert_eq!((portion.first_chapter, portion.last_chapter), (140, 144));
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn test_molad_data_arithmetic() {
+    let molad = MoladData {
+        hours: 9,
+        minutes: 14,
+        chalakim: 3,
+    };
+    assert_eq!(molad.to_string(), "9h 14m 3cp");
+    assert_eq!(molad.total_chalakim(), 15 * 1080 + 14 * 18 + 3);
+    assert_eq!(
+        MoladData::from_total_chalakim(molad.total_chalakim()),
+        Some(molad.clone())
+    );
+    assert_eq!(
+        molad.duration_since_day_start().unwrap().num_seconds(),
+        molad.total_chalakim() * 10 / 3
+    );
+    assert_eq!(MoladData::from_total_chalakim(25920), None);
+
+    let mut calendar = hebrew(5784, JewishMonth::Tishrei, 1);
+    let first = calendar.get_molad().unwrap();
+    for months in 1..40 {
+        calendar = calendar.copy_with_days_added(30).unwrap();
+        let expected = calendar.get_molad().unwrap();
+        let elapsed = calendar.get_chalakim_since_molad_tohu()
+            - hebrew(5784, JewishMonth::Tishrei, 1).get_chalakim_since_molad_tohu();
+        assert_eq!(elapsed % 765433, 0);
+        assert_eq!(first.add_months(elapsed / 765433), Some(expected), "months: {}", months);
+    }
+    assert_eq!(first.add_months(i64::MAX), None);
+}
+
+#[test]
+fn test_vesein_tal_umatar_start_date() {
+    let calendar = hebrew(5785, JewishMonth::Tishrei, 1);
+    for (year, day) in [(2022, 5), (2023, 6), (2024, 5), (2025, 5), (2027, 6)] {
+        let start = calendar
+            .get_vesein_tal_umatar_start_date_in_gregorian_year(year)
+            .unwrap();
+        assert_eq!(
+            start,
+            chrono::NaiveDate::from_ymd_opt(year, 12, day).unwrap(),
+            "year: {}",
+            year
+        );
+    }
+    let start = calendar.get_vesein_tal_umatar_start_date().unwrap();
+    assert!(start.is_vesein_tal_umatar_start_date());
+    assert!(!start.copy_with_days_added(-1).unwrap().is_vesein_tal_umatar_recited());
+}
+
+#[test]
+fn test_birkas_hachamah() {
+    let calendar = JewishCalendar::from_gregorian_date(2010, 1, 1, false, false, false, NOAACalculator).unwrap();
+    let next = calendar.get_next_birkas_hachamah().unwrap();
+    assert!(next.is_birkas_hachamah());
+    assert_eq!(
+        next.get_gregorian_naive_date(),
+        chrono::NaiveDate::from_ymd_opt(2037, 4, 8)
+    );
+
+    let jerusalem = GeoLocation::new(31.778, 35.2354, 0.0).unwrap();
+    let start = chrono::NaiveDate::from_ymd_opt(2009, 4, 8).unwrap();
+    let times = BirkasHachamah::next_after(start, chrono_tz::Asia::Jerusalem, &jerusalem, NOAACalculator).unwrap();
+    assert_eq!(times.date, start);
+    assert_eq!(times.sunrise.date_naive(), start);
+    assert!(times.sunrise < times.chatzos);
+}
+
+#[test]
+fn test_recurrence_occurrences() {
+    // 5784 is a leap year, running from October 2023 to October 2024.
+    let start = hebrew(5784, JewishMonth::Tishrei, 1);
+    let end = hebrew(5784, JewishMonth::Elul, 29).get_gregorian_naive_date().unwrap();
+    let seventh_of_adar = |adar_policy| Recurrence::HebrewDate {
+        month: JewishMonth::Adar,
+        day: 7,
+        adar_policy,
+    };
+    let mut occurrences = seventh_of_adar(AdarPolicy::AdarII).occurrences(&start, end);
+    assert_eq!(occurrences.next(), Some(hebrew(5784, JewishMonth::AdarII, 7)));
+    assert_eq!(occurrences.next(), None);
+    assert_eq!(seventh_of_adar(AdarPolicy::Both).occurrences(&start, end).count(), 2);
+
+    // The first night of Chanukah 5785 was the evening of December 25 2024.
+    let start = hebrew(5785, JewishMonth::Kislev, 1);
+    let end = start
+        .copy_with_days_added(60)
+        .unwrap()
+        .get_gregorian_naive_date()
+        .unwrap();
+    let first_night = Recurrence::NightOfChanukah(1).occurrences(&start, end).next().unwrap();
+    assert_eq!(
+        first_night.get_gregorian_naive_date(),
+        chrono::NaiveDate::from_ymd_opt(2024, 12, 25)
+    );
+    assert_eq!(Recurrence::Weekly(Weekday::Thu).occurrences(&start, end).count(), 9);
+
+    let start = hebrew(5785, JewishMonth::Tishrei, 2);
+    let end = hebrew(5785, JewishMonth::Cheshvan, 1)
+        .get_gregorian_naive_date()
+        .unwrap();
+    assert_eq!(Recurrence::RoshChodesh.occurrences(&start, end).count(), 2);
+}
+
+#[test]
+fn test_tomorrow_queries() {
+    let erev_rosh_chodesh = hebrew(5785, JewishMonth::Tishrei, 29);
+    assert!(erev_rosh_chodesh.is_tomorrow_rosh_chodesh());
+    assert_eq!(
+        erev_rosh_chodesh.tomorrow(),
+        Some(hebrew(5785, JewishMonth::Tishrei, 30))
+    );
+    let erev_pesach = hebrew(5785, JewishMonth::Nissan, 14);
+    assert_eq!(erev_pesach.get_yom_tov_index_tomorrow(), Some(JewishHoliday::Pesach));
+    assert!(erev_pesach.is_tomorrow_yom_tov() && erev_pesach.is_tomorrow_assur_bemelacha());
+    assert!(!hebrew(5785, JewishMonth::Nissan, 15).is_tomorrow_rosh_chodesh());
+    assert_eq!(
+        hebrew(5785, JewishMonth::Kislev, 24).get_day_of_chanukah_tomorrow(),
+        Some(1)
+    );
+    assert_eq!(
+        hebrew(5785, JewishMonth::Nissan, 15).get_day_of_omer_tomorrow(),
+        Some(1)
+    );
+    assert!(hebrew(5784, JewishMonth::Av, 8).is_tomorrow_taanis());
+}
+
+#[test]
+fn test_be_hab_rules() {
+    for year in 5780..5800 {
+        for month in [JewishMonth::Cheshvan, JewishMonth::Iyar] {
+            let days = hebrew(year, month, 1)
+                .get_be_hab_days(BehabRule::AfterFirstShabbos)
+                .unwrap();
+            for day in 1..=29 {
+                let date = hebrew(year, month, day);
+                assert_eq!(date.is_be_hab_with(BehabRule::AfterFirstShabbos), date.is_be_hab());
+                assert_eq!(
+                    date.is_shabbos_before_be_hab(BehabRule::AfterFirstShabbos),
+                    date.copy_with_days_added(2).as_ref() == Some(&days[0])
+                );
+            }
+        }
+    }
+    // Rosh Chodesh Cheshvan 5785 ended on Shabbos, November 2 2024.
+    let days = hebrew(5785, JewishMonth::Cheshvan, 10)
+        .get_be_hab_days(BehabRule::FirstMondayAfterRoshChodesh)
+        .unwrap();
+    assert_eq!(days.map(|day| day.get_jewish_day_of_month()), [3, 6, 10]);
+    assert!(
+        hebrew(5785, JewishMonth::Kislev, 10)
+            .get_be_hab_days(BehabRule::default())
+            .is_none()
+    );
+}
+
+#[test]
+fn test_month_being_blessed() {
+    // Shabbos Mevorchim for Adar 5785 was 24 Shevat, February 22 2025.
+    let shabbos = hebrew(5785, JewishMonth::Shevat, 24);
+    assert!(shabbos.is_shabbos_mevorchim());
+    assert_eq!(shabbos.get_month_being_blessed(), Some(JewishMonth::Adar));
+    assert_eq!(
+        shabbos.get_molad_of_month_being_blessed(),
+        hebrew(5785, JewishMonth::Adar, 1).get_molad()
+    );
+    assert!(shabbos.get_molad_date_of_month_being_blessed().is_some());
+    assert_eq!(hebrew(5785, JewishMonth::Shevat, 23).get_month_being_blessed(), None);
+}
+
+#[test]
+fn test_yom_kippur_katan_observance() {
+    let rules = TefilaRules::new(
+        false, false, false, false, false, false, true, true, true, true, false, true, true,
+    );
+    let practice = YomKippurKatanPractice::default();
+    // 29 Tammuz 5784 was a Sunday.
+    let observance = rules
+        .get_yom_kippur_katan_observance(&hebrew(5784, JewishMonth::Tammuz, 29), &practice)
+        .unwrap();
+    assert!(observance.fast);
+    assert!(observance.mincha_with_selichos);
+
+    let without_selichos = YomKippurKatanPractice {
+        fast: false,
+        mincha_with_selichos: false,
+    };
+    let observance = rules
+        .get_yom_kippur_katan_observance(&hebrew(5784, JewishMonth::Tammuz, 29), &without_selichos)
+        .unwrap();
+    assert!(!observance.fast);
+    assert!(!observance.mincha_with_selichos);
+
+    assert!(
+        rules
+            .get_yom_kippur_katan_observance(&hebrew(5784, JewishMonth::Tammuz, 28), &practice)
+            .is_none()
+    );
+}
+
+#[test]
+fn test_rosh_chodesh_days() {
+    use chrono::NaiveDate;
+
+    let date = hebrew(5785, JewishMonth::Tishrei, 20);
+    let cheshvan = date.get_rosh_chodesh_days(JewishMonth::Cheshvan).unwrap();
+    assert!(cheshvan.is_two_days());
+    assert_eq!(cheshvan.first, NaiveDate::from_ymd_opt(2024, 11, 1).unwrap());
+    assert_eq!(cheshvan.last(), NaiveDate::from_ymd_opt(2024, 11, 2).unwrap());
+
+    let nissan = date.get_rosh_chodesh_days(JewishMonth::Nissan).unwrap();
+    assert!(!nissan.is_two_days());
+    assert_eq!(nissan.first, NaiveDate::from_ymd_opt(2025, 3, 30).unwrap());
+
+    // Once Rosh Chodesh has passed, the following year's is returned.
+    let date = hebrew(5785, JewishMonth::Cheshvan, 2);
+    let cheshvan = date.get_rosh_chodesh_days(JewishMonth::Cheshvan).unwrap();
+    assert_eq!(cheshvan.first, NaiveDate::from_ymd_opt(2025, 10, 22).unwrap());
+
+    assert_eq!(date.get_rosh_chodesh_days(JewishMonth::Tishrei), None);
+}
+
+#[test]
+fn test_omer_counter() {
+    let mut counter = OmerCounter::new();
+    assert_eq!(counter.count(1, OmerCountTime::Night), Some(true));
+    // Forgot at night, counted during the day.
+    assert_eq!(counter.count(2, OmerCountTime::Day), Some(false));
+    assert_eq!(counter.count(3, OmerCountTime::Night), Some(true));
+    assert_eq!(counter.count(3, OmerCountTime::Night), None);
+    assert!(!counter.is_broken());
+
+    // Day 4 missed entirely.
+    assert!(!counter.is_bracha_recited(5));
+    assert_eq!(counter.count(5, OmerCountTime::Night), Some(false));
+    assert!(counter.is_broken());
+    assert_eq!(counter.count(6, OmerCountTime::Night), Some(false));
+
+    let date = hebrew(5785, JewishMonth::Nissan, 20);
+    let counter = OmerCounter::resume(date.get_day_of_omer().unwrap(), false).unwrap();
+    assert!(counter.is_bracha_recited(date.get_day_of_omer_tomorrow().unwrap()));
+    assert_eq!(OmerCounter::resume(50, false), None);
+}
+
+#[test]
+fn test_omer_day_to_count() {
+    use chrono::TimeZone;
+
+    let jerusalem = GeoLocation::new(31.778, 35.2354, 0.0).unwrap();
+    let at = |hour| {
+        chrono_tz::Asia::Jerusalem
+            .with_ymd_and_hms(2025, 4, 20, hour, 0, 0)
+            .unwrap()
+    };
+    // 22 Nissan 5785, the 7th day. Sunset is ~19:13, tzais ~19:48 and tzais 72 ~20:25.
+    let count = |hour, nightfall| get_omer_day_to_count(at(hour), &jerusalem, nightfall, true, NOAACalculator);
+    assert_eq!(count(12, DayBoundary::Tzais), Some(7));
+    assert_eq!(count(21, DayBoundary::Tzais), Some(8));
+    assert_eq!(count(20, DayBoundary::Tzais), Some(8));
+    assert_eq!(count(20, DayBoundary::Zman(Zman::Tzais72)), Some(7));
+}
+
+#[test]
+fn test_mourning_periods() {
+    assert!(!hebrew(5784, JewishMonth::Tammuz, 16).is_bein_hametzarim());
+    assert!(hebrew(5784, JewishMonth::Tammuz, 17).is_bein_hametzarim());
+    assert!(!hebrew(5784, JewishMonth::Tammuz, 29).is_nine_days());
+    assert!(hebrew(5784, JewishMonth::Av, 1).is_nine_days());
+    assert!(hebrew(5784, JewishMonth::Av, 9).is_nine_days());
+    assert!(!hebrew(5784, JewishMonth::Av, 10).is_bein_hametzarim());
+
+    // Tisha B'Av 5784 was on Tuesday.
+    assert!(!hebrew(5784, JewishMonth::Av, 6).is_shavua_shechal_bo());
+    assert!(hebrew(5784, JewishMonth::Av, 7).is_shavua_shechal_bo());
+    assert!(hebrew(5784, JewishMonth::Av, 8).is_shavua_shechal_bo());
+    assert!(!hebrew(5784, JewishMonth::Av, 9).is_shavua_shechal_bo());
+
+    // 9 Av 5785 was on Shabbos, so the fast was postponed to Sunday.
+    assert!(hebrew(5785, JewishMonth::Av, 10).is_nine_days());
+    assert!(!hebrew(5785, JewishMonth::Av, 8).is_shavua_shechal_bo());
+
+    let lag_baomer = hebrew(5785, JewishMonth::Iyar, 18);
+    assert!(!lag_baomer.is_sefirah_mourning(SefirahMinhag::UntilLagBaOmer));
+    assert!(!lag_baomer.is_sefirah_mourning(SefirahMinhag::EntireSefirah));
+    assert!(hebrew(5785, JewishMonth::Iyar, 17).is_sefirah_mourning(SefirahMinhag::UntilLagBaOmer));
+    assert!(!hebrew(5785, JewishMonth::Nissan, 20).is_sefirah_mourning(SefirahMinhag::FromRoshChodeshIyar));
+    assert!(hebrew(5785, JewishMonth::Iyar, 25).is_sefirah_mourning(SefirahMinhag::FromRoshChodeshIyar));
+    assert!(!hebrew(5785, JewishMonth::Iyar, 25).is_sefirah_mourning(SefirahMinhag::UntilLagBaOmer));
+}
+
+#[test]
+fn test_aseres_yemei_teshuva() {
+    use chrono::NaiveDate;
+
+    // 3 Tishrei 5785 was on Shabbos.
+    let tzom_gedalyah = hebrew(5785, JewishMonth::Tishrei, 1).get_tzom_gedalyah_date().unwrap();
+    assert_eq!(tzom_gedalyah.get_jewish_day_of_month(), 4);
+    assert!(tzom_gedalyah.is_tzom_gedalyah());
+    assert!(hebrew(5785, JewishMonth::Tishrei, 3).is_shabbos_shuva());
+    assert!(hebrew(5785, JewishMonth::Tishrei, 9).is_erev_yom_kippur());
+
+    // Rosh Hashana 5785 was on Thursday and 5786 on Tuesday.
+    let selichos = hebrew(5784, JewishMonth::Av, 1).get_selichos_start_date().unwrap();
+    assert_eq!(
+        selichos.get_gregorian_naive_date(),
+        NaiveDate::from_ymd_opt(2024, 9, 28)
+    );
+    let selichos = hebrew(5785, JewishMonth::Av, 1).get_selichos_start_date().unwrap();
+    assert_eq!(selichos.get_day_of_week(), Weekday::Sat);
+    assert_eq!(
+        selichos.get_gregorian_naive_date(),
+        NaiveDate::from_ymd_opt(2025, 9, 13)
+    );
+}
+
+#[test]
+fn test_daf_yomi_siyum() {
+    use chrono::NaiveDate;
+
+    let date = JewishCalendar::from_gregorian_date(2020, 2, 1, false, false, false, NOAACalculator).unwrap();
+    let berachos = date.get_daf_yomi_bavli_masechta_siyum().unwrap();
+    assert_eq!(berachos.tractate, BavliTractate::Berachos);
+    assert_eq!(berachos.date, NaiveDate::from_ymd_opt(2020, 3, 7).unwrap());
+    assert_eq!(berachos.days_until, 35);
+    let shas = date.get_daf_yomi_bavli_cycle_siyum().unwrap();
+    assert_eq!(shas.tractate, BavliTractate::Niddah);
+    assert_eq!(shas.date, NaiveDate::from_ymd_opt(2027, 6, 7).unwrap());
+
+    let masechta = date.get_daf_yomi_yerushalmi_masechta_siyum().unwrap();
+    let cycle = date.get_daf_yomi_yerushalmi_cycle_siyum().unwrap();
+    assert_eq!(masechta.tractate, date.get_daf_yomi_yerushalmi().unwrap().tractate);
+    assert!(masechta.days_until >= 0 && masechta.date <= cycle.date);
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn test_scheduled_learning() {
+    let entries = [
+        (JewishMonth::Adar, 1, "first of Adar"),
+        (JewishMonth::Adar, 29, "29 Adar"),
+        (JewishMonth::Adar, 30, "30 Adar"),
+        (JewishMonth::Nissan, 1, "first of Nissan"),
+    ];
+    let schedule = StudySchedule {
+        entries: &entries,
+        leap_year_entries: None,
+        adar_policy: AdarPolicy::AdarII,
+    };
+    let portions = |date: JewishCalendar<NOAACalculator>, schedule: &StudySchedule<&str>| {
+        date.get_scheduled_learning(schedule).copied().collect::<Vec<_>>()
+    };
+    assert_eq!(
+        portions(hebrew(5785, JewishMonth::Nissan, 1), &schedule),
+        ["first of Nissan"]
+    );
+    assert!(portions(hebrew(5785, JewishMonth::Nissan, 2), &schedule).is_empty());
+    // Adar always has 29 days.
+    assert_eq!(
+        portions(hebrew(5785, JewishMonth::Adar, 29), &schedule),
+        ["29 Adar", "30 Adar"]
+    );
+    // 5784 is a leap year.
+    assert!(portions(hebrew(5784, JewishMonth::Adar, 1), &schedule).is_empty());
+    assert_eq!(
+        portions(hebrew(5784, JewishMonth::AdarII, 1), &schedule),
+        ["first of Adar"]
+    );
+    let both = StudySchedule {
+        adar_policy: AdarPolicy::Both,
+        ..schedule
+    };
+    assert_eq!(portions(hebrew(5784, JewishMonth::Adar, 1), &both), ["first of Adar"]);
+    let leap_year_entries = [(JewishMonth::AdarII, 1, "first of Adar II")];
+    let leap = StudySchedule {
+        leap_year_entries: Some(&leap_year_entries),
+        ..schedule
+    };
+    assert_eq!(
+        portions(hebrew(5784, JewishMonth::AdarII, 1), &leap),
+        ["first of Adar II"]
+    );
+    assert_eq!(portions(hebrew(5785, JewishMonth::Adar, 1), &leap), ["first of Adar"]);
+}
+
+#[test]
+#[cfg(all(feature = "std", feature = "names"))]
+fn test_full_date() {
+    use crate::full_date::{FullDateLocale, FullDateOptions, HebrewNumeral, TransliterationStyle};
+
+    let calendar = hebrew(5784, JewishMonth::Nissan, 15);
+    assert_eq!(
+        calendar.format_full_date(FullDateOptions::default()).to_string(),
+        "יום שלישי, ט״ו בניסן תשפ״ד / Tuesday, Nissan 15, 5784"
+    );
+    let yiddish = FullDateOptions {
+        locale: Some(FullDateLocale::Yiddish),
+        transliteration: None,
+    };
+    assert_eq!(
+        hebrew(5784, JewishMonth::AdarII, 8)
+            .format_full_date(yiddish)
+            .to_string(),
+        "מאנטיק, ח׳ אדר שני תשפ״ד"
+    );
+    let transliterated = FullDateOptions {
+        locale: None,
+        transliteration: Some(TransliterationStyle::DayFirst),
+    };
+    assert_eq!(
+        hebrew(5785, JewishMonth::Tishrei, 1)
+            .format_full_date(transliterated)
+            .to_string(),
+        "Thursday, 1 Tishrei 5785"
+    );
+    assert_eq!(HebrewNumeral(16).to_string(), "ט״ז");
+    assert_eq!(HebrewNumeral(30).to_string(), "ל׳");
+    assert_eq!(HebrewNumeral(5770).to_string(), "תש״ע");
+}
+
+#[test]
+fn test_jewish_year_span() {
+    use chrono::NaiveDate;
+
+    use crate::jewish_year::{get_gregorian_span, get_jewish_years_in_gregorian_year, get_rosh_hashana_date};
+
+    assert_eq!(get_rosh_hashana_date(5785), NaiveDate::from_ymd_opt(2024, 10, 3));
+    let span = get_jewish_years_in_gregorian_year(2025).unwrap();
+    assert_eq!((span.first_year, span.second_year), (5785, 5786));
+    assert_eq!(Some(span.rosh_hashana), NaiveDate::from_ymd_opt(2025, 9, 23));
+    let (first, last) = get_gregorian_span(5785).unwrap();
+    assert_eq!(Some(last), NaiveDate::from_ymd_opt(2025, 9, 22));
+    assert_eq!((last - first).num_days() + 1, 355);
+    assert_eq!(
+        hebrew(5786, JewishMonth::Tishrei, 1).get_gregorian_naive_date(),
+        Some(span.rosh_hashana)
+    );
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn test_rosh_hashana_dechiyos() {
+    use crate::jewish_year::{Dechiya, get_jewish_calendar_elapsed_days, get_rosh_hashana_dechiyos};
+
+    // The molad of 5781 was on Thursday afternoon, postponed to Friday and then to Shabbos.
+    let dechiyos = get_rosh_hashana_dechiyos(5781);
+    assert_eq!(dechiyos.molad_weekday, Weekday::Thu);
+    assert_eq!(
+        dechiyos.get_dechiyos().collect::<Vec<_>>(),
+        [Dechiya::MoladZaken, Dechiya::LoAdu]
+    );
+    assert_eq!(dechiyos.get_days_postponed(), 2);
+    assert_eq!(dechiyos.rosh_hashana_day, get_jewish_calendar_elapsed_days(5781));
+    assert_eq!(hebrew(5781, JewishMonth::Tishrei, 1).get_day_of_week(), Weekday::Sat);
+
+    assert_eq!(get_rosh_hashana_dechiyos(5789).postponement, Some(Dechiya::Gatarad));
+    assert_eq!(get_rosh_hashana_dechiyos(5766).postponement, Some(Dechiya::Betutakpat));
+    let dechiyos = get_rosh_hashana_dechiyos(5782);
+    assert_eq!(dechiyos.get_dechiyos().count(), 0);
+    assert_eq!(dechiyos.molad_weekday, Weekday::Tue);
+}
+
+#[test]
+fn test_date_only_ordering() {
+    use core::cmp::Ordering;
+
+    let elul = hebrew(5784, JewishMonth::Elul, 29);
+    let tishrei = hebrew(5785, JewishMonth::Tishrei, 1);
+    let nissan = hebrew(5785, JewishMonth::Nissan, 1);
+    assert!(elul < tishrei && tishrei < nissan);
+    assert!(hebrew(5784, JewishMonth::Adar, 30) < hebrew(5784, JewishMonth::AdarII, 1));
+    let in_israel =
+        JewishCalendar::from_hebrew_date(5785, JewishMonth::Tishrei, 1, true, false, false, NOAACalculator).unwrap();
+    assert_eq!(tishrei.partial_cmp(&in_israel), None);
+    assert_eq!(tishrei.cmp_date(&in_israel), Ordering::Equal);
+    assert!(elul < in_israel);
+    let mut calendars = [nissan.clone(), in_israel, elul.clone()];
+    calendars.sort_by_key(JewishCalendar::get_date_key);
+    assert_eq!(calendars[0], elul);
+    assert_eq!(calendars[2], nissan);
+    assert!((elul.get_date_key()..nissan.get_date_key()).contains(&tishrei.get_date_key()));
+}
+
+#[test]
+#[cfg(all(feature = "std", feature = "names"))]
+fn test_parse_jewish_date() {
+    use crate::full_date::{FullDateOptions, HebrewNumeral, JewishDate};
+
+    let date = |year, month, day| Some(JewishDate { year, month, day });
+    assert_eq!(
+        JewishDate::parse("ט\"ו בשבט תשפ\"ד"),
+        date(5784, JewishMonth::Shevat, 15)
+    );
+    assert_eq!(JewishDate::parse("15 Shevat 5784"), date(5784, JewishMonth::Shevat, 15));
+    assert_eq!(
+        JewishDate::parse("Sh'vat 15, 5784"),
+        date(5784, JewishMonth::Shevat, 15)
+    );
+    assert_eq!(JewishDate::parse("1 tevet 5785"), date(5785, JewishMonth::Teves, 1));
+    assert_eq!(JewishDate::parse("ב׳ מנחם אב ה׳תשפ״ד"), date(5784, JewishMonth::Av, 2));
+    assert_eq!(JewishDate::parse("ח׳ אדר ב׳ תשפ״ד"), date(5784, JewishMonth::AdarII, 8));
+    assert_eq!(JewishDate::parse("Adar 2, 5784"), date(5784, JewishMonth::Adar, 2));
+    // Adar II only occurs in a leap year, and Iyar has 29 days.
+    assert_eq!(JewishDate::parse("8 Adar II 5785"), None);
+    assert_eq!(JewishDate::parse("ל׳ באייר תשפ״ד"), None);
+    assert_eq!(JewishDate::parse("15 Shevat"), None);
+    assert_eq!(JewishDate::parse("15 Shevat 5784 Nissan"), None);
+    assert_eq!(HebrewNumeral::parse("שבט"), None);
+
+    for calendar in [
+        hebrew(5784, JewishMonth::Nissan, 15),
+        hebrew(5784, JewishMonth::AdarII, 8),
+        hebrew(5785, JewishMonth::Cheshvan, 3),
+    ] {
+        let text = calendar.format_full_date(FullDateOptions::default()).to_string();
+        let parsed = JewishDate::parse(&text).unwrap();
+        assert_eq!(
+            (parsed.year, parsed.month, parsed.day),
+            (
+                calendar.get_jewish_year(),
+                calendar.get_jewish_month(),
+                calendar.get_jewish_day_of_month()
+            )
+        );
+    }
+}
+
+#[test]
+fn test_supported_jewish_years() {
+    use crate::jewish_year::{
+        MAX_JEWISH_YEAR, MIN_JEWISH_YEAR, get_gregorian_span, get_jewish_years_in_gregorian_year, get_rosh_hashana_date,
+    };
+
+    let from_hebrew =
+        |year| JewishCalendar::from_hebrew_date(year, JewishMonth::Tishrei, 1, false, false, false, NOAACalculator);
+    let from_gregorian = |year| JewishCalendar::from_gregorian_date(year, 1, 1, false, false, false, NOAACalculator);
+    assert!(from_hebrew(MIN_JEWISH_YEAR).is_some());
+    assert!(from_hebrew(MIN_JEWISH_YEAR - 1).is_none());
+    assert!(from_hebrew(MAX_JEWISH_YEAR + 1).is_none());
+    assert!(from_hebrew(i32::MIN).is_none());
+    assert!(from_gregorian(1).is_some());
+    assert!(from_gregorian(0).is_none());
+    assert_eq!(get_rosh_hashana_date(i32::MAX), None);
+    assert!(get_gregorian_span(MAX_JEWISH_YEAR).is_some());
+    assert_eq!(get_gregorian_span(MAX_JEWISH_YEAR + 1), None);
+    assert_eq!(get_jewish_years_in_gregorian_year(i32::MAX), None);
+    // The calendar drifts about a day every 216 years, so far enough ahead Rosh Hashana moves
+    // into the next Gregorian year.
+    assert_eq!(get_jewish_years_in_gregorian_year(200_000), None);
+}
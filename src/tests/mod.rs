@@ -1,6 +1,12 @@
 #![cfg_attr(test, allow(clippy::unwrap_used, clippy::expect_used, clippy::panic))]
+mod constants;
 #[cfg(feature = "std")]
 mod java;
+mod jewish_calendar;
+#[cfg(feature = "std")]
+mod send_sync;
+mod timezone;
+mod zmanim_calendar;
 use crate::prelude::JewishMonth;
 use chrono::{DateTime, Datelike, Duration, TimeZone};
 use chrono_tz::Tz;
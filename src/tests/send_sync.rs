@@ -0,0 +1,46 @@
+//! Compile-time checks that the public types can be shared between threads.
+use chrono::Utc;
+use chrono_tz::Tz;
+
+use crate::prelude::*;
+
+const fn assert_send_sync<T: Send + Sync>() {}
+
+const _: () = {
+    assert_send_sync::<NOAACalculator>();
+    assert_send_sync::<GeoLocation>();
+    assert_send_sync::<EarthModel>();
+    assert_send_sync::<EmbeddedTimeZone>();
+    assert_send_sync::<JewishCalendar<NOAACalculator>>();
+    assert_send_sync::<ZmanimCalendar<Utc, GeoLocation, NOAACalculator>>();
+    assert_send_sync::<ZmanimCalendar<Tz, GeoLocation, NOAACalculator>>();
+    assert_send_sync::<ZmanimCalendar<EmbeddedTimeZone, GeoLocation, NOAACalculator>>();
+    assert_send_sync::<HalachicDay<Tz, NOAACalculator>>();
+    assert_send_sync::<AnniversaryDates<NOAACalculator>>();
+    assert_send_sync::<BirkasHachamah<Tz>>();
+    assert_send_sync::<ClockChange<Tz>>();
+    assert_send_sync::<TefilaRules>();
+    assert_send_sync::<MoladData>();
+    assert_send_sync::<BavliDaf>();
+    assert_send_sync::<YerushalmiDaf>();
+    assert_send_sync::<DailyLearning>();
+    assert_send_sync::<Zman>();
+    assert_send_sync::<JewishHoliday>();
+    assert_send_sync::<Parsha>();
+};
+
+#[test]
+fn test_calendars_are_shared_between_threads() {
+    let calendar = std::sync::Arc::new(
+        JewishCalendar::from_gregorian_date(2025, 3, 13, false, false, false, NOAACalculator).unwrap(),
+    );
+    let handles: Vec<_> = (0..4)
+        .map(|_| {
+            let calendar = calendar.clone();
+            std::thread::spawn(move || calendar.get_jewish_day_of_month())
+        })
+        .collect();
+    for handle in handles {
+        assert_eq!(handle.join().unwrap(), 13);
+    }
+}
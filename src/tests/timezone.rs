@@ -0,0 +1,60 @@
+use chrono::{Offset, TimeZone, Utc, Weekday};
+use rand::Rng;
+
+use crate::prelude::*;
+
+fn new_york() -> EmbeddedTimeZone {
+    EmbeddedTimeZone::new(
+        -5 * 3600,
+        Some(DstRule {
+            start: DstTransition::new(3, WeekOfMonth::Second, Weekday::Sun, 2 * 3600).unwrap(),
+            end: DstTransition::new(11, WeekOfMonth::First, Weekday::Sun, 2 * 3600).unwrap(),
+            save_seconds: 3600,
+        }),
+    )
+    .unwrap()
+}
+
+#[test]
+fn test_embedded_timezone_against_chrono_tz() {
+    let mut rng = rand::thread_rng();
+    let embedded = new_york();
+    let tz = chrono_tz::America::New_York;
+    // The current US rules have been in effect since 2007.
+    let start = Utc.with_ymd_and_hms(2007, 1, 1, 0, 0, 0).unwrap().timestamp();
+    let end = Utc.with_ymd_and_hms(2037, 1, 1, 0, 0, 0).unwrap().timestamp();
+    for _ in 0..100_000 {
+        let utc = Utc.timestamp_opt(rng.gen_range(start..end), 0).unwrap();
+        let expected = utc.with_timezone(&tz);
+        let actual = utc.with_timezone(&embedded);
+        assert_eq!(expected.offset().fix(), actual.offset().fix(), "utc: {}", utc);
+
+        let local = expected.naive_local();
+        let expected = tz.from_local_datetime(&local).map(|d| d.timestamp());
+        let actual = embedded.from_local_datetime(&local).map(|d| d.timestamp());
+        assert_eq!(expected, actual, "local: {}", local);
+    }
+}
+
+#[test]
+fn test_embedded_timezone_gaps_and_folds() {
+    let embedded = new_york();
+    let gap = chrono::NaiveDate::from_ymd_opt(2025, 3, 9)
+        .unwrap()
+        .and_hms_opt(2, 30, 0)
+        .unwrap();
+    assert!(embedded.from_local_datetime(&gap).single().is_none());
+    assert!(embedded.from_local_datetime(&gap).earliest().is_none());
+
+    let fold = chrono::NaiveDate::from_ymd_opt(2025, 11, 2)
+        .unwrap()
+        .and_hms_opt(1, 30, 0)
+        .unwrap();
+    let (earliest, latest) = match embedded.from_local_datetime(&fold) {
+        chrono::LocalResult::Ambiguous(earliest, latest) => (earliest, latest),
+        other => panic!("expected an ambiguous result, got {:?}", other),
+    };
+    assert!(earliest.offset().is_dst());
+    assert!(!latest.offset().is_dst());
+    assert_eq!((latest - earliest).num_hours(), 1);
+}
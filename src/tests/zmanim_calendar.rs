@@ -0,0 +1,1557 @@
+use chrono::{Duration, NaiveDate, TimeZone, Weekday};
+
+use crate::prelude::*;
+
+fn jerusalem_calendar(year: i32, month: u32, day: u32) -> ZmanimCalendar<chrono_tz::Tz, GeoLocation, NOAACalculator> {
+    ZmanimCalendar::new(
+        NaiveDate::from_ymd_opt(year, month, day).unwrap(),
+        chrono_tz::Asia::Jerusalem,
+        GeoLocation::new(31.778, 35.2354, 0.0).unwrap(),
+        NOAACalculator,
+        false,
+        false,
+        Duration::minutes(40),
+        Duration::minutes(40),
+    )
+    .unwrap()
+}
+
+#[test]
+fn test_degrees_below_horizon() {
+    let calendar = jerusalem_calendar(2025, 3, 13);
+    for degrees in [-2.0, 3.7, 8.5, 16.1] {
+        let tzais = calendar.get_sunset_offset_by_degrees(90.0 + degrees).unwrap();
+        assert!(
+            (calendar.get_degrees_below_horizon(&tzais) - degrees).abs() < 0.01,
+            "degrees: {}",
+            degrees
+        );
+        let alos = calendar.get_sunrise_offset_by_degrees(90.0 + degrees).unwrap();
+        assert!(
+            (calendar.get_degrees_below_horizon(&alos) - degrees).abs() < 0.01,
+            "degrees: {}",
+            degrees
+        );
+    }
+    let noon = calendar.get_sun_transit().unwrap();
+    assert!(calendar.get_degrees_below_horizon(&noon) < -50.0);
+}
+
+#[test]
+fn test_clock_change() {
+    let tz = chrono_tz::America::New_York;
+    let start = NaiveDate::from_ymd_opt(2025, 3, 3).unwrap();
+    let end = NaiveDate::from_ymd_opt(2025, 3, 9).unwrap();
+    let change = ClockChange::find(&tz, start, end).unwrap();
+    assert_eq!(change.date, end);
+    assert_eq!(change.shift(), Duration::hours(1));
+    assert_eq!(change.instant.naive_local(), end.and_hms_opt(3, 0, 0).unwrap());
+    let new_york = GeoLocation::new(40.7128, -74.006, 0.0).unwrap();
+    assert_eq!(
+        change.affected_zmanim(&new_york, NOAACalculator).count(),
+        Zman::values().len()
+    );
+
+    let next_week = NaiveDate::from_ymd_opt(2025, 3, 10).unwrap();
+    assert!(ClockChange::find(&tz, next_week, next_week + Duration::days(6)).is_none());
+    let fall = ClockChange::find(
+        &tz,
+        NaiveDate::from_ymd_opt(2025, 10, 27).unwrap(),
+        NaiveDate::from_ymd_opt(2025, 11, 2).unwrap(),
+    );
+    assert_eq!(fall.unwrap().shift(), Duration::hours(-1));
+}
+
+#[test]
+#[cfg(feature = "cache")]
+fn test_cache_is_cleared() {
+    let mut calendar = jerusalem_calendar(2025, 3, 13);
+    let sunrise = calendar.get_sunrise().unwrap();
+    assert_eq!(calendar.get_sunrise(), Some(sunrise));
+    assert_eq!(calendar, jerusalem_calendar(2025, 3, 13));
+    calendar
+        .set_date(NaiveDate::from_ymd_opt(2025, 3, 14).unwrap())
+        .unwrap();
+    assert_eq!(calendar.get_sunrise(), jerusalem_calendar(2025, 3, 14).get_sunrise());
+    let mut geo_location = calendar.get_geo_location().clone();
+    geo_location.elevation = 800.0;
+    calendar.set_geo_location(geo_location);
+    assert!(calendar.get_sunrise() < jerusalem_calendar(2025, 3, 14).get_sunrise());
+    calendar.set_use_elevation(false);
+    assert_eq!(calendar.get_sunrise(), jerusalem_calendar(2025, 3, 14).get_sunrise());
+}
+
+#[test]
+fn test_borrowed_calculator() {
+    let calculator = NOAACalculator;
+    let calendar = jerusalem_calendar(2025, 3, 13);
+    let borrowed = ZmanimCalendar::new(
+        NaiveDate::from_ymd_opt(2025, 3, 13).unwrap(),
+        chrono_tz::Asia::Jerusalem,
+        calendar.get_geo_location().clone(),
+        &calculator,
+        false,
+        false,
+        Duration::minutes(40),
+        Duration::minutes(40),
+    )
+    .unwrap();
+    for zman in Zman::values() {
+        assert_eq!(borrowed.get_zman(&zman), calendar.get_zman(&zman), "zman: {:?}", zman);
+    }
+}
+
+#[test]
+fn test_any_calculator() {
+    let date = NaiveDate::from_ymd_opt(2025, 3, 13).unwrap();
+    let reference = jerusalem_calendar(2025, 3, 13);
+    let single_pass = NOAACalculatorWithAccuracy::new(NOAAAccuracy::SinglePass);
+    let calendar = |calculator: AnyCalculator| -> AnyZmanimCalendar<chrono_tz::Tz> {
+        ZmanimCalendar::new(
+            date,
+            chrono_tz::Asia::Jerusalem,
+            reference.get_geo_location().clone(),
+            calculator,
+            false,
+            false,
+            Duration::minutes(40),
+            Duration::minutes(40),
+        )
+        .unwrap()
+    };
+    let noaa = calendar(NOAACalculator.into());
+    let erased_single_pass = calendar(single_pass.into());
+    let concrete_single_pass = ZmanimCalendar::new(
+        date,
+        chrono_tz::Asia::Jerusalem,
+        reference.get_geo_location().clone(),
+        single_pass,
+        false,
+        false,
+        Duration::minutes(40),
+        Duration::minutes(40),
+    )
+    .unwrap();
+    for zman in Zman::values() {
+        assert_eq!(noaa.get_zman(&zman), reference.get_zman(&zman), "zman: {:?}", zman);
+        assert_eq!(
+            erased_single_pass.get_zman(&zman),
+            concrete_single_pass.get_zman(&zman),
+            "zman: {:?}",
+            zman
+        );
+    }
+}
+
+#[cfg(feature = "tokio")]
+#[tokio::test(start_paused = true)]
+async fn test_zmanim_stream_rolls_over_to_the_next_day() {
+    use crate::zmanim_stream::ZmanimStream;
+    use std::time::{Duration as StdDuration, UNIX_EPOCH};
+    // 2025-03-20 12:00 in Jerusalem. The clock of tokio is paused, so the stream yields each zman
+    // without waiting for it.
+    let clock = || UNIX_EPOCH + StdDuration::from_secs(1_742_464_800);
+    let now = chrono::DateTime::from_timestamp(1_742_464_800, 0).unwrap();
+    let today = jerusalem_calendar(2025, 3, 20);
+    let tomorrow = jerusalem_calendar(2025, 3, 21);
+    let mut expected: Vec<_> = Zman::values()
+        .into_iter()
+        .filter_map(|zman| today.get_zman(&zman))
+        .filter(|time| *time > now)
+        .collect();
+    expected.sort();
+    let first_tomorrow = Zman::values()
+        .into_iter()
+        .filter_map(|zman| tomorrow.get_zman(&zman))
+        .min()
+        .unwrap();
+    expected.push(first_tomorrow);
+
+    let mut stream = ZmanimStream::with_clock(today, clock);
+    let mut times = Vec::new();
+    for _ in 0..expected.len() {
+        times.push(stream.next().await.unwrap().1);
+    }
+    assert_eq!(times, expected);
+    let rollover = times.iter().position(|time| time.date_naive() != times[0].date_naive());
+    assert_eq!(rollover, Some(expected.len() - 1));
+}
+
+#[test]
+fn test_unix_timestamp_zmanim() {
+    use crate::functions::ZmanimSettings;
+
+    let settings = ZmanimSettings::default();
+    let geo_location = GeoLocation::new(31.778, 35.2354, 0.0).unwrap();
+    // 2025-03-13 12:00 UTC, when Jerusalem is two hours ahead of UTC.
+    let timestamp = 1_741_867_200;
+    let zman_timestamp = |utc_offset_seconds, settings, zman| {
+        get_zman_timestamp(
+            timestamp,
+            utc_offset_seconds,
+            &geo_location,
+            NOAACalculator,
+            settings,
+            zman,
+        )
+    };
+    let chatzos = zman_timestamp(2 * 3600, &settings, &Zman::Chatzos).unwrap();
+    let expected = jerusalem_calendar(2025, 3, 13).get_zman(&Zman::Chatzos).unwrap();
+    assert_eq!(chatzos as i64, expected.timestamp());
+    let zmanim = get_zmanim_timestamps(timestamp, 2 * 3600, &geo_location, NOAACalculator, &settings).unwrap();
+    assert!(
+        zmanim
+            .filter(|(zman, _)| *zman == Zman::Chatzos)
+            .all(|(_, time)| time == Some(chatzos))
+    );
+    assert!(zman_timestamp(30 * 3600, &settings, &Zman::Chatzos).is_none());
+
+    let earlier = ZmanimSettings {
+        candle_lighting_offset: Duration::minutes(40),
+        ..settings
+    };
+    assert_eq!(
+        zman_timestamp(2 * 3600, &settings, &Zman::CandleLighting).unwrap()
+            - zman_timestamp(2 * 3600, &earlier, &Zman::CandleLighting).unwrap(),
+        22 * 60
+    );
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn test_iso8601_formatting() {
+    let time = chrono::FixedOffset::west_opt(4 * 3600 + 30 * 60)
+        .unwrap()
+        .with_ymd_and_hms(2025, 3, 13, 6, 5, 9)
+        .unwrap();
+    assert_eq!(time.iso8601().to_string(), "2025-03-13T06:05:09-04:30");
+    let time = time + Duration::milliseconds(42);
+    assert_eq!(
+        Some(time).iso8601().unwrap().to_string(),
+        "2025-03-13T06:05:09.042-04:30"
+    );
+    let calendar = jerusalem_calendar(2025, 3, 13);
+    let chatzos = calendar.get_zman(&Zman::Chatzos).unwrap();
+    assert_eq!(
+        calendar.get_zman_iso(&Zman::Chatzos),
+        Some(chatzos.iso8601().to_string())
+    );
+    assert!(calendar.get_zman_iso(&Zman::Chatzos).unwrap().ends_with("+02:00"));
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn test_duration_format() {
+    let duration = Duration::milliseconds(4_345_678);
+    let minutes = DurationFormat::default();
+    assert_eq!(minutes.apply(duration), Duration::minutes(72));
+    assert_eq!(minutes.display(duration).to_string(), "1h 12m");
+    let truncated = DurationFormat::new(DurationPrecision::Seconds, Rounding::Truncate);
+    assert_eq!(truncated.display(duration).to_string(), "1h 12m 25s");
+    let exact = DurationFormat::new(DurationPrecision::Milliseconds, Rounding::Nearest);
+    assert_eq!(exact.display(-duration).to_string(), "-1h 12m 25.678s");
+    let shaah_zmanis = jerusalem_calendar(2025, 3, 13).get_shaah_zmanis_gra().unwrap();
+    assert_eq!(minutes.apply(shaah_zmanis).num_seconds() % 60, 0);
+}
+
+#[test]
+fn test_early_shabbos() {
+    let geo_location = GeoLocation::new(40.7128, -74.0060, 0.0).unwrap();
+    let minimum = chrono::NaiveTime::from_hms_opt(18, 30, 0).unwrap();
+    let early = |date| {
+        EarlyShabbos::for_week(
+            date,
+            chrono_tz::America::New_York,
+            &geo_location,
+            NOAACalculator,
+            Duration::minutes(18),
+            minimum,
+        )
+        .unwrap()
+    };
+    // Wednesday, June 18 2025 is followed by the Friday of June 20.
+    let summer = early(NaiveDate::from_ymd_opt(2025, 6, 18).unwrap());
+    assert_eq!(summer.date, NaiveDate::from_ymd_opt(2025, 6, 20).unwrap());
+    assert!(summer.mincha_gedola < summer.plag_hamincha);
+    assert!(summer.plag_hamincha < summer.candle_lighting && summer.candle_lighting < summer.sunset);
+    assert!(!summer.before_minimum);
+    let spring = early(NaiveDate::from_ymd_opt(2025, 3, 14).unwrap());
+    assert_eq!(spring.date, NaiveDate::from_ymd_opt(2025, 3, 14).unwrap());
+    assert!(spring.before_minimum);
+    let weeks = EarlyShabbos::for_weeks(
+        NaiveDate::from_ymd_opt(2025, 6, 1).unwrap(),
+        NaiveDate::from_ymd_opt(2025, 6, 30).unwrap(),
+        chrono_tz::America::New_York,
+        &geo_location,
+        NOAACalculator,
+        Duration::minutes(18),
+        minimum,
+    );
+    assert_eq!(weeks.count(), 4);
+}
+
+#[test]
+fn test_zman_bounds() {
+    let calendar = jerusalem_calendar(2025, 3, 13);
+    let shma = calendar.get_concept_bounds(ZmanConcept::SofZmanShma).unwrap();
+    assert_eq!(shma.earliest.0, Zman::SofZmanShmaMGA);
+    assert_eq!(shma.latest.0, Zman::SofZmanShmaGRA);
+    assert_eq!(Some(shma.latest.1), calendar.get_zman(&Zman::SofZmanShmaGRA));
+    let tzais = calendar.get_concept_bounds(ZmanConcept::Tzais).unwrap();
+    assert_eq!((tzais.earliest.0, tzais.latest.0), (Zman::Tzais, Zman::Tzais72));
+    let single = calendar.get_zman_bounds(&[Zman::Chatzos]).unwrap();
+    assert_eq!(single.earliest, single.latest);
+    assert!(calendar.get_zman_bounds(&[]).is_none());
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn test_compute_selected_zmanim() {
+    let calendar = jerusalem_calendar(2025, 3, 13);
+    let zmanim = [Zman::SofZmanShmaGRA, Zman::PlagHamincha, Zman::Tzais72];
+    let computed: Vec<_> = calendar.compute(&zmanim).collect();
+    assert_eq!(computed.len(), zmanim.len());
+    for (zman, time) in computed {
+        assert!(time.is_some());
+        assert_eq!(time, calendar.get_zman(&zman));
+    }
+
+    let mut calendar = jerusalem_calendar(2025, 6, 20);
+    calendar.set_geo_location(GeoLocation::new(31.778, 35.2354, 800.0).unwrap());
+    for (use_elevation, use_astronomical_chatzos) in [(true, false), (false, true), (true, true)] {
+        calendar.set_use_elevation(use_elevation);
+        calendar.set_use_astronomical_chatzos(use_astronomical_chatzos);
+        calendar.use_astronomical_chatzos_for_other_zmanim = use_astronomical_chatzos;
+        for (zman, time) in calendar.compute(&Zman::values()) {
+            assert_eq!(time, calendar.get_zman(&zman), "zman: {:?}", zman);
+        }
+    }
+}
+
+#[test]
+fn test_get_all_zmanim() {
+    let calendar = jerusalem_calendar(2025, 3, 13);
+    let all = calendar.get_all_zmanim();
+    assert_eq!(all.len(), Zman::values().len());
+    for ((zman, time), expected) in all.into_iter().zip(Zman::values()) {
+        assert_eq!(zman, expected);
+        assert_eq!(time, calendar.get_zman(&zman), "zman: {:?}", zman);
+    }
+}
+
+#[test]
+fn test_solar_transits() {
+    let calendar = jerusalem_calendar(2025, 3, 13);
+    let yesterday = jerusalem_calendar(2025, 3, 12);
+    let tomorrow = jerusalem_calendar(2025, 3, 14);
+    assert_eq!(calendar.get_upper_transit(), calendar.get_sun_transit());
+
+    let preceding = calendar.get_lower_transit(Night::Preceding).unwrap();
+    assert_eq!(Some(preceding), yesterday.get_solar_midnight());
+    assert!(yesterday.get_sunset().unwrap() < preceding);
+    assert!(preceding < calendar.get_sunrise().unwrap());
+
+    let following = calendar.get_lower_transit(Night::Following).unwrap();
+    assert_eq!(Some(following), calendar.get_solar_midnight());
+    assert!(calendar.get_sunset().unwrap() < following);
+    assert!(following < tomorrow.get_sunrise().unwrap());
+    assert!((following - preceding - Duration::hours(24)).abs() < Duration::minutes(1));
+}
+
+#[test]
+fn test_zman_drift() {
+    // Sunset gets later each day in the spring, and jumps an hour when the clocks change on
+    // March 28 2025.
+    let drift = jerusalem_calendar(2025, 3, 13).get_zman_drift(&Zman::Tzais);
+    let since_previous_day = drift.since_previous_day.unwrap();
+    assert!(since_previous_day > Duration::zero() && since_previous_day < Duration::minutes(2));
+    assert!(drift.until_next_day.unwrap() > Duration::zero());
+    let drift = jerusalem_calendar(2025, 3, 27).get_zman_drift(&Zman::Tzais);
+    assert!(drift.until_next_day.unwrap() > Duration::minutes(60));
+    let zmanim = [Zman::AlosHashachar, Zman::Tzais];
+    let calendar = jerusalem_calendar(2025, 3, 13);
+    assert_eq!(calendar.get_zmanim_drift(&zmanim).count(), 2);
+    // Late in May, tzais 120 minutes after sunset in Oslo moves past midnight.
+    let oslo = |date: NaiveDate| {
+        ZmanimCalendar::new(
+            date,
+            chrono_tz::Europe::Oslo,
+            GeoLocation::new(59.9139, 10.7522, 0.0).unwrap(),
+            NOAACalculator,
+            false,
+            false,
+            Duration::minutes(18),
+            Duration::minutes(40),
+        )
+        .unwrap()
+    };
+    let dates = NaiveDate::from_ymd_opt(2025, 5, 1).unwrap().iter_days().take(40);
+    assert!(
+        dates
+            .clone()
+            .any(|date| oslo(date).get_zman(&Zman::Tzais120).unwrap().date_naive() != date)
+    );
+    for date in dates {
+        let drift = oslo(date).get_zman_drift(&Zman::Tzais120);
+        let since_previous_day = drift.since_previous_day.unwrap();
+        assert!(
+            since_previous_day.abs() < Duration::minutes(5),
+            "{date}: {since_previous_day}"
+        );
+    }
+}
+
+#[test]
+fn test_bedikas_chametz_time() {
+    // Erev Pesach 5785 was Shabbos, April 12 2025, so the search was made on Thursday night.
+    let calendar = jerusalem_calendar(2025, 1, 1);
+    let bedika = calendar.get_bedikas_chametz_time(&Zman::Tzais).unwrap();
+    assert_eq!(bedika.date_naive(), NaiveDate::from_ymd_opt(2025, 4, 10).unwrap());
+    assert_eq!(Some(bedika), jerusalem_calendar(2025, 4, 10).get_zman(&Zman::Tzais));
+    // Erev Pesach 5784 was Monday, April 22 2024.
+    let bedika = jerusalem_calendar(2024, 3, 1)
+        .get_bedikas_chametz_time(&Zman::Tzais72)
+        .unwrap();
+    assert_eq!(bedika.date_naive(), NaiveDate::from_ymd_opt(2024, 4, 21).unwrap());
+}
+
+#[test]
+fn test_public_zenith_constants() {
+    use crate::zenith;
+    let calendar = jerusalem_calendar(2025, 3, 13);
+    assert_eq!(
+        calendar.get_sunrise_offset_by_degrees(zenith::DEGREES_16_POINT_1),
+        calendar.get_zman(&Zman::AlosHashachar)
+    );
+    assert_eq!(zenith::CIVIL - zenith::GEOMETRIC, 6.0);
+    assert!(zenith::DEGREES_MINUS_2_POINT_1 < zenith::GEOMETRIC);
+}
+
+#[test]
+fn test_elevation_pairs() {
+    let mut calendar = jerusalem_calendar(2025, 3, 13);
+    let mut geo_location = calendar.get_geo_location().clone();
+    geo_location.elevation = 800.0;
+    calendar.set_geo_location(geo_location);
+    let sunrise = calendar.get_sunrise_pair();
+    assert!(sunrise.elevated.unwrap() < sunrise.sea_level.unwrap());
+    assert_eq!(sunrise.sea_level, jerusalem_calendar(2025, 3, 13).get_sunrise());
+    let sunset = calendar.get_sunset_pair();
+    assert!(sunset.elevated.unwrap() > sunset.sea_level.unwrap());
+    let alos = calendar.get_sunrise_offset_by_degrees_pair(crate::zenith::DEGREES_16_POINT_1);
+    assert_eq!(alos.elevated, alos.sea_level);
+    let alos_72 = calendar.get_zman_pair(&Zman::Alos72);
+    assert_eq!(
+        alos_72.sea_level,
+        jerusalem_calendar(2025, 3, 13).get_zman(&Zman::Alos72)
+    );
+}
+
+#[test]
+fn test_earth_model() {
+    assert_eq!(
+        GeoLocation::new(31.778, 35.2354, 800.0).unwrap().get_earth_model(),
+        EarthModel::KosherJava
+    );
+    assert!(
+        GeoLocation::new(31.778, 35.2354, 800.0)
+            .unwrap()
+            .with_earth_model(EarthModel::Spherical(0.0))
+            .is_none()
+    );
+
+    // A smaller earth dips the horizon further below an elevated observer, so the sun rises
+    // earlier and sets later. Sea level sunrise does not depend on the model.
+    let calendar_with = |earth_model| {
+        let mut calendar = jerusalem_calendar(2025, 3, 13);
+        calendar.set_geo_location(
+            GeoLocation::new(31.778, 35.2354, 800.0)
+                .unwrap()
+                .with_earth_model(earth_model)
+                .unwrap(),
+        );
+        calendar
+    };
+    let small = calendar_with(EarthModel::Spherical(3000.0));
+    let kosher_java = calendar_with(EarthModel::KosherJava);
+    let wgs84 = calendar_with(EarthModel::Wgs84Mean);
+    assert!(small.get_sunrise().unwrap() < kosher_java.get_sunrise().unwrap());
+    assert!(kosher_java.get_sunrise().unwrap() < wgs84.get_sunrise().unwrap());
+    assert!(small.get_sunset().unwrap() > kosher_java.get_sunset().unwrap());
+    assert!(kosher_java.get_sunset().unwrap() > wgs84.get_sunset().unwrap());
+    assert!(kosher_java.get_sunrise().unwrap() - small.get_sunrise().unwrap() > Duration::minutes(1));
+    assert_eq!(small.get_sea_level_sunrise(), wgs84.get_sea_level_sunrise());
+
+    let tel_aviv = GeoLocation::new(32.0853, 34.7818, 0.0).unwrap();
+    let jerusalem = GeoLocation::new(31.778, 35.2354, 0.0).unwrap();
+    let wgs84_distance = jerusalem
+        .clone()
+        .with_earth_model(EarthModel::Wgs84Mean)
+        .unwrap()
+        .get_rhumb_line_distance(&tel_aviv);
+    let ratio = wgs84_distance / jerusalem.get_rhumb_line_distance(&tel_aviv);
+    assert!((ratio - 6371008.8 / 6378137.0).abs() < 1e-9);
+}
+
+#[test]
+fn test_use_elevation() {
+    let mut calendar = jerusalem_calendar(2025, 3, 13);
+    let mut geo_location = calendar.get_geo_location().clone();
+    geo_location.elevation = 800.0;
+    calendar.set_geo_location(geo_location);
+    let elevated = calendar.get_zman(&Zman::SofZmanShmaGRA);
+    calendar.set_use_elevation(false);
+    let sea_level = calendar.get_zman(&Zman::SofZmanShmaGRA);
+    assert_ne!(elevated, sea_level);
+    assert_eq!(
+        sea_level,
+        jerusalem_calendar(2025, 3, 13).get_zman(&Zman::SofZmanShmaGRA)
+    );
+    assert_eq!(
+        calendar.get_elevation_adjusted_sunrise(),
+        calendar.get_sea_level_sunrise()
+    );
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn test_chanukah_schedule() {
+    let geo_location = GeoLocation::new(40.7128, -74.0060, 0.0).unwrap();
+    let nights: Vec<_> = ChanukahNight::for_year(
+        5785,
+        chrono_tz::America::New_York,
+        &geo_location,
+        NOAACalculator,
+        Duration::minutes(18),
+    )
+    .collect();
+    assert_eq!(nights.len(), 8);
+    // The first candle of 5785 was lit on Wednesday, December 25 2024.
+    assert_eq!(nights[0].date, NaiveDate::from_ymd_opt(2024, 12, 25).unwrap());
+    assert_eq!(nights[0].lighting, ChanukahLighting::Weekday);
+    assert!(nights[0].earliest < nights[0].preferred);
+    assert_eq!(nights[2].lighting, ChanukahLighting::ErevShabbos);
+    assert!(nights[2].preferred < nights[3].earliest);
+    assert_eq!(nights[3].lighting, ChanukahLighting::MotzaiShabbos);
+    assert_eq!(nights[3].earliest, nights[3].preferred);
+    assert_eq!(nights[7].night, 8);
+}
+
+#[test]
+#[cfg(feature = "serde")]
+fn test_zmanim_day() {
+    use crate::zmanim_day::ZmanimDay;
+
+    let calendar = jerusalem_calendar(2025, 3, 13);
+    let jewish_calendar = JewishCalendar::from_gregorian_date(2025, 3, 14, true, false, false, NOAACalculator).unwrap();
+    let rules = TefilaRules::new(
+        false, false, false, false, false, false, true, true, true, true, false, true, true,
+    );
+    let day = ZmanimDay::new(&jewish_calendar, &calendar, &rules, &[Zman::Chatzos, Zman::Tzais]).unwrap();
+    assert_eq!(day.date, NaiveDate::from_ymd_opt(2025, 3, 14).unwrap());
+    assert_eq!(day.holidays, [JewishHoliday::Purim]);
+    assert!(day.tefila.al_hanissim);
+    assert_eq!(day.zmanim.len(), 2);
+    let chatzos = day.zmanim[&Zman::Chatzos].unwrap();
+    assert_eq!(chatzos.date_naive(), day.date);
+
+    // The zmanim are keyed by their identifiers, not the names of the variants.
+    let json = serde_json::to_value(&day).unwrap();
+    let keys: Vec<&str> = json["zmanim"].as_object().unwrap().keys().map(String::as_str).collect();
+    assert_eq!(keys, ["chatzos", "tzais"]);
+    assert_eq!(serde_json::from_value::<ZmanimDay>(json).unwrap(), day);
+
+    // Rosh Chodesh Teves falls on Chanukah.
+    let jewish_calendar = JewishCalendar::from_gregorian_date(2025, 1, 1, true, false, false, NOAACalculator).unwrap();
+    let day = ZmanimDay::new(&jewish_calendar, &calendar, &rules, &[]).unwrap();
+    assert_eq!(day.holidays, [JewishHoliday::Chanukah, JewishHoliday::RoshChodesh]);
+}
+
+#[test]
+fn test_set_date() {
+    let mut calendar = jerusalem_calendar(2025, 3, 13);
+    calendar.set_use_elevation(false);
+    let sunrise = calendar.get_sunrise();
+    let date = NaiveDate::from_ymd_opt(2025, 3, 14).unwrap();
+    let next_day = calendar.with_date(date).unwrap();
+    assert!(!next_day.use_elevation());
+    calendar.set_date(date).unwrap();
+    assert_eq!(calendar, next_day);
+    assert_ne!(calendar.get_sunrise(), sunrise);
+    assert_eq!(calendar.get_sunrise(), next_day.get_sunrise());
+}
+
+#[test]
+fn test_day_definition() {
+    let calendar = jerusalem_calendar(2025, 3, 13);
+    assert_eq!(
+        calendar.get_sof_zman_shma_with(&DayDefinition::GRA),
+        calendar.get_zman(&Zman::SofZmanShmaGRA)
+    );
+    assert_eq!(
+        calendar.get_sof_zman_shma_with(&DayDefinition::MGA),
+        calendar.get_zman(&Zman::SofZmanShmaMGA)
+    );
+    assert_eq!(
+        calendar.get_plag_hamincha_with(&DayDefinition::GRA),
+        calendar.get_zman(&Zman::PlagHamincha)
+    );
+    let fixed = DayDefinition::new(
+        DayEdge::Offset(Duration::minutes(72)),
+        DayEdge::Offset(Duration::minutes(72)),
+    );
+    assert_eq!(
+        calendar.get_sof_zman_shma_with(&fixed),
+        calendar.get_zman(&Zman::SofZmanShmaMGA)
+    );
+    let mixed = DayDefinition::new(DayEdge::Degrees(16.1), DayEdge::Sun);
+    assert!(!mixed.is_synchronous());
+    let (start, end) = calendar.get_day_bounds(&mixed).unwrap();
+    assert_eq!(
+        Some(start),
+        calendar.get_sunrise_offset_by_degrees(crate::zenith::DEGREES_16_POINT_1)
+    );
+    assert_eq!(Some(end), calendar.get_sunset());
+    assert_eq!(
+        calendar.get_shaah_zmanis_with(&mixed),
+        calendar.get_temporal_hour_from_times(&start, &end)
+    );
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn test_zman_inputs() {
+    let mut calendar = jerusalem_calendar(2025, 3, 13);
+    let inputs = |calendar: &ZmanimCalendar<_, _, _>, zman| calendar.get_zman_inputs(&zman).collect::<Vec<_>>();
+    assert_eq!(
+        inputs(&calendar, Zman::Alos72),
+        [ZmanInput::Sunrise {
+            elevation: true,
+            offset: Duration::minutes(-72)
+        }]
+    );
+    assert_eq!(
+        inputs(&calendar, Zman::Tzais),
+        [ZmanInput::SunsetOffsetByDegrees {
+            zenith: crate::zenith::DEGREES_8_POINT_5
+        }]
+    );
+    assert_eq!(inputs(&calendar, Zman::SofZmanShmaGRA).len(), 2);
+
+    calendar.set_use_astronomical_chatzos(true);
+    calendar.use_astronomical_chatzos_for_other_zmanim = true;
+    calendar.set_use_elevation(false);
+    assert_eq!(inputs(&calendar, Zman::Chatzos), [ZmanInput::SunTransit]);
+    assert_eq!(
+        inputs(&calendar, Zman::PlagHamincha),
+        [
+            ZmanInput::SunTransit,
+            ZmanInput::Sunset {
+                elevation: false,
+                offset: Duration::zero()
+            }
+        ]
+    );
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn test_compute_at_locations() {
+    let calendar = jerusalem_calendar(2025, 3, 13);
+    // Filling the cache of the template must not affect the other locations.
+    calendar.get_sunrise();
+    let tel_aviv = GeoLocation::new(32.0853, 34.7818, 0.0).unwrap();
+    let zmanim = Zman::values();
+    let results = calendar.compute_at_locations(
+        [
+            ("jerusalem", calendar.get_geo_location().clone()),
+            ("tel aviv", tel_aviv.clone()),
+        ],
+        &zmanim,
+    );
+    // The position of the sun is interpolated for the date, so the times are within a few
+    // milliseconds of calculating each location in full.
+    type Times = Vec<(Zman, Option<chrono::DateTime<chrono_tz::Tz>>)>;
+    let assert_close = |actual: &Times, expected: Times| {
+        assert_eq!(actual.len(), expected.len());
+        for ((zman, actual), (expected_zman, expected)) in actual.iter().zip(expected) {
+            assert_eq!(*zman, expected_zman);
+            match (actual, expected) {
+                (Some(actual), Some(expected)) => {
+                    let difference = (*actual - expected).num_milliseconds().abs();
+                    assert!(difference <= 5, "zman: {:?}, off by {}ms", zman, difference);
+                }
+                (actual, expected) => assert_eq!(*actual, expected, "zman: {:?}", zman),
+            }
+        }
+    };
+    assert_close(&results["jerusalem"], calendar.compute(&zmanim).collect());
+    let expected = ZmanimCalendar::new(
+        NaiveDate::from_ymd_opt(2025, 3, 13).unwrap(),
+        chrono_tz::Asia::Jerusalem,
+        tel_aviv,
+        NOAACalculator,
+        false,
+        false,
+        Duration::minutes(40),
+        Duration::minutes(40),
+    )
+    .unwrap();
+    assert_close(&results["tel aviv"], expected.compute(&zmanim).collect());
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn test_stateless_functions() {
+    use crate::functions::{ZmanimSettings, get_zman, get_zmanim};
+
+    let calendar = jerusalem_calendar(2025, 3, 13);
+    let settings = ZmanimSettings {
+        candle_lighting_offset: Duration::minutes(40),
+        ..ZmanimSettings::default()
+    };
+    let date = NaiveDate::from_ymd_opt(2025, 3, 13).unwrap();
+    let tz = chrono_tz::Asia::Jerusalem;
+    for zman in Zman::values() {
+        let time = get_zman(date, tz, calendar.get_geo_location(), NOAACalculator, &settings, &zman);
+        assert_eq!(time, calendar.get_zman(&zman), "zman: {:?}", zman);
+    }
+    let zmanim = [Zman::CandleLighting, Zman::Tzais];
+    let times: Vec<_> = get_zmanim(
+        date,
+        tz,
+        calendar.get_geo_location(),
+        NOAACalculator,
+        &settings,
+        &zmanim,
+    )
+    .unwrap()
+    .collect();
+    assert_eq!(times, calendar.compute(&zmanim).collect::<Vec<_>>());
+}
+
+#[test]
+fn test_day_of_week() {
+    let thursday = jerusalem_calendar(2025, 3, 13);
+    assert_eq!(thursday.get_day_of_week(), chrono::Weekday::Thu);
+    assert!(!thursday.is_friday() && !thursday.is_saturday());
+    assert!(jerusalem_calendar(2025, 3, 14).is_friday());
+    assert!(jerusalem_calendar(2025, 3, 15).is_saturday());
+}
+
+#[test]
+fn test_luach_day() {
+    let day = |d| {
+        let jewish_calendar =
+            JewishCalendar::from_gregorian_date(2025, 3, d, true, false, false, NOAACalculator).unwrap();
+        LuachDay::new(jewish_calendar, &jerusalem_calendar(2025, 1, 1)).unwrap()
+    };
+    // Taanis Esther, Thursday 13 Adar 5785.
+    let fast = day(13);
+    assert_eq!(fast.zmanim_calendar, jerusalem_calendar(2025, 3, 13));
+    assert_eq!(
+        fast.get_fast_end(&Zman::Tzais),
+        jerusalem_calendar(2025, 3, 13).get_zman(&Zman::Tzais)
+    );
+    assert_eq!(fast.get_candle_lighting(), None);
+    let friday = day(14);
+    assert_eq!(friday.get_fast_end(&Zman::Tzais), None);
+    assert_eq!(
+        friday.get_candle_lighting(),
+        jerusalem_calendar(2025, 3, 14).get_zman(&Zman::CandleLighting)
+    );
+    assert_eq!(friday.get_omer_count_at_nightfall(&Zman::Tzais), None);
+
+    // The first night of the omer follows the first day of Pesach, Sunday 13 April 2025.
+    let jewish_calendar = JewishCalendar::from_gregorian_date(2025, 4, 13, true, false, false, NOAACalculator).unwrap();
+    let pesach = LuachDay::new(jewish_calendar, &jerusalem_calendar(2025, 1, 1)).unwrap();
+    let tzais = jerusalem_calendar(2025, 4, 13).get_zman(&Zman::Tzais).unwrap();
+    assert_eq!(pesach.get_omer_count_at_nightfall(&Zman::Tzais), Some((1, tzais)));
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn test_week() {
+    let jewish_calendar = JewishCalendar::from_gregorian_date(2025, 3, 11, true, false, false, NOAACalculator).unwrap();
+    let day = LuachDay::new(jewish_calendar, &jerusalem_calendar(2025, 1, 1)).unwrap();
+    let week = day.get_week().unwrap();
+    assert_eq!(week.days[0].zmanim_calendar, jerusalem_calendar(2025, 3, 9));
+    assert_eq!(week.get_day(Weekday::Tue), &day);
+    assert_eq!(week.get_shabbos().zmanim_calendar, jerusalem_calendar(2025, 3, 15));
+    assert_eq!(week.get_parshah(), week.get_shabbos().jewish_calendar.get_parshah());
+    let holidays: Vec<_> = week
+        .holidays()
+        .take(2)
+        .map(|(day, holiday)| (day.zmanim_calendar.get_day_of_week(), holiday))
+        .collect();
+    assert_eq!(
+        holidays,
+        [
+            (Weekday::Thu, JewishHoliday::FastOfEsther),
+            (Weekday::Fri, JewishHoliday::Purim)
+        ]
+    );
+    assert_eq!(
+        week.get_shabbos_start(),
+        jerusalem_calendar(2025, 3, 14).get_zman(&Zman::CandleLighting)
+    );
+    assert_eq!(week.get_shabbos_end(), jerusalem_calendar(2025, 3, 15).get_havdalah());
+    let next = week.next().unwrap();
+    assert_eq!(next.days[0].zmanim_calendar, jerusalem_calendar(2025, 3, 16));
+    assert_eq!(next.previous(), Some(week));
+}
+
+#[test]
+fn test_next_and_previous_zman() {
+    let calendar = jerusalem_calendar(2025, 3, 13);
+    let tomorrow = jerusalem_calendar(2025, 3, 14);
+    let zmanim = [Zman::AlosHashachar, Zman::Tzais];
+    let tzais = calendar.get_zman(&Zman::Tzais).unwrap();
+    let alos = tomorrow.get_zman(&Zman::AlosHashachar).unwrap();
+
+    let before_tzais = tzais - Duration::minutes(1);
+    assert_eq!(
+        calendar.get_next_zman(&before_tzais, &zmanim),
+        Some((Zman::Tzais, tzais))
+    );
+    // After the last zman of the day, the next is on the following day.
+    assert_eq!(
+        calendar.get_next_zman(&tzais, &zmanim),
+        Some((Zman::AlosHashachar, alos))
+    );
+    assert_eq!(
+        tomorrow.get_next_zman(&tzais, &zmanim),
+        Some((Zman::AlosHashachar, alos))
+    );
+    assert_eq!(tomorrow.get_previous_zman(&alos, &zmanim), Some((Zman::Tzais, tzais)));
+    assert_eq!(calendar.get_previous_zman(&alos, &zmanim), Some((Zman::Tzais, tzais)));
+    assert_eq!(calendar.get_next_zman(&tzais, &[]), None);
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn test_day_events() {
+    use crate::day_events::DayFlag;
+
+    // The first day of Pesach in Israel, after which the omer is counted and chol hamoed begins.
+    let jewish_calendar = JewishCalendar::from_gregorian_date(2025, 4, 13, true, false, false, NOAACalculator).unwrap();
+    let day = LuachDay::new(jewish_calendar, &jerusalem_calendar(2025, 4, 13)).unwrap();
+    let events = day.get_events().unwrap();
+    assert_eq!(events.date, NaiveDate::from_ymd_opt(2025, 4, 13).unwrap());
+    assert_eq!(events.holidays, [JewishHoliday::Pesach]);
+    assert_eq!(events.omer_tonight, Some(1));
+    assert_eq!(events.candle_lighting, None);
+    assert_eq!(events.havdalah, day.get_havdalah());
+    assert!(events.havdalah.is_some());
+    assert!(events.starting_tonight.contains(&DayFlag::Omer));
+    assert!(events.starting_tonight.contains(&DayFlag::CholHamoed));
+    assert!(events.ending_tonight.contains(&DayFlag::AssurBemelacha));
+    assert!(!events.started.contains(&DayFlag::AssurBemelacha));
+    assert!(events.zmanim.windows(2).all(|pair| pair[0].1 <= pair[1].1));
+    assert!(
+        events
+            .zmanim
+            .contains(&(Zman::Tzais, day.zmanim_calendar.get_zman(&Zman::Tzais).unwrap()))
+    );
+}
+
+#[test]
+fn test_sun_table() {
+    let offset = chrono::FixedOffset::east_opt(2 * 3600).unwrap();
+    let calendar = ZmanimCalendar::new(
+        NaiveDate::from_ymd_opt(2025, 1, 1).unwrap(),
+        offset,
+        GeoLocation::new(31.778, 35.2354, 800.0).unwrap(),
+        NOAACalculator,
+        false,
+        false,
+        Duration::minutes(40),
+        Duration::minutes(40),
+    )
+    .unwrap();
+    let table = SunTable::generate(&calendar, 2025).unwrap();
+    assert_eq!(
+        table.days[365],
+        SunTableDay {
+            sunrise: NO_EVENT,
+            sunset: NO_EVENT
+        }
+    );
+    for (month, day) in [(1, 1), (3, 20), (6, 21), (12, 31)] {
+        let date = NaiveDate::from_ymd_opt(2025, month, day).unwrap();
+        let calendar = calendar.with_date(date).unwrap();
+        for zman in [
+            Zman::SofZmanShmaGRA,
+            Zman::PlagHamincha,
+            Zman::SofZmanTfilaMGA,
+            Zman::Tzais72,
+        ] {
+            let difference = table.get_zman(date, &zman).unwrap() - calendar.get_zman(&zman).unwrap();
+            assert!(difference.num_seconds().abs() <= 60, "{date} {zman:?}: {difference}");
+        }
+    }
+    let date = NaiveDate::from_ymd_opt(2025, 3, 20).unwrap();
+    assert_eq!(table.get_zman(date, &Zman::Tzais), None);
+    assert_eq!(table.get_sunrise(NaiveDate::from_ymd_opt(2026, 1, 1).unwrap()), None);
+}
+
+#[test]
+fn test_noaa_accuracy() {
+    let date = NaiveDate::from_ymd_opt(2025, 3, 20).unwrap();
+    let reference = jerusalem_calendar(2025, 3, 20);
+    let calendar = |accuracy| {
+        ZmanimCalendar::new(
+            date,
+            chrono_tz::Asia::Jerusalem,
+            reference.get_geo_location().clone(),
+            NOAACalculatorWithAccuracy::new(accuracy),
+            false,
+            false,
+            Duration::minutes(40),
+            Duration::minutes(40),
+        )
+        .unwrap()
+    };
+    for zman in [Zman::AlosHashachar, Zman::SofZmanShmaGRA, Zman::Tzais] {
+        let expected = reference.get_zman(&zman).unwrap();
+        assert_eq!(calendar(NOAAAccuracy::TwoPass).get_zman(&zman).unwrap(), expected);
+        let iterative = calendar(NOAAAccuracy::Iterative).get_zman(&zman).unwrap();
+        assert!((iterative - expected).num_milliseconds().abs() < 250, "{zman:?}");
+        let single_pass = calendar(NOAAAccuracy::SinglePass).get_zman(&zman).unwrap();
+        assert!((single_pass - expected).num_seconds().abs() < 90, "{zman:?}");
+    }
+}
+
+#[test]
+fn test_time_zone_plausibility() {
+    let new_york = GeoLocation::new(40.7128, -74.006, 0.0).unwrap();
+    assert!(new_york.is_time_zone_plausible(&chrono_tz::America::New_York, 2025));
+    assert!(!new_york.is_time_zone_plausible(&chrono_tz::Asia::Tokyo, 2025));
+    // Tokyo time is almost 14 hours ahead of the local mean time of New York, or 10 behind.
+    let deviation = new_york.get_time_zone_deviation(&chrono_tz::Asia::Tokyo, 2025).unwrap();
+    assert_eq!(deviation.num_hours(), -10);
+    // Kiritimati, at 157°W, is 14 hours ahead of UTC.
+    let kiritimati = GeoLocation::new(1.87, -157.4, 0.0).unwrap();
+    assert!(kiritimati.is_time_zone_plausible(&chrono_tz::Pacific::Kiritimati, 2025));
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn test_solar_events_in_utc_window() {
+    use chrono::Utc;
+
+    let calendar = jerusalem_calendar(2025, 3, 20);
+    let start = Utc.with_ymd_and_hms(2025, 3, 20, 0, 0, 0).unwrap();
+    let events: Vec<_> =
+        get_solar_events_in_utc_window(start, calendar.get_geo_location(), NOAACalculator, zenith::GEOMETRIC)
+            .map(|(event, _)| event)
+            .collect();
+    assert_eq!(
+        events,
+        [
+            SolarEvent::Sunrise,
+            SolarEvent::Noon,
+            SolarEvent::Sunset,
+            SolarEvent::Midnight
+        ]
+    );
+
+    // Starting at noon UTC, the window runs from Jerusalem's afternoon to the next morning.
+    let start = Utc.with_ymd_and_hms(2025, 3, 20, 12, 0, 0).unwrap();
+    let events: Vec<_> =
+        get_solar_events_in_utc_window(start, calendar.get_geo_location(), NOAACalculator, zenith::GEOMETRIC).collect();
+    let kinds: Vec<_> = events.iter().map(|(event, _)| *event).collect();
+    assert_eq!(
+        kinds,
+        [
+            SolarEvent::Sunset,
+            SolarEvent::Midnight,
+            SolarEvent::Sunrise,
+            SolarEvent::Noon
+        ]
+    );
+    assert_eq!(events[0].1, calendar.get_sunset().unwrap());
+    assert!(
+        events
+            .iter()
+            .all(|(_, time)| *time >= start && *time < start + Duration::hours(24))
+    );
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn test_sun_altitude_and_shadows() {
+    // At the equinox the sun is at 90° less the latitude at noon.
+    let calendar = jerusalem_calendar(2025, 3, 20);
+    let altitude = calendar.get_sun_altitude_at_transit().unwrap();
+    assert!((altitude - (90.0 - 31.778)).abs() < 0.5, "{altitude}");
+    let noon = calendar.get_sun_transit().unwrap();
+    let ratio = calendar.get_shadow_length_ratio(&noon).unwrap();
+    assert!((ratio - altitude.to_radians().tan().recip()).abs() < 1e-9);
+    // Shadows lengthen through the afternoon and disappear after sunset.
+    let plag = calendar.get_zman(&Zman::PlagHamincha).unwrap();
+    assert!(calendar.get_shadow_length_ratio(&plag).unwrap() > ratio);
+    let tzais = calendar.get_zman(&Zman::Tzais).unwrap();
+    assert_eq!(calendar.get_shadow_length_ratio(&tzais), None);
+}
+
+#[test]
+fn test_alos_family() {
+    let calendar = jerusalem_calendar(2025, 3, 20);
+    let sunrise = calendar.get_sunrise().unwrap();
+    assert_eq!(calendar.get_alos_72(), calendar.get_zman(&Zman::Alos72));
+    assert_eq!(
+        calendar.get_alos_16_point_1_degrees(),
+        calendar.get_zman(&Zman::AlosHashachar)
+    );
+    assert_eq!(calendar.get_alos_90().unwrap(), sunrise - Duration::minutes(90));
+    // Around the equinox, 72 minutes zmaniyos are close to 72 minutes.
+    let zmanis = calendar.get_alos_72_zmanis().unwrap();
+    assert!((zmanis - calendar.get_alos_72().unwrap()).num_minutes().abs() <= 1);
+    assert_eq!(calendar.get_zmanis_based_offset(0.0), None);
+    let deeper = [
+        calendar.get_alos_16_point_1_degrees(),
+        calendar.get_alos_18_degrees(),
+        calendar.get_alos_19_degrees(),
+        calendar.get_alos_19_point_8_degrees(),
+        calendar.get_alos_26_degrees(),
+    ];
+    assert!(deeper.windows(2).all(|pair| pair[0].unwrap() > pair[1].unwrap()));
+}
+
+#[test]
+fn test_tzais_family() {
+    let calendar = jerusalem_calendar(2025, 3, 20);
+    let sunset = calendar.get_sunset().unwrap();
+    assert_eq!(calendar.get_tzais_72(), calendar.get_zman(&Zman::Tzais72));
+    assert_eq!(calendar.get_tzais_50().unwrap(), sunset + Duration::minutes(50));
+    let zmanis = calendar.get_tzais_72_zmanis().unwrap();
+    assert!((zmanis - calendar.get_tzais_72().unwrap()).num_minutes().abs() <= 1);
+    let deeper = [
+        calendar.get_zman(&Zman::Tzais),
+        calendar.get_tzais_16_point_1_degrees(),
+        calendar.get_tzais_18_degrees(),
+        calendar.get_tzais_19_point_8_degrees(),
+        calendar.get_tzais_26_degrees(),
+    ];
+    assert!(deeper.windows(2).all(|pair| pair[0].unwrap() < pair[1].unwrap()));
+}
+
+#[test]
+fn test_sof_zman_shma_mga_family() {
+    let calendar = jerusalem_calendar(2025, 3, 20);
+    assert_eq!(
+        calendar.get_sof_zman_shma_mga_72_minutes(),
+        calendar.get_zman(&Zman::SofZmanShmaMGA)
+    );
+    // Three of the twelve hours of the day from alos to tzais.
+    let alos = calendar.get_alos_90().unwrap();
+    let tzais = calendar.get_tzais_90().unwrap();
+    let shma = calendar.get_sof_zman_shma_mga_90_minutes().unwrap();
+    assert!((shma - (alos + (tzais - alos) / 4)).num_milliseconds().abs() <= 1);
+    let earlier = [
+        calendar.get_zman(&Zman::SofZmanShmaGRA),
+        calendar.get_sof_zman_shma_mga_72_minutes(),
+        calendar.get_sof_zman_shma_mga_90_minutes(),
+        calendar.get_sof_zman_shma_mga_120_minutes(),
+    ];
+    assert!(earlier.windows(2).all(|pair| pair[0].unwrap() > pair[1].unwrap()));
+    assert!(calendar.get_sof_zman_shma_mga_19_point_8_degrees() < calendar.get_sof_zman_shma_mga_16_point_1_degrees());
+    assert!(calendar.get_sof_zman_shma_mga_96_minutes_zmanis().is_some());
+}
+
+#[test]
+fn test_sof_zman_tfila_mga_family() {
+    let calendar = jerusalem_calendar(2025, 3, 20);
+    assert_eq!(
+        calendar.get_sof_zman_tfila_mga_72_minutes(),
+        calendar.get_zman(&Zman::SofZmanTfilaMGA)
+    );
+    assert!(calendar.get_sof_zman_tfila_mga_16_point_1_degrees() > calendar.get_sof_zman_shma_mga_16_point_1_degrees());
+    assert!(calendar.get_sof_zman_tfila_mga_120_minutes() < calendar.get_sof_zman_tfila_mga_90_minutes());
+    assert_eq!(
+        calendar.get_sof_zman_tfila_2_hours_before_chatzos().unwrap(),
+        calendar.get_zman(&Zman::Chatzos).unwrap() - Duration::hours(2)
+    );
+}
+
+#[test]
+fn test_alarm() {
+    let calendar = jerusalem_calendar(2025, 12, 21);
+    let shma = calendar.get_zman(&Zman::SofZmanShmaGRA).unwrap();
+    let alos = calendar.get_zman(&Zman::AlosHashachar).unwrap();
+    let policy = AlarmPolicy {
+        lead_time: LeadTime::Fixed(Duration::minutes(18)),
+        not_before: Some(Zman::AlosHashachar),
+    };
+    assert_eq!(
+        calendar.get_alarm(&Zman::SofZmanShmaGRA, &policy).unwrap(),
+        shma - Duration::minutes(18)
+    );
+    let early = AlarmPolicy {
+        lead_time: LeadTime::Fixed(Duration::hours(5)),
+        ..policy
+    };
+    assert_eq!(calendar.get_alarm(&Zman::SofZmanShmaGRA, &early).unwrap(), alos);
+    let zmaniyos = AlarmPolicy {
+        lead_time: LeadTime::Zmaniyos(60.0),
+        not_before: None,
+    };
+    let alarm = calendar.get_alarm(&Zman::SofZmanShmaGRA, &zmaniyos).unwrap();
+    assert!(
+        (shma - alarm - calendar.get_shaah_zmanis_gra().unwrap())
+            .num_milliseconds()
+            .abs()
+            <= 1
+    );
+    // A limit after the zman leaves the notification at the zman.
+    let late = AlarmPolicy {
+        not_before: Some(Zman::Chatzos),
+        ..policy
+    };
+    assert_eq!(calendar.get_alarm(&Zman::SofZmanShmaGRA, &late).unwrap(), shma);
+}
+
+#[test]
+fn test_three_day_yom_tov_candle_lighting() {
+    // Rosh Hashana 5785 fell on Thursday and Friday, 3 and 4 October 2024, before Shabbos.
+    let day = |d| {
+        let jewish_calendar =
+            JewishCalendar::from_gregorian_date(2024, 10, d, false, false, false, NOAACalculator).unwrap();
+        LuachDay::new(jewish_calendar, &jerusalem_calendar(2024, 1, 1)).unwrap()
+    };
+    let erev = day(2).get_candle_lighting_event().unwrap();
+    assert_eq!(erev.kind, CandleLightingKind::BeforeSunset);
+    assert_eq!((erev.night, erev.nights), (1, 3));
+    let first = day(3);
+    let second = first.get_candle_lighting_event().unwrap();
+    assert_eq!(second.kind, CandleLightingKind::AfterNightfallFromExistingFlame);
+    assert_eq!(Some(second.time), first.zmanim_calendar.get_havdalah());
+    assert_eq!((second.night, second.nights), (2, 3));
+    assert_eq!(first.get_havdalah(), None);
+    let friday = day(4);
+    let shabbos = friday.get_candle_lighting_event().unwrap();
+    assert_eq!(shabbos.kind, CandleLightingKind::BeforeShabbosFromExistingFlame);
+    assert_eq!(
+        Some(shabbos.time),
+        friday.zmanim_calendar.get_zman(&Zman::CandleLighting)
+    );
+    assert_eq!((shabbos.night, shabbos.nights), (3, 3));
+    assert_eq!(friday.get_havdalah(), None);
+    assert_eq!(day(5).get_candle_lighting_event(), None);
+    assert!(day(5).get_havdalah().is_some());
+}
+
+#[test]
+fn test_mincha_variants() {
+    // A winter day, when half an hour zmanis is shorter than 30 minutes.
+    let calendar = jerusalem_calendar(2025, 12, 21);
+    let chatzos = calendar.get_zman(&Zman::Chatzos).unwrap();
+    let thirty = calendar.get_mincha_gedola_30_minutes().unwrap();
+    assert_eq!(thirty, chatzos + Duration::minutes(30));
+    assert!(calendar.get_zman(&Zman::MinchaGedola).unwrap() < thirty);
+    assert_eq!(calendar.get_mincha_gedola_greater_than_30(), Some(thirty));
+    let summer = jerusalem_calendar(2025, 6, 21);
+    assert_eq!(
+        summer.get_mincha_gedola_greater_than_30(),
+        summer.get_zman(&Zman::MinchaGedola)
+    );
+    assert!(calendar.get_mincha_gedola_16_point_1_degrees().unwrap() > chatzos);
+    assert!(calendar.get_mincha_ketana_72_minutes() > calendar.get_zman(&Zman::MinchaKetana));
+    assert!(calendar.get_mincha_ketana_16_point_1_degrees().is_some());
+    let fixed = calendar.get_mincha_ketana_gra_fixed_local_chatzos_to_sunset().unwrap();
+    assert!(fixed > chatzos && fixed < calendar.get_sunset().unwrap());
+}
+
+#[test]
+fn test_bain_hashmashos_rabbeinu_tam() {
+    let calendar = jerusalem_calendar(2025, 3, 20);
+    let sunset = calendar.get_sunset().unwrap();
+    assert_eq!(
+        calendar.get_bain_hashmashos_rt_58_point_5_minutes().unwrap(),
+        sunset + Duration::seconds(3510)
+    );
+    let degrees = calendar.get_bain_hashmashos_rt_13_point_24_degrees().unwrap();
+    assert!((55..=62).contains(&(degrees - sunset).num_minutes()));
+    // 5/18 of the 90 minutes from alos at 19.8° to sunrise around the equinox.
+    let two_stars = calendar.get_bain_hashmashos_rt_2_stars().unwrap();
+    assert!((22..=28).contains(&(two_stars - sunset).num_minutes()));
+    let before_tzais = calendar
+        .get_bain_hashmashos_rt_13_point_5_minutes_before_7_point_083_degrees()
+        .unwrap();
+    assert!(before_tzais > sunset && before_tzais < calendar.get_zman(&Zman::Tzais).unwrap());
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn test_assur_bemelacha_intervals() {
+    // Rosh Hashana 5785 on Thursday and Friday, 3 and 4 October 2024, followed by Shabbos.
+    let calendar = jerusalem_calendar(2024, 10, 1);
+    let local = |d, h| {
+        chrono_tz::Asia::Jerusalem
+            .with_ymd_and_hms(2024, 10, d, h, 0, 0)
+            .unwrap()
+    };
+    let intervals: Vec<_> = calendar
+        .get_assur_bemelacha_intervals(&local(1, 0), &local(10, 0), true, Zman::CandleLighting, Zman::Tzais)
+        .collect();
+    assert_eq!(intervals.len(), 1);
+    assert_eq!(
+        Some(intervals[0].start),
+        jerusalem_calendar(2024, 10, 2).get_zman(&Zman::CandleLighting)
+    );
+    assert_eq!(
+        Some(intervals[0].end),
+        jerusalem_calendar(2024, 10, 5).get_zman(&Zman::Tzais)
+    );
+    // Clipped to a window that starts in the middle of yom tov and ends before Yom Kippur ends.
+    let intervals: Vec<_> = calendar
+        .get_assur_bemelacha_intervals(&local(4, 12), &local(12, 12), true, Zman::CandleLighting, Zman::Tzais72)
+        .collect();
+    assert_eq!(intervals.len(), 2);
+    assert_eq!(intervals[0].start, local(4, 12));
+    assert_eq!(
+        Some(intervals[1].start),
+        jerusalem_calendar(2024, 10, 11).get_zman(&Zman::CandleLighting)
+    );
+    assert_eq!(intervals[1].end, local(12, 12));
+}
+
+#[test]
+fn test_bain_hashmashos_yereim() {
+    let calendar = jerusalem_calendar(2025, 3, 20);
+    let sunset = calendar.get_sunset().unwrap();
+    assert_eq!(
+        calendar.get_bain_hashmashos_yereim_16_point_875_minutes().unwrap(),
+        sunset - Duration::milliseconds(1_012_500)
+    );
+    // Around the equinox in Yerushalayim each degree variant is close to its fixed variant.
+    let pairs = [
+        (
+            calendar.get_bain_hashmashos_yereim_3_point_05_degrees(),
+            calendar.get_bain_hashmashos_yereim_18_minutes(),
+        ),
+        (
+            calendar.get_bain_hashmashos_yereim_2_point_8_degrees(),
+            calendar.get_bain_hashmashos_yereim_16_point_875_minutes(),
+        ),
+        (
+            calendar.get_bain_hashmashos_yereim_2_point_1_degrees(),
+            calendar.get_bain_hashmashos_yereim_13_point_5_minutes(),
+        ),
+    ];
+    for (degrees, minutes) in pairs {
+        assert!((degrees.unwrap() - minutes.unwrap()).num_minutes().abs() <= 2);
+        assert!(degrees.unwrap() < sunset);
+    }
+}
+
+#[test]
+fn test_tzais_geonim() {
+    let calendar = jerusalem_calendar(2025, 3, 20);
+    assert_eq!(
+        calendar.get_tzais_geonim_8_point_5_degrees(),
+        calendar.get_zman(&Zman::Tzais)
+    );
+    let geonim = [
+        calendar.get_sunset(),
+        calendar.get_tzais_geonim_3_point_65_degrees(),
+        calendar.get_tzais_geonim_3_point_676_degrees(),
+        calendar.get_tzais_geonim_3_point_7_degrees(),
+        calendar.get_tzais_geonim_3_point_8_degrees(),
+        calendar.get_tzais_geonim_4_point_37_degrees(),
+        calendar.get_tzais_geonim_4_point_61_degrees(),
+        calendar.get_tzais_geonim_4_point_8_degrees(),
+        calendar.get_tzais_geonim_5_point_88_degrees(),
+        calendar.get_tzais_geonim_5_point_95_degrees(),
+        calendar.get_tzais_geonim_6_point_45_degrees(),
+        calendar.get_tzais_geonim_7_point_083_degrees(),
+        calendar.get_tzais_geonim_7_point_67_degrees(),
+        calendar.get_tzais_geonim_8_point_5_degrees(),
+        calendar.get_tzais_geonim_9_point_3_degrees(),
+        calendar.get_tzais_geonim_9_point_75_degrees(),
+    ];
+    assert!(geonim.windows(2).all(|pair| pair[0].unwrap() < pair[1].unwrap()));
+    let sunset = calendar.get_sunset().unwrap();
+    let minutes = (calendar.get_tzais_geonim_3_point_7_degrees().unwrap() - sunset).num_minutes();
+    assert!((12..=15).contains(&minutes));
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn test_now() {
+    use crate::{functions::ZmanimSettings, now::get_now};
+
+    let timezone = chrono_tz::Asia::Jerusalem;
+    let geo_location = GeoLocation::new(31.778, 35.2354, 0.0).unwrap();
+    let before = get_now(&timezone).unwrap();
+    let calendar = ZmanimCalendar::now(
+        timezone,
+        geo_location.clone(),
+        NOAACalculator,
+        &ZmanimSettings::default(),
+    )
+    .unwrap();
+    let after = get_now(&timezone).unwrap();
+    assert!([before.date_naive(), after.date_naive()].contains(&calendar.get_date_time().date_naive()));
+    let civil = JewishCalendar::now(&timezone, true, NOAACalculator).unwrap();
+    let halachic = JewishCalendar::now_at(&timezone, &geo_location, DayBoundary::Sunset, true, NOAACalculator).unwrap();
+    let next = civil.copy_with_days_added(1).unwrap();
+    assert!(halachic.cmp_date(&civil).is_eq() || halachic.cmp_date(&next).is_eq());
+}
+
+#[test]
+fn test_shaah_zmanis_family() {
+    let calendar = jerusalem_calendar(2025, 3, 20);
+    assert_eq!(calendar.get_shaah_zmanis_72_minutes(), calendar.get_shaah_zmanis_mga());
+    let fixed = [
+        calendar.get_shaah_zmanis_gra(),
+        calendar.get_shaah_zmanis_60_minutes(),
+        calendar.get_shaah_zmanis_72_minutes(),
+        calendar.get_shaah_zmanis_90_minutes(),
+        calendar.get_shaah_zmanis_96_minutes(),
+        calendar.get_shaah_zmanis_120_minutes(),
+    ];
+    assert!(fixed.windows(2).all(|pair| pair[0].unwrap() < pair[1].unwrap()));
+    // Each fixed minute before sunrise and after sunset adds a sixth of a minute to an hour.
+    let gra = calendar.get_shaah_zmanis_gra().unwrap();
+    let difference = calendar.get_shaah_zmanis_72_minutes().unwrap() - gra - Duration::minutes(12);
+    assert!(difference.num_milliseconds().abs() <= 1);
+    // 72 minutes zmaniyos on each side lengthen the day by 2.4 shaos zmaniyos of the GRA.
+    let zmanis = calendar.get_shaah_zmanis_72_minutes_zmanis().unwrap();
+    let expected = gra.num_milliseconds() as f64 * 1.2;
+    assert!((zmanis.num_milliseconds() as f64 - expected).abs() <= 2.0);
+    let zmaniyos = [
+        calendar.get_shaah_zmanis_72_minutes_zmanis(),
+        calendar.get_shaah_zmanis_90_minutes_zmanis(),
+        calendar.get_shaah_zmanis_96_minutes_zmanis(),
+        calendar.get_shaah_zmanis_120_minutes_zmanis(),
+    ];
+    assert!(zmaniyos.windows(2).all(|pair| pair[0].unwrap() < pair[1].unwrap()));
+    let degrees = [
+        calendar.get_shaah_zmanis_16_point_1_degrees(),
+        calendar.get_shaah_zmanis_18_degrees(),
+        calendar.get_shaah_zmanis_19_point_8_degrees(),
+        calendar.get_shaah_zmanis_26_degrees(),
+    ];
+    assert!(degrees.windows(2).all(|pair| pair[0].unwrap() < pair[1].unwrap()));
+    assert!(
+        calendar.get_shaah_zmanis_alos_16_point_1_to_tzais_3_point_7().unwrap()
+            < calendar.get_shaah_zmanis_alos_16_point_1_to_tzais_3_point_8().unwrap()
+    );
+    // The Ateret Torah day ends 40 minutes after sunset, earlier than 72 minutes zmaniyos.
+    assert!(calendar.get_shaah_zmanis_ateret_torah().unwrap() < zmanis);
+    assert!(calendar.get_shaah_zmanis_ateret_torah().unwrap() > gra);
+}
+
+#[test]
+fn test_zman_interval() {
+    let calendar = jerusalem_calendar(2025, 3, 20);
+    let day = ZmanInterval::new(calendar.get_sunrise().unwrap(), calendar.get_sunset().unwrap());
+    assert_eq!(Some(day.get_shaah_zmanis()), calendar.get_shaah_zmanis_gra());
+    assert_eq!(
+        day.get_shaah_zmanis_based_zman(3.0),
+        calendar.get_zman(&Zman::SofZmanShmaGRA)
+    );
+    let close = |a: Option<chrono::DateTime<chrono_tz::Tz>>, b: chrono::DateTime<chrono_tz::Tz>| {
+        (a.unwrap() - b).num_milliseconds().abs() <= 1
+    };
+    assert!(close(day.get_proportional_point(0.0), day.start.clone()));
+    assert!(close(day.get_proportional_point(1.0), day.end.clone()));
+    assert!(close(day.get_proportional_point(0.5), day.get_midpoint()));
+    let mga = calendar.get_interval(&Zman::Alos72, &Zman::Tzais72).unwrap();
+    assert_eq!(mga.get_duration(), day.get_duration() + Duration::minutes(144));
+    assert!(close(
+        mga.get_proportional_point(0.25),
+        mga.get_shaah_zmanis_based_zman(3.0).unwrap()
+    ));
+}
+
+#[test]
+fn test_ateret_torah() {
+    let calendar = jerusalem_calendar(2025, 3, 20);
+    let tzais = calendar.get_tzais_ateret_torah().unwrap();
+    assert_eq!(tzais, calendar.get_sunset().unwrap() + Duration::minutes(40));
+    let day = ZmanInterval::new(calendar.get_alos_72_zmanis().unwrap(), tzais);
+    assert_eq!(calendar.get_shaah_zmanis_ateret_torah(), Some(day.get_shaah_zmanis()));
+    assert_eq!(
+        calendar.get_sof_zman_shma_ateret_torah(),
+        day.get_shaah_zmanis_based_zman(3.0)
+    );
+    assert_eq!(
+        calendar.get_plag_hamincha_ateret_torah(),
+        day.get_shaah_zmanis_based_zman(10.75)
+    );
+    let zmanim = [
+        calendar.get_sof_zman_shma_ateret_torah(),
+        calendar.get_sof_zman_tfila_ateret_torah(),
+        calendar.get_mincha_gedola_ateret_torah(),
+        calendar.get_mincha_ketana_ateret_torah(),
+        calendar.get_plag_hamincha_ateret_torah(),
+        Some(tzais),
+    ];
+    assert!(zmanim.windows(2).all(|pair| pair[0].unwrap() < pair[1].unwrap()));
+}
+
+#[test]
+#[cfg(all(feature = "std", feature = "names"))]
+fn test_digest() {
+    use chrono::Timelike;
+
+    use crate::digest::{DEFAULT_DIGEST_ZMANIM, DigestFormat};
+
+    let day = |d| {
+        let jewish_calendar =
+            JewishCalendar::from_gregorian_date(2024, 10, d, false, false, false, NOAACalculator).unwrap();
+        LuachDay::new(jewish_calendar, &jerusalem_calendar(2024, 1, 1)).unwrap()
+    };
+    let rosh_hashana = day(3);
+    let digest = rosh_hashana
+        .digest(&DEFAULT_DIGEST_ZMANIM, DigestFormat::PlainText)
+        .to_string();
+    let lines: Vec<_> = digest.lines().collect();
+    assert_eq!(
+        lines[0],
+        "יום חמישי, א׳ בתשרי תשפ״ה / Thursday, Tishrei 1, 5785 (2024-10-03)"
+    );
+    assert_eq!(lines[1], "Rosh Hashana");
+    assert!(lines.iter().any(|line| line.starts_with("Candle Lighting: ")));
+    assert!(!digest.contains("Havdalah"));
+    let chatzos = rosh_hashana.zmanim_calendar.get_zman(&Zman::Chatzos).unwrap();
+    let chatzos = format!("Chatzos: {:02}:{:02}", chatzos.hour(), chatzos.minute());
+    assert!(lines.contains(&chatzos.as_str()));
+
+    let shabbos = day(5).digest(&[Zman::Tzais], DigestFormat::Markdown).to_string();
+    assert!(shabbos.starts_with("**"));
+    assert!(shabbos.contains("- Parsha: Ha'Azinu (Shuva)\n"));
+    assert!(shabbos.contains("- Tzais: "));
+    assert!(shabbos.contains("- Havdalah: "));
+    assert!(!shabbos.contains("Candle Lighting"));
+}
+
+#[test]
+fn test_get_zman_covers_every_zman() {
+    let calendar = jerusalem_calendar(2025, 3, 13);
+    for zman in Zman::values() {
+        assert!(calendar.get_zman(&zman).is_some(), "zman: {:?}", zman);
+    }
+    assert_eq!(
+        calendar.get_zman(&Zman::SofZmanShmaMGA16Point1Degrees),
+        calendar.get_sof_zman_shma_mga_16_point_1_degrees()
+    );
+    assert_eq!(
+        calendar.get_zman(&Zman::TzaisAteretTorah),
+        calendar.get_tzais_ateret_torah()
+    );
+    assert_eq!(calendar.get_zman(&Zman::Alos120Zmanis), calendar.get_alos_120_zmanis());
+}
+
+#[test]
+fn test_zman_uncertainty() {
+    let mut calendar = jerusalem_calendar(2025, 3, 13);
+    let tzais = calendar.get_zman_detailed(&Zman::Tzais).unwrap();
+    assert_eq!(Some(tzais.time), calendar.get_zman(&Zman::Tzais));
+    let sunset = calendar.get_zman_uncertainty(&Zman::CandleLighting).unwrap();
+    assert!(sunset > Duration::seconds(30) && sunset < Duration::minutes(3));
+    assert!(tzais.uncertainty.unwrap() < sunset);
+    assert!(tzais.is_precise_to(Duration::seconds(30)));
+    assert_eq!(
+        calendar.get_zman_uncertainty(&Zman::SofZmanShmaGRA),
+        Some(sunset.max(calendar.get_zman_uncertainty(&Zman::Alos72).unwrap()))
+    );
+    calendar.set_use_astronomical_chatzos(true);
+    assert_eq!(calendar.get_zman_uncertainty(&Zman::Chatzos), Some(Duration::zero()));
+
+    let oslo = ZmanimCalendar::new(
+        NaiveDate::from_ymd_opt(2025, 5, 1).unwrap(),
+        chrono_tz::Europe::Oslo,
+        GeoLocation::new(59.91, 10.75, 0.0).unwrap(),
+        NOAACalculator,
+        false,
+        false,
+        Duration::minutes(18),
+        Duration::minutes(40),
+    )
+    .unwrap();
+    assert!(oslo.get_zman_uncertainty(&Zman::Tzais).unwrap() > tzais.uncertainty.unwrap() * 2);
+    assert!(oslo.get_zman_detailed(&Zman::Alos26Degrees).is_none());
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn test_compare_with_table() {
+    use crate::discrepancy::{TableError, TableErrorKind};
+
+    let calendar = jerusalem_calendar(2025, 1, 1);
+    let time = |day, zman| {
+        let time = calendar
+            .with_date(NaiveDate::from_ymd_opt(2025, 3, day).unwrap())
+            .unwrap()
+            .get_zman(&zman)
+            .unwrap();
+        time.format("%H:%M:%S").to_string()
+    };
+    // A table whose shma matches and whose tzais is two minutes earlier than calculated.
+    let table = format!(
+        "date,shma,tzais\n2025-03-20,{},{}\n\n# comment\n2025-03-21,{},\n",
+        time(20, Zman::SofZmanShmaGRA),
+        (chrono::NaiveTime::parse_from_str(&time(20, Zman::Tzais), "%H:%M:%S").unwrap() - Duration::minutes(2))
+            .format("%H:%M:%S"),
+        time(21, Zman::SofZmanShmaGRA),
+    );
+    let report = calendar
+        .compare_with_table(&table, &[Zman::SofZmanShmaGRA, Zman::Tzais])
+        .unwrap();
+    assert_eq!(report.discrepancies.len(), 3);
+    let summaries = report.get_summaries();
+    let shma = summaries[&Zman::SofZmanShmaGRA];
+    assert_eq!(shma.count, 2);
+    assert!(shma.mean_absolute < Duration::seconds(1));
+    let tzais = summaries[&Zman::Tzais];
+    assert_eq!(tzais.count, 1);
+    assert_eq!(tzais.mean.num_minutes(), 2);
+
+    let error = |line, kind| Err(TableError { line, kind });
+    assert_eq!(
+        calendar.compare_with_table("2025-03-20,10:00,11:00,12:00", &[Zman::Tzais]),
+        error(1, TableErrorKind::TooManyTimes)
+    );
+    assert_eq!(
+        calendar.compare_with_table("date,tzais\n2025-03-20,noon", &[Zman::Tzais]),
+        error(2, TableErrorKind::InvalidTime)
+    );
+    // Only the first line that is not blank or a comment can be a header.
+    assert_eq!(
+        calendar
+            .compare_with_table("# tzais\n\ndate,tzais\n2025-03-20,18:00", &[Zman::Tzais])
+            .map(|report| report.discrepancies.len()),
+        Ok(1)
+    );
+    assert_eq!(
+        calendar.compare_with_table("2025-03-20,18:00\ndate,tzais", &[Zman::Tzais]),
+        error(2, TableErrorKind::InvalidDate)
+    );
+}
+
+#[test]
+fn test_havdalah_zman() {
+    let mut calendar = jerusalem_calendar(2025, 3, 15);
+    assert_eq!(calendar.get_havdalah(), calendar.get_zman(&Zman::Tzais));
+    calendar.havdalah_zman = Zman::Tzais72;
+    assert_eq!(
+        calendar
+            .with_date(NaiveDate::from_ymd_opt(2025, 3, 22).unwrap())
+            .unwrap()
+            .havdalah_zman,
+        Zman::Tzais72
+    );
+    let day = |d| {
+        let jewish_calendar =
+            JewishCalendar::from_gregorian_date(2025, 3, d, true, false, false, NOAACalculator).unwrap();
+        LuachDay::new(jewish_calendar, &calendar).unwrap()
+    };
+    let shabbos = day(15);
+    assert_eq!(shabbos.get_havdalah(), shabbos.zmanim_calendar.get_zman(&Zman::Tzais72));
+    assert_eq!(day(14).get_havdalah(), None);
+    assert_eq!(day(16).get_havdalah(), None);
+}
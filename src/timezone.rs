@@ -0,0 +1,221 @@
+//! A small, self-contained [`TimeZone`] implementation for builds that cannot afford
+//! the full tz database shipped by `chrono-tz`.
+//!
+//! An [`EmbeddedTimeZone`] is a standard UTC offset plus an optional yearly
+//! daylight saving rule. This is enough to describe a single zone on firmware
+//! where the device location is known ahead of time.
+//!
+//! ```
+//! use chrono::Weekday;
+//! use jew_sdk::timezone::{DstRule, DstTransition, EmbeddedTimeZone, WeekOfMonth};
+//!
+//! // America/New_York: UTC-5, DST from the second Sunday of March at 02:00
+//! // until the first Sunday of November at 02:00.
+//! let new_york = EmbeddedTimeZone::new(
+//!     -5 * 3600,
+//!     Some(DstRule {
+//!         start: DstTransition::new(3, WeekOfMonth::Second, Weekday::Sun, 2 * 3600).unwrap(),
+//!         end: DstTransition::new(11, WeekOfMonth::First, Weekday::Sun, 2 * 3600).unwrap(),
+//!         save_seconds: 3600,
+//!     }),
+//! )
+//! .unwrap();
+//! ```
+use chrono::{
+    Datelike, FixedOffset, LocalResult, NaiveDate, NaiveDateTime, NaiveTime, Offset, TimeDelta, TimeZone, Weekday,
+};
+use core::fmt;
+
+/// The occurrence of a weekday within a month.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum WeekOfMonth {
+    First,
+    Second,
+    Third,
+    Fourth,
+    Last,
+}
+
+/// The moment in a year at which daylight saving time starts or ends,
+/// e.g. "the last Sunday of March at 02:00".
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct DstTransition {
+    month: u32,
+    week: WeekOfMonth,
+    weekday: Weekday,
+    seconds_after_midnight: u32,
+}
+
+impl DstTransition {
+    /// Creates a new transition. `month` is 1-based and `seconds_after_midnight` is the
+    /// local wall clock time at which the transition happens (standard time for the start
+    /// of DST, daylight time for the end of DST). Returns `None` if either is out of range.
+    pub fn new(month: u32, week: WeekOfMonth, weekday: Weekday, seconds_after_midnight: u32) -> Option<Self> {
+        if !(1..=12).contains(&month) || seconds_after_midnight >= 86400 {
+            return None;
+        }
+        Some(Self {
+            month,
+            week,
+            weekday,
+            seconds_after_midnight,
+        })
+    }
+
+    /// The local wall clock date and time of this transition in the given year.
+    fn local_datetime(&self, year: i32) -> Option<NaiveDateTime> {
+        let date = match self.week {
+            WeekOfMonth::First => NaiveDate::from_weekday_of_month_opt(year, self.month, self.weekday, 1),
+            WeekOfMonth::Second => NaiveDate::from_weekday_of_month_opt(year, self.month, self.weekday, 2),
+            WeekOfMonth::Third => NaiveDate::from_weekday_of_month_opt(year, self.month, self.weekday, 3),
+            WeekOfMonth::Fourth => NaiveDate::from_weekday_of_month_opt(year, self.month, self.weekday, 4),
+            WeekOfMonth::Last => NaiveDate::from_weekday_of_month_opt(year, self.month, self.weekday, 5)
+                .or_else(|| NaiveDate::from_weekday_of_month_opt(year, self.month, self.weekday, 4)),
+        }?;
+        date.and_time(NaiveTime::MIN)
+            .checked_add_signed(TimeDelta::seconds(self.seconds_after_midnight as i64))
+    }
+}
+
+/// A yearly daylight saving rule. Rules where `start` falls later in the year than `end`
+/// (as in the southern hemisphere) are supported.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct DstRule {
+    pub start: DstTransition,
+    pub end: DstTransition,
+    /// The amount of time added to the standard offset while DST is in effect.
+    pub save_seconds: i32,
+}
+
+/// A single time zone described by a standard offset and an optional [`DstRule`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct EmbeddedTimeZone {
+    standard: FixedOffset,
+    daylight: FixedOffset,
+    dst: Option<DstRule>,
+}
+
+impl EmbeddedTimeZone {
+    /// Creates a new time zone. `standard_offset_seconds` is east of UTC.
+    /// Returns `None` if the standard or daylight offset is out of range.
+    pub fn new(standard_offset_seconds: i32, dst: Option<DstRule>) -> Option<Self> {
+        let standard = FixedOffset::east_opt(standard_offset_seconds)?;
+        let daylight = match dst {
+            Some(rule) => FixedOffset::east_opt(standard_offset_seconds.checked_add(rule.save_seconds)?)?,
+            None => standard,
+        };
+        Some(Self {
+            standard,
+            daylight,
+            dst,
+        })
+    }
+
+    /// Creates a time zone that never observes daylight saving time.
+    pub fn fixed(offset_seconds: i32) -> Option<Self> {
+        Self::new(offset_seconds, None)
+    }
+
+    /// Returns the standard offset of this zone.
+    pub fn standard_offset(&self) -> FixedOffset {
+        self.standard
+    }
+
+    /// Returns the daylight saving rule of this zone, if any.
+    pub fn dst_rule(&self) -> Option<&DstRule> {
+        self.dst.as_ref()
+    }
+
+    /// Returns true if daylight saving time is in effect at the given UTC instant.
+    pub fn is_dst(&self, utc: &NaiveDateTime) -> bool {
+        let Some(rule) = &self.dst else {
+            return false;
+        };
+        let year = utc.year();
+        let start = rule
+            .start
+            .local_datetime(year)
+            .and_then(|local| local.checked_sub_offset(self.standard));
+        let end = rule
+            .end
+            .local_datetime(year)
+            .and_then(|local| local.checked_sub_offset(self.daylight));
+        match (start, end) {
+            (Some(start), Some(end)) if start <= end => *utc >= start && *utc < end,
+            (Some(start), Some(end)) => *utc >= start || *utc < end,
+            _ => false,
+        }
+    }
+
+    fn offset(&self, is_dst: bool) -> EmbeddedOffset {
+        EmbeddedOffset { zone: *self, is_dst }
+    }
+}
+
+/// The offset of an [`EmbeddedTimeZone`] at a particular instant.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct EmbeddedOffset {
+    zone: EmbeddedTimeZone,
+    is_dst: bool,
+}
+
+impl EmbeddedOffset {
+    /// Returns true if this offset is a daylight saving time offset.
+    pub fn is_dst(&self) -> bool {
+        self.is_dst
+    }
+}
+
+impl Offset for EmbeddedOffset {
+    fn fix(&self) -> FixedOffset {
+        match self.is_dst {
+            true => self.zone.daylight,
+            false => self.zone.standard,
+        }
+    }
+}
+
+impl fmt::Display for EmbeddedOffset {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.fix(), f)
+    }
+}
+
+impl TimeZone for EmbeddedTimeZone {
+    type Offset = EmbeddedOffset;
+
+    fn from_offset(offset: &Self::Offset) -> Self {
+        offset.zone
+    }
+
+    fn offset_from_local_date(&self, local: &NaiveDate) -> LocalResult<Self::Offset> {
+        self.offset_from_local_datetime(&local.and_time(NaiveTime::MIN))
+    }
+
+    fn offset_from_local_datetime(&self, local: &NaiveDateTime) -> LocalResult<Self::Offset> {
+        // A local time is valid for an offset if converting it to UTC with that offset
+        // lands on an instant where the same offset is in effect.
+        let standard = local
+            .checked_sub_offset(self.standard)
+            .filter(|utc| !self.is_dst(utc))
+            .map(|_| self.offset(false));
+        let daylight = local
+            .checked_sub_offset(self.daylight)
+            .filter(|utc| self.dst.is_some() && self.is_dst(utc))
+            .map(|_| self.offset(true));
+        match (daylight, standard) {
+            (Some(daylight), Some(standard)) => LocalResult::Ambiguous(daylight, standard),
+            (Some(offset), None) | (None, Some(offset)) => LocalResult::Single(offset),
+            (None, None) => LocalResult::None,
+        }
+    }
+
+    fn offset_from_utc_date(&self, utc: &NaiveDate) -> Self::Offset {
+        self.offset_from_utc_datetime(&utc.and_time(NaiveTime::MIN))
+    }
+
+    fn offset_from_utc_datetime(&self, utc: &NaiveDateTime) -> Self::Offset {
+        self.offset(self.is_dst(utc))
+    }
+}
@@ -0,0 +1,60 @@
+//! Zmanim in Unix timestamps, for devices that keep time with a bare real time clock and have no
+//! use for the chrono types.
+use chrono::{DateTime, FixedOffset};
+
+use crate::{
+    astronomical_calculator::AstronomicalCalculatorTrait,
+    constants::Zman,
+    functions::{self, ZmanimSettings},
+    geolocation::GeoLocation,
+    zmanim_calendar::{ZmanimCalendar, ZmanimCalendarTrait},
+};
+
+/// Returns the Unix timestamp of `zman` on the local day containing `timestamp`, where the local
+/// time is `utc_offset_seconds` ahead of UTC, calculated with `settings`.
+///
+/// Returns `None` if the offset is out of range, the zman does not occur on that day, or it
+/// falls before 1970.
+pub fn get_zman_timestamp<N: AstronomicalCalculatorTrait>(
+    timestamp: u64,
+    utc_offset_seconds: i32,
+    geo_location: &GeoLocation,
+    calculator: N,
+    settings: &ZmanimSettings,
+    zman: &Zman,
+) -> Option<u64> {
+    let calendar = calendar_for_timestamp(timestamp, utc_offset_seconds, geo_location, calculator, settings)?;
+    u64::try_from(calendar.get_zman(zman)?.timestamp()).ok()
+}
+
+/// Returns the Unix timestamps of all the zmanim in [`Zman::values`] on the local day containing
+/// `timestamp`, in the same order. See [`get_zman_timestamp`].
+pub fn get_zmanim_timestamps<N: AstronomicalCalculatorTrait>(
+    timestamp: u64,
+    utc_offset_seconds: i32,
+    geo_location: &GeoLocation,
+    calculator: N,
+    settings: &ZmanimSettings,
+) -> Option<impl Iterator<Item = (Zman, Option<u64>)> + use<N>> {
+    let calendar = calendar_for_timestamp(timestamp, utc_offset_seconds, geo_location, calculator, settings)?;
+    Some(Zman::values().into_iter().map(move |zman| {
+        let time = calendar
+            .get_zman(&zman)
+            .and_then(|time| u64::try_from(time.timestamp()).ok());
+        (zman, time)
+    }))
+}
+
+fn calendar_for_timestamp<N: AstronomicalCalculatorTrait>(
+    timestamp: u64,
+    utc_offset_seconds: i32,
+    geo_location: &GeoLocation,
+    calculator: N,
+    settings: &ZmanimSettings,
+) -> Option<ZmanimCalendar<FixedOffset, GeoLocation, N>> {
+    let offset = FixedOffset::east_opt(utc_offset_seconds)?;
+    let date = DateTime::from_timestamp(i64::try_from(timestamp).ok()?, 0)?
+        .with_timezone(&offset)
+        .date_naive();
+    functions::calendar(date, offset, geo_location, calculator, settings)
+}
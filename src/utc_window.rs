@@ -0,0 +1,64 @@
+//! Solar events within a 24 hour window of UTC rather than a local civil day, for travel and
+//! aviation, where the local date is unclear near the date line or while in the air.
+use chrono::{DateTime, Duration, Utc};
+
+use crate::{
+    astronomical_calculator::AstronomicalCalculatorTrait,
+    constants::SolarEvent,
+    geolocation::GeoLocation,
+    zmanim_calendar::{ZmanimCalendar, ZmanimCalendarTrait},
+};
+
+/// The number of UTC dates whose events can fall within a window.
+const DATES: usize = 3;
+
+/// Returns the solar events at `geo_location` from `start` until 24 hours later, in order.
+/// Sunrise and sunset are when the sun reaches `zenith`, such as [`crate::zenith::GEOMETRIC`]
+/// for sunrise and sunset or [`crate::zenith::CIVIL`] for civil twilight. Events that do not
+/// occur, such as sunrise during the polar night, are left out.
+pub fn get_solar_events_in_utc_window<N: AstronomicalCalculatorTrait>(
+    start: DateTime<Utc>,
+    geo_location: &GeoLocation,
+    calculator: N,
+    zenith: f64,
+) -> impl Iterator<Item = (SolarEvent, DateTime<Utc>)> + use<N> {
+    let end = start + Duration::hours(24);
+    let mut events = [None; DATES * 4];
+    // The window overlaps two UTC dates, and an event calculated for the date before or after
+    // either of them can still fall within it near the date line.
+    let first = start.date_naive().pred_opt();
+    let dates = (0..DATES).filter_map(|day| first?.checked_add_signed(Duration::days(day as i64)));
+    for (date, slots) in dates.zip(events.chunks_mut(4)) {
+        let Some(calendar) = ZmanimCalendar::new(
+            date,
+            Utc,
+            geo_location.clone(),
+            calculator.clone(),
+            false,
+            false,
+            Duration::zero(),
+            Duration::zero(),
+        ) else {
+            continue;
+        };
+        let times = [
+            (SolarEvent::Sunrise, calendar.get_sunrise_offset_by_degrees(zenith)),
+            (SolarEvent::Noon, calendar.get_sun_transit()),
+            (SolarEvent::Sunset, calendar.get_sunset_offset_by_degrees(zenith)),
+            (SolarEvent::Midnight, calendar.get_solar_midnight()),
+        ];
+        for (slot, (event, time)) in slots.iter_mut().zip(times) {
+            *slot = time
+                .filter(|time| (start..end).contains(time))
+                .map(|time| (event, time));
+        }
+    }
+    events.sort_unstable_by_key(|event| (event.is_none(), event.map(|(_, time)| time)));
+    // Adjacent dates can place the same event on the same instant.
+    let mut previous = None;
+    events.into_iter().flatten().filter(move |event| {
+        let duplicate = previous == Some(*event);
+        previous = Some(*event);
+        !duplicate
+    })
+}
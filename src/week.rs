@@ -0,0 +1,100 @@
+//! A week of a luach, from Sunday to Shabbos, for bulletins and widgets that show a week at a
+//! time: the parsha, the holidays of the week and when Shabbos begins and ends.
+use chrono::{DateTime, TimeZone, Weekday};
+
+use crate::{
+    astronomical_calculator::AstronomicalCalculatorTrait,
+    constants::{JewishHoliday, Parsha},
+    geolocation::GeoLocation,
+    jewish_calendar::{JewishCalendar, JewishCalendarTrait},
+    luach_day::LuachDay,
+    zmanim_calendar::ZmanimCalendar,
+};
+
+/// The days from Sunday to Shabbos of one week.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Week<Tz: TimeZone, N: AstronomicalCalculatorTrait> {
+    /// The days of the week, from Sunday.
+    pub days: [LuachDay<Tz, N>; 7],
+}
+
+impl<Tz: TimeZone, N: AstronomicalCalculatorTrait> Week<Tz, N> {
+    /// Returns the week that contains `jewish_calendar`, with the zmanim calculated with the
+    /// location and settings of `zmanim_calendar`.
+    pub fn containing(
+        jewish_calendar: &JewishCalendar<N>,
+        zmanim_calendar: &ZmanimCalendar<Tz, GeoLocation, N>,
+    ) -> Option<Self> {
+        let sunday = -i64::from(jewish_calendar.get_day_of_week().num_days_from_sunday());
+        let day = |days: i64| LuachDay::new(jewish_calendar.copy_with_days_added(sunday + days)?, zmanim_calendar);
+        Some(Self {
+            days: [day(0)?, day(1)?, day(2)?, day(3)?, day(4)?, day(5)?, day(6)?],
+        })
+    }
+
+    /// Returns the week after this one.
+    pub fn next(&self) -> Option<Self> {
+        let shabbos = self.get_shabbos();
+        Self::containing(
+            &shabbos.jewish_calendar.copy_with_days_added(1)?,
+            &shabbos.zmanim_calendar,
+        )
+    }
+
+    /// Returns the week before this one.
+    pub fn previous(&self) -> Option<Self> {
+        let sunday = &self.days[0];
+        Self::containing(
+            &sunday.jewish_calendar.copy_with_days_added(-1)?,
+            &sunday.zmanim_calendar,
+        )
+    }
+
+    /// Returns the day of the week that falls on `weekday`.
+    pub fn get_day(&self, weekday: Weekday) -> &LuachDay<Tz, N> {
+        &self.days[weekday.num_days_from_sunday() as usize]
+    }
+
+    /// Returns the Shabbos that ends the week.
+    pub fn get_shabbos(&self) -> &LuachDay<Tz, N> {
+        &self.days[6]
+    }
+
+    /// Returns the parsha read on the Shabbos of this week, or `None` if that Shabbos is a yom
+    /// tov or chol hamoed with its own reading.
+    pub fn get_parshah(&self) -> Option<Parsha> {
+        self.get_shabbos().jewish_calendar.get_parshah()
+    }
+
+    /// Returns the special Shabbos of this week, such as Shabbos Shekalim, if it is one.
+    pub fn get_special_shabbos(&self) -> Option<Parsha> {
+        self.get_shabbos().jewish_calendar.get_special_shabbos()
+    }
+
+    /// Returns the days of this week that are holidays, with each holiday of
+    /// [`JewishCalendar::get_holidays`]. A day with more than one holiday is returned once for each.
+    pub fn holidays(&self) -> impl Iterator<Item = (&LuachDay<Tz, N>, JewishHoliday)> {
+        self.days
+            .iter()
+            .flat_map(|day| day.jewish_calendar.get_holidays().map(move |holiday| (day, holiday)))
+    }
+
+    /// Returns candle lighting on the Friday of this week, or `None` if there is no sunset that
+    /// day.
+    pub fn get_shabbos_start(&self) -> Option<DateTime<Tz>> {
+        self.get_day(Weekday::Fri).get_candle_lighting()
+    }
+
+    /// Returns the end of Shabbos at [`ZmanimCalendar::havdalah_zman`]. When a yom tov begins
+    /// after Shabbos, this is when its candles may be lit rather than havdalah.
+    pub fn get_shabbos_end(&self) -> Option<DateTime<Tz>> {
+        self.get_shabbos().zmanim_calendar.get_havdalah()
+    }
+}
+
+impl<Tz: TimeZone, N: AstronomicalCalculatorTrait> LuachDay<Tz, N> {
+    /// Returns the week, from Sunday to Shabbos, that contains this day.
+    pub fn get_week(&self) -> Option<Week<Tz, N>> {
+        Week::containing(&self.jewish_calendar, &self.zmanim_calendar)
+    }
+}
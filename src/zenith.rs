@@ -0,0 +1,86 @@
+//! Zeniths for calculating the time the sun is at a given depression below the horizon, as
+//! used by the degree based zmanim.
+//!
+//! A zenith is the angle between the sun and the point directly overhead, so the sun at 16.1°
+//! below the horizon has a zenith of 106.1°. These can be passed to
+//! [`get_sunrise_offset_by_degrees`](crate::zmanim_calendar::ZmanimCalendarTrait::get_sunrise_offset_by_degrees)
+//! and [`get_sunset_offset_by_degrees`](crate::zmanim_calendar::ZmanimCalendarTrait::get_sunset_offset_by_degrees).
+//!
+//! Most of these depressions were derived from the position of the sun a fixed number of
+//! minutes before sunrise or after sunset in Jerusalem around the equinox.
+
+/// The center of the sun on the horizon, without adjustment for refraction or the solar radius.
+pub const GEOMETRIC: f64 = 90.0;
+/// Civil twilight, with the sun 6° below the horizon.
+pub const CIVIL: f64 = 96.0;
+/// Nautical twilight, with the sun 12° below the horizon.
+pub const NAUTICAL: f64 = 102.0;
+/// Astronomical twilight, with the sun 18° below the horizon.
+pub const ASTRONOMICAL: f64 = 108.0;
+
+/// 16.1°, the depression 72 minutes before sunrise and after sunset. Used for alos and tzais
+/// of the Magen Avraham, and for the start and end of the day in many other zmanim.
+pub const DEGREES_16_POINT_1: f64 = 90.0 + 16.1;
+/// 8.5°, used for tzais when three small stars are visible, according to the Ohr Meir.
+pub const DEGREES_8_POINT_5: f64 = 90.0 + 8.5;
+/// 3.7°, used for tzais of the Geonim, 13.5 minutes (3/4 of an 18 minute mil) after sunset.
+pub const DEGREES_3_POINT_7: f64 = 90.0 + 3.7;
+/// 3.8°, used for tzais of the Geonim, 13.5 minutes after sunset, with a different calculation
+/// of the equinox.
+pub const DEGREES_3_POINT_8: f64 = 90.0 + 3.8;
+/// 5.95°, used for tzais of the Geonim, 24 minutes after sunset.
+pub const DEGREES_5_POINT_95: f64 = 90.0 + 5.95;
+/// 7.083° (7° 5'), used for tzais when three medium stars are visible, according to Dr. Baruch
+/// Cohen.
+pub const DEGREES_7_POINT_083: f64 = 90.0 + 7.0 + (5.0 / 60.0);
+/// 10.2°, used for misheyakir, 45 minutes before sunrise.
+pub const DEGREES_10_POINT_2: f64 = 90.0 + 10.2;
+/// 11°, used for misheyakir, 48 minutes before sunrise.
+pub const DEGREES_11: f64 = 90.0 + 11.0;
+/// 11.5°, used for misheyakir, 52 minutes before sunrise.
+pub const DEGREES_11_POINT_5: f64 = 90.0 + 11.5;
+/// 13.24°, used for the start of bein hashmashos of Rabbeinu Tam, 58.5 minutes after sunset.
+pub const DEGREES_13_POINT_24: f64 = 90.0 + 13.24;
+/// 19°, used for alos according to the Rambam.
+pub const DEGREES_19: f64 = 90.0 + 19.0;
+/// 19.8°, used for alos 90 minutes before sunrise.
+pub const DEGREES_19_POINT_8: f64 = 90.0 + 19.8;
+/// 26°, used for alos 120 minutes before sunrise.
+pub const DEGREES_26: f64 = 90.0 + 26.0;
+/// 4.37°, used for tzais of the Geonim, 16.875 minutes (3/4 of a 22.5 minute mil) after sunset.
+pub const DEGREES_4_POINT_37: f64 = 90.0 + 4.37;
+/// 4.61°, used for tzais of the Geonim, 18 minutes (3/4 of a 24 minute mil) after sunset.
+pub const DEGREES_4_POINT_61: f64 = 90.0 + 4.61;
+/// 4.8°, used for tzais of the Geonim.
+pub const DEGREES_4_POINT_8: f64 = 90.0 + 4.8;
+/// 3.65°, used for tzais of the Geonim, 13.5 minutes after sunset.
+pub const DEGREES_3_POINT_65: f64 = 90.0 + 3.65;
+/// 3.676°, used for tzais of the Geonim, 13.5 minutes after sunset.
+pub const DEGREES_3_POINT_676: f64 = 90.0 + 3.676;
+/// 5.88°, used for tzais of the Geonim.
+pub const DEGREES_5_POINT_88: f64 = 90.0 + 5.88;
+/// 1.583°, used for sunrise and sunset (netz and shkiah amiti) according to the Baal Hatanya.
+pub const DEGREES_1_POINT_583: f64 = 90.0 + 1.583;
+/// 16.9°, used for alos according to the Baal Hatanya, 72 minutes before netz amiti.
+pub const DEGREES_16_POINT_9: f64 = 90.0 + 16.9;
+/// 6°, used for tzais according to the Baal Hatanya.
+pub const DEGREES_6: f64 = 90.0 + 6.0;
+/// 6.45°, used for tzais according to Rabbi Yechiel Michel Tucazinsky, 28 to 31 minutes after
+/// sunset in Israel.
+pub const DEGREES_6_POINT_45: f64 = 90.0 + 6.45;
+/// 7.65°, used for tzais 35 to 36 minutes after sunset.
+pub const DEGREES_7_POINT_65: f64 = 90.0 + 7.65;
+/// 7.67°, used for tzais.
+pub const DEGREES_7_POINT_67: f64 = 90.0 + 7.67;
+/// 9.3°, used for tzais.
+pub const DEGREES_9_POINT_3: f64 = 90.0 + 9.3;
+/// 9.5°, used for tzais.
+pub const DEGREES_9_POINT_5: f64 = 90.0 + 9.5;
+/// 9.75°, used for tzais.
+pub const DEGREES_9_POINT_75: f64 = 90.0 + 9.75;
+/// 2.1° above the horizon, used for the start of bein hashmashos of the Yereim.
+pub const DEGREES_MINUS_2_POINT_1: f64 = 90.0 - 2.1;
+/// 2.8° above the horizon, used for the start of bein hashmashos of the Yereim.
+pub const DEGREES_MINUS_2_POINT_8: f64 = 90.0 - 2.8;
+/// 3.05° above the horizon, used for the start of bein hashmashos of the Yereim.
+pub const DEGREES_MINUS_3_POINT_05: f64 = 90.0 - 3.05;
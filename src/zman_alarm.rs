@@ -0,0 +1,56 @@
+//! When to notify of an upcoming zman. A fixed lead time can fall before the start of the
+//! period on short winter days, such as a reminder 90 minutes before sof zman shma that would
+//! come before alos, so the notification is clamped to a zman it may not precede.
+use chrono::{DateTime, Duration, TimeZone};
+
+use crate::{
+    astronomical_calculator::AstronomicalCalculatorTrait,
+    constants::Zman,
+    geolocation::GeoLocation,
+    zmanim_calendar::{ZmanimCalendar, ZmanimCalendarTrait},
+};
+
+/// How long before a zman to notify.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum LeadTime {
+    /// A fixed number of minutes.
+    Fixed(Duration),
+    /// Minutes zmaniyos of the GRA, a sixtieth of [`ZmanimCalendarTrait::get_shaah_zmanis_gra`]
+    /// each, which shorten with the day.
+    Zmaniyos(f64),
+}
+
+/// When to notify of a zman, e.g. 18 minutes before sof zman shma but never before alos.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AlarmPolicy {
+    pub lead_time: LeadTime,
+    /// The zman the notification may not come before, or `None` for no limit.
+    pub not_before: Option<Zman>,
+}
+
+impl<Tz: TimeZone, N: AstronomicalCalculatorTrait> ZmanimCalendar<Tz, GeoLocation, N> {
+    /// Returns when to notify of `zman` under `policy`. The notification is moved later to
+    /// [`AlarmPolicy::not_before`] if the lead time would place it earlier, but never later
+    /// than the zman itself.
+    ///
+    /// Returns `None` if `zman` does not occur on this day, or if `not_before` does not, since
+    /// the notification could then not be checked against it.
+    pub fn get_alarm(&self, zman: &Zman, policy: &AlarmPolicy) -> Option<DateTime<Tz>> {
+        let time = self.get_zman(zman)?;
+        let lead_time = match policy.lead_time {
+            LeadTime::Fixed(lead_time) => lead_time,
+            LeadTime::Zmaniyos(minutes) => {
+                let shaah_zmanis = self.get_shaah_zmanis_gra()?.num_milliseconds() as f64;
+                Duration::milliseconds((shaah_zmanis * minutes / 60.0) as i64)
+            }
+        };
+        let mut alarm = time.clone().checked_sub_signed(lead_time)?;
+        if let Some(not_before) = &policy.not_before {
+            let not_before = self.get_zman(not_before)?;
+            if alarm < not_before {
+                alarm = not_before;
+            }
+        }
+        Some(alarm.min(time))
+    }
+}
@@ -0,0 +1,44 @@
+//! The earliest and latest of several shitos of a zman, for showing the range within which one
+//! can be machmir.
+use chrono::{DateTime, TimeZone};
+
+use crate::{
+    astronomical_calculator::AstronomicalCalculatorTrait,
+    constants::{Zman, ZmanConcept},
+    geolocation::GeoLocation,
+    zmanim_calendar::{ZmanimCalendar, ZmanimCalendarTrait},
+};
+
+/// The earliest and latest times among a set of zmanim.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ZmanBounds<Tz: TimeZone> {
+    pub earliest: (Zman, DateTime<Tz>),
+    pub latest: (Zman, DateTime<Tz>),
+}
+
+impl<Tz: TimeZone, N: AstronomicalCalculatorTrait> ZmanimCalendar<Tz, GeoLocation, N> {
+    /// Returns the earliest and latest of `zmanim`, ignoring those that cannot be calculated on
+    /// this day, or `None` if none of them can be.
+    pub fn get_zman_bounds(&self, zmanim: &[Zman]) -> Option<ZmanBounds<Tz>> {
+        let mut times = zmanim.iter().filter_map(|zman| Some((*zman, self.get_zman(zman)?)));
+        let first = times.next()?;
+        let mut bounds = ZmanBounds {
+            earliest: first.clone(),
+            latest: first,
+        };
+        for (zman, time) in times {
+            if time < bounds.earliest.1 {
+                bounds.earliest = (zman, time);
+            } else if time > bounds.latest.1 {
+                bounds.latest = (zman, time);
+            }
+        }
+        Some(bounds)
+    }
+
+    /// Returns the earliest and latest of the shitos of `concept`. See
+    /// [`ZmanimCalendar::get_zman_bounds`].
+    pub fn get_concept_bounds(&self, concept: ZmanConcept) -> Option<ZmanBounds<Tz>> {
+        self.get_zman_bounds(concept.shitos())
+    }
+}
@@ -0,0 +1,65 @@
+//! How much a zman moves from one day to the next, for messages such as "shkia is 2 minutes
+//! later than yesterday".
+use chrono::{DateTime, Duration, NaiveDate, NaiveTime, TimeZone};
+
+use crate::{
+    astronomical_calculator::AstronomicalCalculatorTrait,
+    constants::Zman,
+    geolocation::GeoLocation,
+    zmanim_calendar::{ZmanimCalendar, ZmanimCalendarTrait},
+};
+
+/// The change in the clock time of a zman between adjacent days. Positive values mean that the
+/// zman is later on the later day.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ZmanDrift {
+    pub zman: Zman,
+    /// The change from the previous day to this one.
+    pub since_previous_day: Option<Duration>,
+    /// The change from this day to the next one.
+    pub until_next_day: Option<Duration>,
+}
+
+impl<Tz: TimeZone, N: AstronomicalCalculatorTrait> ZmanimCalendar<Tz, GeoLocation, N> {
+    /// Returns how much each of `zmanim` moves between the previous day, this day and the next
+    /// day. A drift is `None` when the zman cannot be calculated on one of the two days.
+    ///
+    /// The drift is measured in local clock time, so it includes the shift of the clocks on the
+    /// days that daylight saving time starts or ends.
+    pub fn get_zmanim_drift<'a>(&'a self, zmanim: &'a [Zman]) -> impl Iterator<Item = ZmanDrift> + 'a {
+        let date = self.get_date_time().date_naive();
+        let previous = date.pred_opt().and_then(|date| self.with_date(date));
+        let next = date.succ_opt().and_then(|date| self.with_date(date));
+        zmanim.iter().map(move |zman| {
+            let today = self.get_zman(zman).map(|time| since_local_midnight(&time, date));
+            let drift = |other: Option<&Self>| -> Option<Duration> {
+                let other = other?;
+                let other_date = other.get_date_time().date_naive();
+                Some(today? - since_local_midnight(&other.get_zman(zman)?, other_date))
+            };
+            ZmanDrift {
+                zman: *zman,
+                since_previous_day: drift(previous.as_ref()),
+                until_next_day: drift(next.as_ref()).map(|drift| -drift),
+            }
+        })
+    }
+
+    /// Returns how much `zman` moves between adjacent days. See
+    /// [`ZmanimCalendar::get_zmanim_drift`].
+    pub fn get_zman_drift(&self, zman: &Zman) -> ZmanDrift {
+        let zmanim = [*zman];
+        let mut drift = self.get_zmanim_drift(&zmanim);
+        drift.next().unwrap_or(ZmanDrift {
+            zman: *zman,
+            since_previous_day: None,
+            until_next_day: None,
+        })
+    }
+}
+
+/// Returns the local clock time of `time` measured from the midnight that starts `date`, which is
+/// more than a day for a zman of `date` that falls after the following midnight.
+fn since_local_midnight<Tz: TimeZone>(time: &DateTime<Tz>, date: NaiveDate) -> Duration {
+    time.naive_local() - date.and_time(NaiveTime::MIN)
+}
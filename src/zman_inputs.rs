@@ -0,0 +1,329 @@
+//! Explaining which sunrise, sunset or other solar event each zman is derived from, which depends
+//! on the settings of the calendar. This helps to track down why a zman differs from another
+//! luach, such as one that uses sea level sunrise.
+use chrono::{Duration, TimeZone};
+
+use crate::{
+    astronomical_calculator::AstronomicalCalculatorTrait, constants::Zman, geolocation::GeoLocation, zenith,
+    zmanim_calendar::ZmanimCalendar,
+};
+
+/// The maximum number of inputs of a zman.
+const MAX_INPUTS: usize = 4;
+
+/// A solar event that a zman is calculated from.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ZmanInput {
+    /// Sunrise, adjusted for the elevation of the location or at sea level, moved by `offset`
+    /// (negative for before sunrise).
+    Sunrise { elevation: bool, offset: Duration },
+    /// Sunset, adjusted for the elevation of the location or at sea level, moved by `offset`
+    /// (negative for before sunset).
+    Sunset { elevation: bool, offset: Duration },
+    /// The sun at `zenith` in the morning. See [`crate::zenith`].
+    SunriseOffsetByDegrees { zenith: f64 },
+    /// The sun at `zenith` in the evening. See [`crate::zenith`].
+    SunsetOffsetByDegrees { zenith: f64 },
+    /// Astronomical chatzos, when the sun crosses the meridian.
+    SunTransit,
+    /// Fixed local chatzos, noon in the local mean time of the longitude of the location.
+    FixedLocalChatzos,
+}
+
+impl<Tz: TimeZone, N: AstronomicalCalculatorTrait> ZmanimCalendar<Tz, GeoLocation, N> {
+    /// Returns the solar events that `zman` is calculated from with the settings of this
+    /// calendar. Zmanim based on other zmanim, such as sof zman shma of the Magen Avraham, list
+    /// the inputs of those zmanim.
+    ///
+    /// Chatzos calculated as the midpoint of the day falls back to the sun transit when there is
+    /// no sunrise or sunset, which is not listed.
+    pub fn get_zman_inputs(&self, zman: &Zman) -> impl Iterator<Item = ZmanInput> + use<Tz, N> {
+        let sunrise = |offset| ZmanInput::Sunrise {
+            elevation: self.use_elevation(),
+            offset,
+        };
+        let sunset = |offset| ZmanInput::Sunset {
+            elevation: self.use_elevation(),
+            offset,
+        };
+        let zero = Duration::zero();
+        let sea_level_day = [
+            ZmanInput::Sunrise {
+                elevation: false,
+                offset: zero,
+            },
+            ZmanInput::Sunset {
+                elevation: false,
+                offset: zero,
+            },
+        ];
+        let chatzos: &[ZmanInput] = match self.use_astronomical_chatzos() {
+            true => &[ZmanInput::SunTransit],
+            false => &sea_level_day,
+        };
+        // With astronomical chatzos for other zmanim, the half of the day that a zman is in is
+        // measured from or to chatzos.
+        let halves = self.use_astronomical_chatzos_for_other_zmanim;
+        let mut inputs = [None; MAX_INPUTS];
+        let mut push = |input: &[ZmanInput]| {
+            let used = inputs.iter().take_while(|input| input.is_some()).count();
+            for (slot, input) in inputs.iter_mut().skip(used).zip(input) {
+                *slot = Some(*input);
+            }
+        };
+        match zman {
+            Zman::PlagHamincha | Zman::MinchaKetana | Zman::MinchaGedola => {
+                match halves {
+                    true => push(chatzos),
+                    false => push(&[sunrise(zero)]),
+                }
+                push(&[sunset(zero)]);
+            }
+            Zman::Tzais => push(&[ZmanInput::SunsetOffsetByDegrees {
+                zenith: zenith::DEGREES_8_POINT_5,
+            }]),
+            Zman::AlosHashachar => push(&[ZmanInput::SunriseOffsetByDegrees {
+                zenith: zenith::DEGREES_16_POINT_1,
+            }]),
+            Zman::Alos72 => push(&[sunrise(-Duration::minutes(72))]),
+            Zman::Tzais72 => push(&[sunset(Duration::minutes(72))]),
+            Zman::Chatzos => push(chatzos),
+            Zman::ChatzosAsHalfDay => push(&sea_level_day),
+            Zman::SofZmanShmaGRA | Zman::SofZmanTfilaGRA => {
+                push(&[sunrise(zero)]);
+                match halves {
+                    true => push(chatzos),
+                    false => push(&[sunset(zero)]),
+                }
+            }
+            Zman::SofZmanShmaMGA | Zman::SofZmanTfilaMGA => {
+                push(&[sunrise(-Duration::minutes(72))]);
+                match halves {
+                    true => push(chatzos),
+                    false => push(&[sunset(Duration::minutes(72))]),
+                }
+            }
+            Zman::CandleLighting => push(&[ZmanInput::Sunset {
+                elevation: false,
+                offset: -self.candle_lighting_offset,
+            }]),
+            Zman::Alos120 => push(&[sunrise(-Duration::minutes(120))]),
+            Zman::Alos120Zmanis
+            | Zman::Alos72Zmanis
+            | Zman::Alos90Zmanis
+            | Zman::Alos96Zmanis
+            | Zman::Tzais120Zmanis
+            | Zman::Tzais72Zmanis
+            | Zman::Tzais90Zmanis
+            | Zman::Tzais96Zmanis => push(&[sunrise(zero), sunset(zero)]),
+            Zman::Alos18Degrees => push(&[ZmanInput::SunriseOffsetByDegrees {
+                zenith: zenith::ASTRONOMICAL,
+            }]),
+            Zman::Alos19Degrees => push(&[ZmanInput::SunriseOffsetByDegrees {
+                zenith: zenith::DEGREES_19,
+            }]),
+            Zman::Alos19Point8Degrees => push(&[ZmanInput::SunriseOffsetByDegrees {
+                zenith: zenith::DEGREES_19_POINT_8,
+            }]),
+            Zman::Alos26Degrees => push(&[ZmanInput::SunriseOffsetByDegrees {
+                zenith: zenith::DEGREES_26,
+            }]),
+            Zman::Alos60 => push(&[sunrise(-Duration::minutes(60))]),
+            Zman::Alos90 => push(&[sunrise(-Duration::minutes(90))]),
+            Zman::Alos96 => push(&[sunrise(-Duration::minutes(96))]),
+            Zman::BainHashmashosRT13Point24Degrees => push(&[ZmanInput::SunsetOffsetByDegrees {
+                zenith: zenith::DEGREES_13_POINT_24,
+            }]),
+            Zman::BainHashmashosRT13Point5MinutesBefore7Point083Degrees | Zman::TzaisGeonim7Point083Degrees => {
+                push(&[ZmanInput::SunsetOffsetByDegrees {
+                    zenith: zenith::DEGREES_7_POINT_083,
+                }])
+            }
+            Zman::BainHashmashosRT2Stars => push(&[
+                ZmanInput::SunriseOffsetByDegrees {
+                    zenith: zenith::DEGREES_19_POINT_8,
+                },
+                sunrise(zero),
+                sunset(zero),
+            ]),
+            Zman::BainHashmashosRT58Point5Minutes => push(&[sunset(Duration::seconds(58 * 60 + 30))]),
+            Zman::BainHashmashosYereim13Point5Minutes => push(&[sunset(-Duration::seconds(13 * 60 + 30))]),
+            Zman::BainHashmashosYereim16Point875Minutes => {
+                push(&[sunset(-Duration::milliseconds(16 * 60_000 + 52_500))])
+            }
+            Zman::BainHashmashosYereim18Minutes => push(&[sunset(-Duration::minutes(18))]),
+            Zman::BainHashmashosYereim2Point1Degrees => push(&[ZmanInput::SunsetOffsetByDegrees {
+                zenith: zenith::DEGREES_MINUS_2_POINT_1,
+            }]),
+            Zman::BainHashmashosYereim2Point8Degrees => push(&[ZmanInput::SunsetOffsetByDegrees {
+                zenith: zenith::DEGREES_MINUS_2_POINT_8,
+            }]),
+            Zman::BainHashmashosYereim3Point05Degrees => push(&[ZmanInput::SunsetOffsetByDegrees {
+                zenith: zenith::DEGREES_MINUS_3_POINT_05,
+            }]),
+            Zman::MinchaGedola16Point1Degrees | Zman::MinchaKetana16Point1Degrees => {
+                match halves {
+                    true => push(chatzos),
+                    false => push(&[ZmanInput::SunriseOffsetByDegrees {
+                        zenith: zenith::DEGREES_16_POINT_1,
+                    }]),
+                }
+                push(&[ZmanInput::SunsetOffsetByDegrees {
+                    zenith: zenith::DEGREES_16_POINT_1,
+                }]);
+            }
+            Zman::MinchaGedola30Minutes | Zman::SofZmanTfila2HoursBeforeChatzos => push(chatzos),
+            Zman::MinchaGedolaAteretTorah
+            | Zman::MinchaKetanaAteretTorah
+            | Zman::PlagHaminchaAteretTorah
+            | Zman::SofZmanShmaAteretTorah
+            | Zman::SofZmanTfilaAteretTorah => {
+                push(&[sunrise(zero), sunset(zero), sunset(self.ateret_torah_sunset_offset)])
+            }
+            Zman::MinchaGedolaGreaterThan30 => {
+                if !halves {
+                    push(&[sunrise(zero)]);
+                }
+                push(chatzos);
+                push(&[sunset(zero)]);
+            }
+            Zman::MinchaKetana72Minutes => {
+                match halves {
+                    true => push(chatzos),
+                    false => push(&[sunrise(-Duration::minutes(72))]),
+                }
+                push(&[sunset(Duration::minutes(72))]);
+            }
+            Zman::MinchaKetanaGRAFixedLocalChatzosToSunset => push(&[
+                ZmanInput::FixedLocalChatzos,
+                ZmanInput::Sunset {
+                    elevation: true,
+                    offset: zero,
+                },
+            ]),
+            Zman::SofZmanShmaMGA120Minutes | Zman::SofZmanTfilaMGA120Minutes => {
+                push(&[sunrise(-Duration::minutes(120))]);
+                match halves {
+                    true => push(chatzos),
+                    false => push(&[sunset(Duration::minutes(120))]),
+                }
+            }
+            Zman::SofZmanShmaMGA16Point1Degrees | Zman::SofZmanTfilaMGA16Point1Degrees => {
+                push(&[ZmanInput::SunriseOffsetByDegrees {
+                    zenith: zenith::DEGREES_16_POINT_1,
+                }]);
+                match halves {
+                    true => push(chatzos),
+                    false => push(&[ZmanInput::SunsetOffsetByDegrees {
+                        zenith: zenith::DEGREES_16_POINT_1,
+                    }]),
+                }
+            }
+            Zman::SofZmanShmaMGA18Degrees | Zman::SofZmanTfilaMGA18Degrees => {
+                push(&[ZmanInput::SunriseOffsetByDegrees {
+                    zenith: zenith::ASTRONOMICAL,
+                }]);
+                match halves {
+                    true => push(chatzos),
+                    false => push(&[ZmanInput::SunsetOffsetByDegrees {
+                        zenith: zenith::ASTRONOMICAL,
+                    }]),
+                }
+            }
+            Zman::SofZmanShmaMGA19Point8Degrees | Zman::SofZmanTfilaMGA19Point8Degrees => {
+                push(&[ZmanInput::SunriseOffsetByDegrees {
+                    zenith: zenith::DEGREES_19_POINT_8,
+                }]);
+                match halves {
+                    true => push(chatzos),
+                    false => push(&[ZmanInput::SunsetOffsetByDegrees {
+                        zenith: zenith::DEGREES_19_POINT_8,
+                    }]),
+                }
+            }
+            Zman::SofZmanShmaMGA72MinutesZmanis
+            | Zman::SofZmanShmaMGA90MinutesZmanis
+            | Zman::SofZmanShmaMGA96MinutesZmanis
+            | Zman::SofZmanTfilaMGA72MinutesZmanis
+            | Zman::SofZmanTfilaMGA90MinutesZmanis
+            | Zman::SofZmanTfilaMGA96MinutesZmanis => {
+                push(&[sunrise(zero), sunset(zero)]);
+                if halves {
+                    push(chatzos);
+                }
+            }
+            Zman::SofZmanShmaMGA90Minutes | Zman::SofZmanTfilaMGA90Minutes => {
+                push(&[sunrise(-Duration::minutes(90))]);
+                match halves {
+                    true => push(chatzos),
+                    false => push(&[sunset(Duration::minutes(90))]),
+                }
+            }
+            Zman::SofZmanShmaMGA96Minutes | Zman::SofZmanTfilaMGA96Minutes => {
+                push(&[sunrise(-Duration::minutes(96))]);
+                match halves {
+                    true => push(chatzos),
+                    false => push(&[sunset(Duration::minutes(96))]),
+                }
+            }
+            Zman::Tzais120 => push(&[sunset(Duration::minutes(120))]),
+            Zman::Tzais16Point1Degrees => push(&[ZmanInput::SunsetOffsetByDegrees {
+                zenith: zenith::DEGREES_16_POINT_1,
+            }]),
+            Zman::Tzais18Degrees => push(&[ZmanInput::SunsetOffsetByDegrees {
+                zenith: zenith::ASTRONOMICAL,
+            }]),
+            Zman::Tzais19Point8Degrees => push(&[ZmanInput::SunsetOffsetByDegrees {
+                zenith: zenith::DEGREES_19_POINT_8,
+            }]),
+            Zman::Tzais26Degrees => push(&[ZmanInput::SunsetOffsetByDegrees {
+                zenith: zenith::DEGREES_26,
+            }]),
+            Zman::Tzais50 => push(&[sunset(Duration::minutes(50))]),
+            Zman::Tzais60 => push(&[sunset(Duration::minutes(60))]),
+            Zman::Tzais90 => push(&[sunset(Duration::minutes(90))]),
+            Zman::Tzais96 => push(&[sunset(Duration::minutes(96))]),
+            Zman::TzaisAteretTorah => push(&[sunset(self.ateret_torah_sunset_offset)]),
+            Zman::TzaisGeonim3Point65Degrees => push(&[ZmanInput::SunsetOffsetByDegrees {
+                zenith: zenith::DEGREES_3_POINT_65,
+            }]),
+            Zman::TzaisGeonim3Point676Degrees => push(&[ZmanInput::SunsetOffsetByDegrees {
+                zenith: zenith::DEGREES_3_POINT_676,
+            }]),
+            Zman::TzaisGeonim3Point7Degrees => push(&[ZmanInput::SunsetOffsetByDegrees {
+                zenith: zenith::DEGREES_3_POINT_7,
+            }]),
+            Zman::TzaisGeonim3Point8Degrees => push(&[ZmanInput::SunsetOffsetByDegrees {
+                zenith: zenith::DEGREES_3_POINT_8,
+            }]),
+            Zman::TzaisGeonim4Point37Degrees => push(&[ZmanInput::SunsetOffsetByDegrees {
+                zenith: zenith::DEGREES_4_POINT_37,
+            }]),
+            Zman::TzaisGeonim4Point61Degrees => push(&[ZmanInput::SunsetOffsetByDegrees {
+                zenith: zenith::DEGREES_4_POINT_61,
+            }]),
+            Zman::TzaisGeonim4Point8Degrees => push(&[ZmanInput::SunsetOffsetByDegrees {
+                zenith: zenith::DEGREES_4_POINT_8,
+            }]),
+            Zman::TzaisGeonim5Point88Degrees => push(&[ZmanInput::SunsetOffsetByDegrees {
+                zenith: zenith::DEGREES_5_POINT_88,
+            }]),
+            Zman::TzaisGeonim5Point95Degrees => push(&[ZmanInput::SunsetOffsetByDegrees {
+                zenith: zenith::DEGREES_5_POINT_95,
+            }]),
+            Zman::TzaisGeonim6Point45Degrees => push(&[ZmanInput::SunsetOffsetByDegrees {
+                zenith: zenith::DEGREES_6_POINT_45,
+            }]),
+            Zman::TzaisGeonim7Point67Degrees => push(&[ZmanInput::SunsetOffsetByDegrees {
+                zenith: zenith::DEGREES_7_POINT_67,
+            }]),
+            Zman::TzaisGeonim9Point3Degrees => push(&[ZmanInput::SunsetOffsetByDegrees {
+                zenith: zenith::DEGREES_9_POINT_3,
+            }]),
+            Zman::TzaisGeonim9Point75Degrees => push(&[ZmanInput::SunsetOffsetByDegrees {
+                zenith: zenith::DEGREES_9_POINT_75,
+            }]),
+        }
+        inputs.into_iter().flatten()
+    }
+}
@@ -0,0 +1,65 @@
+//! The interval between two zmanim, such as sunrise to sunset or alos to tzais, and the times
+//! that divide it proportionally. The shaos zmaniyos of [`ZmanimCalendarTrait`] are twelfths of
+//! such an interval.
+use chrono::{DateTime, Duration, TimeZone};
+
+use crate::{
+    astronomical_calculator::AstronomicalCalculatorTrait,
+    constants::Zman,
+    geolocation::GeoLocation,
+    zmanim_calendar::{ZmanimCalendar, ZmanimCalendarTrait, multiply_duration},
+};
+
+/// The interval from `start` to `end`. The end is expected to come after the start; if it does
+/// not, the duration is negative and proportional points count backwards from the start.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ZmanInterval<Tz: TimeZone> {
+    pub start: DateTime<Tz>,
+    pub end: DateTime<Tz>,
+}
+
+impl<Tz: TimeZone> ZmanInterval<Tz> {
+    pub fn new(start: DateTime<Tz>, end: DateTime<Tz>) -> Self {
+        Self { start, end }
+    }
+
+    /// Returns the time from the start to the end.
+    pub fn get_duration(&self) -> Duration {
+        self.end.clone() - &self.start
+    }
+
+    /// Returns the time halfway between the start and the end, such as chatzos for sunrise to
+    /// sunset.
+    pub fn get_midpoint(&self) -> DateTime<Tz> {
+        self.start.clone() + self.get_duration() / 2
+    }
+
+    /// Returns the time `fraction` of the way from the start to the end, so that 0 is the start
+    /// and 1 is the end. Fractions outside that range give times before the start or after the
+    /// end. Returns `None` if the time cannot be represented.
+    pub fn get_proportional_point(&self, fraction: f64) -> Option<DateTime<Tz>> {
+        self.start
+            .clone()
+            .checked_add_signed(multiply_duration(self.get_duration(), fraction)?)
+    }
+
+    /// Returns a shaah zmanis of a day of this interval, a twelfth of its duration.
+    pub fn get_shaah_zmanis(&self) -> Duration {
+        self.get_duration() / 12
+    }
+
+    /// Returns the time `hours` shaos zmaniyos after the start, such as sof zman shma at 3 hours.
+    pub fn get_shaah_zmanis_based_zman(&self, hours: f64) -> Option<DateTime<Tz>> {
+        self.start
+            .clone()
+            .checked_add_signed(multiply_duration(self.get_shaah_zmanis(), hours)?)
+    }
+}
+
+impl<Tz: TimeZone, N: AstronomicalCalculatorTrait> ZmanimCalendar<Tz, GeoLocation, N> {
+    /// Returns the interval from `start` to `end` on this day, such as [`Zman::AlosHashachar`] to
+    /// [`Zman::Tzais`], or `None` if either does not occur.
+    pub fn get_interval(&self, start: &Zman, end: &Zman) -> Option<ZmanInterval<Tz>> {
+        Some(ZmanInterval::new(self.get_zman(start)?, self.get_zman(end)?))
+    }
+}
@@ -0,0 +1,81 @@
+//! An estimate of how precise a calculated zman is. The calculation itself is exact to within a
+//! second or so, but the atmosphere is not: refraction near the horizon changes with temperature
+//! and pressure, so the sun may be seen to rise or set a minute or more from the calculated time.
+//! The further the sun travels at a shallow angle, as at high latitudes, the more a small error in
+//! its position moves a zman. Fixed offsets add no uncertainty of their own.
+use chrono::{DateTime, Duration, TimeZone};
+
+use crate::{
+    astronomical_calculator::AstronomicalCalculatorTrait,
+    constants::Zman,
+    geolocation::GeoLocation,
+    zman_inputs::ZmanInput,
+    zmanim_calendar::{ZmanimCalendar, ZmanimCalendarTrait},
+};
+
+/// The zenith of the sun at sunrise and sunset, with the standard refraction and the radius of the
+/// sun.
+const HORIZON_ZENITH: f64 = 90.0 + 50.0 / 60.0;
+/// How far refraction at the horizon commonly differs from the standard, in degrees.
+const HORIZON_UNCERTAINTY: f64 = 0.25;
+/// How far the position of the sun below the horizon may differ from the calculated position, in
+/// degrees, for zmanim defined by the depth of the sun.
+const DEPRESSION_UNCERTAINTY: f64 = 0.1;
+
+/// A zman together with an estimate of its precision.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DetailedZman<Tz: TimeZone> {
+    pub zman: Zman,
+    pub time: DateTime<Tz>,
+    /// How far the zman may be from `time` either way, or `None` if the sun is so close to the
+    /// depth of the zman all night that it may not reach it at all.
+    pub uncertainty: Option<Duration>,
+}
+
+impl<Tz: TimeZone> DetailedZman<Tz> {
+    /// Returns true if the zman is known to within `resolution` either way, such as 30 seconds
+    /// before showing its seconds.
+    pub fn is_precise_to(&self, resolution: Duration) -> bool {
+        self.uncertainty.is_some_and(|uncertainty| uncertainty <= resolution)
+    }
+}
+
+impl<Tz: TimeZone, N: AstronomicalCalculatorTrait> ZmanimCalendar<Tz, GeoLocation, N> {
+    /// Returns `zman` with an estimate of its precision, or `None` if it does not occur on this
+    /// day. See [`ZmanimCalendar::get_zman_uncertainty`].
+    pub fn get_zman_detailed(&self, zman: &Zman) -> Option<DetailedZman<Tz>> {
+        Some(DetailedZman {
+            zman: *zman,
+            time: self.get_zman(zman)?,
+            uncertainty: self.get_zman_uncertainty(zman),
+        })
+    }
+
+    /// Returns how far `zman` may be from the calculated time either way, the largest uncertainty
+    /// of the solar events it is derived from (see [`ZmanimCalendar::get_zman_inputs`]). Sunrise
+    /// and sunset are taken to be uncertain by a quarter of a degree of refraction and the depth
+    /// of the sun by a tenth of a degree. Chatzos and fixed offsets are exact. Returns `None` if
+    /// the sun does not reach a depth that far from that of an input on this day.
+    pub fn get_zman_uncertainty(&self, zman: &Zman) -> Option<Duration> {
+        self.get_zman_inputs(zman).try_fold(Duration::zero(), |max, input| {
+            Some(max.max(self.get_input_uncertainty(&input)?))
+        })
+    }
+
+    fn get_input_uncertainty(&self, input: &ZmanInput) -> Option<Duration> {
+        let (zenith, uncertainty, morning) = match *input {
+            ZmanInput::Sunrise { .. } => (HORIZON_ZENITH, HORIZON_UNCERTAINTY, true),
+            ZmanInput::Sunset { .. } => (HORIZON_ZENITH, HORIZON_UNCERTAINTY, false),
+            ZmanInput::SunriseOffsetByDegrees { zenith } => (zenith, DEPRESSION_UNCERTAINTY, true),
+            ZmanInput::SunsetOffsetByDegrees { zenith } => (zenith, DEPRESSION_UNCERTAINTY, false),
+            ZmanInput::SunTransit | ZmanInput::FixedLocalChatzos => return Some(Duration::zero()),
+        };
+        let at = |zenith| match morning {
+            true => self.get_sunrise_offset_by_degrees(zenith),
+            false => self.get_sunset_offset_by_degrees(zenith),
+        };
+        let earlier = at(zenith - uncertainty)?;
+        let later = at(zenith + uncertainty)?;
+        Some((later - earlier).abs() / 2)
+    }
+}
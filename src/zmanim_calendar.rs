@@ -1,25 +1,129 @@
 use crate::{
-    astronomical_calculator::AstronomicalCalculatorTrait,
+    astronomical_calculator::{AnyCalculator, AstronomicalCalculatorTrait, DynCalculator, ErasedCalculator},
     constants::*,
+    functions::ZmanimSettings,
     geolocation::GeoLocationTrait,
     prelude::{GeoLocation, JewishCalendar, JewishCalendarTrait},
+    zman_interval::ZmanInterval,
 };
-use chrono::{DateTime, Datelike, Days, Duration, NaiveDate, Offset, TimeDelta, TimeZone, Utc};
-use core::time::Duration as StdDuration;
+use chrono::{
+    DateTime, Datelike, Days, Duration, FixedOffset, NaiveDate, NaiveDateTime, Offset, TimeDelta, TimeZone, Utc,
+    Weekday,
+};
+use core::{cell::OnceCell, time::Duration as StdDuration};
 use icu_calendar::{
     options::{DateAddOptions, Overflow},
     types::DateDuration,
 };
 use time::Duration as TimeDuration;
+/// Calculates zmanim for a single date at a location.
+///
+/// The zmanim are calculated once, for times with a fixed UTC offset and a calculator behind a
+/// `dyn` reference, so that code is compiled once no matter how many time zone and calculator
+/// types a program uses. The methods of each calendar type only convert their arguments and
+/// results to and from its time zone.
+///
+/// The date, location, calculator and the settings that the cached values of the `cache` feature
+/// depend on are read with getters and changed with setters, which clear the cache.
 #[derive(Debug, Clone, PartialEq, PartialOrd)]
 pub struct ZmanimCalendar<Tz: TimeZone, G: GeoLocationTrait, N: AstronomicalCalculatorTrait> {
-    pub date_time: DateTime<Tz>,
-    pub geo_location: G,
-    pub noaa_calculator: N,
-    pub use_astronomical_chatzos: bool,
+    date_time: DateTime<Tz>,
+    geo_location: G,
+    noaa_calculator: N,
+    use_astronomical_chatzos: bool,
     pub use_astronomical_chatzos_for_other_zmanim: bool,
     pub candle_lighting_offset: Duration,
     pub ateret_torah_sunset_offset: Duration,
+    use_elevation: bool,
+    /// The nightfall that ends Shabbos and yom tov, which is often later than the tzais used for
+    /// other zmanim, such as [`Zman::Tzais72`]. Defaults to [`Zman::Tzais`].
+    pub havdalah_zman: Zman,
+    #[cfg(feature = "cache")]
+    cache: ZmanimCache,
+}
+
+/// A calendar whose calculator is chosen at runtime.
+pub type AnyZmanimCalendar<Tz> = ZmanimCalendar<Tz, GeoLocation, AnyCalculator>;
+
+/// The zeniths of the alos and tzais that the most zmanim are based on, whose times are cached
+/// like sunrise and sunset. Indexes `sunrise_offset_by_degrees` and `sunset_offset_by_degrees`.
+fn cached_zenith(zenith: f64) -> Option<usize> {
+    [
+        _ZENITH_16_POINT_1,
+        _ASTRONOMICAL_ZENITH,
+        _ZENITH_19_POINT_8,
+        _ZENITH_7_POINT_083,
+    ]
+    .iter()
+    .position(|cached| *cached == zenith)
+}
+
+/// Lazily computed values that most zmanim are based on, enabled by the `cache` feature. The
+/// cache is filled on first use and cleared by the setters of the values it depends on.
+#[cfg(feature = "cache")]
+#[derive(Debug, Clone, Default)]
+struct ZmanimCache {
+    sunrise: std::sync::OnceLock<Option<DateTime<FixedOffset>>>,
+    sea_level_sunrise: std::sync::OnceLock<Option<DateTime<FixedOffset>>>,
+    sunset: std::sync::OnceLock<Option<DateTime<FixedOffset>>>,
+    sea_level_sunset: std::sync::OnceLock<Option<DateTime<FixedOffset>>>,
+    sun_transit: std::sync::OnceLock<Option<DateTime<FixedOffset>>>,
+    chatzos: std::sync::OnceLock<Option<DateTime<FixedOffset>>>,
+    temporal_hour: std::sync::OnceLock<Option<Duration>>,
+    shaah_zmanis_gra: std::sync::OnceLock<Option<Duration>>,
+    sunrise_offset_by_degrees: [std::sync::OnceLock<Option<DateTime<FixedOffset>>>; 4],
+    sunset_offset_by_degrees: [std::sync::OnceLock<Option<DateTime<FixedOffset>>>; 4],
+}
+
+// The cache holds derived values only, so it never affects comparisons.
+#[cfg(feature = "cache")]
+impl PartialEq for ZmanimCache {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+#[cfg(feature = "cache")]
+impl PartialOrd for ZmanimCache {
+    fn partial_cmp(&self, _other: &Self) -> Option<core::cmp::Ordering> {
+        Some(core::cmp::Ordering::Equal)
+    }
+}
+
+/// The same values as the cache, kept for the life of one [`ZmanimCore`], so that the zmanim of
+/// one call to [`ZmanimCalendar::compute`] or [`ZmanimCalendar::get_all_zmanim`] share them
+/// without the `cache` feature.
+#[derive(Default)]
+struct ZmanimMemo {
+    sunrise: OnceCell<Option<DateTime<FixedOffset>>>,
+    sea_level_sunrise: OnceCell<Option<DateTime<FixedOffset>>>,
+    sunset: OnceCell<Option<DateTime<FixedOffset>>>,
+    sea_level_sunset: OnceCell<Option<DateTime<FixedOffset>>>,
+    sun_transit: OnceCell<Option<DateTime<FixedOffset>>>,
+    chatzos: OnceCell<Option<DateTime<FixedOffset>>>,
+    temporal_hour: OnceCell<Option<Duration>>,
+    shaah_zmanis_gra: OnceCell<Option<Duration>>,
+    sunrise_offset_by_degrees: [OnceCell<Option<DateTime<FixedOffset>>>; 4],
+    sunset_offset_by_degrees: [OnceCell<Option<DateTime<FixedOffset>>>; 4],
+}
+
+/// Returns the value of `$field` from the cache of the calendar if there is one, or else from the
+/// memo of the core, computing it on first use.
+#[cfg(feature = "cache")]
+macro_rules! cached {
+    ($self:ident . $field:ident $([$index:expr])?, $compute:expr) => {
+        match $self.cache {
+            Some(cache) => *cache.$field $([$index])?.get_or_init($compute),
+            None => *$self.memo.$field $([$index])?.get_or_init($compute),
+        }
+    };
+}
+
+#[cfg(not(feature = "cache"))]
+macro_rules! cached {
+    ($self:ident . $field:ident $([$index:expr])?, $compute:expr) => {
+        *$self.memo.$field $([$index])?.get_or_init($compute)
+    };
 }
 
 impl<N: AstronomicalCalculatorTrait> ZmanimCalendar<Utc, GeoLocation, N> {
@@ -75,32 +179,254 @@ impl<Tz: TimeZone, N: AstronomicalCalculatorTrait> ZmanimCalendar<Tz, GeoLocatio
             use_astronomical_chatzos_for_other_zmanim,
             candle_lighting_offset,
             ateret_torah_sunset_offset,
+            use_elevation: true,
+            havdalah_zman: Zman::Tzais,
+            #[cfg(feature = "cache")]
+            cache: ZmanimCache::default(),
         })
     }
 
-    fn get_adjusted_date_time(&self, date_time: &DateTime<Tz>) -> Option<DateTime<Tz>> {
-        let offset = self.get_geo_location().get_antimeridian_adjustment(date_time);
-        if offset == 0 {
-            Some(date_time.clone())
-        } else if offset > 0 {
-            date_time
-                .clone()
-                .checked_add_days(Days::new(offset.unsigned_abs() as u64))
-        } else {
-            date_time
-                .clone()
-                .checked_sub_days(Days::new(offset.unsigned_abs() as u64))
+    /// Returns a calendar for `date` with the same location and settings. Returns `None` if
+    /// midnight at the start of `date` does not exist in the time zone.
+    pub fn with_date(&self, date: NaiveDate) -> Option<Self> {
+        let mut calendar = Self::new(
+            date,
+            self.date_time.timezone(),
+            self.geo_location.clone(),
+            self.noaa_calculator.clone(),
+            self.use_astronomical_chatzos,
+            self.use_astronomical_chatzos_for_other_zmanim,
+            self.candle_lighting_offset,
+            self.ateret_torah_sunset_offset,
+        )?;
+        calendar.use_elevation = self.use_elevation;
+        calendar.havdalah_zman = self.havdalah_zman;
+        Some(calendar)
+    }
+
+    /// Returns the end of Shabbos or yom tov according to [`ZmanimCalendar::havdalah_zman`].
+    pub fn get_havdalah(&self) -> Option<DateTime<Tz>> {
+        self.get_zman(&self.havdalah_zman)
+    }
+
+    /// Returns the day of the week of the date of this calendar.
+    pub fn get_day_of_week(&self) -> Weekday {
+        self.date_time.weekday()
+    }
+
+    /// Returns true if the date of this calendar is a Friday, when candles are lit for Shabbos.
+    pub fn is_friday(&self) -> bool {
+        self.get_day_of_week() == Weekday::Fri
+    }
+
+    /// Returns true if the date of this calendar is Shabbos, which ends with havdalah.
+    pub fn is_saturday(&self) -> bool {
+        self.get_day_of_week() == Weekday::Sat
+    }
+
+    /// Moves this calendar to `date`, keeping its location and settings, and clears any cached
+    /// values. Returns `None`, leaving the calendar unchanged, if midnight at the start of `date`
+    /// does not exist in the time zone.
+    pub fn set_date(&mut self, date: NaiveDate) -> Option<()> {
+        self.date_time = self
+            .date_time
+            .timezone()
+            .from_local_datetime(&date.and_hms_opt(0, 0, 0)?)
+            .single()?;
+        self.clear_cache();
+        Some(())
+    }
+
+    /// Moves this calendar to `geo_location`, keeping its date and settings, and clears any
+    /// cached values.
+    pub fn set_geo_location(&mut self, geo_location: GeoLocation) {
+        self.geo_location = geo_location;
+        self.clear_cache();
+    }
+
+    /// Replaces the calculator of this calendar and clears any cached values.
+    pub fn set_calculator(&mut self, calculator: N) {
+        self.noaa_calculator = calculator;
+        self.clear_cache();
+    }
+
+    /// Returns whether chatzos is the astronomical transit of the sun rather than the midpoint
+    /// between sunrise and sunset.
+    pub fn use_astronomical_chatzos(&self) -> bool {
+        self.use_astronomical_chatzos
+    }
+
+    /// Sets whether chatzos is the astronomical transit of the sun, and clears any cached values.
+    pub fn set_use_astronomical_chatzos(&mut self, use_astronomical_chatzos: bool) {
+        self.use_astronomical_chatzos = use_astronomical_chatzos;
+        self.clear_cache();
+    }
+
+    /// Returns whether zmanim based on sunrise and sunset use the elevation adjusted times, like
+    /// KosherJava's `isUseElevation`. Defaults to `true`.
+    pub fn use_elevation(&self) -> bool {
+        self.use_elevation
+    }
+
+    /// Sets whether zmanim based on sunrise and sunset use the elevation adjusted times, like
+    /// KosherJava's `setUseElevation`, and clears any cached values. Set this to `false` to
+    /// reproduce luchos that are calculated at sea level.
+    pub fn set_use_elevation(&mut self, use_elevation: bool) {
+        self.use_elevation = use_elevation;
+        self.clear_cache();
+    }
+
+    /// Returns the time of bedikas chametz before the Pesach of the Jewish year of this date,
+    /// which is nightfall according to the `tzais` shita on the evening of
+    /// [`JewishCalendar::get_bedikas_chametz_date`].
+    pub fn get_bedikas_chametz_time(&self, tzais: &Zman) -> Option<DateTime<Tz>> {
+        let date = self.date_time.date_naive();
+        let jewish_calendar = JewishCalendar::from_gregorian_date(
+            date.year(),
+            date.month() as u8,
+            date.day() as u8,
+            false,
+            false,
+            false,
+            self.noaa_calculator.clone(),
+        )?;
+        let bedikas_chametz = jewish_calendar.get_bedikas_chametz_date()?;
+        self.with_date(bedikas_chametz.get_gregorian_naive_date()?)?
+            .get_zman(tzais)
+    }
+
+    /// Clears the values cached by the `cache` feature. The setters of this calendar call this,
+    /// so it is only needed to free the memory of a calendar that is kept around.
+    pub fn clear_cache(&mut self) {
+        #[cfg(feature = "cache")]
+        {
+            self.cache = ZmanimCache::default();
+        }
+    }
+
+    /// Computes only the requested zmanim, in the same order, calculating the sunrise, sunset,
+    /// chatzos and shaah zmanis they are based on once.
+    pub fn compute<'a>(
+        &'a self,
+        zmanim: &'a [Zman],
+    ) -> impl Iterator<Item = (Zman, Option<DateTime<Tz>>)> + use<'a, Tz, N> {
+        let core = self.core();
+        zmanim
+            .iter()
+            .map(move |zman| (*zman, self.localize(core.get_zman(zman))))
+    }
+
+    /// Computes every zman in [`Zman::values`], in that order, calculating the sunrise, sunset,
+    /// chatzos and shaah zmanis they are based on once. See [`ZmanimCalendar::compute`].
+    pub fn get_all_zmanim(&self) -> [(Zman, Option<DateTime<Tz>>); Zman::COUNT] {
+        let core = self.core();
+        Zman::values().map(|zman| (zman, self.localize(core.get_zman(&zman))))
+    }
+
+    /// Returns the calculation of the zmanim of this calendar, which the methods of
+    /// [`ZmanimCalendarTrait`] call.
+    fn core(&self) -> ZmanimCore<'_> {
+        let core = self.core_at(&self.geo_location, ErasedCalculator(&self.noaa_calculator));
+        #[cfg(feature = "cache")]
+        let core = ZmanimCore {
+            cache: Some(&self.cache),
+            ..core
+        };
+        core
+    }
+
+    /// Returns the calculation of the zmanim of the date and settings of this calendar at
+    /// `geo_location` with `calculator`, without the cache of this calendar.
+    pub(crate) fn core_at<'a>(
+        &'a self,
+        geo_location: &'a GeoLocation,
+        calculator: ErasedCalculator<'a>,
+    ) -> ZmanimCore<'a> {
+        ZmanimCore::new(
+            &self.date_time,
+            geo_location,
+            calculator,
+            ZmanimSettings {
+                use_astronomical_chatzos: self.use_astronomical_chatzos,
+                use_astronomical_chatzos_for_other_zmanim: self.use_astronomical_chatzos_for_other_zmanim,
+                candle_lighting_offset: self.candle_lighting_offset,
+                ateret_torah_sunset_offset: self.ateret_torah_sunset_offset,
+                use_elevation: self.use_elevation,
+                havdalah_zman: self.havdalah_zman,
+            },
+        )
+    }
+
+    /// Converts a time calculated by [`ZmanimCalendar::core`] to the time zone of this calendar.
+    pub(crate) fn localize(&self, time: Option<DateTime<FixedOffset>>) -> Option<DateTime<Tz>> {
+        time.map(|time| time.with_timezone(&self.date_time.timezone()))
+    }
+}
+
+/// Finds the UTC offset of a time zone at an instant, so that [`ZmanimCore`] can give its times
+/// the offset of the time zone of the calendar without being generic over it.
+trait DynTimeZone {
+    fn offset_at(&self, utc: &NaiveDateTime) -> FixedOffset;
+}
+
+impl<Tz: TimeZone> DynTimeZone for DateTime<Tz> {
+    fn offset_at(&self, utc: &NaiveDateTime) -> FixedOffset {
+        self.timezone().offset_from_utc_datetime(utc).fix()
+    }
+}
+
+/// The zmanim of a calendar, calculated with times that have a fixed UTC offset and with an
+/// [`ErasedCalculator`], so that they are compiled once rather than for each time zone and
+/// calculator type. Every time it returns has the offset of the time zone at that instant.
+pub(crate) struct ZmanimCore<'a> {
+    date_time: DateTime<FixedOffset>,
+    timezone: &'a dyn DynTimeZone,
+    geo_location: &'a GeoLocation,
+    calculator: ErasedCalculator<'a>,
+    settings: ZmanimSettings,
+    #[cfg(feature = "cache")]
+    cache: Option<&'a ZmanimCache>,
+    memo: ZmanimMemo,
+}
+
+impl<'a> ZmanimCore<'a> {
+    /// Returns the zmanim of the date of `date_time`, which is midnight at the start of the date,
+    /// in its time zone, without a cache.
+    pub(crate) fn new<Tz: TimeZone>(
+        date_time: &'a DateTime<Tz>,
+        geo_location: &'a GeoLocation,
+        calculator: ErasedCalculator<'a>,
+        settings: ZmanimSettings,
+    ) -> Self {
+        Self {
+            date_time: date_time.fixed_offset(),
+            timezone: date_time,
+            geo_location,
+            calculator,
+            settings,
+            #[cfg(feature = "cache")]
+            cache: None,
+            memo: ZmanimMemo::default(),
         }
     }
 
+    /// Returns the instant `utc` with the offset that the time zone of the calendar has then.
+    fn localize(&self, utc: &NaiveDateTime) -> DateTime<FixedOffset> {
+        DateTime::from_naive_utc_and_offset(*utc, self.timezone.offset_at(utc))
+    }
+
+    fn get_adjusted_date_time(&self, date_time: &DateTime<FixedOffset>) -> Option<DateTime<FixedOffset>> {
+        adjusted_date_time(date_time, self.geo_location)
+    }
+
     fn _get_molad_based_time(
         &self,
         utc_molad_based_time: DateTime<Utc>,
-        alos: Option<&DateTime<Tz>>,
-        tzais: Option<&DateTime<Tz>>,
+        alos: Option<&DateTime<FixedOffset>>,
+        tzais: Option<&DateTime<FixedOffset>>,
         techila: bool,
-    ) -> Option<DateTime<Tz>> {
-        let molad_based_time = self._localized_datetime(utc_molad_based_time);
+    ) -> Option<DateTime<FixedOffset>> {
+        let molad_based_time = self.localize(&utc_molad_based_time.naive_utc());
 
         if molad_based_time.date_naive() != self.get_date_time().date_naive() {
             None
@@ -109,8 +435,8 @@ impl<Tz: TimeZone, N: AstronomicalCalculatorTrait> ZmanimCalendar<Tz, GeoLocatio
                 (Some(alos), Some(tzais)) => {
                     if molad_based_time > *alos && molad_based_time < *tzais {
                         match techila {
-                            true => Some(tzais.clone()),
-                            false => Some(alos.clone()),
+                            true => Some(*tzais),
+                            false => Some(*alos),
                         }
                     } else {
                         Some(molad_based_time)
@@ -120,7 +446,8 @@ impl<Tz: TimeZone, N: AstronomicalCalculatorTrait> ZmanimCalendar<Tz, GeoLocatio
             }
         }
     }
-    fn _get_jewish_calendar(&self) -> Option<JewishCalendar<N>> {
+
+    fn _get_jewish_calendar(&self) -> Option<JewishCalendar<ErasedCalculator<'a>>> {
         JewishCalendar::from_gregorian_date(
             self.get_date_time().year(),
             self.get_date_time().month() as u8,
@@ -128,12 +455,26 @@ impl<Tz: TimeZone, N: AstronomicalCalculatorTrait> ZmanimCalendar<Tz, GeoLocatio
             false,
             false,
             false,
-            self.get_calculator().clone(),
+            self.calculator,
         )
     }
-    fn _localized_datetime(&self, datetime: DateTime<Utc>) -> DateTime<Tz> {
-        self.get_date_time().timezone().from_utc_datetime(&datetime.naive_utc())
-    }
+}
+
+/// Implements methods of [`ZmanimCalendarTrait`] that take no arguments by calling the same
+/// method of [`ZmanimCore`], converting the times it returns to the time zone of the calendar.
+macro_rules! delegate_to_core {
+    (times: [$($time:ident),* $(,)?], durations: [$($duration:ident),* $(,)?] $(,)?) => {
+        $(
+            fn $time(&self) -> Option<DateTime<Tz>> {
+                self.localize(self.core().$time())
+            }
+        )*
+        $(
+            fn $duration(&self) -> Option<Duration> {
+                self.core().$duration()
+            }
+        )*
+    };
 }
 
 pub trait ZmanimCalendarTrait<Tz: TimeZone, G: GeoLocationTrait, N: AstronomicalCalculatorTrait> {
@@ -142,6 +483,14 @@ pub trait ZmanimCalendarTrait<Tz: TimeZone, G: GeoLocationTrait, N: Astronomical
     fn get_calculator(&self) -> &N;
     fn get_sunrise(&self) -> Option<DateTime<Tz>>;
     fn get_sea_level_sunrise(&self) -> Option<DateTime<Tz>>;
+    /// Returns the sunrise that zmanim are based on, at sea level if elevation is not used.
+    fn get_elevation_adjusted_sunrise(&self) -> Option<DateTime<Tz>> {
+        self.get_sunrise()
+    }
+    /// Returns the sunset that zmanim are based on, at sea level if elevation is not used.
+    fn get_elevation_adjusted_sunset(&self) -> Option<DateTime<Tz>> {
+        self.get_sunset()
+    }
     fn get_begin_civil_twilight(&self) -> Option<DateTime<Tz>>;
     fn get_begin_nautical_twilight(&self) -> Option<DateTime<Tz>>;
     fn get_begin_astronomical_twilight(&self) -> Option<DateTime<Tz>>;
@@ -152,10 +501,39 @@ pub trait ZmanimCalendarTrait<Tz: TimeZone, G: GeoLocationTrait, N: Astronomical
     fn get_end_astronomical_twilight(&self) -> Option<DateTime<Tz>>;
     fn get_sunrise_offset_by_degrees(&self, offset_zenith: f64) -> Option<DateTime<Tz>>;
     fn get_sunset_offset_by_degrees(&self, offset_zenith: f64) -> Option<DateTime<Tz>>;
+    /// Returns how many degrees the sun is below the geometric horizon at `date_time`.
+    fn get_degrees_below_horizon(&self, date_time: &DateTime<Tz>) -> f64 {
+        -self
+            .get_calculator()
+            .get_solar_elevation(date_time, self.get_geo_location())
+    }
+    /// Returns the time of sunrise for the given zenith, adjusted for elevation, as fractional hours
+    /// after midnight UTC of the calendar's date (e.g. `5.5` is 05:30 UTC). The value is not normalized
+    /// to the 0-24 range, and `None` is returned if the sun does not reach the zenith on this date.
     fn get_utc_sunrise(&self, zenith: f64) -> Option<f64>;
+    /// Same as [`ZmanimCalendarTrait::get_utc_sunrise`] but without the elevation adjustment.
     fn get_utc_sea_level_sunrise(&self, zenith: f64) -> Option<f64>;
+    /// Returns the time of sunset for the given zenith, adjusted for elevation, as fractional hours
+    /// after midnight UTC of the calendar's date. See [`ZmanimCalendarTrait::get_utc_sunrise`].
     fn get_utc_sunset(&self, zenith: f64) -> Option<f64>;
+    /// Same as [`ZmanimCalendarTrait::get_utc_sunset`] but without the elevation adjustment.
     fn get_utc_sea_level_sunset(&self, zenith: f64) -> Option<f64>;
+    /// Returns solar noon as fractional hours after midnight UTC of the calendar's date.
+    fn get_utc_noon(&self) -> Option<f64> {
+        let adjusted_date_time = adjusted_date_time(self.get_date_time(), self.get_geo_location())?;
+        let noon = self
+            .get_calculator()
+            .get_utc_noon(&adjusted_date_time, self.get_geo_location());
+        if noon.is_nan() { None } else { Some(noon) }
+    }
+    /// Returns solar midnight as fractional hours after midnight UTC of the calendar's date.
+    fn get_utc_midnight(&self) -> Option<f64> {
+        let adjusted_date_time = adjusted_date_time(self.get_date_time(), self.get_geo_location())?;
+        let midnight = self
+            .get_calculator()
+            .get_utc_midnight(&adjusted_date_time, self.get_geo_location());
+        if midnight.is_nan() { None } else { Some(midnight) }
+    }
     fn get_temporal_hour(&self) -> Option<Duration>;
     fn get_temporal_hour_from_times(&self, start_of_day: &DateTime<Tz>, end_of_day: &DateTime<Tz>) -> Option<Duration>;
     fn get_sun_transit(&self) -> Option<DateTime<Tz>>;
@@ -165,11 +543,518 @@ pub trait ZmanimCalendarTrait<Tz: TimeZone, G: GeoLocationTrait, N: Astronomical
         start_of_day: &DateTime<Tz>,
         end_of_day: &DateTime<Tz>,
     ) -> Option<DateTime<Tz>>;
-    fn get_date_from_time(&self, calculated_time: f64, solar_event: _SolarEvent) -> Option<DateTime<Tz>>;
+    /// Converts a time in fractional UTC hours, as returned by
+    /// [`ZmanimCalendarTrait::get_utc_sunrise`], to a date and time on the calendar's day.
+    /// `solar_event` is the event the time was calculated for. See [`SolarEvent`].
+    fn get_date_from_time(&self, calculated_time: f64, solar_event: SolarEvent) -> Option<DateTime<Tz>>;
     fn get_local_mean_time(&self, hours: f64) -> Option<DateTime<Tz>>;
     fn get_percent_of_shaah_zmanis_from_degrees(&self, degrees: f64, sunset: bool) -> Option<f64>;
     fn get_shaah_zmanis_gra(&self) -> Option<Duration>;
     fn get_shaah_zmanis_mga(&self) -> Option<Duration>;
+    /// Returns a shaah zmanis of a day from alos to tzais at 16.1°.
+    fn get_shaah_zmanis_16_point_1_degrees(&self) -> Option<Duration> {
+        self.get_temporal_hour_from_times(
+            &self.get_alos_16_point_1_degrees()?,
+            &self.get_tzais_16_point_1_degrees()?,
+        )
+    }
+    /// Returns a shaah zmanis of a day from alos to tzais at 18°.
+    fn get_shaah_zmanis_18_degrees(&self) -> Option<Duration> {
+        self.get_temporal_hour_from_times(&self.get_alos_18_degrees()?, &self.get_tzais_18_degrees()?)
+    }
+    /// Returns a shaah zmanis of a day from alos to tzais at 19.8°.
+    fn get_shaah_zmanis_19_point_8_degrees(&self) -> Option<Duration> {
+        self.get_temporal_hour_from_times(
+            &self.get_alos_19_point_8_degrees()?,
+            &self.get_tzais_19_point_8_degrees()?,
+        )
+    }
+    /// Returns a shaah zmanis of a day from alos to tzais at 26°.
+    fn get_shaah_zmanis_26_degrees(&self) -> Option<Duration> {
+        self.get_temporal_hour_from_times(&self.get_alos_26_degrees()?, &self.get_tzais_26_degrees()?)
+    }
+    /// Returns a shaah zmanis of a day from alos to tzais of 60 minutes.
+    fn get_shaah_zmanis_60_minutes(&self) -> Option<Duration> {
+        self.get_temporal_hour_from_times(&self.get_alos_60()?, &self.get_tzais_60()?)
+    }
+    /// Returns a shaah zmanis of a day from alos to tzais of 72 minutes.
+    fn get_shaah_zmanis_72_minutes(&self) -> Option<Duration> {
+        self.get_temporal_hour_from_times(&self.get_alos_72()?, &self.get_tzais_72()?)
+    }
+    /// Returns a shaah zmanis of a day from alos to tzais of 72 minutes zmaniyos.
+    fn get_shaah_zmanis_72_minutes_zmanis(&self) -> Option<Duration> {
+        self.get_temporal_hour_from_times(&self.get_alos_72_zmanis()?, &self.get_tzais_72_zmanis()?)
+    }
+    /// Returns a shaah zmanis of a day from alos to tzais of 90 minutes.
+    fn get_shaah_zmanis_90_minutes(&self) -> Option<Duration> {
+        self.get_temporal_hour_from_times(&self.get_alos_90()?, &self.get_tzais_90()?)
+    }
+    /// Returns a shaah zmanis of a day from alos to tzais of 90 minutes zmaniyos.
+    fn get_shaah_zmanis_90_minutes_zmanis(&self) -> Option<Duration> {
+        self.get_temporal_hour_from_times(&self.get_alos_90_zmanis()?, &self.get_tzais_90_zmanis()?)
+    }
+    /// Returns a shaah zmanis of a day from alos to tzais of 96 minutes.
+    fn get_shaah_zmanis_96_minutes(&self) -> Option<Duration> {
+        self.get_temporal_hour_from_times(&self.get_alos_96()?, &self.get_tzais_96()?)
+    }
+    /// Returns a shaah zmanis of a day from alos to tzais of 96 minutes zmaniyos.
+    fn get_shaah_zmanis_96_minutes_zmanis(&self) -> Option<Duration> {
+        self.get_temporal_hour_from_times(&self.get_alos_96_zmanis()?, &self.get_tzais_96_zmanis()?)
+    }
+    /// Returns a shaah zmanis of a day from alos to tzais of 120 minutes.
+    fn get_shaah_zmanis_120_minutes(&self) -> Option<Duration> {
+        self.get_temporal_hour_from_times(&self.get_alos_120()?, &self.get_tzais_120()?)
+    }
+    /// Returns a shaah zmanis of a day from alos to tzais of 120 minutes zmaniyos.
+    fn get_shaah_zmanis_120_minutes_zmanis(&self) -> Option<Duration> {
+        self.get_temporal_hour_from_times(&self.get_alos_120_zmanis()?, &self.get_tzais_120_zmanis()?)
+    }
+    /// Returns a shaah zmanis of a day from alos 72 minutes zmaniyos to tzais of the Ateret Torah.
+    fn get_shaah_zmanis_ateret_torah(&self) -> Option<Duration> {
+        self.get_temporal_hour_from_times(&self.get_alos_72_zmanis()?, &self.get_tzais_ateret_torah()?)
+    }
+    /// Returns a shaah zmanis of a day from alos at 16.1° to tzais at 3.7°.
+    fn get_shaah_zmanis_alos_16_point_1_to_tzais_3_point_7(&self) -> Option<Duration> {
+        self.get_temporal_hour_from_times(
+            &self.get_alos_16_point_1_degrees()?,
+            &self.get_tzais_geonim_3_point_7_degrees()?,
+        )
+    }
+    /// Returns a shaah zmanis of a day from alos at 16.1° to tzais at 3.8°.
+    fn get_shaah_zmanis_alos_16_point_1_to_tzais_3_point_8(&self) -> Option<Duration> {
+        self.get_temporal_hour_from_times(
+            &self.get_alos_16_point_1_degrees()?,
+            &self.get_tzais_geonim_3_point_8_degrees()?,
+        )
+    }
+    /// Returns `hours` shaos zmaniyos of the GRA after sunset, or before sunrise if negative.
+    fn get_zmanis_based_offset(&self, hours: f64) -> Option<DateTime<Tz>> {
+        let shaah_zmanis = self.get_shaah_zmanis_gra()?;
+        if hours == 0.0 {
+            return None;
+        }
+        let offset = Duration::milliseconds((shaah_zmanis.num_milliseconds() as f64 * hours) as i64);
+        if hours > 0.0 {
+            Some(self.get_elevation_adjusted_sunset()? + offset)
+        } else {
+            Some(self.get_elevation_adjusted_sunrise()? + offset)
+        }
+    }
+    /// Returns alos at 16.1° below the horizon.
+    fn get_alos_16_point_1_degrees(&self) -> Option<DateTime<Tz>> {
+        self.get_sunrise_offset_by_degrees(_ZENITH_16_POINT_1)
+    }
+    /// Returns alos at 18° below the horizon.
+    fn get_alos_18_degrees(&self) -> Option<DateTime<Tz>> {
+        self.get_sunrise_offset_by_degrees(_ASTRONOMICAL_ZENITH)
+    }
+    /// Returns alos at 19° below the horizon.
+    fn get_alos_19_degrees(&self) -> Option<DateTime<Tz>> {
+        self.get_sunrise_offset_by_degrees(_ZENITH_19_DEGREES)
+    }
+    /// Returns alos at 19.8° below the horizon.
+    fn get_alos_19_point_8_degrees(&self) -> Option<DateTime<Tz>> {
+        self.get_sunrise_offset_by_degrees(_ZENITH_19_POINT_8)
+    }
+    /// Returns alos at 26° below the horizon.
+    fn get_alos_26_degrees(&self) -> Option<DateTime<Tz>> {
+        self.get_sunrise_offset_by_degrees(_ZENITH_26_DEGREES)
+    }
+    /// Returns alos 60 minutes before sunrise.
+    fn get_alos_60(&self) -> Option<DateTime<Tz>> {
+        self.get_elevation_adjusted_sunrise()
+            .map(|sunrise| sunrise - Duration::minutes(60))
+    }
+    /// Returns alos 72 minutes before sunrise.
+    fn get_alos_72(&self) -> Option<DateTime<Tz>> {
+        self.get_elevation_adjusted_sunrise()
+            .map(|sunrise| sunrise - Duration::minutes(72))
+    }
+    /// Returns alos 90 minutes before sunrise.
+    fn get_alos_90(&self) -> Option<DateTime<Tz>> {
+        self.get_elevation_adjusted_sunrise()
+            .map(|sunrise| sunrise - Duration::minutes(90))
+    }
+    /// Returns alos 96 minutes before sunrise.
+    fn get_alos_96(&self) -> Option<DateTime<Tz>> {
+        self.get_elevation_adjusted_sunrise()
+            .map(|sunrise| sunrise - Duration::minutes(96))
+    }
+    /// Returns alos 120 minutes before sunrise.
+    fn get_alos_120(&self) -> Option<DateTime<Tz>> {
+        self.get_elevation_adjusted_sunrise()
+            .map(|sunrise| sunrise - Duration::minutes(120))
+    }
+    /// Returns alos 72 minutes zmaniyos before sunrise.
+    fn get_alos_72_zmanis(&self) -> Option<DateTime<Tz>> {
+        self.get_zmanis_based_offset(-1.2)
+    }
+    /// Returns alos 90 minutes zmaniyos before sunrise.
+    fn get_alos_90_zmanis(&self) -> Option<DateTime<Tz>> {
+        self.get_zmanis_based_offset(-1.5)
+    }
+    /// Returns alos 96 minutes zmaniyos before sunrise.
+    fn get_alos_96_zmanis(&self) -> Option<DateTime<Tz>> {
+        self.get_zmanis_based_offset(-1.6)
+    }
+    /// Returns alos 120 minutes zmaniyos before sunrise.
+    fn get_alos_120_zmanis(&self) -> Option<DateTime<Tz>> {
+        self.get_zmanis_based_offset(-2.0)
+    }
+    /// Returns tzais at 16.1° below the horizon.
+    fn get_tzais_16_point_1_degrees(&self) -> Option<DateTime<Tz>> {
+        self.get_sunset_offset_by_degrees(_ZENITH_16_POINT_1)
+    }
+    /// Returns tzais at 18° below the horizon.
+    fn get_tzais_18_degrees(&self) -> Option<DateTime<Tz>> {
+        self.get_sunset_offset_by_degrees(_ASTRONOMICAL_ZENITH)
+    }
+    /// Returns tzais at 19.8° below the horizon.
+    fn get_tzais_19_point_8_degrees(&self) -> Option<DateTime<Tz>> {
+        self.get_sunset_offset_by_degrees(_ZENITH_19_POINT_8)
+    }
+    /// Returns tzais at 26° below the horizon.
+    fn get_tzais_26_degrees(&self) -> Option<DateTime<Tz>> {
+        self.get_sunset_offset_by_degrees(_ZENITH_26_DEGREES)
+    }
+    /// Returns tzais 50 minutes after sunset.
+    fn get_tzais_50(&self) -> Option<DateTime<Tz>> {
+        self.get_elevation_adjusted_sunset()
+            .map(|sunset| sunset + Duration::minutes(50))
+    }
+    /// Returns tzais 60 minutes after sunset.
+    fn get_tzais_60(&self) -> Option<DateTime<Tz>> {
+        self.get_elevation_adjusted_sunset()
+            .map(|sunset| sunset + Duration::minutes(60))
+    }
+    /// Returns tzais 72 minutes after sunset.
+    fn get_tzais_72(&self) -> Option<DateTime<Tz>> {
+        self.get_elevation_adjusted_sunset()
+            .map(|sunset| sunset + Duration::minutes(72))
+    }
+    /// Returns tzais 90 minutes after sunset.
+    fn get_tzais_90(&self) -> Option<DateTime<Tz>> {
+        self.get_elevation_adjusted_sunset()
+            .map(|sunset| sunset + Duration::minutes(90))
+    }
+    /// Returns tzais 96 minutes after sunset.
+    fn get_tzais_96(&self) -> Option<DateTime<Tz>> {
+        self.get_elevation_adjusted_sunset()
+            .map(|sunset| sunset + Duration::minutes(96))
+    }
+    /// Returns tzais 120 minutes after sunset.
+    fn get_tzais_120(&self) -> Option<DateTime<Tz>> {
+        self.get_elevation_adjusted_sunset()
+            .map(|sunset| sunset + Duration::minutes(120))
+    }
+    /// Returns tzais 72 minutes zmaniyos after sunset.
+    fn get_tzais_72_zmanis(&self) -> Option<DateTime<Tz>> {
+        self.get_zmanis_based_offset(1.2)
+    }
+    /// Returns tzais 90 minutes zmaniyos after sunset.
+    fn get_tzais_90_zmanis(&self) -> Option<DateTime<Tz>> {
+        self.get_zmanis_based_offset(1.5)
+    }
+    /// Returns tzais 96 minutes zmaniyos after sunset.
+    fn get_tzais_96_zmanis(&self) -> Option<DateTime<Tz>> {
+        self.get_zmanis_based_offset(1.6)
+    }
+    /// Returns tzais 120 minutes zmaniyos after sunset.
+    fn get_tzais_120_zmanis(&self) -> Option<DateTime<Tz>> {
+        self.get_zmanis_based_offset(2.0)
+    }
+    /// Returns sof zman krias shma of the Magen Avraham for a day from alos to tzais at 16.1°.
+    fn get_sof_zman_shma_mga_16_point_1_degrees(&self) -> Option<DateTime<Tz>> {
+        self.get_sof_zman_shma_from_times(
+            &self.get_alos_16_point_1_degrees()?,
+            self.get_tzais_16_point_1_degrees().as_ref(),
+            true,
+        )
+    }
+    /// Returns sof zman krias shma of the Magen Avraham for a day from alos to tzais at 18°.
+    fn get_sof_zman_shma_mga_18_degrees(&self) -> Option<DateTime<Tz>> {
+        self.get_sof_zman_shma_from_times(&self.get_alos_18_degrees()?, self.get_tzais_18_degrees().as_ref(), true)
+    }
+    /// Returns sof zman krias shma of the Magen Avraham for a day from alos to tzais at 19.8°.
+    fn get_sof_zman_shma_mga_19_point_8_degrees(&self) -> Option<DateTime<Tz>> {
+        self.get_sof_zman_shma_from_times(
+            &self.get_alos_19_point_8_degrees()?,
+            self.get_tzais_19_point_8_degrees().as_ref(),
+            true,
+        )
+    }
+    /// Returns sof zman krias shma of the Magen Avraham for a day from alos to tzais of 72 minutes.
+    fn get_sof_zman_shma_mga_72_minutes(&self) -> Option<DateTime<Tz>> {
+        self.get_sof_zman_shma_from_times(&self.get_alos_72()?, self.get_tzais_72().as_ref(), true)
+    }
+    /// Returns sof zman krias shma of the Magen Avraham for a day from alos to tzais of 72 minutes zmaniyos.
+    fn get_sof_zman_shma_mga_72_minutes_zmanis(&self) -> Option<DateTime<Tz>> {
+        self.get_sof_zman_shma_from_times(&self.get_alos_72_zmanis()?, self.get_tzais_72_zmanis().as_ref(), true)
+    }
+    /// Returns sof zman krias shma of the Magen Avraham for a day from alos to tzais of 90 minutes.
+    fn get_sof_zman_shma_mga_90_minutes(&self) -> Option<DateTime<Tz>> {
+        self.get_sof_zman_shma_from_times(&self.get_alos_90()?, self.get_tzais_90().as_ref(), true)
+    }
+    /// Returns sof zman krias shma of the Magen Avraham for a day from alos to tzais of 90 minutes zmaniyos.
+    fn get_sof_zman_shma_mga_90_minutes_zmanis(&self) -> Option<DateTime<Tz>> {
+        self.get_sof_zman_shma_from_times(&self.get_alos_90_zmanis()?, self.get_tzais_90_zmanis().as_ref(), true)
+    }
+    /// Returns sof zman krias shma of the Magen Avraham for a day from alos to tzais of 96 minutes.
+    fn get_sof_zman_shma_mga_96_minutes(&self) -> Option<DateTime<Tz>> {
+        self.get_sof_zman_shma_from_times(&self.get_alos_96()?, self.get_tzais_96().as_ref(), true)
+    }
+    /// Returns sof zman krias shma of the Magen Avraham for a day from alos to tzais of 96 minutes zmaniyos.
+    fn get_sof_zman_shma_mga_96_minutes_zmanis(&self) -> Option<DateTime<Tz>> {
+        self.get_sof_zman_shma_from_times(&self.get_alos_96_zmanis()?, self.get_tzais_96_zmanis().as_ref(), true)
+    }
+    /// Returns sof zman krias shma of the Magen Avraham for a day from alos to tzais of 120 minutes.
+    fn get_sof_zman_shma_mga_120_minutes(&self) -> Option<DateTime<Tz>> {
+        self.get_sof_zman_shma_from_times(&self.get_alos_120()?, self.get_tzais_120().as_ref(), true)
+    }
+    /// Returns sof zman tfila of the Magen Avraham for a day from alos to tzais at 16.1°.
+    fn get_sof_zman_tfila_mga_16_point_1_degrees(&self) -> Option<DateTime<Tz>> {
+        self.get_sof_zman_tfila_from_times(
+            &self.get_alos_16_point_1_degrees()?,
+            self.get_tzais_16_point_1_degrees().as_ref(),
+            true,
+        )
+    }
+    /// Returns sof zman tfila of the Magen Avraham for a day from alos to tzais at 18°.
+    fn get_sof_zman_tfila_mga_18_degrees(&self) -> Option<DateTime<Tz>> {
+        self.get_sof_zman_tfila_from_times(&self.get_alos_18_degrees()?, self.get_tzais_18_degrees().as_ref(), true)
+    }
+    /// Returns sof zman tfila of the Magen Avraham for a day from alos to tzais at 19.8°.
+    fn get_sof_zman_tfila_mga_19_point_8_degrees(&self) -> Option<DateTime<Tz>> {
+        self.get_sof_zman_tfila_from_times(
+            &self.get_alos_19_point_8_degrees()?,
+            self.get_tzais_19_point_8_degrees().as_ref(),
+            true,
+        )
+    }
+    /// Returns sof zman tfila of the Magen Avraham for a day from alos to tzais of 72 minutes.
+    fn get_sof_zman_tfila_mga_72_minutes(&self) -> Option<DateTime<Tz>> {
+        self.get_sof_zman_tfila_from_times(&self.get_alos_72()?, self.get_tzais_72().as_ref(), true)
+    }
+    /// Returns sof zman tfila of the Magen Avraham for a day from alos to tzais of 72 minutes zmaniyos.
+    fn get_sof_zman_tfila_mga_72_minutes_zmanis(&self) -> Option<DateTime<Tz>> {
+        self.get_sof_zman_tfila_from_times(&self.get_alos_72_zmanis()?, self.get_tzais_72_zmanis().as_ref(), true)
+    }
+    /// Returns sof zman tfila of the Magen Avraham for a day from alos to tzais of 90 minutes.
+    fn get_sof_zman_tfila_mga_90_minutes(&self) -> Option<DateTime<Tz>> {
+        self.get_sof_zman_tfila_from_times(&self.get_alos_90()?, self.get_tzais_90().as_ref(), true)
+    }
+    /// Returns sof zman tfila of the Magen Avraham for a day from alos to tzais of 90 minutes zmaniyos.
+    fn get_sof_zman_tfila_mga_90_minutes_zmanis(&self) -> Option<DateTime<Tz>> {
+        self.get_sof_zman_tfila_from_times(&self.get_alos_90_zmanis()?, self.get_tzais_90_zmanis().as_ref(), true)
+    }
+    /// Returns sof zman tfila of the Magen Avraham for a day from alos to tzais of 96 minutes.
+    fn get_sof_zman_tfila_mga_96_minutes(&self) -> Option<DateTime<Tz>> {
+        self.get_sof_zman_tfila_from_times(&self.get_alos_96()?, self.get_tzais_96().as_ref(), true)
+    }
+    /// Returns sof zman tfila of the Magen Avraham for a day from alos to tzais of 96 minutes zmaniyos.
+    fn get_sof_zman_tfila_mga_96_minutes_zmanis(&self) -> Option<DateTime<Tz>> {
+        self.get_sof_zman_tfila_from_times(&self.get_alos_96_zmanis()?, self.get_tzais_96_zmanis().as_ref(), true)
+    }
+    /// Returns sof zman tfila of the Magen Avraham for a day from alos to tzais of 120 minutes.
+    fn get_sof_zman_tfila_mga_120_minutes(&self) -> Option<DateTime<Tz>> {
+        self.get_sof_zman_tfila_from_times(&self.get_alos_120()?, self.get_tzais_120().as_ref(), true)
+    }
+    /// Returns sof zman tfila as a fixed 2 hours before chatzos.
+    fn get_sof_zman_tfila_2_hours_before_chatzos(&self) -> Option<DateTime<Tz>> {
+        self.get_zman(&Zman::Chatzos)
+            .map(|chatzos| chatzos - Duration::hours(2))
+    }
+    /// Returns mincha gedola as a fixed 30 minutes after chatzos.
+    fn get_mincha_gedola_30_minutes(&self) -> Option<DateTime<Tz>> {
+        self.get_zman(&Zman::Chatzos)
+            .map(|chatzos| chatzos + Duration::minutes(30))
+    }
+    /// Returns mincha gedola of a day from alos to tzais at 16.1°.
+    fn get_mincha_gedola_16_point_1_degrees(&self) -> Option<DateTime<Tz>> {
+        self.get_mincha_gedola_from_times(
+            self.get_alos_16_point_1_degrees().as_ref(),
+            &self.get_tzais_16_point_1_degrees()?,
+            true,
+        )
+    }
+    /// Returns the later of mincha gedola and 30 minutes after chatzos.
+    fn get_mincha_gedola_greater_than_30(&self) -> Option<DateTime<Tz>> {
+        let mincha_gedola = self.get_zman(&Zman::MinchaGedola)?;
+        Some(mincha_gedola.max(self.get_mincha_gedola_30_minutes()?))
+    }
+    /// Returns mincha ketana of a day from alos to tzais at 16.1°.
+    fn get_mincha_ketana_16_point_1_degrees(&self) -> Option<DateTime<Tz>> {
+        self.get_mincha_ketana_from_times(
+            self.get_alos_16_point_1_degrees().as_ref(),
+            &self.get_tzais_16_point_1_degrees()?,
+            true,
+        )
+    }
+    /// Returns mincha ketana of a day from alos to tzais of 72 minutes.
+    fn get_mincha_ketana_72_minutes(&self) -> Option<DateTime<Tz>> {
+        self.get_mincha_ketana_from_times(self.get_alos_72().as_ref(), &self.get_tzais_72()?, true)
+    }
+    /// Returns mincha ketana of a half day from fixed local chatzos to sunset.
+    fn get_mincha_ketana_gra_fixed_local_chatzos_to_sunset(&self) -> Option<DateTime<Tz>> {
+        self.get_half_day_based_zman_from_times(&self.get_local_mean_time(12.0)?, &self.get_sunset()?, 3.5)
+    }
+    /// Returns bein hashmashos of Rabbeinu Tam at 13.24° below the horizon.
+    fn get_bain_hashmashos_rt_13_point_24_degrees(&self) -> Option<DateTime<Tz>> {
+        self.get_sunset_offset_by_degrees(_ZENITH_13_POINT_24)
+    }
+    /// Returns bein hashmashos of Rabbeinu Tam 58.5 minutes after sunset.
+    fn get_bain_hashmashos_rt_58_point_5_minutes(&self) -> Option<DateTime<Tz>> {
+        self.get_elevation_adjusted_sunset()
+            .map(|sunset| sunset + Duration::seconds(58 * 60 + 30))
+    }
+    /// Returns bein hashmashos of Rabbeinu Tam 13.5 minutes before tzais at 7.083°.
+    fn get_bain_hashmashos_rt_13_point_5_minutes_before_7_point_083_degrees(&self) -> Option<DateTime<Tz>> {
+        self.get_sunset_offset_by_degrees(_ZENITH_7_POINT_083)
+            .map(|tzais| tzais - Duration::seconds(13 * 60 + 30))
+    }
+    /// Returns bein hashmashos of Rabbeinu Tam, 5/18 of the time from alos at 19.8° to sunrise after sunset.
+    fn get_bain_hashmashos_rt_2_stars(&self) -> Option<DateTime<Tz>> {
+        let alos = self.get_alos_19_point_8_degrees()?;
+        let sunrise = self.get_elevation_adjusted_sunrise()?;
+        let offset = (sunrise - alos).num_milliseconds() as f64 * (5.0 / 18.0);
+        Some(self.get_elevation_adjusted_sunset()? + Duration::milliseconds(offset as i64))
+    }
+    /// Returns bein hashmashos of the Yereim 18 minutes before sunset.
+    fn get_bain_hashmashos_yereim_18_minutes(&self) -> Option<DateTime<Tz>> {
+        self.get_elevation_adjusted_sunset()
+            .map(|sunset| sunset - Duration::minutes(18))
+    }
+    /// Returns bein hashmashos of the Yereim at 3.05° above the horizon.
+    fn get_bain_hashmashos_yereim_3_point_05_degrees(&self) -> Option<DateTime<Tz>> {
+        self.get_sunset_offset_by_degrees(_ZENITH_MINUS_3_POINT_05)
+    }
+    /// Returns bein hashmashos of the Yereim 16.875 minutes before sunset.
+    fn get_bain_hashmashos_yereim_16_point_875_minutes(&self) -> Option<DateTime<Tz>> {
+        self.get_elevation_adjusted_sunset()
+            .map(|sunset| sunset - Duration::milliseconds(16 * 60_000 + 52_500))
+    }
+    /// Returns bein hashmashos of the Yereim at 2.8° above the horizon.
+    fn get_bain_hashmashos_yereim_2_point_8_degrees(&self) -> Option<DateTime<Tz>> {
+        self.get_sunset_offset_by_degrees(_ZENITH_MINUS_2_POINT_8)
+    }
+    /// Returns bein hashmashos of the Yereim 13.5 minutes before sunset.
+    fn get_bain_hashmashos_yereim_13_point_5_minutes(&self) -> Option<DateTime<Tz>> {
+        self.get_elevation_adjusted_sunset()
+            .map(|sunset| sunset - Duration::seconds(13 * 60 + 30))
+    }
+    /// Returns bein hashmashos of the Yereim at 2.1° above the horizon.
+    fn get_bain_hashmashos_yereim_2_point_1_degrees(&self) -> Option<DateTime<Tz>> {
+        self.get_sunset_offset_by_degrees(_ZENITH_MINUS_2_POINT_1)
+    }
+    /// Returns tzais of the Geonim at 3.65° below the horizon.
+    fn get_tzais_geonim_3_point_65_degrees(&self) -> Option<DateTime<Tz>> {
+        self.get_sunset_offset_by_degrees(_ZENITH_3_POINT_65)
+    }
+    /// Returns tzais of the Geonim at 3.676° below the horizon.
+    fn get_tzais_geonim_3_point_676_degrees(&self) -> Option<DateTime<Tz>> {
+        self.get_sunset_offset_by_degrees(_ZENITH_3_POINT_676)
+    }
+    /// Returns tzais of the Geonim at 3.7° below the horizon.
+    fn get_tzais_geonim_3_point_7_degrees(&self) -> Option<DateTime<Tz>> {
+        self.get_sunset_offset_by_degrees(_ZENITH_3_POINT_7)
+    }
+    /// Returns tzais of the Geonim at 3.8° below the horizon.
+    fn get_tzais_geonim_3_point_8_degrees(&self) -> Option<DateTime<Tz>> {
+        self.get_sunset_offset_by_degrees(_ZENITH_3_POINT_8)
+    }
+    /// Returns tzais of the Geonim at 4.37° below the horizon.
+    fn get_tzais_geonim_4_point_37_degrees(&self) -> Option<DateTime<Tz>> {
+        self.get_sunset_offset_by_degrees(_ZENITH_4_POINT_37)
+    }
+    /// Returns tzais of the Geonim at 4.61° below the horizon.
+    fn get_tzais_geonim_4_point_61_degrees(&self) -> Option<DateTime<Tz>> {
+        self.get_sunset_offset_by_degrees(_ZENITH_4_POINT_61)
+    }
+    /// Returns tzais of the Geonim at 4.8° below the horizon.
+    fn get_tzais_geonim_4_point_8_degrees(&self) -> Option<DateTime<Tz>> {
+        self.get_sunset_offset_by_degrees(_ZENITH_4_POINT_8)
+    }
+    /// Returns tzais of the Geonim at 5.88° below the horizon.
+    fn get_tzais_geonim_5_point_88_degrees(&self) -> Option<DateTime<Tz>> {
+        self.get_sunset_offset_by_degrees(_ZENITH_5_POINT_88)
+    }
+    /// Returns tzais of the Geonim at 5.95° below the horizon.
+    fn get_tzais_geonim_5_point_95_degrees(&self) -> Option<DateTime<Tz>> {
+        self.get_sunset_offset_by_degrees(_ZENITH_5_POINT_95)
+    }
+    /// Returns tzais of the Geonim at 6.45° below the horizon.
+    fn get_tzais_geonim_6_point_45_degrees(&self) -> Option<DateTime<Tz>> {
+        self.get_sunset_offset_by_degrees(_ZENITH_6_POINT_45)
+    }
+    /// Returns tzais of the Geonim at 7.083° below the horizon.
+    fn get_tzais_geonim_7_point_083_degrees(&self) -> Option<DateTime<Tz>> {
+        self.get_sunset_offset_by_degrees(_ZENITH_7_POINT_083)
+    }
+    /// Returns tzais of the Geonim at 7.67° below the horizon.
+    fn get_tzais_geonim_7_point_67_degrees(&self) -> Option<DateTime<Tz>> {
+        self.get_sunset_offset_by_degrees(_ZENITH_7_POINT_67)
+    }
+    /// Returns tzais of the Geonim at 8.5° below the horizon.
+    fn get_tzais_geonim_8_point_5_degrees(&self) -> Option<DateTime<Tz>> {
+        self.get_sunset_offset_by_degrees(_ZENITH_8_POINT_5)
+    }
+    /// Returns tzais of the Geonim at 9.3° below the horizon.
+    fn get_tzais_geonim_9_point_3_degrees(&self) -> Option<DateTime<Tz>> {
+        self.get_sunset_offset_by_degrees(_ZENITH_9_POINT_3)
+    }
+    /// Returns tzais of the Geonim at 9.75° below the horizon.
+    fn get_tzais_geonim_9_point_75_degrees(&self) -> Option<DateTime<Tz>> {
+        self.get_sunset_offset_by_degrees(_ZENITH_9_POINT_75)
+    }
+    /// Returns how long after sunset tzais of the Ateret Torah is, 40 minutes by default.
+    fn get_ateret_torah_sunset_offset(&self) -> Duration {
+        Duration::minutes(40)
+    }
+    /// Returns tzais of the Ateret Torah, `get_ateret_torah_sunset_offset` after sunset.
+    fn get_tzais_ateret_torah(&self) -> Option<DateTime<Tz>> {
+        self.get_elevation_adjusted_sunset()
+            .map(|sunset| sunset + self.get_ateret_torah_sunset_offset())
+    }
+    /// Returns sof zman krias shma of the Ateret Torah.
+    fn get_sof_zman_shma_ateret_torah(&self) -> Option<DateTime<Tz>> {
+        self.get_sof_zman_shma_from_times(
+            &self.get_alos_72_zmanis()?,
+            self.get_tzais_ateret_torah().as_ref(),
+            false,
+        )
+    }
+    /// Returns sof zman tfila of the Ateret Torah.
+    fn get_sof_zman_tfila_ateret_torah(&self) -> Option<DateTime<Tz>> {
+        self.get_sof_zman_tfila_from_times(
+            &self.get_alos_72_zmanis()?,
+            self.get_tzais_ateret_torah().as_ref(),
+            false,
+        )
+    }
+    /// Returns mincha gedola of the Ateret Torah.
+    fn get_mincha_gedola_ateret_torah(&self) -> Option<DateTime<Tz>> {
+        self.get_mincha_gedola_from_times(
+            self.get_alos_72_zmanis().as_ref(),
+            &self.get_tzais_ateret_torah()?,
+            false,
+        )
+    }
+    /// Returns mincha ketana of the Ateret Torah.
+    fn get_mincha_ketana_ateret_torah(&self) -> Option<DateTime<Tz>> {
+        self.get_mincha_ketana_from_times(
+            self.get_alos_72_zmanis().as_ref(),
+            &self.get_tzais_ateret_torah()?,
+            false,
+        )
+    }
+    /// Returns plag hamincha of the Ateret Torah.
+    fn get_plag_hamincha_ateret_torah(&self) -> Option<DateTime<Tz>> {
+        self.get_plag_hamincha_from_times(
+            self.get_alos_72_zmanis().as_ref(),
+            &self.get_tzais_ateret_torah()?,
+            false,
+        )
+    }
     fn get_zman(&self, zman: &Zman) -> Option<DateTime<Tz>>;
     fn get_half_day_based_zman_from_times(
         &self,
@@ -252,14 +1137,12 @@ pub trait ZmanimCalendarTrait<Tz: TimeZone, G: GeoLocationTrait, N: Astronomical
     ) -> Option<DateTime<Tz>>;
 }
 
-impl<Tz: TimeZone, N: AstronomicalCalculatorTrait> ZmanimCalendarTrait<Tz, GeoLocation, N>
-    for ZmanimCalendar<Tz, GeoLocation, N>
-{
+impl<'a> ZmanimCalendarTrait<FixedOffset, GeoLocation, ErasedCalculator<'a>> for ZmanimCore<'a> {
     fn get_tchilas_zman_kidush_levana_7_days_from_times(
         &self,
-        alos: Option<&DateTime<Tz>>,
-        tzais: Option<&DateTime<Tz>>,
-    ) -> Option<DateTime<Tz>> {
+        alos: Option<&DateTime<FixedOffset>>,
+        tzais: Option<&DateTime<FixedOffset>>,
+    ) -> Option<DateTime<FixedOffset>> {
         let jewish_calendar = self._get_jewish_calendar()?;
         if jewish_calendar.get_jewish_day_of_month() < 4 || jewish_calendar.get_jewish_day_of_month() > 9 {
             return None;
@@ -271,9 +1154,9 @@ impl<Tz: TimeZone, N: AstronomicalCalculatorTrait> ZmanimCalendarTrait<Tz, GeoLo
 
     fn get_sof_zman_kidush_levana_15_days_from_times(
         &self,
-        alos: Option<&DateTime<Tz>>,
-        tzais: Option<&DateTime<Tz>>,
-    ) -> Option<DateTime<Tz>> {
+        alos: Option<&DateTime<FixedOffset>>,
+        tzais: Option<&DateTime<FixedOffset>>,
+    ) -> Option<DateTime<FixedOffset>> {
         let jewish_calendar = self._get_jewish_calendar()?;
         if jewish_calendar.get_jewish_day_of_month() < 11 || jewish_calendar.get_jewish_day_of_month() > 17 {
             return None;
@@ -284,9 +1167,9 @@ impl<Tz: TimeZone, N: AstronomicalCalculatorTrait> ZmanimCalendarTrait<Tz, GeoLo
 
     fn get_tchilas_zman_kidush_levana_3_days_from_times(
         &self,
-        alos: Option<&DateTime<Tz>>,
-        tzais: Option<&DateTime<Tz>>,
-    ) -> Option<DateTime<Tz>> {
+        alos: Option<&DateTime<FixedOffset>>,
+        tzais: Option<&DateTime<FixedOffset>>,
+    ) -> Option<DateTime<FixedOffset>> {
         let mut jewish_calendar = self._get_jewish_calendar()?;
         if jewish_calendar.get_jewish_day_of_month() > 5 && jewish_calendar.get_jewish_day_of_month() < 30 {
             return None;
@@ -319,9 +1202,9 @@ impl<Tz: TimeZone, N: AstronomicalCalculatorTrait> ZmanimCalendarTrait<Tz, GeoLo
 
     fn get_sof_zman_kidush_levana_between_moldos_from_times(
         &self,
-        alos: Option<&DateTime<Tz>>,
-        tzais: Option<&DateTime<Tz>>,
-    ) -> Option<DateTime<Tz>> {
+        alos: Option<&DateTime<FixedOffset>>,
+        tzais: Option<&DateTime<FixedOffset>>,
+    ) -> Option<DateTime<FixedOffset>> {
         let jewish_calendar = self._get_jewish_calendar()?;
         if jewish_calendar.get_jewish_day_of_month() < 11 || jewish_calendar.get_jewish_day_of_month() > 16 {
             return None;
@@ -358,44 +1241,42 @@ impl<Tz: TimeZone, N: AstronomicalCalculatorTrait> ZmanimCalendarTrait<Tz, GeoLo
 
     fn get_half_day_based_zman_from_times(
         &self,
-        start_of_half_day: &DateTime<Tz>,
-        end_of_half_day: &DateTime<Tz>,
+        start_of_half_day: &DateTime<FixedOffset>,
+        end_of_half_day: &DateTime<FixedOffset>,
         hours: f64,
-    ) -> Option<DateTime<Tz>> {
+    ) -> Option<DateTime<FixedOffset>> {
         let shaah_zmanis = self.get_half_day_based_shaah_zmanis_from_times(start_of_half_day, end_of_half_day)?;
         if hours >= 0.0 {
-            Some(start_of_half_day.clone() + multiply_duration(shaah_zmanis, hours)?)
+            Some(*start_of_half_day + multiply_duration(shaah_zmanis, hours)?)
         } else {
-            Some(end_of_half_day.clone() + multiply_duration(shaah_zmanis, hours)?)
+            Some(*end_of_half_day + multiply_duration(shaah_zmanis, hours)?)
         }
     }
 
     fn get_half_day_based_shaah_zmanis_from_times(
         &self,
-        start_of_half_day: &DateTime<Tz>,
-        end_of_half_day: &DateTime<Tz>,
+        start_of_half_day: &DateTime<FixedOffset>,
+        end_of_half_day: &DateTime<FixedOffset>,
     ) -> Option<Duration> {
-        Some((end_of_half_day.clone() - start_of_half_day) / 6)
+        Some(ZmanInterval::new(*start_of_half_day, *end_of_half_day).get_duration() / 6)
     }
 
     fn get_shaah_zmanis_based_zman_from_times(
         &self,
-        start_of_day: &DateTime<Tz>,
-        end_of_day: &DateTime<Tz>,
+        start_of_day: &DateTime<FixedOffset>,
+        end_of_day: &DateTime<FixedOffset>,
         hours: f64,
-    ) -> Option<DateTime<Tz>> {
-        let shaah_zmanis = self.get_temporal_hour_from_times(start_of_day, end_of_day)?;
-
-        Some(start_of_day.clone() + multiply_duration(shaah_zmanis, hours)?)
+    ) -> Option<DateTime<FixedOffset>> {
+        ZmanInterval::new(*start_of_day, *end_of_day).get_shaah_zmanis_based_zman(hours)
     }
 
     fn get_sof_zman_shma_from_times(
         &self,
-        start_of_day: &DateTime<Tz>,
-        end_of_day: Option<&DateTime<Tz>>,
+        start_of_day: &DateTime<FixedOffset>,
+        end_of_day: Option<&DateTime<FixedOffset>>,
         synchronous: bool,
-    ) -> Option<DateTime<Tz>> {
-        if self.use_astronomical_chatzos_for_other_zmanim && synchronous {
+    ) -> Option<DateTime<FixedOffset>> {
+        if self.settings.use_astronomical_chatzos_for_other_zmanim && synchronous {
             self.get_half_day_based_zman_from_times(start_of_day, &self.get_zman(&Zman::Chatzos)?, 3.0)
         } else {
             self.get_shaah_zmanis_based_zman_from_times(start_of_day, end_of_day?, 3.0)
@@ -404,11 +1285,11 @@ impl<Tz: TimeZone, N: AstronomicalCalculatorTrait> ZmanimCalendarTrait<Tz, GeoLo
 
     fn get_mincha_gedola_from_times(
         &self,
-        start_of_day: Option<&DateTime<Tz>>,
-        end_of_day: &DateTime<Tz>,
+        start_of_day: Option<&DateTime<FixedOffset>>,
+        end_of_day: &DateTime<FixedOffset>,
         synchronous: bool,
-    ) -> Option<DateTime<Tz>> {
-        if self.use_astronomical_chatzos_for_other_zmanim && synchronous {
+    ) -> Option<DateTime<FixedOffset>> {
+        if self.settings.use_astronomical_chatzos_for_other_zmanim && synchronous {
             self.get_half_day_based_zman_from_times(&self.get_zman(&Zman::Chatzos)?, end_of_day, 0.5)
         } else {
             self.get_shaah_zmanis_based_zman_from_times(start_of_day?, end_of_day, 6.5)
@@ -416,7 +1297,12 @@ impl<Tz: TimeZone, N: AstronomicalCalculatorTrait> ZmanimCalendarTrait<Tz, GeoLo
     }
 
     fn get_shaah_zmanis_gra(&self) -> Option<Duration> {
-        self.get_temporal_hour_from_times(&self.get_sunrise()?, &self.get_sunset()?)
+        cached!(self.shaah_zmanis_gra, || {
+            self.get_temporal_hour_from_times(
+                &self.get_elevation_adjusted_sunrise()?,
+                &self.get_elevation_adjusted_sunset()?,
+            )
+        })
     }
 
     fn get_shaah_zmanis_mga(&self) -> Option<Duration> {
@@ -424,11 +1310,11 @@ impl<Tz: TimeZone, N: AstronomicalCalculatorTrait> ZmanimCalendarTrait<Tz, GeoLo
     }
     fn get_mincha_ketana_from_times(
         &self,
-        start_of_day: Option<&DateTime<Tz>>,
-        end_of_day: &DateTime<Tz>,
+        start_of_day: Option<&DateTime<FixedOffset>>,
+        end_of_day: &DateTime<FixedOffset>,
         synchronous: bool,
-    ) -> Option<DateTime<Tz>> {
-        if self.use_astronomical_chatzos_for_other_zmanim && synchronous {
+    ) -> Option<DateTime<FixedOffset>> {
+        if self.settings.use_astronomical_chatzos_for_other_zmanim && synchronous {
             self.get_half_day_based_zman_from_times(&self.get_zman(&Zman::Chatzos)?, end_of_day, 3.5)
         } else {
             self.get_shaah_zmanis_based_zman_from_times(start_of_day?, end_of_day, 9.5)
@@ -436,11 +1322,11 @@ impl<Tz: TimeZone, N: AstronomicalCalculatorTrait> ZmanimCalendarTrait<Tz, GeoLo
     }
     fn get_sof_zman_tfila_from_times(
         &self,
-        start_of_day: &DateTime<Tz>,
-        end_of_day: Option<&DateTime<Tz>>,
+        start_of_day: &DateTime<FixedOffset>,
+        end_of_day: Option<&DateTime<FixedOffset>>,
         synchronous: bool,
-    ) -> Option<DateTime<Tz>> {
-        if self.use_astronomical_chatzos_for_other_zmanim && synchronous {
+    ) -> Option<DateTime<FixedOffset>> {
+        if self.settings.use_astronomical_chatzos_for_other_zmanim && synchronous {
             self.get_half_day_based_zman_from_times(start_of_day, &self.get_zman(&Zman::Chatzos)?, 4.0)
         } else {
             self.get_shaah_zmanis_based_zman_from_times(start_of_day, end_of_day?, 4.0)
@@ -449,11 +1335,11 @@ impl<Tz: TimeZone, N: AstronomicalCalculatorTrait> ZmanimCalendarTrait<Tz, GeoLo
 
     fn get_samuch_le_mincha_ketana_from_times(
         &self,
-        start_of_day: Option<&DateTime<Tz>>,
-        end_of_day: &DateTime<Tz>,
+        start_of_day: Option<&DateTime<FixedOffset>>,
+        end_of_day: &DateTime<FixedOffset>,
         synchronous: bool,
-    ) -> Option<DateTime<Tz>> {
-        if self.use_astronomical_chatzos_for_other_zmanim && synchronous {
+    ) -> Option<DateTime<FixedOffset>> {
+        if self.settings.use_astronomical_chatzos_for_other_zmanim && synchronous {
             self.get_half_day_based_zman_from_times(&self.get_zman(&Zman::Chatzos)?, end_of_day, 3.0)
         } else {
             self.get_shaah_zmanis_based_zman_from_times(start_of_day?, end_of_day, 9.0)
@@ -462,224 +1348,369 @@ impl<Tz: TimeZone, N: AstronomicalCalculatorTrait> ZmanimCalendarTrait<Tz, GeoLo
 
     fn get_plag_hamincha_from_times(
         &self,
-        start_of_day: Option<&DateTime<Tz>>,
-        end_of_day: &DateTime<Tz>,
+        start_of_day: Option<&DateTime<FixedOffset>>,
+        end_of_day: &DateTime<FixedOffset>,
         synchronous: bool,
-    ) -> Option<DateTime<Tz>> {
-        if self.use_astronomical_chatzos_for_other_zmanim && synchronous {
+    ) -> Option<DateTime<FixedOffset>> {
+        if self.settings.use_astronomical_chatzos_for_other_zmanim && synchronous {
             self.get_half_day_based_zman_from_times(&self.get_zman(&Zman::Chatzos)?, end_of_day, 4.75)
         } else {
             self.get_shaah_zmanis_based_zman_from_times(start_of_day?, end_of_day, 10.75)
         }
     }
 
-    fn get_zman(&self, zman: &Zman) -> Option<DateTime<Tz>> {
+    fn get_zman(&self, zman: &Zman) -> Option<DateTime<FixedOffset>> {
         let astro = self;
         match zman {
-            Zman::PlagHamincha => {
-                self.get_plag_hamincha_from_times(astro.get_sunrise().as_ref(), &astro.get_sunset()?, true)
-            }
-            Zman::MinchaKetana => {
-                self.get_mincha_ketana_from_times(astro.get_sunrise().as_ref(), &astro.get_sunset()?, true)
-            }
-            Zman::MinchaGedola => {
-                self.get_mincha_gedola_from_times(astro.get_sunrise().as_ref(), &astro.get_sunset()?, true)
-            }
+            Zman::PlagHamincha => self.get_plag_hamincha_from_times(
+                self.get_elevation_adjusted_sunrise().as_ref(),
+                &self.get_elevation_adjusted_sunset()?,
+                true,
+            ),
+            Zman::MinchaKetana => self.get_mincha_ketana_from_times(
+                self.get_elevation_adjusted_sunrise().as_ref(),
+                &self.get_elevation_adjusted_sunset()?,
+                true,
+            ),
+            Zman::MinchaGedola => self.get_mincha_gedola_from_times(
+                self.get_elevation_adjusted_sunrise().as_ref(),
+                &self.get_elevation_adjusted_sunset()?,
+                true,
+            ),
             Zman::Tzais => astro.get_sunset_offset_by_degrees(_ZENITH_8_POINT_5),
             Zman::AlosHashachar => astro.get_sunrise_offset_by_degrees(_ZENITH_16_POINT_1),
-            Zman::Alos72 => astro.get_sunrise().map(|sunrise| sunrise - Duration::minutes(72)),
-            Zman::Chatzos => {
-                if self.use_astronomical_chatzos {
+            Zman::Alos72 => self
+                .get_elevation_adjusted_sunrise()
+                .map(|sunrise| sunrise - Duration::minutes(72)),
+            Zman::Chatzos => cached!(self.chatzos, || {
+                if self.settings.use_astronomical_chatzos {
                     astro.get_sun_transit()
                 } else {
                     self.get_zman(&Zman::ChatzosAsHalfDay).or(astro.get_sun_transit())
                 }
-            }
+            }),
             Zman::ChatzosAsHalfDay => {
                 let sunrise = astro.get_sea_level_sunrise()?;
                 let sunset = astro.get_sea_level_sunset()?;
                 astro.get_sun_transit_from_times(&sunrise, &sunset)
             }
-            Zman::SofZmanShmaGRA => {
-                self.get_sof_zman_shma_from_times(&astro.get_sunrise()?, astro.get_sunset().as_ref(), true)
-            }
+            Zman::SofZmanShmaGRA => self.get_sof_zman_shma_from_times(
+                &self.get_elevation_adjusted_sunrise()?,
+                self.get_elevation_adjusted_sunset().as_ref(),
+                true,
+            ),
             Zman::SofZmanShmaMGA => self.get_sof_zman_shma_from_times(
                 &self.get_zman(&Zman::Alos72)?,
                 self.get_zman(&Zman::Tzais72).as_ref(),
                 true,
             ),
-            Zman::Tzais72 => astro.get_sunset().map(|sunset| sunset + Duration::minutes(72)),
+            Zman::Tzais72 => self
+                .get_elevation_adjusted_sunset()
+                .map(|sunset| sunset + Duration::minutes(72)),
             Zman::CandleLighting => astro
                 .get_sea_level_sunset()
-                .map(|sunset| sunset - self.candle_lighting_offset),
-            Zman::SofZmanTfilaGRA => {
-                self.get_sof_zman_tfila_from_times(&astro.get_sunrise()?, astro.get_sunset().as_ref(), true)
-            }
+                .map(|sunset| sunset - self.settings.candle_lighting_offset),
+            Zman::SofZmanTfilaGRA => self.get_sof_zman_tfila_from_times(
+                &self.get_elevation_adjusted_sunrise()?,
+                self.get_elevation_adjusted_sunset().as_ref(),
+                true,
+            ),
             Zman::SofZmanTfilaMGA => self.get_sof_zman_tfila_from_times(
                 &self.get_zman(&Zman::Alos72)?,
                 self.get_zman(&Zman::Tzais72).as_ref(),
                 true,
             ),
+            Zman::Alos120 => self.get_alos_120(),
+            Zman::Alos120Zmanis => self.get_alos_120_zmanis(),
+            Zman::Alos18Degrees => self.get_alos_18_degrees(),
+            Zman::Alos19Degrees => self.get_alos_19_degrees(),
+            Zman::Alos19Point8Degrees => self.get_alos_19_point_8_degrees(),
+            Zman::Alos26Degrees => self.get_alos_26_degrees(),
+            Zman::Alos60 => self.get_alos_60(),
+            Zman::Alos72Zmanis => self.get_alos_72_zmanis(),
+            Zman::Alos90 => self.get_alos_90(),
+            Zman::Alos90Zmanis => self.get_alos_90_zmanis(),
+            Zman::Alos96 => self.get_alos_96(),
+            Zman::Alos96Zmanis => self.get_alos_96_zmanis(),
+            Zman::BainHashmashosRT13Point24Degrees => self.get_bain_hashmashos_rt_13_point_24_degrees(),
+            Zman::BainHashmashosRT13Point5MinutesBefore7Point083Degrees => {
+                self.get_bain_hashmashos_rt_13_point_5_minutes_before_7_point_083_degrees()
+            }
+            Zman::BainHashmashosRT2Stars => self.get_bain_hashmashos_rt_2_stars(),
+            Zman::BainHashmashosRT58Point5Minutes => self.get_bain_hashmashos_rt_58_point_5_minutes(),
+            Zman::BainHashmashosYereim13Point5Minutes => self.get_bain_hashmashos_yereim_13_point_5_minutes(),
+            Zman::BainHashmashosYereim16Point875Minutes => self.get_bain_hashmashos_yereim_16_point_875_minutes(),
+            Zman::BainHashmashosYereim18Minutes => self.get_bain_hashmashos_yereim_18_minutes(),
+            Zman::BainHashmashosYereim2Point1Degrees => self.get_bain_hashmashos_yereim_2_point_1_degrees(),
+            Zman::BainHashmashosYereim2Point8Degrees => self.get_bain_hashmashos_yereim_2_point_8_degrees(),
+            Zman::BainHashmashosYereim3Point05Degrees => self.get_bain_hashmashos_yereim_3_point_05_degrees(),
+            Zman::MinchaGedola16Point1Degrees => self.get_mincha_gedola_16_point_1_degrees(),
+            Zman::MinchaGedola30Minutes => self.get_mincha_gedola_30_minutes(),
+            Zman::MinchaGedolaAteretTorah => self.get_mincha_gedola_ateret_torah(),
+            Zman::MinchaGedolaGreaterThan30 => self.get_mincha_gedola_greater_than_30(),
+            Zman::MinchaKetana16Point1Degrees => self.get_mincha_ketana_16_point_1_degrees(),
+            Zman::MinchaKetana72Minutes => self.get_mincha_ketana_72_minutes(),
+            Zman::MinchaKetanaAteretTorah => self.get_mincha_ketana_ateret_torah(),
+            Zman::MinchaKetanaGRAFixedLocalChatzosToSunset => {
+                self.get_mincha_ketana_gra_fixed_local_chatzos_to_sunset()
+            }
+            Zman::PlagHaminchaAteretTorah => self.get_plag_hamincha_ateret_torah(),
+            Zman::SofZmanShmaAteretTorah => self.get_sof_zman_shma_ateret_torah(),
+            Zman::SofZmanShmaMGA120Minutes => self.get_sof_zman_shma_mga_120_minutes(),
+            Zman::SofZmanShmaMGA16Point1Degrees => self.get_sof_zman_shma_mga_16_point_1_degrees(),
+            Zman::SofZmanShmaMGA18Degrees => self.get_sof_zman_shma_mga_18_degrees(),
+            Zman::SofZmanShmaMGA19Point8Degrees => self.get_sof_zman_shma_mga_19_point_8_degrees(),
+            Zman::SofZmanShmaMGA72MinutesZmanis => self.get_sof_zman_shma_mga_72_minutes_zmanis(),
+            Zman::SofZmanShmaMGA90Minutes => self.get_sof_zman_shma_mga_90_minutes(),
+            Zman::SofZmanShmaMGA90MinutesZmanis => self.get_sof_zman_shma_mga_90_minutes_zmanis(),
+            Zman::SofZmanShmaMGA96Minutes => self.get_sof_zman_shma_mga_96_minutes(),
+            Zman::SofZmanShmaMGA96MinutesZmanis => self.get_sof_zman_shma_mga_96_minutes_zmanis(),
+            Zman::SofZmanTfila2HoursBeforeChatzos => self.get_sof_zman_tfila_2_hours_before_chatzos(),
+            Zman::SofZmanTfilaAteretTorah => self.get_sof_zman_tfila_ateret_torah(),
+            Zman::SofZmanTfilaMGA120Minutes => self.get_sof_zman_tfila_mga_120_minutes(),
+            Zman::SofZmanTfilaMGA16Point1Degrees => self.get_sof_zman_tfila_mga_16_point_1_degrees(),
+            Zman::SofZmanTfilaMGA18Degrees => self.get_sof_zman_tfila_mga_18_degrees(),
+            Zman::SofZmanTfilaMGA19Point8Degrees => self.get_sof_zman_tfila_mga_19_point_8_degrees(),
+            Zman::SofZmanTfilaMGA72MinutesZmanis => self.get_sof_zman_tfila_mga_72_minutes_zmanis(),
+            Zman::SofZmanTfilaMGA90Minutes => self.get_sof_zman_tfila_mga_90_minutes(),
+            Zman::SofZmanTfilaMGA90MinutesZmanis => self.get_sof_zman_tfila_mga_90_minutes_zmanis(),
+            Zman::SofZmanTfilaMGA96Minutes => self.get_sof_zman_tfila_mga_96_minutes(),
+            Zman::SofZmanTfilaMGA96MinutesZmanis => self.get_sof_zman_tfila_mga_96_minutes_zmanis(),
+            Zman::Tzais120 => self.get_tzais_120(),
+            Zman::Tzais120Zmanis => self.get_tzais_120_zmanis(),
+            Zman::Tzais16Point1Degrees => self.get_tzais_16_point_1_degrees(),
+            Zman::Tzais18Degrees => self.get_tzais_18_degrees(),
+            Zman::Tzais19Point8Degrees => self.get_tzais_19_point_8_degrees(),
+            Zman::Tzais26Degrees => self.get_tzais_26_degrees(),
+            Zman::Tzais50 => self.get_tzais_50(),
+            Zman::Tzais60 => self.get_tzais_60(),
+            Zman::Tzais72Zmanis => self.get_tzais_72_zmanis(),
+            Zman::Tzais90 => self.get_tzais_90(),
+            Zman::Tzais90Zmanis => self.get_tzais_90_zmanis(),
+            Zman::Tzais96 => self.get_tzais_96(),
+            Zman::Tzais96Zmanis => self.get_tzais_96_zmanis(),
+            Zman::TzaisAteretTorah => self.get_tzais_ateret_torah(),
+            Zman::TzaisGeonim3Point65Degrees => self.get_tzais_geonim_3_point_65_degrees(),
+            Zman::TzaisGeonim3Point676Degrees => self.get_tzais_geonim_3_point_676_degrees(),
+            Zman::TzaisGeonim3Point7Degrees => self.get_tzais_geonim_3_point_7_degrees(),
+            Zman::TzaisGeonim3Point8Degrees => self.get_tzais_geonim_3_point_8_degrees(),
+            Zman::TzaisGeonim4Point37Degrees => self.get_tzais_geonim_4_point_37_degrees(),
+            Zman::TzaisGeonim4Point61Degrees => self.get_tzais_geonim_4_point_61_degrees(),
+            Zman::TzaisGeonim4Point8Degrees => self.get_tzais_geonim_4_point_8_degrees(),
+            Zman::TzaisGeonim5Point88Degrees => self.get_tzais_geonim_5_point_88_degrees(),
+            Zman::TzaisGeonim5Point95Degrees => self.get_tzais_geonim_5_point_95_degrees(),
+            Zman::TzaisGeonim6Point45Degrees => self.get_tzais_geonim_6_point_45_degrees(),
+            Zman::TzaisGeonim7Point083Degrees => self.get_tzais_geonim_7_point_083_degrees(),
+            Zman::TzaisGeonim7Point67Degrees => self.get_tzais_geonim_7_point_67_degrees(),
+            Zman::TzaisGeonim9Point3Degrees => self.get_tzais_geonim_9_point_3_degrees(),
+            Zman::TzaisGeonim9Point75Degrees => self.get_tzais_geonim_9_point_75_degrees(),
         }
     }
-    fn get_date_time(&self) -> &DateTime<Tz> {
+    fn get_date_time(&self) -> &DateTime<FixedOffset> {
         &self.date_time
     }
 
     fn get_geo_location(&self) -> &GeoLocation {
-        &self.geo_location
+        self.geo_location
     }
 
-    fn get_calculator(&self) -> &N {
-        &self.noaa_calculator
+    fn get_calculator(&self) -> &ErasedCalculator<'a> {
+        &self.calculator
     }
-    fn get_sunrise(&self) -> Option<DateTime<Tz>> {
-        let result = self.get_utc_sunrise(_GEOMETRIC_ZENITH)?;
-        if result.is_nan() {
-            return None;
+    fn get_sunrise(&self) -> Option<DateTime<FixedOffset>> {
+        cached!(self.sunrise, || {
+            let result = self.get_utc_sunrise(_GEOMETRIC_ZENITH)?;
+            if result.is_nan() {
+                return None;
+            }
+            self.get_date_from_time(result, SolarEvent::Sunrise)
+        })
+    }
+
+    fn get_elevation_adjusted_sunrise(&self) -> Option<DateTime<FixedOffset>> {
+        match self.settings.use_elevation {
+            true => self.get_sunrise(),
+            false => self.get_sea_level_sunrise(),
         }
-        self.get_date_from_time(result, _SolarEvent::Sunrise)
     }
 
-    fn get_sea_level_sunrise(&self) -> Option<DateTime<Tz>> {
-        let result = self.get_utc_sea_level_sunrise(_GEOMETRIC_ZENITH)?;
-        if result.is_nan() {
-            return None;
+    fn get_elevation_adjusted_sunset(&self) -> Option<DateTime<FixedOffset>> {
+        match self.settings.use_elevation {
+            true => self.get_sunset(),
+            false => self.get_sea_level_sunset(),
         }
-        self.get_date_from_time(result, _SolarEvent::Sunrise)
     }
 
-    fn get_begin_civil_twilight(&self) -> Option<DateTime<Tz>> {
+    fn get_ateret_torah_sunset_offset(&self) -> Duration {
+        self.settings.ateret_torah_sunset_offset
+    }
+
+    fn get_sea_level_sunrise(&self) -> Option<DateTime<FixedOffset>> {
+        cached!(self.sea_level_sunrise, || {
+            let result = self.get_utc_sea_level_sunrise(_GEOMETRIC_ZENITH)?;
+            if result.is_nan() {
+                return None;
+            }
+            self.get_date_from_time(result, SolarEvent::Sunrise)
+        })
+    }
+
+    fn get_begin_civil_twilight(&self) -> Option<DateTime<FixedOffset>> {
         self.get_sunrise_offset_by_degrees(_CIVIL_ZENITH)
     }
 
-    fn get_begin_nautical_twilight(&self) -> Option<DateTime<Tz>> {
+    fn get_begin_nautical_twilight(&self) -> Option<DateTime<FixedOffset>> {
         self.get_sunrise_offset_by_degrees(_NAUTICAL_ZENITH)
     }
 
-    fn get_begin_astronomical_twilight(&self) -> Option<DateTime<Tz>> {
+    fn get_begin_astronomical_twilight(&self) -> Option<DateTime<FixedOffset>> {
         self.get_sunrise_offset_by_degrees(_ASTRONOMICAL_ZENITH)
     }
 
-    fn get_sunset(&self) -> Option<DateTime<Tz>> {
-        let result = self.get_utc_sunset(_GEOMETRIC_ZENITH)?;
-        if result.is_nan() {
-            return None;
-        }
-        self.get_date_from_time(result, _SolarEvent::Sunset)
+    fn get_sunset(&self) -> Option<DateTime<FixedOffset>> {
+        cached!(self.sunset, || {
+            let result = self.get_utc_sunset(_GEOMETRIC_ZENITH)?;
+            if result.is_nan() {
+                return None;
+            }
+            self.get_date_from_time(result, SolarEvent::Sunset)
+        })
     }
 
-    fn get_sea_level_sunset(&self) -> Option<DateTime<Tz>> {
-        let result = self.get_utc_sea_level_sunset(_GEOMETRIC_ZENITH)?;
-        if result.is_nan() {
-            return None;
-        }
-        self.get_date_from_time(result, _SolarEvent::Sunset)
+    fn get_sea_level_sunset(&self) -> Option<DateTime<FixedOffset>> {
+        cached!(self.sea_level_sunset, || {
+            let result = self.get_utc_sea_level_sunset(_GEOMETRIC_ZENITH)?;
+            if result.is_nan() {
+                return None;
+            }
+            self.get_date_from_time(result, SolarEvent::Sunset)
+        })
     }
 
-    fn get_end_civil_twilight(&self) -> Option<DateTime<Tz>> {
+    fn get_end_civil_twilight(&self) -> Option<DateTime<FixedOffset>> {
         self.get_sunset_offset_by_degrees(_CIVIL_ZENITH)
     }
 
-    fn get_end_nautical_twilight(&self) -> Option<DateTime<Tz>> {
+    fn get_end_nautical_twilight(&self) -> Option<DateTime<FixedOffset>> {
         self.get_sunset_offset_by_degrees(_NAUTICAL_ZENITH)
     }
 
-    fn get_end_astronomical_twilight(&self) -> Option<DateTime<Tz>> {
+    fn get_end_astronomical_twilight(&self) -> Option<DateTime<FixedOffset>> {
         self.get_sunset_offset_by_degrees(_ASTRONOMICAL_ZENITH)
     }
 
-    fn get_sunrise_offset_by_degrees(&self, offset_zenith: f64) -> Option<DateTime<Tz>> {
-        let result = self.get_utc_sunrise(offset_zenith)?;
-        if result.is_nan() {
-            return None;
+    fn get_sunrise_offset_by_degrees(&self, offset_zenith: f64) -> Option<DateTime<FixedOffset>> {
+        let compute = || {
+            let result = self.get_utc_sunrise(offset_zenith)?;
+            if result.is_nan() {
+                return None;
+            }
+            self.get_date_from_time(result, SolarEvent::Sunrise)
+        };
+        match cached_zenith(offset_zenith) {
+            Some(index) => cached!(self.sunrise_offset_by_degrees[index], compute),
+            None => compute(),
         }
-        self.get_date_from_time(result, _SolarEvent::Sunrise)
     }
 
-    fn get_sunset_offset_by_degrees(&self, offset_zenith: f64) -> Option<DateTime<Tz>> {
-        let result = self.get_utc_sunset(offset_zenith)?;
-        if result.is_nan() {
-            return None;
+    fn get_sunset_offset_by_degrees(&self, offset_zenith: f64) -> Option<DateTime<FixedOffset>> {
+        let compute = || {
+            let result = self.get_utc_sunset(offset_zenith)?;
+            if result.is_nan() {
+                return None;
+            }
+            self.get_date_from_time(result, SolarEvent::Sunset)
+        };
+        match cached_zenith(offset_zenith) {
+            Some(index) => cached!(self.sunset_offset_by_degrees[index], compute),
+            None => compute(),
         }
-        self.get_date_from_time(result, _SolarEvent::Sunset)
     }
 
+    // The solar calculations call the calculator through `dyn` directly rather than through
+    // `AstronomicalCalculatorTrait`, which would copy the location for each call.
     fn get_utc_sunrise(&self, zenith: f64) -> Option<f64> {
         let adjusted_date_time = self.get_adjusted_date_time(self.get_date_time())?;
-        self.get_calculator()
-            .get_utc_sunrise(&adjusted_date_time, self.get_geo_location(), zenith, true)
+        self.calculator
+            .0
+            .utc_sunrise(&adjusted_date_time, self.geo_location, zenith, true)
     }
 
     fn get_utc_sea_level_sunrise(&self, zenith: f64) -> Option<f64> {
-        self.get_calculator().get_utc_sunrise(
-            &self.get_adjusted_date_time(self.get_date_time())?,
-            self.get_geo_location(),
-            zenith,
-            false,
-        )
+        let adjusted_date_time = self.get_adjusted_date_time(self.get_date_time())?;
+        self.calculator
+            .0
+            .utc_sunrise(&adjusted_date_time, self.geo_location, zenith, false)
     }
 
     fn get_utc_sunset(&self, zenith: f64) -> Option<f64> {
-        self.get_calculator().get_utc_sunset(
-            &self.get_adjusted_date_time(self.get_date_time())?,
-            self.get_geo_location(),
-            zenith,
-            true,
-        )
+        let adjusted_date_time = self.get_adjusted_date_time(self.get_date_time())?;
+        self.calculator
+            .0
+            .utc_sunset(&adjusted_date_time, self.geo_location, zenith, true)
     }
 
     fn get_utc_sea_level_sunset(&self, zenith: f64) -> Option<f64> {
-        self.get_calculator().get_utc_sunset(
-            &self.get_adjusted_date_time(self.get_date_time())?,
-            self.get_geo_location(),
-            zenith,
-            false,
-        )
+        let adjusted_date_time = self.get_adjusted_date_time(self.get_date_time())?;
+        self.calculator
+            .0
+            .utc_sunset(&adjusted_date_time, self.geo_location, zenith, false)
+    }
+
+    fn get_utc_noon(&self) -> Option<f64> {
+        let adjusted_date_time = self.get_adjusted_date_time(self.get_date_time())?;
+        let noon = self.calculator.0.utc_noon(&adjusted_date_time, self.geo_location);
+        if noon.is_nan() { None } else { Some(noon) }
+    }
+
+    fn get_utc_midnight(&self) -> Option<f64> {
+        let adjusted_date_time = self.get_adjusted_date_time(self.get_date_time())?;
+        let midnight = self.calculator.0.utc_midnight(&adjusted_date_time, self.geo_location);
+        if midnight.is_nan() { None } else { Some(midnight) }
+    }
+
+    fn get_degrees_below_horizon(&self, date_time: &DateTime<FixedOffset>) -> f64 {
+        -self.calculator.0.solar_elevation(date_time, self.geo_location)
     }
 
     fn get_temporal_hour(&self) -> Option<Duration> {
-        let sea_level_sunrise = self.get_sea_level_sunrise()?;
-        let sea_level_sunset = self.get_sea_level_sunset()?;
-        self.get_temporal_hour_from_times(&sea_level_sunrise, &sea_level_sunset)
+        cached!(self.temporal_hour, || {
+            let sea_level_sunrise = self.get_sea_level_sunrise()?;
+            let sea_level_sunset = self.get_sea_level_sunset()?;
+            self.get_temporal_hour_from_times(&sea_level_sunrise, &sea_level_sunset)
+        })
     }
 
-    fn get_temporal_hour_from_times(&self, start_of_day: &DateTime<Tz>, end_of_day: &DateTime<Tz>) -> Option<Duration> {
-        Some((end_of_day.clone() - start_of_day) / 12)
+    fn get_temporal_hour_from_times(
+        &self,
+        start_of_day: &DateTime<FixedOffset>,
+        end_of_day: &DateTime<FixedOffset>,
+    ) -> Option<Duration> {
+        Some(ZmanInterval::new(*start_of_day, *end_of_day).get_shaah_zmanis())
     }
 
-    fn get_sun_transit(&self) -> Option<DateTime<Tz>> {
-        let adjusted_date_time = self.get_adjusted_date_time(self.get_date_time())?;
-        let noon = self
-            .get_calculator()
-            .get_utc_noon(&adjusted_date_time, self.get_geo_location());
-        if noon.is_nan() {
-            return None;
-        }
-        self.get_date_from_time(noon, _SolarEvent::Noon)
+    fn get_sun_transit(&self) -> Option<DateTime<FixedOffset>> {
+        cached!(self.sun_transit, || {
+            let noon = self.get_utc_noon()?;
+            self.get_date_from_time(noon, SolarEvent::Noon)
+        })
     }
 
-    fn get_solar_midnight(&self) -> Option<DateTime<Tz>> {
-        let adjusted_date_time = self.get_adjusted_date_time(self.get_date_time())?;
-        let midnight = self
-            .get_calculator()
-            .get_utc_midnight(&adjusted_date_time, self.get_geo_location());
-        if midnight.is_nan() {
-            return None;
-        }
-        self.get_date_from_time(midnight, _SolarEvent::Midnight)
+    fn get_solar_midnight(&self) -> Option<DateTime<FixedOffset>> {
+        let midnight = self.get_utc_midnight()?;
+        self.get_date_from_time(midnight, SolarEvent::Midnight)
     }
 
     fn get_sun_transit_from_times(
         &self,
-        start_of_day: &DateTime<Tz>,
-        end_of_day: &DateTime<Tz>,
-    ) -> Option<DateTime<Tz>> {
+        start_of_day: &DateTime<FixedOffset>,
+        end_of_day: &DateTime<FixedOffset>,
+    ) -> Option<DateTime<FixedOffset>> {
         let temporal_hour = self.get_temporal_hour_from_times(start_of_day, end_of_day)?;
-        Some(start_of_day.clone() + (temporal_hour * 6))
+        Some(*start_of_day + (temporal_hour * 6))
     }
 
-    fn get_date_from_time(&self, mut calculated_time: f64, solar_event: _SolarEvent) -> Option<DateTime<Tz>> {
+    fn get_date_from_time(&self, mut calculated_time: f64, solar_event: SolarEvent) -> Option<DateTime<FixedOffset>> {
         let adjusted_dt = self.get_adjusted_date_time(self.get_date_time())?;
 
         let cal_result = Utc.with_ymd_and_hms(adjusted_dt.year(), adjusted_dt.month(), adjusted_dt.day(), 0, 0, 0);
@@ -702,13 +1733,13 @@ impl<Tz: TimeZone, N: AstronomicalCalculatorTrait> ZmanimCalendarTrait<Tz, GeoLo
 
         let local_time_hours = (self.get_geo_location().get_longitude() / 15.0) as i64;
         #[allow(clippy::if_same_then_else)]
-        if solar_event == _SolarEvent::Sunrise && local_time_hours + hours > 18 {
+        if solar_event == SolarEvent::Sunrise && local_time_hours + hours > 18 {
             cal = cal.checked_sub_days(Days::new(1))?;
-        } else if solar_event == _SolarEvent::Sunset && local_time_hours + hours < 6 {
+        } else if solar_event == SolarEvent::Sunset && local_time_hours + hours < 6 {
             cal = cal.checked_add_days(Days::new(1))?;
-        } else if solar_event == _SolarEvent::Midnight && local_time_hours + hours < 12 {
+        } else if solar_event == SolarEvent::Midnight && local_time_hours + hours < 12 {
             cal = cal.checked_add_days(Days::new(1))?;
-        } else if solar_event == _SolarEvent::Noon && local_time_hours + hours > 24 {
+        } else if solar_event == SolarEvent::Noon && local_time_hours + hours > 24 {
             cal = cal.checked_sub_days(Days::new(1))?;
         }
 
@@ -719,25 +1750,421 @@ impl<Tz: TimeZone, N: AstronomicalCalculatorTrait> ZmanimCalendarTrait<Tz, GeoLo
                 + TimeDelta::nanoseconds((calculated_time * 1_000_000_000.0) as i64),
         )?;
 
-        Some(adjusted_dt.timezone().from_utc_datetime(&cal.naive_utc()))
+        Some(self.localize(&cal.naive_utc()))
     }
 
-    fn get_local_mean_time(&self, hours: f64) -> Option<DateTime<Tz>> {
+    fn get_local_mean_time(&self, hours: f64) -> Option<DateTime<FixedOffset>> {
         if !(0.0..24.0).contains(&hours) {
             return None;
         }
         let timezone_offset_hours = self.date_time.offset().fix().local_minus_utc() as f64 / 60.0 / 60.0;
         println!("timezone_offset_hours: {:?}", timezone_offset_hours);
         println!("millis: {:?}", self.date_time.timestamp_millis());
-        let start = self.get_date_from_time(hours - timezone_offset_hours, _SolarEvent::Sunrise)?;
+        let start = self.get_date_from_time(hours - timezone_offset_hours, SolarEvent::Sunrise)?;
         let offset = self.get_geo_location().get_local_mean_time_offset(&self.date_time);
         Some(start - offset)
     }
 }
 
+impl<Tz: TimeZone, N: AstronomicalCalculatorTrait> ZmanimCalendarTrait<Tz, GeoLocation, N>
+    for ZmanimCalendar<Tz, GeoLocation, N>
+{
+    delegate_to_core! {
+        times: [
+            get_sunrise,
+            get_sea_level_sunrise,
+            get_elevation_adjusted_sunrise,
+            get_elevation_adjusted_sunset,
+            get_begin_civil_twilight,
+            get_begin_nautical_twilight,
+            get_begin_astronomical_twilight,
+            get_sunset,
+            get_sea_level_sunset,
+            get_end_civil_twilight,
+            get_end_nautical_twilight,
+            get_end_astronomical_twilight,
+            get_sun_transit,
+            get_solar_midnight,
+            get_alos_16_point_1_degrees,
+            get_alos_18_degrees,
+            get_alos_19_degrees,
+            get_alos_19_point_8_degrees,
+            get_alos_26_degrees,
+            get_alos_60,
+            get_alos_72,
+            get_alos_90,
+            get_alos_96,
+            get_alos_120,
+            get_alos_72_zmanis,
+            get_alos_90_zmanis,
+            get_alos_96_zmanis,
+            get_alos_120_zmanis,
+            get_tzais_16_point_1_degrees,
+            get_tzais_18_degrees,
+            get_tzais_19_point_8_degrees,
+            get_tzais_26_degrees,
+            get_tzais_50,
+            get_tzais_60,
+            get_tzais_72,
+            get_tzais_90,
+            get_tzais_96,
+            get_tzais_120,
+            get_tzais_72_zmanis,
+            get_tzais_90_zmanis,
+            get_tzais_96_zmanis,
+            get_tzais_120_zmanis,
+            get_sof_zman_shma_mga_16_point_1_degrees,
+            get_sof_zman_shma_mga_18_degrees,
+            get_sof_zman_shma_mga_19_point_8_degrees,
+            get_sof_zman_shma_mga_72_minutes,
+            get_sof_zman_shma_mga_72_minutes_zmanis,
+            get_sof_zman_shma_mga_90_minutes,
+            get_sof_zman_shma_mga_90_minutes_zmanis,
+            get_sof_zman_shma_mga_96_minutes,
+            get_sof_zman_shma_mga_96_minutes_zmanis,
+            get_sof_zman_shma_mga_120_minutes,
+            get_sof_zman_tfila_mga_16_point_1_degrees,
+            get_sof_zman_tfila_mga_18_degrees,
+            get_sof_zman_tfila_mga_19_point_8_degrees,
+            get_sof_zman_tfila_mga_72_minutes,
+            get_sof_zman_tfila_mga_72_minutes_zmanis,
+            get_sof_zman_tfila_mga_90_minutes,
+            get_sof_zman_tfila_mga_90_minutes_zmanis,
+            get_sof_zman_tfila_mga_96_minutes,
+            get_sof_zman_tfila_mga_96_minutes_zmanis,
+            get_sof_zman_tfila_mga_120_minutes,
+            get_sof_zman_tfila_2_hours_before_chatzos,
+            get_mincha_gedola_30_minutes,
+            get_mincha_gedola_16_point_1_degrees,
+            get_mincha_gedola_greater_than_30,
+            get_mincha_ketana_16_point_1_degrees,
+            get_mincha_ketana_72_minutes,
+            get_mincha_ketana_gra_fixed_local_chatzos_to_sunset,
+            get_bain_hashmashos_rt_13_point_24_degrees,
+            get_bain_hashmashos_rt_58_point_5_minutes,
+            get_bain_hashmashos_rt_13_point_5_minutes_before_7_point_083_degrees,
+            get_bain_hashmashos_rt_2_stars,
+            get_bain_hashmashos_yereim_18_minutes,
+            get_bain_hashmashos_yereim_3_point_05_degrees,
+            get_bain_hashmashos_yereim_16_point_875_minutes,
+            get_bain_hashmashos_yereim_2_point_8_degrees,
+            get_bain_hashmashos_yereim_13_point_5_minutes,
+            get_bain_hashmashos_yereim_2_point_1_degrees,
+            get_tzais_geonim_3_point_65_degrees,
+            get_tzais_geonim_3_point_676_degrees,
+            get_tzais_geonim_3_point_7_degrees,
+            get_tzais_geonim_3_point_8_degrees,
+            get_tzais_geonim_4_point_37_degrees,
+            get_tzais_geonim_4_point_61_degrees,
+            get_tzais_geonim_4_point_8_degrees,
+            get_tzais_geonim_5_point_88_degrees,
+            get_tzais_geonim_5_point_95_degrees,
+            get_tzais_geonim_6_point_45_degrees,
+            get_tzais_geonim_7_point_083_degrees,
+            get_tzais_geonim_7_point_67_degrees,
+            get_tzais_geonim_8_point_5_degrees,
+            get_tzais_geonim_9_point_3_degrees,
+            get_tzais_geonim_9_point_75_degrees,
+            get_tzais_ateret_torah,
+            get_sof_zman_shma_ateret_torah,
+            get_sof_zman_tfila_ateret_torah,
+            get_mincha_gedola_ateret_torah,
+            get_mincha_ketana_ateret_torah,
+            get_plag_hamincha_ateret_torah,
+        ],
+        durations: [
+            get_temporal_hour,
+            get_shaah_zmanis_gra,
+            get_shaah_zmanis_mga,
+            get_shaah_zmanis_16_point_1_degrees,
+            get_shaah_zmanis_18_degrees,
+            get_shaah_zmanis_19_point_8_degrees,
+            get_shaah_zmanis_26_degrees,
+            get_shaah_zmanis_60_minutes,
+            get_shaah_zmanis_72_minutes,
+            get_shaah_zmanis_72_minutes_zmanis,
+            get_shaah_zmanis_90_minutes,
+            get_shaah_zmanis_90_minutes_zmanis,
+            get_shaah_zmanis_96_minutes,
+            get_shaah_zmanis_96_minutes_zmanis,
+            get_shaah_zmanis_120_minutes,
+            get_shaah_zmanis_120_minutes_zmanis,
+            get_shaah_zmanis_ateret_torah,
+            get_shaah_zmanis_alos_16_point_1_to_tzais_3_point_7,
+            get_shaah_zmanis_alos_16_point_1_to_tzais_3_point_8,
+        ],
+    }
+
+    fn get_date_time(&self) -> &DateTime<Tz> {
+        &self.date_time
+    }
+
+    fn get_geo_location(&self) -> &GeoLocation {
+        &self.geo_location
+    }
+
+    fn get_calculator(&self) -> &N {
+        &self.noaa_calculator
+    }
+
+    fn get_ateret_torah_sunset_offset(&self) -> Duration {
+        self.ateret_torah_sunset_offset
+    }
+
+    fn get_zman(&self, zman: &Zman) -> Option<DateTime<Tz>> {
+        self.localize(self.core().get_zman(zman))
+    }
+
+    fn get_sunrise_offset_by_degrees(&self, offset_zenith: f64) -> Option<DateTime<Tz>> {
+        self.localize(self.core().get_sunrise_offset_by_degrees(offset_zenith))
+    }
+
+    fn get_sunset_offset_by_degrees(&self, offset_zenith: f64) -> Option<DateTime<Tz>> {
+        self.localize(self.core().get_sunset_offset_by_degrees(offset_zenith))
+    }
+
+    fn get_degrees_below_horizon(&self, date_time: &DateTime<Tz>) -> f64 {
+        self.core().get_degrees_below_horizon(&date_time.fixed_offset())
+    }
+
+    fn get_utc_sunrise(&self, zenith: f64) -> Option<f64> {
+        self.core().get_utc_sunrise(zenith)
+    }
+
+    fn get_utc_sea_level_sunrise(&self, zenith: f64) -> Option<f64> {
+        self.core().get_utc_sea_level_sunrise(zenith)
+    }
+
+    fn get_utc_sunset(&self, zenith: f64) -> Option<f64> {
+        self.core().get_utc_sunset(zenith)
+    }
+
+    fn get_utc_sea_level_sunset(&self, zenith: f64) -> Option<f64> {
+        self.core().get_utc_sea_level_sunset(zenith)
+    }
+
+    fn get_utc_noon(&self) -> Option<f64> {
+        self.core().get_utc_noon()
+    }
+
+    fn get_utc_midnight(&self) -> Option<f64> {
+        self.core().get_utc_midnight()
+    }
+
+    fn get_date_from_time(&self, calculated_time: f64, solar_event: SolarEvent) -> Option<DateTime<Tz>> {
+        self.localize(self.core().get_date_from_time(calculated_time, solar_event))
+    }
+
+    fn get_local_mean_time(&self, hours: f64) -> Option<DateTime<Tz>> {
+        self.localize(self.core().get_local_mean_time(hours))
+    }
+
+    fn get_zmanis_based_offset(&self, hours: f64) -> Option<DateTime<Tz>> {
+        self.localize(self.core().get_zmanis_based_offset(hours))
+    }
+
+    fn get_percent_of_shaah_zmanis_from_degrees(&self, degrees: f64, sunset: bool) -> Option<f64> {
+        self.core().get_percent_of_shaah_zmanis_from_degrees(degrees, sunset)
+    }
+
+    fn get_temporal_hour_from_times(&self, start_of_day: &DateTime<Tz>, end_of_day: &DateTime<Tz>) -> Option<Duration> {
+        self.core()
+            .get_temporal_hour_from_times(&start_of_day.fixed_offset(), &end_of_day.fixed_offset())
+    }
+
+    fn get_sun_transit_from_times(
+        &self,
+        start_of_day: &DateTime<Tz>,
+        end_of_day: &DateTime<Tz>,
+    ) -> Option<DateTime<Tz>> {
+        self.localize(
+            self.core()
+                .get_sun_transit_from_times(&start_of_day.fixed_offset(), &end_of_day.fixed_offset()),
+        )
+    }
+
+    fn get_half_day_based_zman_from_times(
+        &self,
+        start_of_half_day: &DateTime<Tz>,
+        end_of_half_day: &DateTime<Tz>,
+        hours: f64,
+    ) -> Option<DateTime<Tz>> {
+        self.localize(self.core().get_half_day_based_zman_from_times(
+            &start_of_half_day.fixed_offset(),
+            &end_of_half_day.fixed_offset(),
+            hours,
+        ))
+    }
+
+    fn get_half_day_based_shaah_zmanis_from_times(
+        &self,
+        start_of_half_day: &DateTime<Tz>,
+        end_of_half_day: &DateTime<Tz>,
+    ) -> Option<Duration> {
+        self.core().get_half_day_based_shaah_zmanis_from_times(
+            &start_of_half_day.fixed_offset(),
+            &end_of_half_day.fixed_offset(),
+        )
+    }
+
+    fn get_shaah_zmanis_based_zman_from_times(
+        &self,
+        start_of_day: &DateTime<Tz>,
+        end_of_day: &DateTime<Tz>,
+        hours: f64,
+    ) -> Option<DateTime<Tz>> {
+        self.localize(self.core().get_shaah_zmanis_based_zman_from_times(
+            &start_of_day.fixed_offset(),
+            &end_of_day.fixed_offset(),
+            hours,
+        ))
+    }
+
+    fn get_sof_zman_shma_from_times(
+        &self,
+        start_of_day: &DateTime<Tz>,
+        end_of_day: Option<&DateTime<Tz>>,
+        synchronous: bool,
+    ) -> Option<DateTime<Tz>> {
+        self.localize(self.core().get_sof_zman_shma_from_times(
+            &start_of_day.fixed_offset(),
+            end_of_day.map(DateTime::fixed_offset).as_ref(),
+            synchronous,
+        ))
+    }
+
+    fn get_mincha_ketana_from_times(
+        &self,
+        start_of_day: Option<&DateTime<Tz>>,
+        end_of_day: &DateTime<Tz>,
+        synchronous: bool,
+    ) -> Option<DateTime<Tz>> {
+        self.localize(self.core().get_mincha_ketana_from_times(
+            start_of_day.map(DateTime::fixed_offset).as_ref(),
+            &end_of_day.fixed_offset(),
+            synchronous,
+        ))
+    }
+
+    fn get_sof_zman_tfila_from_times(
+        &self,
+        start_of_day: &DateTime<Tz>,
+        end_of_day: Option<&DateTime<Tz>>,
+        synchronous: bool,
+    ) -> Option<DateTime<Tz>> {
+        self.localize(self.core().get_sof_zman_tfila_from_times(
+            &start_of_day.fixed_offset(),
+            end_of_day.map(DateTime::fixed_offset).as_ref(),
+            synchronous,
+        ))
+    }
+
+    fn get_mincha_gedola_from_times(
+        &self,
+        start_of_day: Option<&DateTime<Tz>>,
+        end_of_day: &DateTime<Tz>,
+        synchronous: bool,
+    ) -> Option<DateTime<Tz>> {
+        self.localize(self.core().get_mincha_gedola_from_times(
+            start_of_day.map(DateTime::fixed_offset).as_ref(),
+            &end_of_day.fixed_offset(),
+            synchronous,
+        ))
+    }
+
+    fn get_plag_hamincha_from_times(
+        &self,
+        start_of_day: Option<&DateTime<Tz>>,
+        end_of_day: &DateTime<Tz>,
+        synchronous: bool,
+    ) -> Option<DateTime<Tz>> {
+        self.localize(self.core().get_plag_hamincha_from_times(
+            start_of_day.map(DateTime::fixed_offset).as_ref(),
+            &end_of_day.fixed_offset(),
+            synchronous,
+        ))
+    }
+
+    fn get_samuch_le_mincha_ketana_from_times(
+        &self,
+        start_of_day: Option<&DateTime<Tz>>,
+        end_of_day: &DateTime<Tz>,
+        synchronous: bool,
+    ) -> Option<DateTime<Tz>> {
+        self.localize(self.core().get_samuch_le_mincha_ketana_from_times(
+            start_of_day.map(DateTime::fixed_offset).as_ref(),
+            &end_of_day.fixed_offset(),
+            synchronous,
+        ))
+    }
+
+    fn get_sof_zman_kidush_levana_15_days_from_times(
+        &self,
+        alos: Option<&DateTime<Tz>>,
+        tzais: Option<&DateTime<Tz>>,
+    ) -> Option<DateTime<Tz>> {
+        self.localize(self.core().get_sof_zman_kidush_levana_15_days_from_times(
+            alos.map(DateTime::fixed_offset).as_ref(),
+            tzais.map(DateTime::fixed_offset).as_ref(),
+        ))
+    }
+
+    fn get_sof_zman_kidush_levana_between_moldos_from_times(
+        &self,
+        alos: Option<&DateTime<Tz>>,
+        tzais: Option<&DateTime<Tz>>,
+    ) -> Option<DateTime<Tz>> {
+        self.localize(self.core().get_sof_zman_kidush_levana_between_moldos_from_times(
+            alos.map(DateTime::fixed_offset).as_ref(),
+            tzais.map(DateTime::fixed_offset).as_ref(),
+        ))
+    }
+
+    fn get_tchilas_zman_kidush_levana_3_days_from_times(
+        &self,
+        alos: Option<&DateTime<Tz>>,
+        tzais: Option<&DateTime<Tz>>,
+    ) -> Option<DateTime<Tz>> {
+        self.localize(self.core().get_tchilas_zman_kidush_levana_3_days_from_times(
+            alos.map(DateTime::fixed_offset).as_ref(),
+            tzais.map(DateTime::fixed_offset).as_ref(),
+        ))
+    }
+
+    fn get_tchilas_zman_kidush_levana_7_days_from_times(
+        &self,
+        alos: Option<&DateTime<Tz>>,
+        tzais: Option<&DateTime<Tz>>,
+    ) -> Option<DateTime<Tz>> {
+        self.localize(self.core().get_tchilas_zman_kidush_levana_7_days_from_times(
+            alos.map(DateTime::fixed_offset).as_ref(),
+            tzais.map(DateTime::fixed_offset).as_ref(),
+        ))
+    }
+}
+
+/// Moves `date_time` by the days that the time zone is across the antimeridian from the location.
+fn adjusted_date_time<Tz: TimeZone, G: GeoLocationTrait>(
+    date_time: &DateTime<Tz>,
+    geo_location: &G,
+) -> Option<DateTime<Tz>> {
+    let offset = geo_location.get_antimeridian_adjustment(date_time);
+    if offset == 0 {
+        Some(date_time.clone())
+    } else if offset > 0 {
+        date_time
+            .clone()
+            .checked_add_days(Days::new(offset.unsigned_abs() as u64))
+    } else {
+        date_time
+            .clone()
+            .checked_sub_days(Days::new(offset.unsigned_abs() as u64))
+    }
+}
+
 /// A helper function to multiply a duration by a factor.
 /// This uses a clever workaround to handle negative durations which std duration does not support.
-fn multiply_duration(core_timedelta: TimeDelta, factor: f64) -> Option<TimeDelta> {
+pub(crate) fn multiply_duration(core_timedelta: TimeDelta, factor: f64) -> Option<TimeDelta> {
     let is_timedelta_negative = core_timedelta < TimeDelta::zero();
     let factor_is_negative = factor < 0.0;
     let std_duration = core_timedelta.abs().to_std().ok()?;
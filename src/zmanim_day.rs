@@ -0,0 +1,87 @@
+//! A serializable summary of a day, enabled by the `serde` feature, for serving the calendar
+//! and zmanim of a date from a backend.
+use std::collections::BTreeMap;
+
+use chrono::{DateTime, FixedOffset, NaiveDate, TimeZone};
+
+use crate::{
+    astronomical_calculator::AstronomicalCalculatorTrait,
+    constants::{JewishHoliday, JewishMonth, Parsha, Zman},
+    daf::BavliDaf,
+    geolocation::GeoLocation,
+    jewish_calendar::{JewishCalendar, JewishCalendarTrait},
+    tefila_rules::{TefilaRules, TefilaRulesTrait},
+    zmanim_calendar::{ZmanimCalendar, ZmanimCalendarTrait},
+};
+
+/// The changes to the tefillos of a day.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct TefilaFlags {
+    pub tachanun_shacharis: bool,
+    pub tachanun_mincha: Option<bool>,
+    pub hallel: bool,
+    pub hallel_shalem: bool,
+    pub al_hanissim: bool,
+    pub yaaleh_veyavo: bool,
+    pub mizmor_lesoda: bool,
+    pub vesein_tal_umatar: bool,
+    pub mashiv_haruach: Option<bool>,
+}
+
+/// The calendar and zmanim of a day.
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct ZmanimDay {
+    pub date: NaiveDate,
+    pub jewish_year: i32,
+    pub jewish_month: JewishMonth,
+    pub jewish_day_of_month: u8,
+    pub parsha: Option<Parsha>,
+    /// The holidays of this day, such as both Chanukah and Rosh Chodesh on Rosh Chodesh Teves.
+    pub holidays: Vec<JewishHoliday>,
+    pub day_of_omer: Option<u8>,
+    pub daf_yomi_bavli: Option<BavliDaf>,
+    pub tefila: TefilaFlags,
+    /// The requested zmanim, serialized with the identifiers of [`Zman::as_str`] as keys, such as
+    /// `"sof_zman_shma_gra"`. A zman that does not occur on this day, such as alos in the far
+    /// north in summer, is `None`.
+    pub zmanim: BTreeMap<Zman, Option<DateTime<FixedOffset>>>,
+}
+
+impl ZmanimDay {
+    /// Builds the summary of the date of `jewish_calendar`, with the listed `zmanim` calculated
+    /// at the location and with the settings of `zmanim_calendar`.
+    pub fn new<Tz: TimeZone, N: AstronomicalCalculatorTrait>(
+        jewish_calendar: &JewishCalendar<N>,
+        zmanim_calendar: &ZmanimCalendar<Tz, GeoLocation, N>,
+        tefila_rules: &TefilaRules,
+        zmanim: &[Zman],
+    ) -> Option<Self> {
+        let date = jewish_calendar.get_gregorian_naive_date()?;
+        let zmanim_calendar = zmanim_calendar.with_date(date)?;
+        Some(Self {
+            date,
+            jewish_year: jewish_calendar.get_jewish_year(),
+            jewish_month: jewish_calendar.get_jewish_month(),
+            jewish_day_of_month: jewish_calendar.get_jewish_day_of_month(),
+            parsha: jewish_calendar.get_parshah(),
+            holidays: jewish_calendar.get_holidays().collect(),
+            day_of_omer: jewish_calendar.get_day_of_omer(),
+            daf_yomi_bavli: jewish_calendar.get_daf_yomi_bavli(),
+            tefila: TefilaFlags {
+                tachanun_shacharis: tefila_rules.is_tachanun_recited_shacharis(jewish_calendar),
+                tachanun_mincha: tefila_rules.is_tachanun_recited_mincha(jewish_calendar),
+                hallel: tefila_rules.is_hallel_recited(jewish_calendar),
+                hallel_shalem: tefila_rules.is_hallel_shalem_recited(jewish_calendar),
+                al_hanissim: tefila_rules.is_al_hanissim_recited(jewish_calendar),
+                yaaleh_veyavo: tefila_rules.is_yaaleh_veyavo_recited(jewish_calendar),
+                mizmor_lesoda: tefila_rules.is_mizmor_lesoda_recited(jewish_calendar),
+                vesein_tal_umatar: tefila_rules.is_vesein_tal_umatar_recited(jewish_calendar),
+                mashiv_haruach: tefila_rules.is_mashiv_haruach_recited(jewish_calendar),
+            },
+            zmanim: zmanim
+                .iter()
+                .map(|zman| (*zman, zmanim_calendar.get_zman(zman).map(|time| time.fixed_offset())))
+                .collect(),
+        })
+    }
+}
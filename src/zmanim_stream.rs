@@ -0,0 +1,100 @@
+//! Waiting for zmanim as they happen, enabled by the `tokio` feature.
+//!
+//! ```ignore
+//! let mut stream = ZmanimStream::new(calendar);
+//! while let Some((zman, time)) = stream.next().await {
+//!     notify(zman, time);
+//! }
+//! ```
+use std::time::{Duration as StdDuration, SystemTime, UNIX_EPOCH};
+
+use chrono::{DateTime, TimeZone};
+
+use crate::{
+    astronomical_calculator::AstronomicalCalculatorTrait,
+    constants::Zman,
+    geolocation::GeoLocation,
+    zmanim_calendar::{ZmanimCalendar, ZmanimCalendarTrait},
+};
+
+/// The number of days without any zmanim after which the stream gives up, such as in the polar
+/// night.
+const MAX_EMPTY_DAYS: u32 = 366;
+
+/// Yields each upcoming zman when it becomes due, moving on to the next day's zmanim after the
+/// last zman of the day.
+///
+/// The zmanim are calculated with the settings of the calendar the stream was created from.
+pub struct ZmanimStream<Tz: TimeZone, N: AstronomicalCalculatorTrait> {
+    calendar: ZmanimCalendar<Tz, GeoLocation, N>,
+    /// The remaining zmanim of the current day, latest first.
+    pending: Vec<(Zman, DateTime<Tz>)>,
+    /// Returns the current time, [`SystemTime::now`] unless set with
+    /// [`ZmanimStream::with_clock`].
+    clock: fn() -> SystemTime,
+}
+
+impl<Tz: TimeZone, N: AstronomicalCalculatorTrait> ZmanimStream<Tz, N> {
+    /// Creates a stream of the zmanim from the date of `calendar` onwards. Zmanim that have
+    /// already passed when their day is reached are skipped, on the first day and on every day
+    /// after it.
+    pub fn new(calendar: ZmanimCalendar<Tz, GeoLocation, N>) -> Self {
+        Self::with_clock(calendar, SystemTime::now)
+    }
+
+    /// Creates a stream like [`ZmanimStream::new`] that reads the current time from `clock`
+    /// instead of the system clock, such as to replay the zmanim of a past date.
+    pub fn with_clock(calendar: ZmanimCalendar<Tz, GeoLocation, N>, clock: fn() -> SystemTime) -> Self {
+        let mut stream = Self {
+            calendar,
+            pending: Vec::new(),
+            clock,
+        };
+        stream.load_day();
+        stream
+    }
+
+    /// Waits until the next zman is due and returns it, or returns `None` if no more zmanim can
+    /// be calculated.
+    pub async fn next(&mut self) -> Option<(Zman, DateTime<Tz>)> {
+        let mut empty_days = 0;
+        while self.pending.is_empty() {
+            if empty_days == MAX_EMPTY_DAYS {
+                return None;
+            }
+            self.calendar = self.next_day()?;
+            self.load_day();
+            empty_days += 1;
+        }
+        let (zman, time) = self.pending.pop()?;
+        let delay = to_system_time(&time)
+            .duration_since((self.clock)())
+            .unwrap_or(StdDuration::ZERO);
+        tokio::time::sleep(delay).await;
+        Some((zman, time))
+    }
+
+    fn load_day(&mut self) {
+        let now = (self.clock)();
+        self.pending = Zman::values()
+            .into_iter()
+            .filter_map(|zman| Some((zman, self.calendar.get_zman(&zman)?)))
+            .filter(|(_, time)| to_system_time(time) > now)
+            .collect();
+        self.pending.sort_by(|(_, a), (_, b)| b.cmp(a));
+    }
+
+    fn next_day(&self) -> Option<ZmanimCalendar<Tz, GeoLocation, N>> {
+        self.calendar
+            .with_date(self.calendar.get_date_time().date_naive().succ_opt()?)
+    }
+}
+
+fn to_system_time<Tz: TimeZone>(time: &DateTime<Tz>) -> SystemTime {
+    let millis = time.timestamp_millis();
+    if millis >= 0 {
+        UNIX_EPOCH + StdDuration::from_millis(millis.unsigned_abs())
+    } else {
+        UNIX_EPOCH - StdDuration::from_millis(millis.unsigned_abs())
+    }
+}